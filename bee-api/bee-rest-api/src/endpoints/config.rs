@@ -3,8 +3,9 @@
 
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
 
+use ipnet::{AddrParseError, IpNet};
 use multiaddr::{Multiaddr, Protocol};
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 
 pub(crate) const DEFAULT_BIND_ADDRESS: &str = "/ip4/0.0.0.0/tcp/14265";
 
@@ -13,12 +14,14 @@ pub(crate) const ROUTE_ADD_PEER: &str = "/api/v1/peers";
 pub(crate) const ROUTE_BALANCE_BECH32: &str = "/api/v1/addresses/:address";
 pub(crate) const ROUTE_BALANCE_ED25519: &str = "/api/v1/addresses/ed25519/:address";
 pub(crate) const ROUTE_HEALTH: &str = "/health";
+pub(crate) const ROUTE_INDEXER_MESSAGES: &str = "/api/plugins/indexer/messages";
 pub(crate) const ROUTE_INFO: &str = "/api/v1/info";
 pub(crate) const ROUTE_MESSAGE: &str = "/api/v1/messages/:messageId";
 pub(crate) const ROUTE_MESSAGE_CHILDREN: &str = "/api/v1/messages/:messageId/children";
 pub(crate) const ROUTE_MESSAGE_METADATA: &str = "/api/v1/messages/:messageId/metadata";
 pub(crate) const ROUTE_MESSAGE_RAW: &str = "/api/v1/messages/:messageId/raw";
 pub(crate) const ROUTE_MESSAGES_FIND: &str = "/api/v1/messages";
+pub(crate) const ROUTE_METRICS: &str = "/api/plugins/metrics";
 pub(crate) const ROUTE_MILESTONE: &str = "/api/v1/milestones/:milestoneIndex";
 pub(crate) const ROUTE_MILESTONE_UTXO_CHANGES: &str = "/api/v1/milestones/:milestoneIndex/utxo-changes";
 pub(crate) const ROUTE_OUTPUT: &str = "/api/v1/outputs/:outputId";
@@ -66,6 +69,9 @@ pub(crate) const DEFAULT_ALLOWED_IPS: [IpAddr; 2] = [
 ];
 pub(crate) const DEFAULT_FEATURE_PROOF_OF_WORK: bool = true;
 pub(crate) const DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT: u64 = 2;
+pub(crate) const DEFAULT_HEALTH_MAX_MILESTONE_LAG: u32 = 2;
+pub(crate) const DEFAULT_RATE_LIMIT_REQUESTS_PER_SECOND: u32 = 0;
+pub(crate) const DEFAULT_RATE_LIMIT_BURST: u32 = 20;
 
 /// REST API configuration builder.
 #[derive(Default, Deserialize, PartialEq)]
@@ -75,12 +81,18 @@ pub struct RestApiConfigBuilder {
     bind_address: Option<Multiaddr>,
     #[serde(alias = "publicRoutes")]
     public_routes: Option<Vec<String>>,
-    #[serde(alias = "allowedIps")]
-    allowed_ips: Option<Vec<IpAddr>>,
+    #[serde(alias = "allowedIps", default, deserialize_with = "deserialize_allowed_ips")]
+    allowed_ips: Option<Vec<IpNet>>,
     #[serde(alias = "featureProofOfWork")]
     feature_proof_of_work: Option<bool>,
     #[serde(alias = "whiteFlagSolidificationTimeout")]
     white_flag_solidification_timeout: Option<u64>,
+    #[serde(alias = "healthMaxMilestoneLag")]
+    health_max_milestone_lag: Option<u32>,
+    #[serde(alias = "rateLimitRequestsPerSecond")]
+    rate_limit_requests_per_second: Option<u32>,
+    #[serde(alias = "rateLimitBurst")]
+    rate_limit_burst: Option<u32>,
 }
 
 impl RestApiConfigBuilder {
@@ -100,7 +112,7 @@ impl RestApiConfigBuilder {
         self
     }
 
-    /// Sets all the routes that are available for public use.
+    /// Sets all the routes that are available for public use, a trailing `*` exposing a whole route tree.
     pub fn public_routes(mut self, routes: Vec<String>) -> Self {
         self.public_routes.replace(routes);
         self
@@ -108,7 +120,14 @@ impl RestApiConfigBuilder {
 
     /// Sets the IP addresses that are allowed to access all the routes.
     pub fn allowed_ips(mut self, allowed_ips: Vec<IpAddr>) -> Self {
-        self.allowed_ips.replace(allowed_ips);
+        self.allowed_ips
+            .replace(allowed_ips.into_iter().map(IpNet::from).collect());
+        self
+    }
+
+    /// Sets the IP networks, in CIDR notation, that are allowed to access all the routes.
+    pub fn allowed_networks(mut self, allowed_networks: Vec<IpNet>) -> Self {
+        self.allowed_ips.replace(allowed_networks);
         self
     }
 
@@ -124,6 +143,25 @@ impl RestApiConfigBuilder {
         self
     }
 
+    /// Sets the maximum number of milestones the confirmed milestone may lag behind the latest one for the node to be
+    /// healthy.
+    pub fn health_max_milestone_lag(mut self, lag: u32) -> Self {
+        self.health_max_milestone_lag.replace(lag);
+        self
+    }
+
+    /// Sets the number of requests per second a client IP address is allowed to make, `0` disabling rate limiting.
+    pub fn rate_limit_requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.rate_limit_requests_per_second.replace(requests_per_second);
+        self
+    }
+
+    /// Sets the number of requests a client IP address is allowed to make in a single burst.
+    pub fn rate_limit_burst(mut self, burst: u32) -> Self {
+        self.rate_limit_burst.replace(burst);
+        self
+    }
+
     /// Builds the REST API config.
     pub fn finish(self) -> RestApiConfig {
         let multi_addr = self
@@ -157,33 +195,66 @@ impl RestApiConfigBuilder {
             .public_routes
             .unwrap_or_else(|| DEFAULT_PUBLIC_ROUTES.iter().map(|s| s.to_string()).collect())
             .into_boxed_slice();
-        let allowed_ips: Box<[IpAddr]> = self
+        let allowed_networks: Box<[IpNet]> = self
             .allowed_ips
-            .unwrap_or_else(|| DEFAULT_ALLOWED_IPS.to_vec())
+            .unwrap_or_else(|| DEFAULT_ALLOWED_IPS.iter().copied().map(IpNet::from).collect())
             .into_boxed_slice();
         let feature_proof_of_work = self.feature_proof_of_work.unwrap_or(DEFAULT_FEATURE_PROOF_OF_WORK);
         let white_flag_solidification_timeout = self
             .white_flag_solidification_timeout
             .unwrap_or(DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT);
+        let health_max_milestone_lag = self
+            .health_max_milestone_lag
+            .unwrap_or(DEFAULT_HEALTH_MAX_MILESTONE_LAG);
+        let rate_limit_requests_per_second = self
+            .rate_limit_requests_per_second
+            .unwrap_or(DEFAULT_RATE_LIMIT_REQUESTS_PER_SECOND);
+        let rate_limit_burst = self.rate_limit_burst.unwrap_or(DEFAULT_RATE_LIMIT_BURST);
 
         RestApiConfig {
             binding_socket_addr: SocketAddr::new(address, port),
             public_routes,
-            allowed_ips,
+            allowed_networks,
             feature_proof_of_work,
             white_flag_solidification_timeout,
+            health_max_milestone_lag,
+            rate_limit_requests_per_second,
+            rate_limit_burst,
         }
     }
 }
 
+/// Parses an allowed IP entry, which is either a single IP address or a range of IP addresses in CIDR notation.
+fn parse_allowed_ip(ip: &str) -> Result<IpNet, AddrParseError> {
+    match ip.parse::<IpAddr>() {
+        Ok(ip) => Ok(IpNet::from(ip)),
+        Err(_) => ip.parse::<IpNet>(),
+    }
+}
+
+fn deserialize_allowed_ips<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<IpNet>>, D::Error> {
+    Option::<Vec<String>>::deserialize(deserializer)?
+        .map(|ips| {
+            ips.iter()
+                .map(|ip| {
+                    parse_allowed_ip(ip).map_err(|e| de::Error::custom(format!("invalid allowed IP `{}`: {}", ip, e)))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
 /// REST API configuration.
 #[derive(Clone)]
 pub struct RestApiConfig {
     pub(crate) binding_socket_addr: SocketAddr,
     pub(crate) public_routes: Box<[String]>,
-    pub(crate) allowed_ips: Box<[IpAddr]>,
+    pub(crate) allowed_networks: Box<[IpNet]>,
     pub(crate) feature_proof_of_work: bool,
     pub(crate) white_flag_solidification_timeout: u64,
+    pub(crate) health_max_milestone_lag: u32,
+    pub(crate) rate_limit_requests_per_second: u32,
+    pub(crate) rate_limit_burst: u32,
 }
 
 impl RestApiConfig {
@@ -202,9 +273,21 @@ impl RestApiConfig {
         &self.public_routes
     }
 
-    /// Returns the IP addresses that are allowed to access all the routes.
-    pub fn allowed_ips(&self) -> &[IpAddr] {
-        &self.allowed_ips
+    /// Returns the IP networks that are allowed to access all the routes, a single IP address being a network of one
+    /// address.
+    pub fn allowed_networks(&self) -> &[IpNet] {
+        &self.allowed_networks
+    }
+
+    /// Returns the single IP addresses that are allowed to access all the routes, the allowed IP networks spanning more
+    /// than one address being left out.
+    #[deprecated(note = "use allowed_networks")]
+    pub fn allowed_ips(&self) -> Vec<IpAddr> {
+        self.allowed_networks
+            .iter()
+            .filter(|net| net.prefix_len() == net.max_prefix_len())
+            .map(IpNet::addr)
+            .collect()
     }
 
     /// Returns if feature "Proof-of-Work" is enabled or not.
@@ -216,4 +299,63 @@ impl RestApiConfig {
     pub fn white_flag_solidification_timeout(&self) -> u64 {
         self.white_flag_solidification_timeout
     }
+
+    /// Returns the maximum number of milestones the confirmed milestone may lag behind the latest one for the node to
+    /// be healthy.
+    pub fn health_max_milestone_lag(&self) -> u32 {
+        self.health_max_milestone_lag
+    }
+
+    /// Returns the number of requests per second a client IP address is allowed to make, `0` if rate limiting is
+    /// disabled.
+    pub fn rate_limit_requests_per_second(&self) -> u32 {
+        self.rate_limit_requests_per_second
+    }
+
+    /// Returns the number of requests a client IP address is allowed to make in a single burst.
+    pub fn rate_limit_burst(&self) -> u32 {
+        self.rate_limit_burst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_ips_accept_addresses_and_networks() {
+        let config =
+            serde_json::from_str::<RestApiConfigBuilder>(r#"{"allowedIps": ["127.0.0.1", "10.0.0.0/8", "fd00::/8"]}"#)
+                .unwrap()
+                .finish();
+
+        assert_eq!(
+            config.allowed_networks(),
+            [
+                "127.0.0.1/32".parse::<IpNet>().unwrap(),
+                "10.0.0.0/8".parse().unwrap(),
+                "fd00::/8".parse().unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn allowed_ips_return_single_addresses() {
+        let config = serde_json::from_str::<RestApiConfigBuilder>(
+            r#"{"allowedIps": ["127.0.0.1", "10.0.0.0/8", "::1", "fd00::/8"]}"#,
+        )
+        .unwrap()
+        .finish();
+
+        assert_eq!(
+            config.allowed_ips(),
+            ["127.0.0.1".parse::<IpAddr>().unwrap(), "::1".parse().unwrap()]
+        );
+    }
+
+    #[test]
+    fn allowed_ips_reject_invalid_entries() {
+        assert!(serde_json::from_str::<RestApiConfigBuilder>(r#"{"allowedIps": ["10.0.0.0/33"]}"#).is_err());
+    }
 }
@@ -5,8 +5,11 @@ use std::convert::Infallible;
 
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+use bee_protocol::{
+    types::metrics::NodeMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+    },
 };
 use bee_runtime::{event::Bus, node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::Tangle;
@@ -63,6 +66,12 @@ pub(crate) fn with_peer_manager(
     warp::any().map(move || peer_manager.clone())
 }
 
+pub(crate) fn with_node_metrics(
+    node_metrics: ResourceHandle<NodeMetrics>,
+) -> impl Filter<Extract = (ResourceHandle<NodeMetrics>,), Error = Infallible> + Clone {
+    warp::any().map(move || node_metrics.clone())
+}
+
 pub(crate) fn with_network_command_sender(
     command_sender: ResourceHandle<NetworkCommandSender>,
 ) -> impl Filter<Extract = (ResourceHandle<NetworkCommandSender>,), Error = Infallible> + Clone {
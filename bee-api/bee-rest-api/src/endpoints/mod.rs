@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod filters;
+mod rate_limit;
 
 pub mod config;
 pub mod path_params;
@@ -15,9 +16,12 @@ use std::{any::TypeId, convert::Infallible};
 use async_trait::async_trait;
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorker;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorker, PeerManager, PeerManagerResWorker,
-    RequestedMessages,
+use bee_protocol::{
+    types::metrics::NodeMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorker, MetricsWorker, PeerManager,
+        PeerManagerResWorker, RequestedMessages,
+    },
 };
 use bee_runtime::{
     node::{Node, NodeBuilder},
@@ -27,7 +31,12 @@ use bee_tangle::{Tangle, TangleWorker};
 use log::{error, info};
 use warp::{http::StatusCode, Filter, Rejection, Reply};
 
-use self::{config::RestApiConfig, rejection::CustomRejection, storage::StorageBackend};
+use self::{
+    config::RestApiConfig,
+    rate_limit::{with_rate_limit, RateLimiter},
+    rejection::CustomRejection,
+    storage::StorageBackend,
+};
 use crate::types::body::{DefaultErrorResponse, ErrorBody};
 
 pub(crate) type NetworkId = (String, u64);
@@ -63,6 +72,7 @@ where
             TypeId::of::<TangleWorker>(),
             TypeId::of::<MessageSubmitterWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
+            TypeId::of::<MetricsWorker>(),
         ]
         .leak()
     }
@@ -80,6 +90,7 @@ where
         let message_requester = node.worker::<MessageRequesterWorker>().unwrap().clone();
         let requested_messages = node.resource::<RequestedMessages>();
         let peer_manager = node.resource::<PeerManager>();
+        let node_metrics = node.resource::<NodeMetrics>();
         let network_controller = node.resource::<NetworkCommandSender>();
         let node_info = node.info();
         let bus = node.bus();
@@ -87,25 +98,34 @@ where
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Running.");
 
-            let routes = routes::filter_all(
-                rest_api_config.public_routes.clone(),
-                rest_api_config.allowed_ips.clone(),
-                tangle,
-                storage,
-                message_submitter,
-                network_id,
-                bech32_hrp,
-                rest_api_config.clone(),
-                protocol_config,
-                peer_manager,
-                network_controller,
-                node_info,
-                bus,
-                message_requester,
-                requested_messages,
-                consensus_worker,
-            )
-            .recover(|err| async { handle_rejection(err) });
+            let rate_limiter = (rest_api_config.rate_limit_requests_per_second() > 0).then(|| {
+                RateLimiter::new(
+                    rest_api_config.rate_limit_requests_per_second(),
+                    rest_api_config.rate_limit_burst(),
+                )
+            });
+
+            let routes = with_rate_limit(rate_limiter)
+                .and(routes::filter_all(
+                    rest_api_config.public_routes.clone(),
+                    rest_api_config.allowed_networks.clone(),
+                    tangle,
+                    storage,
+                    message_submitter,
+                    network_id,
+                    bech32_hrp,
+                    rest_api_config.clone(),
+                    protocol_config,
+                    peer_manager,
+                    network_controller,
+                    node_info,
+                    bus,
+                    message_requester,
+                    requested_messages,
+                    consensus_worker,
+                    node_metrics,
+                ))
+                .recover(|err| async { handle_rejection(err) });
 
             let (_, server) =
                 warp::serve(routes).bind_with_graceful_shutdown(rest_api_config.bind_socket_addr(), async {
@@ -128,6 +148,7 @@ fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
         Some(CustomRejection::NotFound(reason)) => (StatusCode::NOT_FOUND, "404", reason.as_str()),
         Some(CustomRejection::BadRequest(reason)) => (StatusCode::BAD_REQUEST, "400", reason.as_str()),
         Some(CustomRejection::ServiceUnavailable(reason)) => (StatusCode::SERVICE_UNAVAILABLE, "503", reason.as_str()),
+        Some(CustomRejection::TooManyRequests) => (StatusCode::TOO_MANY_REQUESTS, "429", "too many requests"),
         // handle default rejections
         _ => {
             if err.is_not_found() {
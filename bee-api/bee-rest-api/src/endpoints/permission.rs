@@ -3,23 +3,42 @@
 
 use std::net::{IpAddr, SocketAddr};
 
+use ipnet::IpNet;
 use warp::{reject, Filter, Rejection};
 
 use crate::endpoints::rejection::CustomRejection;
 
-pub fn has_permission(
+/// Checks whether a route is public, a public route ending with `*` exposing its prefix and every route below it.
+/// The prefix only matches whole path segments, `/api/plugins*` exposes `/api/plugins/metrics` but not
+/// `/api/pluginsX`.
+fn is_public_route(route: &str, public_routes: &[String]) -> bool {
+    public_routes
+        .iter()
+        .any(|public_route| match public_route.strip_suffix('*') {
+            Some(prefix) => matches!(
+                route.strip_prefix(prefix),
+                Some(rest) if rest.is_empty() || rest.starts_with('/') || prefix.ends_with('/')
+            ),
+            None => public_route == route,
+        })
+}
+
+/// Checks whether a remote address is allowed to access a route, either because the route is public or because the
+/// address belongs to one of the allowed IP networks.
+pub fn has_network_permission(
     route: &'static str,
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_networks: Box<[IpNet]>,
 ) -> impl Filter<Extract = (), Error = Rejection> + Clone {
     warp::addr::remote()
         .and_then(move |addr: Option<SocketAddr>| {
-            let route = route.to_owned();
             let public_routes = public_routes.clone();
-            let allowed_ips = allowed_ips.clone();
+            let allowed_networks = allowed_networks.clone();
             async move {
                 if let Some(v) = addr {
-                    if allowed_ips.contains(&v.ip()) || public_routes.contains(&route) {
+                    if allowed_networks.iter().any(|net| net.contains(&v.ip()))
+                        || is_public_route(route, &public_routes)
+                    {
                         return Ok(());
                     }
                 }
@@ -28,3 +47,130 @@ pub fn has_permission(
         })
         .untuple_one()
 }
+
+/// Checks whether a remote address is allowed to access a route, either because the route is public or because the
+/// address is one of the allowed IP addresses.
+#[deprecated(note = "use has_network_permission")]
+#[allow(clippy::boxed_local)]
+pub fn has_permission(
+    route: &'static str,
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpAddr]>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    has_network_permission(
+        route,
+        public_routes,
+        allowed_ips.iter().copied().map(IpNet::from).collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use warp::http::StatusCode;
+
+    use super::*;
+    use crate::endpoints::handle_rejection;
+
+    async fn status(route: &'static str, public_routes: &[&str]) -> StatusCode {
+        let public_routes = public_routes.iter().map(|route| route.to_string()).collect();
+
+        warp::test::request()
+            .remote_addr("203.0.113.1:14265".parse().unwrap())
+            .reply(
+                &has_network_permission(route, public_routes, Box::new([]))
+                    .map(|| StatusCode::OK)
+                    .recover(|err| async { handle_rejection(err) }),
+            )
+            .await
+            .status()
+    }
+
+    #[tokio::test]
+    async fn wildcard_exposes_children() {
+        assert_eq!(
+            status("/api/plugins/metrics", &["/api/plugins/*"]).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status("/api/plugins/indexer/messages", &["/api/plugins/*"]).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn wildcard_matches_whole_segments() {
+        assert_eq!(status("/api/plugins", &["/api/plugins*"]).await, StatusCode::OK);
+        assert_eq!(status("/api/plugins/metrics", &["/api/plugins*"]).await, StatusCode::OK);
+        assert_eq!(status("/api/pluginsX", &["/api/plugins*"]).await, StatusCode::FORBIDDEN);
+        assert_eq!(
+            status("/api/pluginsX/metrics", &["/api/plugins*"]).await,
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    #[tokio::test]
+    async fn non_public_route_is_forbidden() {
+        assert_eq!(
+            status("/api/v1/peers", &["/api/plugins/*"]).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status("/api/plugins/metrics", &["/api/plugins"]).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status("/api/plugins/metrics", &["/api/plugins/metrics"]).await,
+            StatusCode::OK
+        );
+    }
+
+    async fn is_allowed(allowed_ips: &[&str], remote_addr: &str) -> bool {
+        let allowed_ips = allowed_ips.iter().map(|net| net.parse().unwrap()).collect();
+
+        warp::test::request()
+            .remote_addr(remote_addr.parse().unwrap())
+            .filter(&has_network_permission("/api/v1/peers", Box::new([]), allowed_ips))
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn ipv4_network() {
+        assert!(is_allowed(&["10.0.0.0/8"], "10.42.0.1:14265").await);
+        assert!(!is_allowed(&["10.0.0.0/8"], "11.0.0.1:14265").await);
+    }
+
+    #[tokio::test]
+    async fn ipv6_network() {
+        assert!(is_allowed(&["fd00::/8"], "[fd12:3456::1]:14265").await);
+        assert!(!is_allowed(&["fd00::/8"], "[fe80::1]:14265").await);
+    }
+
+    #[tokio::test]
+    async fn single_address() {
+        assert!(is_allowed(&["127.0.0.1/32", "::1/128"], "127.0.0.1:14265").await);
+        assert!(is_allowed(&["127.0.0.1/32", "::1/128"], "[::1]:14265").await);
+        assert!(!is_allowed(&["127.0.0.1/32", "::1/128"], "127.0.0.2:14265").await);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn deprecated_single_addresses() {
+        let filter = has_permission("/api/v1/peers", Box::new([]), Box::new(["127.0.0.1".parse().unwrap()]));
+
+        assert!(
+            warp::test::request()
+                .remote_addr("127.0.0.1:14265".parse().unwrap())
+                .filter(&filter)
+                .await
+                .is_ok()
+        );
+        assert!(
+            warp::test::request()
+                .remote_addr("127.0.0.2:14265".parse().unwrap())
+                .filter(&filter)
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use warp::{reject, Filter, Rejection};
+
+use crate::endpoints::rejection::CustomRejection;
+
+// Maximum number of tracked clients, the least recently seen ones are dropped beyond it.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+// Number of clients dropped at once when the maximum is reached, so that the eviction cost is shared by many clients.
+const EVICTED_CLIENTS: usize = MAX_TRACKED_CLIENTS / 10;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// A token bucket rate limiter, keyed by client IP address, shared by all the routes.
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+}
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter` refilling `requests_per_second` tokens per second up to `burst` tokens.
+    pub(crate) fn new(requests_per_second: u32, burst: u32) -> Self {
+        Self {
+            requests_per_second: requests_per_second as f64,
+            burst: burst.max(1) as f64,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Takes a token from the bucket of the given IP address, returning `false` if the bucket is empty.
+    pub(crate) fn try_acquire(&self, ip: IpAddr) -> bool {
+        self.try_acquire_at(ip, Instant::now())
+    }
+
+    fn try_acquire_at(&self, ip: IpAddr, now: Instant) -> bool {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(&ip) {
+            let mut last_seen = buckets.values().map(|bucket| bucket.last_refill).collect::<Vec<_>>();
+            let (_, &mut threshold, _) = last_seen.select_nth_unstable(EVICTED_CLIENTS - 1);
+            buckets.retain(|_, bucket| bucket.last_refill > threshold);
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: self.burst,
+            last_refill: now,
+        });
+
+        bucket.tokens = (bucket.tokens
+            + now.duration_since(bucket.last_refill).as_secs_f64() * self.requests_per_second)
+            .min(self.burst);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Rejects the requests of clients exceeding their rate limit, lets every request through if there is no limiter.
+pub(crate) fn with_rate_limit(
+    rate_limiter: Option<RateLimiter>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::addr::remote()
+        .and_then(move |addr: Option<SocketAddr>| {
+            let rate_limiter = rate_limiter.clone();
+            async move {
+                match (rate_limiter, addr) {
+                    (Some(rate_limiter), Some(addr)) if !rate_limiter.try_acquire(addr.ip()) => {
+                        Err(reject::custom(CustomRejection::TooManyRequests))
+                    }
+                    _ => Ok(()),
+                }
+            }
+        })
+        .untuple_one()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use warp::http::StatusCode;
+
+    use super::*;
+    use crate::endpoints::handle_rejection;
+
+    async fn burst(rate_limiter: Option<RateLimiter>, remote_addr: &str, requests: usize) -> Vec<StatusCode> {
+        let route = with_rate_limit(rate_limiter)
+            .map(|| StatusCode::OK)
+            .recover(|err| async { handle_rejection(err) });
+        let mut statuses = Vec::new();
+
+        for _ in 0..requests {
+            let response = warp::test::request()
+                .remote_addr(remote_addr.parse().unwrap())
+                .reply(&route)
+                .await;
+            statuses.push(response.status());
+        }
+
+        statuses
+    }
+
+    #[tokio::test]
+    async fn too_many_requests_after_burst() {
+        let rate_limiter = RateLimiter::new(1, 3);
+
+        assert_eq!(
+            burst(Some(rate_limiter.clone()), "203.0.113.1:14265", 5).await,
+            [
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::TOO_MANY_REQUESTS
+            ]
+        );
+        // Every client IP address has its own bucket.
+        assert_eq!(
+            burst(Some(rate_limiter), "203.0.113.2:14265", 1).await,
+            [StatusCode::OK]
+        );
+    }
+
+    #[test]
+    fn least_recently_seen_clients_are_evicted() {
+        let rate_limiter = RateLimiter::new(1, 3);
+        let start = Instant::now();
+        let ip = |i: usize| IpAddr::from((i as u32).to_be_bytes());
+        let at = |i: usize| start + Duration::from_millis(i as u64);
+
+        for i in 0..MAX_TRACKED_CLIENTS {
+            assert!(rate_limiter.try_acquire_at(ip(i), at(i)));
+        }
+        // The first client is seen again, the second one becomes the least recently seen.
+        assert!(rate_limiter.try_acquire_at(ip(0), at(MAX_TRACKED_CLIENTS)));
+        assert!(rate_limiter.try_acquire_at(ip(MAX_TRACKED_CLIENTS), at(MAX_TRACKED_CLIENTS + 1)));
+
+        let buckets = rate_limiter.buckets.lock().unwrap();
+
+        assert_eq!(buckets.len(), MAX_TRACKED_CLIENTS - EVICTED_CLIENTS + 1);
+        assert!(buckets.contains_key(&ip(0)));
+        assert!((1..=EVICTED_CLIENTS).all(|i| !buckets.contains_key(&ip(i))));
+        assert!(buckets.contains_key(&ip(EVICTED_CLIENTS + 1)));
+        assert!(buckets.contains_key(&ip(MAX_TRACKED_CLIENTS)));
+    }
+
+    #[tokio::test]
+    async fn no_limiter() {
+        assert!(burst(None, "203.0.113.1:14265", 50)
+            .await
+            .iter()
+            .all(|status| *status == StatusCode::OK));
+    }
+}
@@ -9,6 +9,7 @@ pub(crate) enum CustomRejection {
     BadRequest(String),
     NotFound(String),
     ServiceUnavailable(String),
+    TooManyRequests,
     InternalError,
     StorageBackend,
 }
@@ -4,15 +4,17 @@
 pub mod plugins;
 pub mod v1;
 
-use std::net::IpAddr;
-
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+use bee_protocol::{
+    types::metrics::NodeMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+    },
 };
 use bee_runtime::{event::Bus, node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use tokio::sync::mpsc;
 use warp::{self, Filter, Rejection, Reply};
 
@@ -25,7 +27,7 @@ pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clo
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
     storage: ResourceHandle<B>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
@@ -40,6 +42,7 @@ pub(crate) fn filter<B: StorageBackend>(
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    node_metrics: ResourceHandle<NodeMetrics>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     v1::filter(
         public_routes.clone(),
@@ -51,7 +54,7 @@ pub(crate) fn filter<B: StorageBackend>(
         bech32_hrp,
         rest_api_config.clone(),
         protocol_config,
-        peer_manager,
+        peer_manager.clone(),
         network_command_sender,
         node_info,
         consensus_worker,
@@ -65,5 +68,7 @@ pub(crate) fn filter<B: StorageBackend>(
         message_requester,
         requested_messages,
         rest_api_config,
+        peer_manager,
+        node_metrics,
     ))
 }
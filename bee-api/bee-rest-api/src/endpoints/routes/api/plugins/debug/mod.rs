@@ -3,11 +3,10 @@
 
 mod white_flag;
 
-use std::net::IpAddr;
-
 use bee_protocol::workers::{MessageRequesterWorker, RequestedMessages};
 use bee_runtime::{event::Bus, resource::ResourceHandle};
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{self, Filter, Rejection, Reply};
 
 use crate::endpoints::{config::RestApiConfig, storage::StorageBackend};
@@ -19,7 +18,7 @@ pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clo
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<Tangle<B>>,
     bus: ResourceHandle<Bus<'static>>,
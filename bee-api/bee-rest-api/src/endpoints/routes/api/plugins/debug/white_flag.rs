@@ -4,7 +4,6 @@
 use std::{
     any::TypeId,
     collections::HashSet,
-    net::IpAddr,
     sync::{Arc, Mutex},
     time::Duration,
 };
@@ -15,6 +14,7 @@ use bee_protocol::workers::{event::MessageSolidified, request_message, MessageRe
 use bee_runtime::{event::Bus, resource::ResourceHandle};
 use bee_tangle::Tangle;
 use futures::channel::oneshot;
+use ipnet::IpNet;
 use serde_json::Value as JsonValue;
 use tokio::time::timeout;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
@@ -25,7 +25,7 @@ use crate::{
         filters::{
             with_bus, with_message_requester, with_requested_messages, with_rest_api_config, with_storage, with_tangle,
         },
-        permission::has_permission,
+        permission::has_network_permission,
         rejection::CustomRejection,
         storage::StorageBackend,
     },
@@ -39,7 +39,7 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<Tangle<B>>,
     bus: ResourceHandle<Bus<'static>>,
@@ -49,7 +49,7 @@ pub(crate) fn filter<B: StorageBackend>(
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::post())
-        .and(has_permission(ROUTE_WHITE_FLAG, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_WHITE_FLAG, public_routes, allowed_ips))
         .and(warp::body::json())
         .and(with_storage(storage))
         .and(with_tangle(tangle))
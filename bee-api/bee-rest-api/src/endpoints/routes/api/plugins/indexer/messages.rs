@@ -0,0 +1,207 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use bee_message::{
+    payload::indexation::{IndexationPayload, PaddedIndex},
+    MessageId,
+};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::access::FetchPage;
+use ipnet::IpNet;
+use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
+
+use crate::{
+    endpoints::{
+        config::ROUTE_INDEXER_MESSAGES, filters::with_storage, permission::has_network_permission,
+        rejection::CustomRejection, storage::StorageBackend,
+    },
+    types::{body::SuccessBody, responses::IndexerMessagesResponse},
+};
+
+const DEFAULT_LIMIT: usize = 1000;
+const MAX_LIMIT: usize = 1000;
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path().and(warp::path("messages")).and(warp::path::end())
+}
+
+/// The query parameters of the route.
+struct MessagesQuery {
+    index: String,
+    cursor: Option<MessageId>,
+    limit: usize,
+}
+
+fn parse_query(query: HashMap<String, String>) -> Result<MessagesQuery, Rejection> {
+    let invalid = |name: &str| {
+        reject::custom(CustomRejection::BadRequest(format!(
+            "invalid query parameter `{}`",
+            name
+        )))
+    };
+
+    Ok(MessagesQuery {
+        index: query
+            .get("index")
+            .ok_or_else(|| {
+                reject::custom(CustomRejection::BadRequest(
+                    "missing query parameter `index`".to_string(),
+                ))
+            })?
+            .to_string(),
+        cursor: query
+            .get("cursor")
+            .map(|cursor| cursor.parse::<MessageId>().map_err(|_| invalid("cursor")))
+            .transpose()?,
+        limit: match query.get("limit") {
+            Some(limit) => limit
+                .parse::<usize>()
+                .map_err(|_| invalid("limit"))?
+                .clamp(1, MAX_LIMIT),
+            None => DEFAULT_LIMIT,
+        },
+    })
+}
+
+pub(crate) fn filter<B: StorageBackend>(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpNet]>,
+    storage: ResourceHandle<B>,
+) -> BoxedFilter<(impl Reply,)> {
+    self::path()
+        .and(warp::get())
+        .and(has_network_permission(ROUTE_INDEXER_MESSAGES, public_routes, allowed_ips))
+        .and(warp::query().and_then(|query: HashMap<String, String>| async move { parse_query(query) }))
+        .and(with_storage(storage))
+        .and_then(|query, storage| async move { messages(query, storage) })
+        .boxed()
+}
+
+fn messages<B: StorageBackend>(query: MessagesQuery, storage: ResourceHandle<B>) -> Result<impl Reply, Rejection> {
+    let index_bytes = hex::decode(&query.index)
+        .map_err(|_| reject::custom(CustomRejection::BadRequest("Invalid index".to_owned())))?;
+    let hashed_index = IndexationPayload::new(&index_bytes, &[])
+        .map_err(|_| reject::custom(CustomRejection::BadRequest("Invalid index".to_owned())))?
+        .padded_index();
+
+    let (message_ids, next) =
+        FetchPage::<PaddedIndex, MessageId>::fetch_page(&*storage, &hashed_index, query.cursor.as_ref(), query.limit)
+            .map_err(|_| {
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "can not fetch from storage".to_string(),
+            ))
+        })?;
+
+    Ok(warp::reply::json(&SuccessBody::new(IndexerMessagesResponse {
+        index: query.index,
+        limit: query.limit,
+        message_ids: message_ids.iter().map(|id| id.to_string()).collect(),
+        cursor: next.map(|id| id.to_string()),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use bee_storage::access::Insert;
+    use bee_storage_memory::storage::Storage;
+    use serde_json::Value;
+    use warp::http::StatusCode;
+
+    use super::*;
+
+    const INDEX: &str = "6265652d696e6465786572";
+
+    fn storage_with_message_ids(count: u8) -> (ResourceHandle<Storage>, Vec<MessageId>) {
+        let storage = Storage::new();
+        let index = IndexationPayload::new(&hex::decode(INDEX).unwrap(), &[])
+            .unwrap()
+            .padded_index();
+        let message_ids = (0..count).map(|i| MessageId::from([i; 32])).collect::<Vec<_>>();
+
+        for message_id in &message_ids {
+            Insert::<(PaddedIndex, MessageId), ()>::insert(&storage, &(index, *message_id), &()).unwrap();
+        }
+
+        (ResourceHandle::new(storage), message_ids)
+    }
+
+    async fn get(storage: &ResourceHandle<Storage>, query: &str) -> (StatusCode, Value) {
+        let public_routes = vec![ROUTE_INDEXER_MESSAGES.to_string()].into_boxed_slice();
+        let response = warp::test::request()
+            .path(&format!("{}?{}", ROUTE_INDEXER_MESSAGES, query))
+            .remote_addr("203.0.113.1:14265".parse::<SocketAddr>().unwrap())
+            .reply(&filter(public_routes, Box::new([]), storage.clone()))
+            .await;
+
+        (
+            response.status(),
+            serde_json::from_slice(response.body()).unwrap_or_default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn pages_with_cursor() {
+        let (storage, message_ids) = storage_with_message_ids(5);
+        let mut fetched = Vec::new();
+        let mut query = format!("index={}&limit=2", INDEX);
+
+        loop {
+            let (status, body) = get(&storage, &query).await;
+            assert_eq!(status, StatusCode::OK);
+
+            let message_ids = body["data"]["messageIds"].as_array().unwrap();
+            assert!(message_ids.len() <= 2);
+            fetched.extend(
+                message_ids
+                    .iter()
+                    .map(|id| id.as_str().unwrap().parse::<MessageId>().unwrap()),
+            );
+
+            match body["data"]["cursor"].as_str() {
+                Some(cursor) => query = format!("index={}&limit=2&cursor={}", INDEX, cursor),
+                None => break,
+            }
+        }
+
+        assert_eq!(fetched, message_ids);
+    }
+
+    #[tokio::test]
+    async fn unknown_index_is_empty() {
+        let (storage, _) = storage_with_message_ids(5);
+        let (status, body) = get(&storage, "index=00").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["data"]["messageIds"].as_array().unwrap().is_empty());
+        assert!(body["data"].get("cursor").is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_queries_are_rejected() {
+        let (storage, _) = storage_with_message_ids(1);
+        let public_routes = vec![ROUTE_INDEXER_MESSAGES.to_string()].into_boxed_slice();
+
+        for query in [
+            "limit=2".to_string(),
+            "index=zz".to_string(),
+            format!("index={}&cursor=42", INDEX),
+        ] {
+            let rejection = warp::test::request()
+                .path(&format!("{}?{}", ROUTE_INDEXER_MESSAGES, query))
+                .remote_addr("203.0.113.1:14265".parse::<SocketAddr>().unwrap())
+                .filter(&filter(public_routes.clone(), Box::new([]), storage.clone()))
+                .await
+                .err()
+                .unwrap();
+
+            assert!(matches!(
+                rejection.find::<CustomRejection>(),
+                Some(CustomRejection::BadRequest(_))
+            ));
+        }
+    }
+}
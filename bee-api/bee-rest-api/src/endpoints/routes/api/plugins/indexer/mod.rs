@@ -0,0 +1,22 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod messages;
+
+use bee_runtime::resource::ResourceHandle;
+use ipnet::IpNet;
+use warp::{self, Filter, Rejection, Reply};
+
+use crate::endpoints::storage::StorageBackend;
+
+pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    super::path().and(warp::path("indexer"))
+}
+
+pub(crate) fn filter<B: StorageBackend>(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpNet]>,
+    storage: ResourceHandle<B>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    messages::filter(public_routes, allowed_ips, storage)
+}
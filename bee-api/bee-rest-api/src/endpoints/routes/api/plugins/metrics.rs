@@ -0,0 +1,296 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fmt::{Display, Write},
+    sync::{Arc, Mutex},
+};
+
+use bee_protocol::{
+    types::metrics::NodeMetrics,
+    workers::{event::MpsMetricsUpdated, PeerManager},
+};
+use bee_runtime::{event::Bus, resource::ResourceHandle};
+use ipnet::IpNet;
+use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
+
+use crate::endpoints::{
+    config::ROUTE_METRICS,
+    filters::{with_node_metrics, with_peer_manager, with_storage},
+    permission::has_network_permission,
+    storage::StorageBackend,
+    ApiWorkerFullNode,
+};
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    super::path().and(warp::path("metrics")).and(warp::path::end())
+}
+
+pub(crate) fn filter<B: StorageBackend>(
+    public_routes: Box<[String]>,
+    allowed_ips: Box<[IpNet]>,
+    storage: ResourceHandle<B>,
+    bus: ResourceHandle<Bus<'static>>,
+    peer_manager: ResourceHandle<PeerManager>,
+    node_metrics: ResourceHandle<NodeMetrics>,
+) -> BoxedFilter<(impl Reply,)> {
+    // The messages per second are only known through the events of the MPS worker, the latest ones are kept.
+    let mps = Arc::new(Mutex::new(None));
+
+    bus.add_listener::<ApiWorkerFullNode, MpsMetricsUpdated, _>({
+        let mps = mps.clone();
+        move |event: &MpsMetricsUpdated| {
+            // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+            *mps.lock().unwrap() = Some(event.clone());
+        }
+    });
+
+    self::path()
+        .and(warp::get())
+        .and(has_network_permission(ROUTE_METRICS, public_routes, allowed_ips))
+        .and(with_storage(storage))
+        .and(with_peer_manager(peer_manager))
+        .and(with_node_metrics(node_metrics))
+        .and(warp::any().map(move || mps.clone()))
+        .and_then(|storage, peer_manager, node_metrics, mps| async move {
+            metrics(storage, peer_manager, node_metrics, mps)
+        })
+        .boxed()
+}
+
+pub(crate) fn metrics<B: StorageBackend>(
+    storage: ResourceHandle<B>,
+    peer_manager: ResourceHandle<PeerManager>,
+    node_metrics: ResourceHandle<NodeMetrics>,
+    mps: Arc<Mutex<Option<MpsMetricsUpdated>>>,
+) -> Result<impl Reply, Rejection> {
+    let mut exposition = Exposition::default();
+
+    // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+    if let Some(mps) = mps.lock().unwrap().as_ref() {
+        exposition.metric(
+            "bee_messages_per_second",
+            "Number of messages per second, by kind.",
+            "gauge",
+            [
+                ("incoming", mps.incoming),
+                ("new", mps.new),
+                ("known", mps.known),
+                ("invalid", mps.invalid),
+                ("outgoing", mps.outgoing),
+            ]
+            .map(|(kind, value)| (format!("kind=\"{}\"", kind), value)),
+        );
+    }
+
+    exposition.metric(
+        "bee_connected_peers",
+        "Number of connected peers.",
+        "gauge",
+        [(String::new(), peer_manager.connected_peers())],
+    );
+    exposition.metric(
+        "bee_synced_peers",
+        "Number of synced peers.",
+        "gauge",
+        [(String::new(), peer_manager.synced_peers())],
+    );
+    exposition.metric(
+        "bee_messages_received_total",
+        "Number of messages received from all the peers.",
+        "counter",
+        [(String::new(), node_metrics.messages_received())],
+    );
+    exposition.metric(
+        "bee_messages_sent_total",
+        "Number of messages sent to all the peers.",
+        "counter",
+        [(String::new(), node_metrics.messages_sent())],
+    );
+
+    let peers = peer_manager
+        .get_all()
+        .iter()
+        .map(|peer| {
+            (
+                format!("peer_id=\"{}\",alias=\"{}\"", peer.id(), escape_label(peer.alias())),
+                peer.metrics().snapshot(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    exposition.metric(
+        "bee_peer_received_bytes_total",
+        "Number of bytes received from a peer.",
+        "counter",
+        peers
+            .iter()
+            .map(|(labels, snapshot)| (labels.clone(), snapshot.bytes_received)),
+    );
+    exposition.metric(
+        "bee_peer_sent_bytes_total",
+        "Number of bytes sent to a peer.",
+        "counter",
+        peers
+            .iter()
+            .map(|(labels, snapshot)| (labels.clone(), snapshot.bytes_sent)),
+    );
+
+    // Not all the storage backends know their size.
+    storage_metrics(
+        &mut exposition,
+        storage.size().ok().flatten(),
+        storage.tree_sizes().ok().flatten(),
+    );
+
+    Ok(warp::reply::with_header(exposition.0, "content-type", CONTENT_TYPE))
+}
+
+/// Renders the size of the storage and the size of each of its trees, if they are known.
+fn storage_metrics(exposition: &mut Exposition, size: Option<usize>, tree_sizes: Option<Vec<(String, usize)>>) {
+    if let Some(size) = size {
+        exposition.metric(
+            "bee_storage_size_bytes",
+            "Size of the storage in bytes.",
+            "gauge",
+            [(String::new(), size)],
+        );
+    }
+
+    if let Some(tree_sizes) = tree_sizes {
+        exposition.metric(
+            "bee_storage_tree_size_bytes",
+            "Size of a storage tree in bytes.",
+            "gauge",
+            tree_sizes
+                .into_iter()
+                .map(|(tree, size)| (format!("tree=\"{}\"", escape_label(&tree)), size)),
+        );
+    }
+}
+
+/// Escapes a label value of the Prometheus text exposition format.
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Metrics rendered in the Prometheus text exposition format.
+#[derive(Default)]
+struct Exposition(String);
+
+impl Exposition {
+    /// Renders a metric and its samples, given as their labels and their value.
+    fn metric<V: Display>(
+        &mut self,
+        name: &str,
+        help: &str,
+        kind: &str,
+        samples: impl IntoIterator<Item = (String, V)>,
+    ) {
+        // Panic: writing to a `String` never fails.
+        writeln!(self.0, "# HELP {} {}", name, help).unwrap();
+        writeln!(self.0, "# TYPE {} {}", name, kind).unwrap();
+
+        for (labels, value) in samples {
+            if labels.is_empty() {
+                writeln!(self.0, "{} {}", name, value).unwrap();
+            } else {
+                writeln!(self.0, "{}{{{}}} {}", name, labels, value).unwrap();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the samples of the Prometheus text exposition format, failing on any malformed line or on a sample of
+    /// an undeclared metric.
+    fn parse(text: &str) -> Vec<(String, String, f64)> {
+        let is_name = |name: &str| {
+            !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+        };
+        let mut declared = Vec::new();
+        let mut samples = Vec::new();
+
+        for line in text.lines() {
+            if let Some(help) = line.strip_prefix("# HELP ") {
+                assert!(is_name(help.split(' ').next().unwrap()), "{}", line);
+            } else if let Some(kind) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = kind.split_once(' ').unwrap();
+                assert!(is_name(name) && ["counter", "gauge"].contains(&kind), "{}", line);
+                declared.push(name.to_string());
+            } else {
+                let (series, value) = line.rsplit_once(' ').unwrap();
+                let (name, labels) = match series.split_once('{') {
+                    Some((name, labels)) => (name, labels.strip_suffix('}').unwrap()),
+                    None => (series, ""),
+                };
+                assert!(declared.iter().any(|declared| declared == name), "{}", line);
+                samples.push((name.to_string(), labels.to_string(), value.parse().unwrap()));
+            }
+        }
+
+        samples
+    }
+
+    #[test]
+    fn valid_exposition() {
+        let mut exposition = Exposition::default();
+
+        exposition.metric(
+            "bee_connected_peers",
+            "Number of connected peers.",
+            "gauge",
+            [(String::new(), 3)],
+        );
+        exposition.metric(
+            "bee_peer_sent_bytes_total",
+            "Number of bytes sent to a peer.",
+            "counter",
+            [(format!("alias=\"{}\"", escape_label("a \"quoted\" alias")), 42)],
+        );
+        storage_metrics(
+            &mut exposition,
+            Some(1024),
+            Some(vec![("message".to_string(), 768), ("metadata".to_string(), 256)]),
+        );
+
+        assert_eq!(
+            parse(&exposition.0),
+            [
+                ("bee_connected_peers".to_string(), String::new(), 3.0),
+                (
+                    "bee_peer_sent_bytes_total".to_string(),
+                    "alias=\"a \\\"quoted\\\" alias\"".to_string(),
+                    42.0
+                ),
+                ("bee_storage_size_bytes".to_string(), String::new(), 1024.0),
+                (
+                    "bee_storage_tree_size_bytes".to_string(),
+                    "tree=\"message\"".to_string(),
+                    768.0
+                ),
+                (
+                    "bee_storage_tree_size_bytes".to_string(),
+                    "tree=\"metadata\"".to_string(),
+                    256.0
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_storage_sizes_are_omitted() {
+        let mut exposition = Exposition::default();
+
+        storage_metrics(&mut exposition, None, None);
+
+        assert!(exposition.0.is_empty());
+    }
+}
@@ -2,12 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod debug;
+mod indexer;
+mod metrics;
 
-use std::net::IpAddr;
-
-use bee_protocol::workers::{MessageRequesterWorker, RequestedMessages};
+use bee_protocol::{
+    types::metrics::NodeMetrics,
+    workers::{MessageRequesterWorker, PeerManager, RequestedMessages},
+};
 use bee_runtime::{event::Bus, resource::ResourceHandle};
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{self, Filter, Rejection, Reply};
 
 use crate::endpoints::{config::RestApiConfig, storage::StorageBackend};
@@ -19,22 +23,37 @@ pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clo
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<Tangle<B>>,
     bus: ResourceHandle<Bus<'static>>,
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     rest_api_config: RestApiConfig,
+    peer_manager: ResourceHandle<PeerManager>,
+    node_metrics: ResourceHandle<NodeMetrics>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     debug::filter(
-        public_routes,
-        allowed_ips,
-        storage,
+        public_routes.clone(),
+        allowed_ips.clone(),
+        storage.clone(),
         tangle,
-        bus,
+        bus.clone(),
         message_requester,
         requested_messages,
         rest_api_config,
     )
+    .or(indexer::filter(
+        public_routes.clone(),
+        allowed_ips.clone(),
+        storage.clone(),
+    ))
+    .or(metrics::filter(
+        public_routes,
+        allowed_ips,
+        storage,
+        bus,
+        peer_manager,
+        node_metrics,
+    ))
 }
@@ -1,11 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_gossip::{Command::AddPeer, Multiaddr, NetworkCommandSender, PeerId, PeerRelation, Protocol};
 use bee_protocol::workers::PeerManager;
 use bee_runtime::resource::ResourceHandle;
+use ipnet::IpNet;
 use serde_json::Value as JsonValue;
 use warp::{filters::BoxedFilter, http::StatusCode, reject, Filter, Rejection, Reply};
 
@@ -13,7 +12,7 @@ use crate::{
     endpoints::{
         config::ROUTE_ADD_PEER,
         filters::{with_network_command_sender, with_peer_manager},
-        permission::has_permission,
+        permission::has_network_permission,
         rejection::CustomRejection,
     },
     types::{
@@ -29,13 +28,13 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
 
 pub(crate) fn filter(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::post())
-        .and(has_permission(ROUTE_ADD_PEER, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_ADD_PEER, public_routes, allowed_ips))
         .and(warp::body::json())
         .and(with_peer_manager(peer_manager))
         .and(with_network_command_sender(network_command_sender))
@@ -1,16 +1,15 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
 use bee_message::address::Address;
+use ipnet::IpNet;
 use tokio::sync::mpsc;
 use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
 
 use crate::endpoints::{
     config::ROUTE_BALANCE_BECH32, filters::with_consensus_worker, path_params::bech32_address,
-    permission::has_permission, routes::api::v1::balance_ed25519::balance_ed25519,
+    permission::has_network_permission, routes::api::v1::balance_ed25519::balance_ed25519,
 };
 
 fn path() -> impl Filter<Extract = (Address,), Error = warp::Rejection> + Clone {
@@ -22,12 +21,12 @@ fn path() -> impl Filter<Extract = (Address,), Error = warp::Rejection> + Clone
 
 pub(crate) fn filter(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_BALANCE_BECH32, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_BALANCE_BECH32, public_routes, allowed_ips))
         .and(with_consensus_worker(consensus_worker))
         .and_then(|addr, consensus_worker| async move { balance_bech32(addr, consensus_worker).await })
         .boxed()
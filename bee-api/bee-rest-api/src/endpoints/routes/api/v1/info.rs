@@ -1,11 +1,12 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{convert::Infallible, net::IpAddr};
+use std::convert::Infallible;
 
 use bee_protocol::workers::{config::ProtocolConfig, PeerManager};
 use bee_runtime::{node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, Filter, Reply};
 
 use crate::{
@@ -15,7 +16,7 @@ use crate::{
             with_bech32_hrp, with_network_id, with_node_info, with_peer_manager, with_protocol_config,
             with_rest_api_config, with_tangle,
         },
-        permission::has_permission,
+        permission::has_network_permission,
         routes::health,
         storage::StorageBackend,
         Bech32Hrp, NetworkId,
@@ -30,7 +31,7 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
@@ -41,7 +42,7 @@ pub(crate) fn filter<B: StorageBackend>(
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_INFO, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_INFO, public_routes, allowed_ips))
         .and(with_tangle(tangle))
         .and(with_network_id(network_id))
         .and(with_bech32_hrp(bech32_hrp))
@@ -83,7 +84,7 @@ pub(crate) fn info<B: StorageBackend>(
     Ok(warp::reply::json(&SuccessBody::new(InfoResponse {
         name: node_info.name.clone(),
         version: node_info.version.clone(),
-        is_healthy: health::is_healthy(&tangle, &peer_manager),
+        is_healthy: health::is_healthy(&tangle, &peer_manager, rest_api_config.health_max_milestone_lag()),
         network_id: network_id.0,
         bech32_hrp,
         min_pow_score: protocol_config.minimum_pow_score(),
@@ -1,17 +1,16 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_message::MessageId;
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGE_CHILDREN, filters::with_tangle, path_params::message_id, permission::has_permission,
-        storage::StorageBackend,
+        config::ROUTE_MESSAGE_CHILDREN, filters::with_tangle, path_params::message_id,
+        permission::has_network_permission, storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::MessageChildrenResponse},
 };
@@ -26,12 +25,12 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clon
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGE_CHILDREN, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_MESSAGE_CHILDREN, public_routes, allowed_ips))
         .and(with_tangle(tangle))
         .and_then(|message_id, tangle| async move { message_children(message_id, tangle) })
         .boxed()
@@ -1,16 +1,15 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_common::packable::Packable;
 use bee_message::MessageId;
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, http::Response, reject, Filter, Rejection, Reply};
 
 use crate::endpoints::{
-    config::ROUTE_MESSAGE_RAW, filters::with_tangle, path_params::message_id, permission::has_permission,
+    config::ROUTE_MESSAGE_RAW, filters::with_tangle, path_params::message_id, permission::has_network_permission,
     rejection::CustomRejection, storage::StorageBackend,
 };
 
@@ -24,12 +23,12 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clon
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGE_RAW, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_MESSAGE_RAW, public_routes, allowed_ips))
         .and(with_tangle(tangle))
         .and_then(|message_id, tangle| async move { message_raw(message_id, tangle) })
         .boxed()
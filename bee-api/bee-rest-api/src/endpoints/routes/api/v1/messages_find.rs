@@ -1,7 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashMap, net::IpAddr};
+use std::collections::HashMap;
 
 use bee_message::{
     payload::indexation::{IndexationPayload, PaddedIndex},
@@ -9,12 +9,13 @@ use bee_message::{
 };
 use bee_runtime::resource::ResourceHandle;
 use bee_storage::access::Fetch;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGES_FIND, filters::with_storage, permission::has_permission, rejection::CustomRejection,
-        storage::StorageBackend,
+        config::ROUTE_MESSAGES_FIND, filters::with_storage, permission::has_network_permission,
+        rejection::CustomRejection, storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::MessagesFindResponse},
 };
@@ -25,12 +26,12 @@ fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGES_FIND, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_MESSAGES_FIND, public_routes, allowed_ips))
         .and(warp::query().and_then(|query: HashMap<String, String>| async move {
             match query.get("index") {
                 Some(i) => Ok(i.to_string()),
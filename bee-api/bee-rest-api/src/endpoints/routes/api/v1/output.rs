@@ -1,8 +1,6 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_ledger::{
     types::{ConsumedOutput, CreatedOutput, LedgerIndex},
     workers::{consensus::ConsensusWorkerCommand, error::Error},
@@ -11,6 +9,7 @@ use bee_message::output::OutputId;
 use bee_runtime::resource::ResourceHandle;
 use bee_storage::access::Fetch;
 use futures::channel::oneshot;
+use ipnet::IpNet;
 use log::error;
 use tokio::sync::mpsc;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
@@ -20,7 +19,7 @@ use crate::{
         config::ROUTE_OUTPUT,
         filters::{with_consensus_worker, with_storage},
         path_params::output_id,
-        permission::has_permission,
+        permission::has_network_permission,
         rejection::CustomRejection,
         storage::StorageBackend,
     },
@@ -36,13 +35,13 @@ fn path() -> impl Filter<Extract = (OutputId,), Error = Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
     consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_OUTPUT, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_OUTPUT, public_routes, allowed_ips))
         .and(with_storage(storage))
         .and(with_consensus_worker(consensus_worker))
         .and_then(
@@ -1,14 +1,13 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_ledger::{
     types::LedgerIndex,
     workers::{consensus::ConsensusWorkerCommand, error::Error},
 };
 use bee_message::{address::Ed25519Address, output::OutputId, prelude::Address};
 use futures::channel::oneshot;
+use ipnet::IpNet;
 use log::error;
 use tokio::sync::mpsc;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
@@ -16,7 +15,7 @@ use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 use crate::{
     endpoints::{
         config::ROUTE_OUTPUTS_ED25519, filters::with_consensus_worker, path_params::ed25519_address,
-        permission::has_permission, rejection::CustomRejection,
+        permission::has_network_permission, rejection::CustomRejection,
     },
     types::{body::SuccessBody, responses::OutputsAddressResponse},
 };
@@ -32,12 +31,12 @@ fn path() -> impl Filter<Extract = (Ed25519Address,), Error = Rejection> + Clone
 
 pub(crate) fn filter(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_OUTPUTS_ED25519, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_OUTPUTS_ED25519, public_routes, allowed_ips))
         .and(with_consensus_worker(consensus_worker))
         .and_then(|addr, consensus_worker| async move { outputs_ed25519(addr, consensus_worker).await })
         .boxed()
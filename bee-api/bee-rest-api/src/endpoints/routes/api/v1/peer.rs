@@ -1,16 +1,15 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_gossip::PeerId;
 use bee_protocol::workers::PeerManager;
 use bee_runtime::resource::ResourceHandle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_PEER, filters::with_peer_manager, path_params::peer_id, permission::has_permission,
+        config::ROUTE_PEER, filters::with_peer_manager, path_params::peer_id, permission::has_network_permission,
         rejection::CustomRejection,
     },
     types::{body::SuccessBody, dtos::PeerDto, responses::PeerResponse},
@@ -25,12 +24,12 @@ fn path() -> impl Filter<Extract = (PeerId,), Error = Rejection> + Clone {
 
 pub(crate) fn filter(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     peer_manager: ResourceHandle<PeerManager>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_PEER, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_PEER, public_routes, allowed_ips))
         .and(with_peer_manager(peer_manager))
         .and_then(|peer_id, peer_manager| async move { peer(peer_id, peer_manager) })
         .boxed()
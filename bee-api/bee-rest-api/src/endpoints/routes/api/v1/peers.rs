@@ -1,14 +1,15 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{convert::Infallible, net::IpAddr};
+use std::convert::Infallible;
 
 use bee_protocol::workers::PeerManager;
 use bee_runtime::resource::ResourceHandle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
 
 use crate::{
-    endpoints::{config::ROUTE_PEERS, filters::with_peer_manager, permission::has_permission},
+    endpoints::{config::ROUTE_PEERS, filters::with_peer_manager, permission::has_network_permission},
     types::{body::SuccessBody, dtos::PeerDto, responses::PeersResponse},
 };
 
@@ -18,12 +19,12 @@ fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
 
 pub(crate) fn filter(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     peer_manager: ResourceHandle<PeerManager>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_PEERS, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_PEERS, public_routes, allowed_ips))
         .and(with_peer_manager(peer_manager))
         .and_then(|peer_manager| async move { peers(peer_manager) })
         .boxed()
@@ -1,17 +1,16 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_ledger::types::Receipt;
 use bee_message::milestone::MilestoneIndex;
 use bee_runtime::resource::ResourceHandle;
 use bee_storage::access::AsIterator;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_RECEIPTS, filters::with_storage, permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_RECEIPTS, filters::with_storage, permission::has_network_permission, rejection::CustomRejection,
         storage::StorageBackend,
     },
     types::{body::SuccessBody, dtos::ReceiptDto, responses::ReceiptsResponse},
@@ -23,12 +22,12 @@ fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_RECEIPTS, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_RECEIPTS, public_routes, allowed_ips))
         .and(with_storage(storage))
         .and_then(|storage| async move { receipts(storage) })
         .boxed()
@@ -1,15 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_gossip::{Command::RemovePeer, NetworkCommandSender, PeerId};
 use bee_runtime::resource::ResourceHandle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, http::StatusCode, reject, Filter, Rejection, Reply};
 
 use crate::endpoints::{
-    config::ROUTE_REMOVE_PEER, filters::with_network_command_sender, path_params::peer_id, permission::has_permission,
-    rejection::CustomRejection,
+    config::ROUTE_REMOVE_PEER, filters::with_network_command_sender, path_params::peer_id,
+    permission::has_network_permission, rejection::CustomRejection,
 };
 
 fn path() -> impl Filter<Extract = (PeerId,), Error = warp::Rejection> + Clone {
@@ -21,12 +20,12 @@ fn path() -> impl Filter<Extract = (PeerId,), Error = warp::Rejection> + Clone {
 
 pub(crate) fn filter(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::delete())
-        .and(has_permission(ROUTE_REMOVE_PEER, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_REMOVE_PEER, public_routes, allowed_ips))
         .and(with_network_command_sender(network_command_sender))
         .and_then(|peer_id, network_controller| async move { remove_peer(peer_id, network_controller) })
         .boxed()
@@ -1,8 +1,6 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_common::packable::Packable;
 use bee_message::{parents::Parents, payload::Payload, Message, MessageBuilder, MessageId};
 use bee_pow::providers::{miner::MinerBuilder, NonceProviderBuilder};
@@ -10,6 +8,7 @@ use bee_protocol::workers::{config::ProtocolConfig, MessageSubmitterError, Messa
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::Tangle;
 use futures::channel::oneshot;
+use ipnet::IpNet;
 use log::error;
 use serde_json::Value as JsonValue;
 use tokio::sync::mpsc;
@@ -19,7 +18,7 @@ use crate::{
     endpoints::{
         config::{RestApiConfig, ROUTE_SUBMIT_MESSAGE, ROUTE_SUBMIT_MESSAGE_RAW},
         filters::{with_message_submitter, with_network_id, with_protocol_config, with_rest_api_config, with_tangle},
-        permission::has_permission,
+        permission::has_network_permission,
         rejection::CustomRejection,
         storage::StorageBackend,
         NetworkId,
@@ -33,7 +32,7 @@ fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
     network_id: NetworkId,
@@ -44,7 +43,7 @@ pub(crate) fn filter<B: StorageBackend>(
         .and(warp::post())
         .and(
             (warp::header::exact("content-type", "application/json")
-                .and(has_permission(
+                .and(has_network_permission(
                     ROUTE_SUBMIT_MESSAGE,
                     public_routes.clone(),
                     allowed_ips.clone(),
@@ -57,7 +56,7 @@ pub(crate) fn filter<B: StorageBackend>(
                 .and(with_protocol_config(protocol_config))
                 .and_then(submit_message))
             .or(warp::header::exact("content-type", "application/octet-stream")
-                .and(has_permission(ROUTE_SUBMIT_MESSAGE_RAW, public_routes, allowed_ips))
+                .and(has_network_permission(ROUTE_SUBMIT_MESSAGE_RAW, public_routes, allowed_ips))
                 .and(warp::body::bytes())
                 .and(with_tangle(tangle))
                 .and(with_message_submitter(message_submitter))
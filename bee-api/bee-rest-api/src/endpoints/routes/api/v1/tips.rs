@@ -1,15 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_TIPS, filters::with_tangle, permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_TIPS, filters::with_tangle, permission::has_network_permission, rejection::CustomRejection,
         storage::StorageBackend, CONFIRMED_THRESHOLD,
     },
     types::{body::SuccessBody, responses::TipsResponse},
@@ -21,12 +20,12 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_TIPS, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_TIPS, public_routes, allowed_ips))
         .and(with_tangle(tangle))
         .and_then(tips)
         .boxed()
@@ -1,20 +1,19 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_ledger::types::CreatedOutput;
 use bee_message::{output::OutputId, payload::transaction::TransactionId};
 use bee_runtime::resource::ResourceHandle;
 use bee_storage::access::Fetch;
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, reject, Filter, Rejection, Reply};
 
 use crate::endpoints::{
     config::ROUTE_TRANSACTION_INCLUDED_MESSAGE,
     filters::{with_storage, with_tangle},
     path_params::transaction_id,
-    permission::has_permission,
+    permission::has_network_permission,
     rejection::CustomRejection,
     routes::api::v1::message,
     storage::StorageBackend,
@@ -30,13 +29,13 @@ fn path() -> impl Filter<Extract = (TransactionId,), Error = Rejection> + Clone
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<Tangle<B>>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(
+        .and(has_network_permission(
             ROUTE_TRANSACTION_INCLUDED_MESSAGE,
             public_routes,
             allowed_ips,
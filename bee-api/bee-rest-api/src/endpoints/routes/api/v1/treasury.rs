@@ -1,15 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::IpAddr;
-
 use bee_ledger::workers::storage;
 use bee_runtime::resource::ResourceHandle;
+use ipnet::IpNet;
 use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
 
 use crate::{
     endpoints::{
-        config::ROUTE_TREASURY, filters::with_storage, permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_TREASURY, filters::with_storage, permission::has_network_permission, rejection::CustomRejection,
         storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::TreasuryResponse},
@@ -21,12 +20,12 @@ fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     storage: ResourceHandle<B>,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_TREASURY, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_TREASURY, public_routes, allowed_ips))
         .and(with_storage(storage))
         .and_then(|storage| async move { treasury(storage) })
         .boxed()
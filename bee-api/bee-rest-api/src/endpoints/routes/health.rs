@@ -1,25 +1,23 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{
-    convert::Infallible,
-    net::IpAddr,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use bee_message::milestone::MilestoneIndex;
 use bee_protocol::workers::PeerManager;
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::Tangle;
-use warp::{filters::BoxedFilter, http::StatusCode, Filter, Reply};
+use ipnet::IpNet;
+use warp::{filters::BoxedFilter, http::StatusCode, reject, Filter, Rejection, Reply};
 
 use crate::endpoints::{
-    config::ROUTE_HEALTH,
-    filters::{with_peer_manager, with_tangle},
-    permission::has_permission,
+    config::{RestApiConfig, ROUTE_HEALTH},
+    filters::{with_peer_manager, with_rest_api_config, with_tangle},
+    permission::has_network_permission,
+    rejection::CustomRejection,
     storage::StorageBackend,
 };
 
-const HEALTH_CONFIRMED_THRESHOLD: u32 = 2; // in milestones
 const HEALTH_MILESTONE_AGE_MAX: u64 = 5 * 60; // in seconds
 
 fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
@@ -28,48 +26,118 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
 
 pub(crate) fn filter<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
     peer_manager: ResourceHandle<PeerManager>,
+    rest_api_config: RestApiConfig,
 ) -> BoxedFilter<(impl Reply,)> {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_HEALTH, public_routes, allowed_ips))
+        .and(has_network_permission(ROUTE_HEALTH, public_routes, allowed_ips))
         .and(with_tangle(tangle))
         .and(with_peer_manager(peer_manager))
-        .and_then(|tangle, peer_manager| async move { health(tangle, peer_manager) })
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(|tangle, peer_manager, rest_api_config| async move { health(tangle, peer_manager, rest_api_config) })
         .boxed()
 }
 
 pub(crate) fn health<B: StorageBackend>(
     tangle: ResourceHandle<Tangle<B>>,
     peer_manager: ResourceHandle<PeerManager>,
-) -> Result<impl Reply, Infallible> {
-    if is_healthy(&tangle, &peer_manager) {
-        Ok(StatusCode::OK)
-    } else {
-        Ok(StatusCode::SERVICE_UNAVAILABLE)
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    check_health(&tangle, &peer_manager, rest_api_config.health_max_milestone_lag())
+        .map(|_| StatusCode::OK)
+        .map_err(|reason| reject::custom(CustomRejection::ServiceUnavailable(reason)))
+}
+
+/// Returns whether the node is healthy, allowing its confirmed milestone to lag at most `max_milestone_lag` milestones
+/// behind its latest one.
+pub fn is_healthy<B: StorageBackend>(tangle: &Tangle<B>, peer_manager: &PeerManager, max_milestone_lag: u32) -> bool {
+    check_health(tangle, peer_manager, max_milestone_lag).is_ok()
+}
+
+/// Checks whether the node is healthy, returning the reason why it is not otherwise.
+/// The node is healthy if it has at least one connected peer, if its confirmed milestone index lags at most
+/// `max_milestone_lag` milestones behind its latest milestone index and if its latest milestone is recent enough.
+pub fn check_health<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    peer_manager: &PeerManager,
+    max_milestone_lag: u32,
+) -> Result<(), String> {
+    let latest_milestone_index = tangle.get_latest_milestone_index();
+    let latest_milestone_age = tangle.get_milestone(latest_milestone_index).map(|milestone| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_secs()
+            .saturating_sub(milestone.timestamp())
+    });
+
+    check_status(
+        peer_manager.connected_peers(),
+        tangle.get_confirmed_milestone_index(),
+        latest_milestone_index,
+        latest_milestone_age,
+        max_milestone_lag,
+    )
+}
+
+fn check_status(
+    connected_peers: u8,
+    confirmed_milestone_index: MilestoneIndex,
+    latest_milestone_index: MilestoneIndex,
+    latest_milestone_age: Option<u64>,
+    max_milestone_lag: u32,
+) -> Result<(), String> {
+    if connected_peers == 0 {
+        return Err("the node has no connected peers".to_string());
+    }
+
+    if *confirmed_milestone_index < latest_milestone_index.saturating_sub(max_milestone_lag) {
+        return Err(format!(
+            "the node is not synchronized: confirmed milestone {} lags behind latest milestone {}",
+            *confirmed_milestone_index, *latest_milestone_index
+        ));
+    }
+
+    match latest_milestone_age {
+        Some(age) if age > HEALTH_MILESTONE_AGE_MAX => Err(format!("the latest milestone is {} seconds old", age)),
+        Some(_) => Ok(()),
+        None => Err("the latest milestone is unknown".to_string()),
     }
 }
 
-pub fn is_healthy<B: StorageBackend>(tangle: &Tangle<B>, peer_manager: &PeerManager) -> bool {
-    if !tangle.is_confirmed_threshold(HEALTH_CONFIRMED_THRESHOLD) {
-        return false;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy() {
+        assert!(check_status(1, MilestoneIndex(10), MilestoneIndex(10), Some(0), 2).is_ok());
+        assert!(check_status(1, MilestoneIndex(8), MilestoneIndex(10), Some(0), 2).is_ok());
+    }
+
+    #[test]
+    fn no_peers() {
+        assert_eq!(
+            check_status(0, MilestoneIndex(10), MilestoneIndex(10), Some(0), 2),
+            Err("the node has no connected peers".to_string())
+        );
     }
 
-    if peer_manager.connected_peers() == 0 {
-        return false;
+    #[test]
+    fn lagging_sync() {
+        assert!(check_status(1, MilestoneIndex(7), MilestoneIndex(10), Some(0), 2).is_err());
+        // The tolerated lag is the configured one.
+        assert!(check_status(1, MilestoneIndex(7), MilestoneIndex(10), Some(0), 3).is_ok());
     }
 
-    match tangle.get_milestone(tangle.get_latest_milestone_index()) {
-        Some(milestone) => {
-            (SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Clock may have gone backwards")
-                .as_secs() as u64)
-                .saturating_sub(milestone.timestamp())
-                <= HEALTH_MILESTONE_AGE_MAX
-        }
-        None => false,
+    #[test]
+    fn stale_or_unknown_milestone() {
+        let stale = Some(HEALTH_MILESTONE_AGE_MAX + 1);
+
+        assert!(check_status(1, MilestoneIndex(10), MilestoneIndex(10), stale, 2).is_err());
+        assert!(check_status(1, MilestoneIndex(10), MilestoneIndex(10), None, 2).is_err());
     }
 }
@@ -4,15 +4,17 @@
 pub mod api;
 pub mod health;
 
-use std::net::IpAddr;
-
 use bee_gossip::NetworkCommandSender;
 use bee_ledger::workers::consensus::ConsensusWorkerCommand;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+use bee_protocol::{
+    types::metrics::NodeMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+    },
 };
 use bee_runtime::{event::Bus, node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::Tangle;
+use ipnet::IpNet;
 use tokio::sync::mpsc;
 use warp::{self, Filter, Rejection, Reply};
 
@@ -21,7 +23,7 @@ use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn filter_all<B: StorageBackend>(
     public_routes: Box<[String]>,
-    allowed_ips: Box<[IpAddr]>,
+    allowed_ips: Box<[IpNet]>,
     tangle: ResourceHandle<Tangle<B>>,
     storage: ResourceHandle<B>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
@@ -36,7 +38,8 @@ pub(crate) fn filter_all<B: StorageBackend>(
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
     consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
-) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    node_metrics: ResourceHandle<NodeMetrics>,
+) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
     api::filter(
         public_routes.clone(),
         allowed_ips.clone(),
@@ -45,7 +48,7 @@ pub(crate) fn filter_all<B: StorageBackend>(
         message_submitter,
         network_id,
         bech32_hrp,
-        rest_api_config,
+        rest_api_config.clone(),
         protocol_config,
         peer_manager.clone(),
         network_command_sender,
@@ -54,6 +57,13 @@ pub(crate) fn filter_all<B: StorageBackend>(
         message_requester,
         requested_messages,
         consensus_worker,
+        node_metrics,
     )
-    .or(health::filter(public_routes, allowed_ips, tangle, peer_manager))
+    .or(health::filter(
+        public_routes,
+        allowed_ips,
+        tangle,
+        peer_manager,
+        rest_api_config,
+    ))
 }
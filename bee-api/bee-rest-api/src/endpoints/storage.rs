@@ -6,13 +6,14 @@ use bee_message::{
     address::Ed25519Address, milestone::MilestoneIndex, output::OutputId, payload::indexation::PaddedIndex, MessageId,
 };
 use bee_storage::{
-    access::{AsIterator, Fetch},
+    access::{AsIterator, Fetch, FetchPage},
     backend,
 };
 
 pub trait StorageBackend:
     backend::StorageBackend
     + Fetch<PaddedIndex, Vec<MessageId>>
+    + FetchPage<PaddedIndex, MessageId>
     + Fetch<Ed25519Address, Vec<OutputId>>
     + Fetch<MilestoneIndex, OutputDiff>
     + Fetch<MilestoneIndex, Vec<Receipt>>
@@ -26,6 +27,7 @@ pub trait StorageBackend:
 impl<T> StorageBackend for T where
     T: backend::StorageBackend
         + Fetch<PaddedIndex, Vec<MessageId>>
+        + FetchPage<PaddedIndex, MessageId>
         + Fetch<Ed25519Address, Vec<OutputId>>
         + Fetch<MilestoneIndex, OutputDiff>
         + Fetch<MilestoneIndex, Vec<Receipt>>
@@ -257,3 +257,17 @@ pub struct WhiteFlagResponse {
 }
 
 impl BodyInner for WhiteFlagResponse {}
+
+/// Response of GET /api/plugins/indexer/messages?index={INDEX}&cursor={CURSOR}&limit={LIMIT}.
+/// Returns a page of the message ids that match a given indexation key, and the cursor of the next page if any.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerMessagesResponse {
+    pub index: String,
+    pub limit: usize,
+    #[serde(rename = "messageIds")]
+    pub message_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+impl BodyInner for IndexerMessagesResponse {}
@@ -1,7 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_common::packable::Packable;
+use bee_common::packable::{OptionError, Packable, Read, Write};
 
 macro_rules! impl_packable_test_for_num {
     ($name:ident, $ty:ident, $value:expr) => {
@@ -71,6 +71,18 @@ fn packable_vector() {
     );
 }
 
+#[test]
+fn packable_vector_huge_length_prefix() {
+    let mut bytes = u64::MAX.pack_new();
+    bytes.extend_from_slice(&[1, 2, 3]);
+
+    // Elements are unpacked one by one, the length prefix alone never triggers an allocation.
+    assert_eq!(
+        Vec::<u8>::unpack(&mut bytes.as_slice()).unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+}
+
 #[test]
 fn packable_array() {
     let array_1 = [42u8; 1024];
@@ -81,3 +93,77 @@ fn packable_array() {
     assert_eq!(array_1.packed_len(), 1024);
     assert_eq!(array_1, array_2);
 }
+
+#[derive(Debug, PartialEq)]
+struct Foo(u16);
+
+impl Packable for Foo {
+    type Error = std::io::Error;
+
+    fn packed_len(&self) -> usize {
+        self.0.packed_len()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.0.pack(writer)
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        Ok(Self(u16::unpack_inner::<R, CHECK>(reader)?))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Bar {
+    option: Option<u32>,
+    bytes: Vec<u8>,
+    foos: Vec<Foo>,
+}
+
+impl Packable for Bar {
+    type Error = OptionError<std::io::Error>;
+
+    fn packed_len(&self) -> usize {
+        self.option.packed_len() + self.bytes.packed_len() + self.foos.packed_len()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.option.pack(writer)?;
+        self.bytes.pack(writer)?;
+        self.foos.pack(writer)?;
+
+        Ok(())
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        Ok(Self {
+            option: Option::<u32>::unpack_inner::<R, CHECK>(reader)?,
+            bytes: Vec::<u8>::unpack_inner::<R, CHECK>(reader)?,
+            foos: Vec::<Foo>::unpack_inner::<R, CHECK>(reader)?,
+        })
+    }
+}
+
+#[test]
+fn packable_nested_option_vector() {
+    let bar_1 = Bar {
+        option: Some(42),
+        bytes: vec![1, 2, 3],
+        foos: vec![Foo(13), Foo(7)],
+    };
+    let bytes = bar_1.pack_new();
+
+    assert_eq!(bytes.len(), (1 + 4) + (8 + 3) + (8 + 2 * 2));
+    assert_eq!(bar_1.packed_len(), bytes.len());
+    assert_eq!(Bar::unpack(&mut bytes.as_slice()).unwrap(), bar_1);
+
+    let bar_2 = Bar {
+        option: None,
+        bytes: Vec::new(),
+        foos: Vec::new(),
+    };
+    let bytes = bar_2.pack_new();
+
+    assert_eq!(bytes.len(), 1 + 8 + 8);
+    assert_eq!(Bar::unpack(&mut bytes.as_slice()).unwrap(), bar_2);
+}
@@ -12,6 +12,7 @@ use bee_message::{
     MessageId,
 };
 use bee_runtime::{event::Bus, node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_storage::access::Durability;
 use bee_tangle::{ConflictReason, Tangle, TangleWorker};
 use futures::{channel::oneshot, stream::StreamExt};
 use log::{debug, error, info, warn};
@@ -175,6 +176,7 @@ where
         &metadata.consumed_outputs,
         &metadata.balance_diffs,
         &migration,
+        Durability::Sync,
     )?;
 
     *ledger_index = LedgerIndex(milestone.essence().index());
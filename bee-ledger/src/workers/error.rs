@@ -76,6 +76,9 @@ pub enum Error {
     /// Missing unspent output.
     #[error("Missing unspent output {0}")]
     MissingUnspentOutput(Unspent),
+    /// Invalid milestone range, either reversed or too long.
+    #[error("Invalid milestone range: {0} to {1}")]
+    InvalidMilestoneRange(MilestoneIndex, MilestoneIndex),
     /// Storage backend error.
     #[error("Storage backend error: {0}")]
     Storage(Box<dyn std::error::Error + Send>),
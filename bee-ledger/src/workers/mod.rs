@@ -9,6 +9,7 @@ pub mod event;
 pub mod pruning;
 pub mod snapshot;
 pub mod storage;
+pub mod subscription;
 
 use bee_runtime::node::{Node, NodeBuilder};
 
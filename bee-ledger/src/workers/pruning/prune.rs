@@ -8,7 +8,7 @@ use std::{
 
 use bee_message::milestone::MilestoneIndex;
 use bee_runtime::event::Bus;
-use bee_storage::access::{Batch, Truncate};
+use bee_storage::access::{Batch, Durability, Truncate};
 use bee_tangle::{solid_entry_point::SolidEntryPoint, Tangle};
 use log::{debug, info};
 
@@ -155,7 +155,7 @@ pub async fn prune<S: StorageBackend>(
         // Execute the batch operation.
         let batch_commit = Instant::now();
         storage
-            .batch_commit(batch, true)
+            .batch_commit_with(batch, Durability::Async)
             .map_err(|e| Error::Storage(Box::new(e)))?;
         timings.batch_commit = batch_commit.elapsed();
 
@@ -15,7 +15,7 @@ use bee_message::{
     payload::Payload,
     MessageId,
 };
-use bee_storage::access::{Insert, Truncate};
+use bee_storage::access::{Durability, Insert, Truncate};
 use bee_tangle::solid_entry_point::SolidEntryPoint;
 use log::info;
 use time_helper as time;
@@ -117,9 +117,25 @@ fn import_milestone_diffs<R: Read, B: StorageBackend>(
         };
 
         if index == MilestoneIndex(ledger_index + 1) {
-            apply_milestone(&*storage, index, diff.created(), &consumed, &balance_diffs, &migration)?;
+            apply_milestone(
+                storage,
+                index,
+                diff.created(),
+                &consumed,
+                &balance_diffs,
+                &migration,
+                Durability::None,
+            )?;
         } else if index == MilestoneIndex(ledger_index) {
-            rollback_milestone(&*storage, index, diff.created(), &consumed, &balance_diffs, &migration)?;
+            rollback_milestone(
+                storage,
+                index,
+                diff.created(),
+                &consumed,
+                &balance_diffs,
+                &migration,
+                Durability::None,
+            )?;
         } else {
             return Err(Error::Snapshot(SnapshotError::UnexpectedMilestoneDiffIndex(index)));
         }
@@ -290,5 +306,6 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
         }
     }
 
-    Ok(())
+    // The imported batches are not persisted one by one, the whole import is persisted at once.
+    storage::flush(storage)
 }
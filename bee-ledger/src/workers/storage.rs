@@ -3,7 +3,10 @@
 
 //! Module containing ledger storage operations.
 
-use std::collections::HashMap;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
 
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -13,7 +16,7 @@ use bee_message::{
     Message, MessageId,
 };
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Durability, Exist, Fetch, FetchRange, Insert, Truncate},
     backend,
 };
 use bee_tangle::{
@@ -59,6 +62,7 @@ pub trait StorageBackend:
     + Fetch<MilestoneIndex, Vec<Receipt>>
     + Fetch<MilestoneIndex, Vec<UnreferencedMessage>>
     + Fetch<MilestoneIndex, OutputDiff>
+    + FetchRange<MilestoneIndex, OutputDiff>
     + Insert<(), SnapshotInfo>
     + Insert<(), LedgerIndex>
     + Insert<(bool, TreasuryOutput), ()>
@@ -100,6 +104,7 @@ impl<T> StorageBackend for T where
         + Fetch<MilestoneIndex, Vec<Receipt>>
         + Fetch<MilestoneIndex, Vec<UnreferencedMessage>>
         + Fetch<MilestoneIndex, OutputDiff>
+        + FetchRange<MilestoneIndex, OutputDiff>
         + Insert<(), SnapshotInfo>
         + Insert<(), LedgerIndex>
         + Insert<(bool, TreasuryOutput), ()>
@@ -183,6 +188,13 @@ pub(crate) fn delete_created_output_batch<B: StorageBackend>(
     }
 }
 
+/// Persists all the previous commits, durably.
+pub(crate) fn flush<B: StorageBackend>(storage: &B) -> Result<(), Error> {
+    storage
+        .batch_commit_with(B::batch_begin(), Durability::Sync)
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
 pub(crate) fn create_output<B: StorageBackend>(
     storage: &B,
     output_id: &OutputId,
@@ -193,7 +205,7 @@ pub(crate) fn create_output<B: StorageBackend>(
     insert_created_output_batch(storage, &mut batch, output_id, output)?;
 
     storage
-        .batch_commit(batch, true)
+        .batch_commit_with(batch, Durability::None)
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
@@ -225,7 +237,7 @@ pub(crate) fn apply_balance_diffs<B: StorageBackend>(storage: &B, balance_diffs:
     apply_balance_diffs_batch(storage, &mut batch, balance_diffs)?;
 
     storage
-        .batch_commit(batch, true)
+        .batch_commit_with(batch, Durability::None)
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
@@ -256,6 +268,7 @@ pub(crate) fn apply_milestone<B: StorageBackend>(
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     balance_diffs: &BalanceDiffs,
     migration: &Option<Migration>,
+    durability: Durability,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
@@ -301,7 +314,7 @@ pub(crate) fn apply_milestone<B: StorageBackend>(
     .map_err(|e| Error::Storage(Box::new(e)))?;
 
     storage
-        .batch_commit(batch, true)
+        .batch_commit_with(batch, durability)
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
@@ -312,6 +325,7 @@ pub(crate) fn rollback_milestone<B: StorageBackend>(
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     balance_diffs: &BalanceDiffs,
     migration: &Option<Migration>,
+    durability: Durability,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
@@ -338,7 +352,7 @@ pub(crate) fn rollback_milestone<B: StorageBackend>(
         .map_err(|e| Error::Storage(Box::new(e)))?;
 
     storage
-        .batch_commit(batch, true)
+        .batch_commit_with(batch, durability)
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
@@ -467,20 +481,98 @@ pub(crate) fn unspend_treasury_output_batch<B: StorageBackend>(
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
+/// Maximum number of milestones whose output diffs can be fetched at once.
+pub const OUTPUT_DIFFS_RANGE_MAX_LENGTH: u32 = 1000;
+
+/// Fetches the output diffs of the milestones between `start` and `end`, both included, sorted by milestone index.
+/// Milestones without an output diff are omitted.
+///
+/// Fails with `Error::InvalidMilestoneRange` if `start` is greater than `end` or if the range spans more than
+/// `OUTPUT_DIFFS_RANGE_MAX_LENGTH` milestones.
+pub fn fetch_output_diffs_range<B: StorageBackend>(
+    storage: &B,
+    start: MilestoneIndex,
+    end: MilestoneIndex,
+) -> Result<Vec<(MilestoneIndex, OutputDiff)>, Error> {
+    if start > end || *end - *start >= OUTPUT_DIFFS_RANGE_MAX_LENGTH {
+        return Err(Error::InvalidMilestoneRange(start, end));
+    }
+
+    FetchRange::<MilestoneIndex, OutputDiff>::fetch_range(storage, &start, &end)
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+/// A balance ordered by its amount, then by its address.
+struct RankedBalance(Address, Balance);
+
+impl RankedBalance {
+    fn rank(&self) -> (u64, &Address) {
+        (self.1.amount(), &self.0)
+    }
+}
+
+impl PartialEq for RankedBalance {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank() == other.rank()
+    }
+}
+
+impl Eq for RankedBalance {}
+
+impl PartialOrd for RankedBalance {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankedBalance {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+/// Fetches the `n` addresses with the highest balances, sorted by decreasing balance amount.
+/// The balances are streamed and only the current top `n` are kept in memory.
+pub fn top_balances<B: StorageBackend>(storage: &B, n: usize) -> Result<Vec<(Address, Balance)>, Error> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    // A min-heap on the amount, so that the lowest of the current top balances is the one evicted.
+    let mut top = BinaryHeap::with_capacity(n + 1);
+
+    for result in AsIterator::<Address, Balance>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))? {
+        let (address, balance) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+
+        top.push(Reverse(RankedBalance(address, balance)));
+
+        if top.len() > n {
+            top.pop();
+        }
+    }
+
+    Ok(top
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(RankedBalance(address, balance))| (address, balance))
+        .collect())
+}
+
+/// Fetches either the spent or the unspent treasury outputs from the storage.
+/// Only the entries with the requested `spent` flag are read, the tree being keyed by the flag first.
+pub fn fetch_treasury_outputs<B: StorageBackend>(storage: &B, spent: bool) -> Result<Vec<TreasuryOutput>, Error> {
+    Ok(Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .unwrap_or_default())
+}
+
 /// Fetches the unspent treasury output from the storage.
 pub fn fetch_unspent_treasury_output<B: StorageBackend>(storage: &B) -> Result<TreasuryOutput, Error> {
-    if let Some(outputs) =
-        Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &false).map_err(|e| Error::Storage(Box::new(e)))?
-    {
-        match outputs.as_slice() {
-            // There has to be an unspent treasury output at all time.
-            [] => panic!("No unspent treasury output found"),
-            [output] => Ok(output.clone()),
-            // There should be one and only one unspent treasury output at all time.
-            _ => panic!("More than one unspent treasury output found"),
-        }
-    } else {
+    match fetch_treasury_outputs(storage, false)?.as_slice() {
         // There has to be an unspent treasury output at all time.
-        panic!("No unspent treasury output found");
+        [] => panic!("No unspent treasury output found"),
+        [output] => Ok(output.clone()),
+        // There should be one and only one unspent treasury output at all time.
+        _ => panic!("More than one unspent treasury output found"),
     }
 }
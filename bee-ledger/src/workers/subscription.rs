@@ -0,0 +1,110 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing a subscription to the confirmed milestones, replaying the most recent ones from the storage.
+
+use std::{any::Any, collections::VecDeque};
+
+use bee_message::milestone::{Milestone, MilestoneIndex};
+use bee_runtime::event::Bus;
+use bee_storage::access::Fetch;
+use log::warn;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use crate::workers::{
+    error::Error,
+    event::MilestoneConfirmed,
+    storage::{self, StorageBackend},
+};
+
+/// Number of live milestones a subscription buffers before missing the next ones.
+const LIVE_BUFFER_SIZE: usize = 1024;
+
+/// A confirmed milestone yielded by a `MilestoneConfirmedSubscription`.
+#[derive(Clone)]
+pub enum ConfirmedMilestone {
+    /// A milestone that was confirmed before the subscription, replayed from the storage.
+    Backfilled(MilestoneIndex, Milestone),
+    /// A milestone confirmed after the subscription.
+    Live(MilestoneConfirmed),
+}
+
+impl ConfirmedMilestone {
+    /// Returns the index of the confirmed milestone.
+    pub fn index(&self) -> MilestoneIndex {
+        match self {
+            Self::Backfilled(index, _) => *index,
+            Self::Live(event) => event.index,
+        }
+    }
+}
+
+/// A subscription to the `MilestoneConfirmed` events, yielding the backfilled milestones first.
+pub struct MilestoneConfirmedSubscription {
+    backfill: VecDeque<ConfirmedMilestone>,
+    live: mpsc::Receiver<MilestoneConfirmed>,
+    last_backfilled: Option<MilestoneIndex>,
+}
+
+impl MilestoneConfirmedSubscription {
+    /// Subscribes to the `MilestoneConfirmed` events with a listener bound to the type `T`, replaying up to
+    /// `backfill` of the last confirmed milestones from the storage beforehand.
+    ///
+    /// The listener is removed when `Bus::remove_listeners_by_id` is called with the `TypeId` of `T`. A subscriber
+    /// lagging more than `LIVE_BUFFER_SIZE` milestones behind misses the next ones, which shows as a gap in the
+    /// indexes.
+    pub fn subscribe<T: Any, B: StorageBackend>(bus: &Bus<'static>, storage: &B, backfill: u32) -> Result<Self, Error> {
+        let (tx, live) = mpsc::channel(LIVE_BUFFER_SIZE);
+
+        // The listener is added before reading the storage so that no milestone confirmed in between is missed.
+        bus.add_listener::<T, MilestoneConfirmed, _>(move |event: &MilestoneConfirmed| {
+            // The subscription may already have been dropped.
+            if let Err(TrySendError::Full(event)) = tx.try_send(event.clone()) {
+                warn!(
+                    "Confirmed milestone subscription is full, milestone {} is missed.",
+                    *event.index
+                );
+            }
+        });
+
+        let mut milestones = VecDeque::new();
+
+        if let Some(ledger_index) = storage::fetch_ledger_index(storage)? {
+            let end = *ledger_index.0;
+            let start = end.saturating_sub(backfill.saturating_sub(1));
+
+            if backfill > 0 {
+                for index in (start..=end).map(MilestoneIndex) {
+                    // Pruned milestones are skipped.
+                    if let Some(milestone) = Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+                        .map_err(|e| Error::Storage(Box::new(e)))?
+                    {
+                        milestones.push_back(ConfirmedMilestone::Backfilled(index, milestone));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            last_backfilled: milestones.back().map(ConfirmedMilestone::index),
+            backfill: milestones,
+            live,
+        })
+    }
+
+    /// Receives the next confirmed milestone, in index order, or `None` if the event bus was dropped.
+    pub async fn recv(&mut self) -> Option<ConfirmedMilestone> {
+        if let Some(milestone) = self.backfill.pop_front() {
+            return Some(milestone);
+        }
+
+        loop {
+            let event = self.live.recv().await?;
+
+            // Milestones confirmed while the backfill was read are already part of it.
+            if self.last_backfilled.is_none_or(|last| event.index > last) {
+                return Some(ConfirmedMilestone::Live(event));
+            }
+        }
+    }
+}
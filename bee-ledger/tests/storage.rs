@@ -0,0 +1,93 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::{
+    types::{Balance, OutputDiff},
+    workers::{error::Error, storage},
+};
+use bee_message::{address::Address, milestone::MilestoneIndex};
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::storage::Storage;
+use bee_test::rand::{address::rand_address, output_diff::rand_output_diff};
+
+#[test]
+fn top_balances() {
+    let storage = Storage::start(()).unwrap();
+    let balances = [5, 42, 1, 1_000, 7, 0, 300]
+        .into_iter()
+        .map(|amount| (rand_address(), Balance::new(amount, 0, 0).unwrap()))
+        .collect::<Vec<_>>();
+
+    for (address, balance) in &balances {
+        Insert::<Address, Balance>::insert(&storage, address, balance).unwrap();
+    }
+
+    let top = storage::top_balances(&storage, 3).unwrap();
+
+    assert_eq!(
+        top.iter().map(|(_, balance)| balance.amount()).collect::<Vec<_>>(),
+        vec![1_000, 300, 42]
+    );
+    for (address, balance) in &top {
+        assert!(balances.contains(&(*address, balance.clone())));
+    }
+
+    assert_eq!(storage::top_balances(&storage, 100).unwrap().len(), balances.len());
+    assert!(storage::top_balances(&storage, 0).unwrap().is_empty());
+}
+
+#[test]
+fn fetch_output_diffs_range() {
+    let storage = Storage::start(()).unwrap();
+    let output_diffs = [12, 3, 7, 10, 5, 1]
+        .into_iter()
+        .map(|index| (MilestoneIndex(index), rand_output_diff()))
+        .collect::<Vec<_>>();
+
+    for (index, output_diff) in &output_diffs {
+        Insert::<MilestoneIndex, OutputDiff>::insert(&storage, index, output_diff).unwrap();
+    }
+
+    let range = storage::fetch_output_diffs_range(&storage, MilestoneIndex(3), MilestoneIndex(10)).unwrap();
+
+    // The missing indexes are omitted and the diffs are sorted by index.
+    assert_eq!(
+        range.iter().map(|(index, _)| **index).collect::<Vec<_>>(),
+        vec![3, 5, 7, 10]
+    );
+    for (index, output_diff) in &range {
+        assert!(output_diffs.contains(&(*index, output_diff.clone())));
+    }
+
+    assert!(
+        storage::fetch_output_diffs_range(&storage, MilestoneIndex(13), MilestoneIndex(20))
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        storage::fetch_output_diffs_range(&storage, MilestoneIndex(7), MilestoneIndex(7))
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Reversed and overly long ranges are rejected.
+    assert!(matches!(
+        storage::fetch_output_diffs_range(&storage, MilestoneIndex(10), MilestoneIndex(3)),
+        Err(Error::InvalidMilestoneRange(..))
+    ));
+    assert!(matches!(
+        storage::fetch_output_diffs_range(&storage, MilestoneIndex(0), MilestoneIndex(u32::MAX)),
+        Err(Error::InvalidMilestoneRange(..))
+    ));
+    assert_eq!(
+        storage::fetch_output_diffs_range(
+            &storage,
+            MilestoneIndex(1),
+            MilestoneIndex(storage::OUTPUT_DIFFS_RANGE_MAX_LENGTH)
+        )
+        .unwrap()
+        .len(),
+        output_diffs.len()
+    );
+}
@@ -0,0 +1,90 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::{
+    types::LedgerIndex,
+    workers::{
+        event::MilestoneConfirmed,
+        subscription::{ConfirmedMilestone, MilestoneConfirmedSubscription},
+    },
+};
+use bee_message::milestone::{Milestone, MilestoneIndex};
+use bee_runtime::event::Bus;
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::storage::Storage;
+use bee_test::rand::{message::rand_message_id, milestone::rand_milestone};
+
+struct Subscriber;
+
+fn milestone_confirmed(index: u32) -> MilestoneConfirmed {
+    MilestoneConfirmed {
+        message_id: rand_message_id(),
+        index: MilestoneIndex(index),
+        timestamp: 0,
+        referenced_messages: 0,
+        excluded_no_transaction_messages: Vec::new(),
+        excluded_conflicting_messages: Vec::new(),
+        included_messages: Vec::new(),
+        consumed_outputs: 0,
+        created_outputs: 0,
+        receipt: false,
+    }
+}
+
+#[tokio::test]
+async fn backfill_then_live() {
+    let storage = Storage::start(()).unwrap();
+    let bus = Bus::default();
+
+    let milestones = (1..=5)
+        .map(|index| (MilestoneIndex(index), rand_milestone()))
+        .collect::<Vec<_>>();
+
+    for (index, milestone) in &milestones {
+        Insert::<MilestoneIndex, Milestone>::insert(&storage, index, milestone).unwrap();
+    }
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex(MilestoneIndex(5))).unwrap();
+
+    let mut subscription = MilestoneConfirmedSubscription::subscribe::<Subscriber, _>(&bus, &storage, 3).unwrap();
+
+    // A milestone confirmed while subscribing is both stored and dispatched, it must only be yielded once.
+    bus.dispatch(milestone_confirmed(5));
+    bus.dispatch(milestone_confirmed(6));
+    bus.dispatch(milestone_confirmed(7));
+
+    for (index, milestone) in &milestones[2..] {
+        match subscription.recv().await.unwrap() {
+            ConfirmedMilestone::Backfilled(backfilled_index, backfilled_milestone) => {
+                assert_eq!(backfilled_index, *index);
+                assert_eq!(backfilled_milestone, *milestone);
+            }
+            ConfirmedMilestone::Live(_) => panic!("expected a backfilled milestone"),
+        }
+    }
+
+    for index in [6, 7] {
+        let milestone = subscription.recv().await.unwrap();
+
+        assert!(matches!(milestone, ConfirmedMilestone::Live(_)));
+        assert_eq!(milestone.index(), MilestoneIndex(index));
+    }
+
+    bus.remove_listeners_by_id(std::any::TypeId::of::<Subscriber>());
+
+    assert!(subscription.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn no_backfill() {
+    let storage = Storage::start(()).unwrap();
+    let bus = Bus::default();
+
+    Insert::<MilestoneIndex, Milestone>::insert(&storage, &MilestoneIndex(1), &rand_milestone()).unwrap();
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex(MilestoneIndex(1))).unwrap();
+
+    let mut subscription = MilestoneConfirmedSubscription::subscribe::<Subscriber, _>(&bus, &storage, 0).unwrap();
+
+    bus.dispatch(milestone_confirmed(2));
+
+    assert_eq!(subscription.recv().await.unwrap().index(), MilestoneIndex(2));
+}
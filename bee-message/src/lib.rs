@@ -38,5 +38,5 @@ pub mod unlock;
 pub use self::{
     error::Error,
     message::{Message, MessageBuilder, MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN},
-    message_id::{MessageId, MESSAGE_ID_LENGTH},
+    message_id::{Blake2b256MessageIdHasher, MessageId, MessageIdHasher, MESSAGE_ID_LENGTH},
 };
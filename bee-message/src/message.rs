@@ -3,12 +3,11 @@
 
 use bee_common::packable::{Packable, Read, Write};
 use bee_pow::providers::{miner::Miner, NonceProvider, NonceProviderBuilder};
-use crypto::hashes::{blake2b::Blake2b256, Digest};
 
 use crate::{
     parents::Parents,
     payload::{option_payload_pack, option_payload_packed_len, option_payload_unpack, Payload},
-    Error, MessageId,
+    Blake2b256MessageIdHasher, Error, MessageId, MessageIdHasher,
 };
 
 /// The minimum number of bytes in a message.
@@ -42,10 +41,15 @@ impl Message {
 
     /// Computes the identifier of the message.
     pub fn id(&self) -> (MessageId, Vec<u8>) {
+        self.id_with::<Blake2b256MessageIdHasher>()
+    }
+
+    /// Computes the identifier of the message with the given hash function.
+    pub fn id_with<H: MessageIdHasher>(&self) -> (MessageId, Vec<u8>) {
         let bytes = self.pack_new();
-        let id = Blake2b256::digest(&bytes);
+        let id = H::hash(&bytes);
 
-        (MessageId::new(id.into()), bytes)
+        (MessageId::new(id), bytes)
     }
 
     /// Returns the network id of a `Message`.
@@ -4,13 +4,14 @@
 use core::str::FromStr;
 
 use bee_common::packable::{Packable, Read, Write};
+use crypto::hashes::{blake2b::Blake2b256, Digest};
 
 use crate::Error;
 
 /// The length of a message identifier.
 pub const MESSAGE_ID_LENGTH: usize = 32;
 
-/// A message identifier, the BLAKE2b-256 hash of the message bytes.
+/// A message identifier, the hash of the message bytes, BLAKE2b-256 unless another `MessageIdHasher` is used.
 /// See <https://www.blake2.net/> for more information.
 #[derive(Clone, Copy, Eq, Hash, PartialEq, Ord, PartialOrd)]
 pub struct MessageId([u8; MESSAGE_ID_LENGTH]);
@@ -30,6 +31,23 @@ impl MessageId {
 #[cfg(feature = "serde")]
 string_serde_impl!(MessageId);
 
+/// A hash function deriving the identifiers of the messages from their packed bytes.
+///
+/// Test networks may use another hash function than `Blake2b256MessageIdHasher` through `Message::id_with`.
+pub trait MessageIdHasher {
+    /// Hashes the packed bytes of a message into the bytes of its identifier.
+    fn hash(bytes: &[u8]) -> [u8; MESSAGE_ID_LENGTH];
+}
+
+/// The `MessageIdHasher` of the IOTA networks, hashing the message bytes with BLAKE2b-256.
+pub struct Blake2b256MessageIdHasher;
+
+impl MessageIdHasher for Blake2b256MessageIdHasher {
+    fn hash(bytes: &[u8]) -> [u8; MESSAGE_ID_LENGTH] {
+        Blake2b256::digest(bytes).into()
+    }
+}
+
 impl From<[u8; MESSAGE_ID_LENGTH]> for MessageId {
     fn from(bytes: [u8; MESSAGE_ID_LENGTH]) -> Self {
         Self(bytes)
@@ -27,5 +27,6 @@ pub use crate::{
     },
     signature::{Ed25519Signature, SignatureUnlock},
     unlock::{ReferenceUnlock, UnlockBlock, UnlockBlocks},
-    Error, Message, MessageBuilder, MessageId, MESSAGE_ID_LENGTH, MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN,
+    Blake2b256MessageIdHasher, Error, Message, MessageBuilder, MessageId, MessageIdHasher, MESSAGE_ID_LENGTH,
+    MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN,
 };
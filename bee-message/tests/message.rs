@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::str::FromStr;
+
 use bee_common::packable::Packable;
 use bee_message::prelude::*;
 use bee_pow::{
@@ -138,3 +140,41 @@ fn getters() {
     assert_eq!(*message.payload().as_ref().unwrap(), payload);
     assert_eq!(message.nonce(), nonce);
 }
+
+// The smallest valid message: network id 0, the null parent, no payload and nonce 0.
+const MINIMAL_MESSAGE: [u8; MESSAGE_LENGTH_MIN] = {
+    let mut bytes = [0u8; MESSAGE_LENGTH_MIN];
+    bytes[8] = 1;
+    bytes
+};
+
+#[test]
+fn id_default_hasher() {
+    let message = Message::unpack(&mut MINIMAL_MESSAGE.as_slice()).unwrap();
+    let (message_id, bytes) = message.id();
+
+    assert_eq!(bytes, MINIMAL_MESSAGE);
+    assert_eq!(
+        message_id,
+        MessageId::from_str("109bc166f3e17722eb845a9f29e95b97641356c8b9431701291ba399c77dcd06").unwrap()
+    );
+    assert_eq!(message.id_with::<Blake2b256MessageIdHasher>().0, message_id);
+}
+
+struct HeadHasher;
+
+impl MessageIdHasher for HeadHasher {
+    fn hash(bytes: &[u8]) -> [u8; MESSAGE_ID_LENGTH] {
+        bytes[..MESSAGE_ID_LENGTH].try_into().unwrap()
+    }
+}
+
+#[test]
+fn id_custom_hasher() {
+    let message = Message::unpack(&mut MINIMAL_MESSAGE.as_slice()).unwrap();
+    let mut expected = [0u8; MESSAGE_ID_LENGTH];
+    expected[8] = 1;
+
+    assert_eq!(message.id_with::<HeadHasher>().0, MessageId::new(expected));
+    assert_ne!(message.id_with::<HeadHasher>().0, message.id().0);
+}
@@ -101,7 +101,7 @@ async fn main() {
                             stdin().read_line(&mut msg).unwrap();
                             let msg = msg.trim_end().to_string();
 
-                            gossip_out.send(msg.into_bytes()).expect("send message");
+                            gossip_out.blocking_send(msg.into_bytes()).expect("send message");
                         }
                     });
 
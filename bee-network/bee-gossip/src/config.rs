@@ -18,6 +18,9 @@ const MIN_RECONNECT_INTERVAL_SECS: u64 = 1;
 pub const DEFAULT_MAX_UNKNOWN_PEERS: usize = 4;
 pub const DEFAULT_MAX_DISCOVERED_PEERS: usize = 4;
 
+pub const DEFAULT_GOSSIP_QUEUE_CAPACITY: usize = 1024;
+const MIN_GOSSIP_QUEUE_CAPACITY: usize = 1;
+
 /// [`NetworkConfigBuilder`] errors.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -65,7 +68,10 @@ pub struct NetworkConfig {
     pub(crate) reconnect_interval_secs: u64,
     pub(crate) max_unknown_peers: usize,
     pub(crate) max_discovered_peers: usize,
+    pub(crate) gossip_queue_capacity: usize,
     pub(crate) static_peers: HashSet<Peer>,
+    pub(crate) crc: bool,
+    pub(crate) compression: bool,
 }
 
 impl NetworkConfig {
@@ -141,6 +147,20 @@ impl NetworkConfig {
         Ok(())
     }
 
+    /// Sets whether CRC checksummed gossip is offered to peers.
+    ///
+    /// Checksummed gossip is only used with peers that offer it as well, others fall back to unchecksummed gossip.
+    pub fn set_crc(&mut self, crc: bool) {
+        self.crc = crc;
+    }
+
+    /// Sets whether gossip with compressed packets is offered to peers.
+    ///
+    /// Compressed packets are only sent to peers that offer it as well.
+    pub fn set_compression(&mut self, compression: bool) {
+        self.compression = compression;
+    }
+
     /// Returns the configured bind address as a [`Multiaddr`].
     pub fn bind_multiaddr(&self) -> &Multiaddr {
         &self.bind_multiaddr
@@ -161,10 +181,25 @@ impl NetworkConfig {
         self.max_discovered_peers
     }
 
+    /// Returns the number of packets that can be queued for a peer before sending to it fails.
+    pub fn gossip_queue_capacity(&self) -> usize {
+        self.gossip_queue_capacity
+    }
+
     /// Returns the statically configured peers.
     pub fn static_peers(&self) -> &HashSet<Peer> {
         &self.static_peers
     }
+
+    /// Returns whether CRC checksummed gossip is offered to peers.
+    pub fn crc(&self) -> bool {
+        self.crc
+    }
+
+    /// Returns whether gossip with compressed packets is offered to peers.
+    pub fn compression(&self) -> bool {
+        self.compression
+    }
 }
 
 fn resolve_dns_multiaddr(dns: Cow<'_, str>) -> Result<Protocol, Error> {
@@ -191,7 +226,10 @@ impl Default for NetworkConfig {
             reconnect_interval_secs: DEFAULT_RECONNECT_INTERVAL_SECS,
             max_unknown_peers: DEFAULT_MAX_UNKNOWN_PEERS,
             max_discovered_peers: DEFAULT_MAX_DISCOVERED_PEERS,
+            gossip_queue_capacity: DEFAULT_GOSSIP_QUEUE_CAPACITY,
             static_peers: Default::default(),
+            crc: false,
+            compression: false,
         }
     }
 }
@@ -208,6 +246,8 @@ pub struct NetworkConfigBuilder {
     max_unknown_peers: Option<usize>,
     #[serde(alias = "maxDiscoveredPeers")]
     max_discovered_peers: Option<usize>,
+    #[serde(alias = "gossipQueueCapacity")]
+    gossip_queue_capacity: Option<usize>,
     peering: ManualPeeringConfigBuilder,
 }
 
@@ -298,6 +338,15 @@ impl NetworkConfigBuilder {
         self
     }
 
+    /// Specifies the number of packets that can be queued for a peer before sending to it fails.
+    ///
+    /// The allowed minimum value for the `capacity` argument is `1`.
+    pub fn with_gossip_queue_capacity(mut self, capacity: usize) -> Self {
+        let capacity = capacity.max(MIN_GOSSIP_QUEUE_CAPACITY);
+        self.gossip_queue_capacity.replace(capacity);
+        self
+    }
+
     /// Builds the network config.
     pub fn finish(self) -> Result<NetworkConfig, Error> {
         Ok(NetworkConfig {
@@ -309,7 +358,13 @@ impl NetworkConfigBuilder {
             reconnect_interval_secs: self.reconnect_interval_secs.unwrap_or(DEFAULT_RECONNECT_INTERVAL_SECS),
             max_unknown_peers: self.max_unknown_peers.unwrap_or(DEFAULT_MAX_UNKNOWN_PEERS),
             max_discovered_peers: self.max_discovered_peers.unwrap_or(DEFAULT_MAX_DISCOVERED_PEERS),
+            gossip_queue_capacity: self
+                .gossip_queue_capacity
+                .unwrap_or(DEFAULT_GOSSIP_QUEUE_CAPACITY)
+                .max(MIN_GOSSIP_QUEUE_CAPACITY),
             static_peers: self.peering.finish()?.peers,
+            crc: false,
+            compression: false,
         })
     }
 }
@@ -357,7 +412,10 @@ impl InMemoryNetworkConfigBuilder {
             reconnect_interval_secs: DEFAULT_RECONNECT_INTERVAL_SECS,
             max_unknown_peers: DEFAULT_MAX_UNKNOWN_PEERS,
             max_discovered_peers: DEFAULT_MAX_DISCOVERED_PEERS,
+            gossip_queue_capacity: DEFAULT_GOSSIP_QUEUE_CAPACITY,
             static_peers: Default::default(),
+            crc: false,
+            compression: false,
         }
     }
 }
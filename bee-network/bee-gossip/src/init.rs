@@ -33,6 +33,9 @@ pub mod global {
     static NETWORK_ID: OnceCell<u64> = OnceCell::new();
     static MAX_UNKNOWN_PEERS: OnceCell<usize> = OnceCell::new();
     static MAX_DISCOVERED_PEERS: OnceCell<usize> = OnceCell::new();
+    static GOSSIP_QUEUE_CAPACITY: OnceCell<usize> = OnceCell::new();
+    static CRC: OnceCell<bool> = OnceCell::new();
+    static COMPRESSION: OnceCell<bool> = OnceCell::new();
 
     pub fn set_reconnect_interval_secs(reconnect_interval_secs: u64) {
         if cfg!(test) {
@@ -83,6 +86,42 @@ pub mod global {
     pub fn max_discovered_peers() -> usize {
         *MAX_DISCOVERED_PEERS.get().expect("oncecell get")
     }
+
+    pub fn set_gossip_queue_capacity(gossip_queue_capacity: usize) {
+        if cfg!(test) {
+            let _ = GOSSIP_QUEUE_CAPACITY.set(gossip_queue_capacity);
+        } else {
+            GOSSIP_QUEUE_CAPACITY.set(gossip_queue_capacity).expect("oncecell set");
+        }
+    }
+
+    pub fn gossip_queue_capacity() -> usize {
+        *GOSSIP_QUEUE_CAPACITY.get().expect("oncecell get")
+    }
+
+    pub fn set_crc(crc: bool) {
+        if cfg!(test) {
+            let _ = CRC.set(crc);
+        } else {
+            CRC.set(crc).expect("oncecell set");
+        }
+    }
+
+    pub fn crc() -> bool {
+        *CRC.get().expect("oncecell get")
+    }
+
+    pub fn set_compression(compression: bool) {
+        if cfg!(test) {
+            let _ = COMPRESSION.set(compression);
+        } else {
+            COMPRESSION.set(compression).expect("oncecell set");
+        }
+    }
+
+    pub fn compression() -> bool {
+        *COMPRESSION.get().expect("oncecell get")
+    }
 }
 
 /// Initializes a "standalone" version of the network layer.
@@ -165,13 +204,19 @@ fn init(
         reconnect_interval_secs,
         max_unknown_peers,
         max_discovered_peers,
+        gossip_queue_capacity,
         static_peers: peers,
+        crc,
+        compression,
     } = config;
 
     global::set_reconnect_interval_secs(reconnect_interval_secs);
     global::set_network_id(network_id);
     global::set_max_unknown_peers(max_unknown_peers);
     global::set_max_discovered_peers(max_discovered_peers);
+    global::set_gossip_queue_capacity(gossip_queue_capacity);
+    global::set_crc(crc);
+    global::set_compression(compression);
 
     let (command_sender, command_receiver) = command_channel();
     let (internal_command_sender, internal_command_receiver) = command_channel();
@@ -545,7 +545,7 @@ mod peerstate_tests {
     #[test]
     fn peer_state_change() {
         let mut peerstate = PeerState::Disconnected;
-        let (tx, _rx) = channel();
+        let (tx, _rx) = channel(1);
 
         peerstate.set_connected(tx);
         assert!(peerstate.is_connected());
@@ -81,6 +81,10 @@ pub enum Event {
         gossip_in: GossipReceiver,
         /// The peer's message send channel.
         gossip_out: GossipSender,
+        /// Whether gossip exchanged with the peer carries CRC checksums, as negotiated with the peer.
+        crc: bool,
+        /// Whether gossip exchanged with the peer may carry compressed packets, as negotiated with the peer.
+        compression: bool,
     },
 
     /// A peer was disconnected.
@@ -129,6 +133,10 @@ pub enum InternalEvent {
         origin: Origin,
         /// The negotiated substream the protocol is running on.
         substream: Box<NegotiatedSubstream>,
+        /// Whether gossip exchanged with that peer carries CRC checksums.
+        crc: bool,
+        /// Whether gossip exchanged with that peer may carry compressed packets.
+        compression: bool,
     },
 
     /// The gossip protocol with a peer was stopped.
@@ -12,7 +12,10 @@ use futures::{
 use libp2p::{identity, Multiaddr, PeerId};
 use log::*;
 use rand::Rng;
-use tokio::time::{self, Duration, Instant};
+use tokio::{
+    sync::mpsc::error::TrySendError,
+    time::{self, Duration, Instant},
+};
 use tokio_stream::wrappers::{IntervalStream, UnboundedReceiverStream};
 
 use super::{
@@ -404,6 +407,8 @@ async fn process_internal_event(
             peer_addr,
             origin,
             substream,
+            crc,
+            compression,
         } => {
             let mut peerlist = peerlist.0.write().await;
             let mut peer_added = false;
@@ -436,8 +441,8 @@ async fn process_internal_event(
                 let inbound_gossip_rx = BufReader::with_capacity(IO_BUFFER_LEN, r);
                 let outbound_gossip_tx = BufWriter::with_capacity(IO_BUFFER_LEN, w);
 
-                let (inbound_gossip_tx, gossip_in) = iota_gossip::channel();
-                let (gossip_out, outbound_gossip_rx) = iota_gossip::channel();
+                let (inbound_gossip_tx, gossip_in) = iota_gossip::channel(global::gossip_queue_capacity());
+                let (gossip_out, outbound_gossip_rx) = iota_gossip::channel(global::gossip_queue_capacity());
 
                 iota_gossip::start_inbound_gossip_handler(
                     peer_id,
@@ -485,6 +490,8 @@ async fn process_internal_event(
                         info: peer_info,
                         gossip_in,
                         gossip_out,
+                        crc,
+                        compression,
                     })
                     .map_err(|_| Error::SendingEventFailed)?;
             } else {
@@ -653,7 +660,12 @@ async fn disconnect_peer(peer_id: PeerId, senders: &Senders, peerlist: &PeerList
 
             // Try to send the shutdown signal. It has to be a Vec<u8>, but it doesn't have to allocate.
             // We ignore the potential error in case that peer disconnected from us already in the meantime.
-            let _ = gossip_sender.send(Vec::new());
+            // If the queue is full, the signal is sent once the stream writer catches up, without blocking the host.
+            if let Err(TrySendError::Full(signal)) = gossip_sender.try_send(Vec::new()) {
+                tokio::spawn(async move {
+                    let _ = gossip_sender.send(signal).await;
+                });
+            }
 
             Ok(())
         }
@@ -84,6 +84,8 @@ impl NetworkBehaviourEventProcess<IotaGossipEvent> for SwarmBehaviour {
                 peer_addr,
                 origin,
                 substream,
+                crc,
+                compression,
             } => {
                 trace!("Successfully negotiated IOTA gossip protocol with {}.", alias!(peer_id));
 
@@ -93,6 +95,8 @@ impl NetworkBehaviourEventProcess<IotaGossipEvent> for SwarmBehaviour {
                         peer_addr,
                         origin,
                         substream,
+                        crc,
+                        compression,
                     })
                     .expect("send internal event");
             }
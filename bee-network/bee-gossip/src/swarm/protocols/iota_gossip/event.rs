@@ -25,6 +25,8 @@ pub enum IotaGossipEvent {
         peer_addr: Multiaddr,
         origin: Origin,
         substream: Box<NegotiatedSubstream>,
+        crc: bool,
+        compression: bool,
     },
 
     /// An error occured during negotiation.
@@ -47,7 +49,11 @@ pub enum IotaGossipHandlerEvent {
     SentUpgradeRequest { to: PeerId },
 
     /// Successfully upgraded to the IOTA gossip protocol.
-    UpgradeCompleted { substream: Box<NegotiatedSubstream> },
+    UpgradeCompleted {
+        substream: Box<NegotiatedSubstream>,
+        crc: bool,
+        compression: bool,
+    },
 
     /// An errror occured during the upgrade.
     UpgradeError {
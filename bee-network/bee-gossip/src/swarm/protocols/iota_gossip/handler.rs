@@ -24,8 +24,8 @@ use super::{event::IotaGossipHandlerEvent, id::IotaGossipIdentifier, upgrade::Io
 use crate::network::origin::Origin;
 
 pub struct GossipProtocolHandler {
-    /// Exchanged protocol information necessary during negotiation.
-    info: IotaGossipIdentifier,
+    /// Exchanged protocol information necessary during negotiation, in order of preference.
+    info: Vec<IotaGossipIdentifier>,
 
     /// Keep alive setting.
     keep_alive: KeepAlive,
@@ -40,7 +40,7 @@ pub struct IotaGossipHandlerInEvent {
 }
 
 impl GossipProtocolHandler {
-    pub fn new(info: IotaGossipIdentifier) -> Self {
+    pub fn new(info: Vec<IotaGossipIdentifier>) -> Self {
         Self {
             info,
             keep_alive: KeepAlive::Yes,
@@ -96,9 +96,16 @@ impl ProtocolsHandler for GossipProtocolHandler {
     /// **libp2p docs**:
     ///
     /// Injects the output of a successful upgrade on a new inbound substream.
-    fn inject_fully_negotiated_inbound(&mut self, new_inbound: NegotiatedSubstream, _: Self::InboundOpenInfo) {
+    fn inject_fully_negotiated_inbound(
+        &mut self,
+        new_inbound: (NegotiatedSubstream, IotaGossipIdentifier),
+        _: Self::InboundOpenInfo,
+    ) {
+        let (substream, info) = new_inbound;
         let negotiated_inbound = ProtocolsHandlerEvent::Custom(IotaGossipHandlerEvent::UpgradeCompleted {
-            substream: Box::new(new_inbound),
+            substream: Box::new(substream),
+            crc: info.crc(),
+            compression: info.compression(),
         });
 
         debug!("gossip handler: fully negotiated inbound.");
@@ -112,9 +119,16 @@ impl ProtocolsHandler for GossipProtocolHandler {
     ///
     /// The second argument is the information that was previously passed to
     /// [`ProtocolsHandlerEvent::OutboundSubstreamRequest`].
-    fn inject_fully_negotiated_outbound(&mut self, new_outbound: NegotiatedSubstream, _: Self::OutboundOpenInfo) {
+    fn inject_fully_negotiated_outbound(
+        &mut self,
+        new_outbound: (NegotiatedSubstream, IotaGossipIdentifier),
+        _: Self::OutboundOpenInfo,
+    ) {
+        let (substream, info) = new_outbound;
         let negotiated_outbound = ProtocolsHandlerEvent::Custom(IotaGossipHandlerEvent::UpgradeCompleted {
-            substream: Box::new(new_outbound),
+            substream: Box::new(substream),
+            crc: info.crc(),
+            compression: info.compression(),
         });
 
         debug!("gossip handler: fully negotiated outbound.");
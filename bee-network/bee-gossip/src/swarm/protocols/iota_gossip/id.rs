@@ -4,22 +4,40 @@
 use std::fmt;
 
 #[derive(Debug, Clone)]
-pub struct IotaGossipIdentifier(String);
+pub struct IotaGossipIdentifier {
+    id: String,
+    crc: bool,
+    compression: bool,
+}
 
 impl IotaGossipIdentifier {
-    pub fn new(name: impl AsRef<str>, network_id: u64, version: impl AsRef<str>) -> Self {
-        Self(format!("/{}/{}/{}", name.as_ref(), network_id, version.as_ref()))
+    pub fn new(name: impl AsRef<str>, network_id: u64, version: impl AsRef<str>, crc: bool, compression: bool) -> Self {
+        Self {
+            id: format!("/{}/{}/{}", name.as_ref(), network_id, version.as_ref()),
+            crc,
+            compression,
+        }
+    }
+
+    /// Returns whether gossip exchanged over this protocol version carries CRC checksums.
+    pub fn crc(&self) -> bool {
+        self.crc
+    }
+
+    /// Returns whether gossip exchanged over this protocol version may carry compressed packets.
+    pub fn compression(&self) -> bool {
+        self.compression
     }
 }
 
 impl fmt::Display for IotaGossipIdentifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.id)
     }
 }
 
 impl AsRef<[u8]> for IotaGossipIdentifier {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.id.as_ref()
     }
 }
@@ -8,7 +8,7 @@ use futures::{
 use libp2p::{swarm::NegotiatedSubstream, PeerId};
 use log::*;
 use tokio::sync::mpsc;
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::ReceiverStream;
 
 use crate::{
     alias,
@@ -17,15 +17,15 @@ use crate::{
 
 const MSG_BUFFER_LEN: usize = 32768;
 
-/// A type alias for an unbounded channel sender.
-pub type GossipSender = mpsc::UnboundedSender<Vec<u8>>;
+/// A type alias for a bounded channel sender.
+pub type GossipSender = mpsc::Sender<Vec<u8>>;
 
-/// A type alias for an unbounded channel receiver.
-pub type GossipReceiver = UnboundedReceiverStream<Vec<u8>>;
+/// A type alias for a bounded channel receiver.
+pub type GossipReceiver = ReceiverStream<Vec<u8>>;
 
-pub fn channel() -> (GossipSender, GossipReceiver) {
-    let (sender, receiver) = mpsc::unbounded_channel();
-    (sender, UnboundedReceiverStream::new(receiver))
+pub fn channel(capacity: usize) -> (GossipSender, GossipReceiver) {
+    let (sender, receiver) = mpsc::channel(capacity);
+    (sender, ReceiverStream::new(receiver))
 }
 
 pub fn start_inbound_gossip_handler(
@@ -44,7 +44,8 @@ pub fn start_inbound_gossip_handler(
                 .ok()
                 .filter(|len| *len > 0)
             {
-                if inbound_gossip_tx.send(buf[..len].to_vec()).is_err() {
+                // Reading from the peer is paused while the queue is full.
+                if inbound_gossip_tx.send(buf[..len].to_vec()).await.is_err() {
                     debug!("Terminating gossip protocol with {}.", alias!(peer_id));
 
                     break;
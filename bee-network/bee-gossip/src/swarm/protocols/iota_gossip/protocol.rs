@@ -18,10 +18,23 @@ use super::{
     handler::{GossipProtocolHandler, IotaGossipHandlerInEvent},
     id::IotaGossipIdentifier,
 };
-use crate::{alias, init::global::network_id, network::origin::Origin};
+use crate::{
+    alias,
+    init::global::{compression, crc, network_id},
+    network::origin::Origin,
+};
 
 const IOTA_GOSSIP_NAME: &str = "iota-gossip";
-const IOTA_GOSSIP_VERSION: &str = "1.0.0";
+/// The gossip protocol versions, in order of preference, along with whether they carry CRC checksummed gossip and
+/// whether they allow compressed packets.
+///
+/// Versions with CRC checksums or compression are only offered if enabled.
+const IOTA_GOSSIP_VERSIONS: [(&str, bool, bool); 4] = [
+    ("1.3.0", true, true),
+    ("1.1.0", true, false),
+    ("1.2.0", false, true),
+    ("1.0.0", false, false),
+];
 
 type GossipBehaviourAction = NetworkBehaviourAction<IotaGossipEvent, GossipProtocolHandler, IotaGossipHandlerInEvent>;
 
@@ -30,10 +43,10 @@ struct ConnectionInfo {
     origin: Origin,
 }
 
-/// Substream upgrade protocol for `/iota-gossip/1.0.0`.
+/// Substream upgrade protocol for `/iota-gossip/1.0.0` to `/iota-gossip/1.3.0`.
 pub struct IotaGossipProtocol {
-    /// The gossip protocol identifier.
-    id: IotaGossipIdentifier,
+    /// The supported gossip protocol identifiers, in order of preference.
+    ids: Vec<IotaGossipIdentifier>,
 
     /// Counts the number of handlers created.
     num_handlers: usize,
@@ -60,7 +73,7 @@ impl IotaGossipProtocol {
 impl Default for IotaGossipProtocol {
     fn default() -> Self {
         Self {
-            id: IotaGossipIdentifier::new(IOTA_GOSSIP_NAME, network_id(), IOTA_GOSSIP_VERSION),
+            ids: protocol_ids(network_id(), crc(), compression()),
             num_handlers: 0,
             num_inbounds: 0,
             num_outbounds: 0,
@@ -70,6 +83,26 @@ impl Default for IotaGossipProtocol {
     }
 }
 
+/// Returns the gossip protocol identifiers to offer, in order of preference.
+///
+/// The versions with the enabled features are preferred, so that they are negotiated with peers that offer them as
+/// well, while others fall back to versions with fewer features down to the plain version.
+fn protocol_ids(network_id: u64, crc: bool, compression: bool) -> Vec<IotaGossipIdentifier> {
+    IOTA_GOSSIP_VERSIONS
+        .iter()
+        .filter(|(_, version_crc, version_compression)| (crc || !version_crc) && (compression || !version_compression))
+        .map(|(version, version_crc, version_compression)| {
+            IotaGossipIdentifier::new(
+                IOTA_GOSSIP_NAME,
+                network_id,
+                version,
+                *version_crc,
+                *version_compression,
+            )
+        })
+        .collect()
+}
+
 impl NetworkBehaviour for IotaGossipProtocol {
     type ProtocolsHandler = GossipProtocolHandler;
     type OutEvent = IotaGossipEvent;
@@ -92,7 +125,7 @@ impl NetworkBehaviour for IotaGossipProtocol {
         self.num_handlers += 1;
         debug!("gossip protocol: new handler ({}).", self.num_handlers);
 
-        GossipProtocolHandler::new(self.id.clone())
+        GossipProtocolHandler::new(self.ids.clone())
     }
 
     /// **libp2p docs**:
@@ -183,13 +216,19 @@ impl NetworkBehaviour for IotaGossipProtocol {
             IotaGossipHandlerEvent::SentUpgradeRequest { to } => {
                 NetworkBehaviourAction::GenerateEvent(IotaGossipEvent::SentUpgradeRequest { to })
             }
-            IotaGossipHandlerEvent::UpgradeCompleted { substream } => {
+            IotaGossipHandlerEvent::UpgradeCompleted {
+                substream,
+                crc,
+                compression,
+            } => {
                 if let Some(conn_info) = self.peers.remove(&peer_id) {
                     NetworkBehaviourAction::GenerateEvent(IotaGossipEvent::UpgradeCompleted {
                         peer_id,
                         peer_addr: conn_info.addr,
                         origin: conn_info.origin,
                         substream,
+                        crc,
+                        compression,
                     })
                 } else {
                     return;
@@ -255,3 +294,47 @@ impl NetworkBehaviour for IotaGossipProtocol {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc_version_only_offered_if_enabled() {
+        let ids = protocol_ids(1, false, false);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].to_string(), "/iota-gossip/1/1.0.0");
+        assert!(!ids[0].crc());
+
+        let ids = protocol_ids(1, true, false);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].to_string(), "/iota-gossip/1/1.1.0");
+        assert!(ids[0].crc());
+        assert_eq!(ids[1].to_string(), "/iota-gossip/1/1.0.0");
+        assert!(!ids[1].crc());
+    }
+
+    #[test]
+    fn compression_versions_only_offered_if_enabled() {
+        let ids = protocol_ids(1, false, true);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].to_string(), "/iota-gossip/1/1.2.0");
+        assert!(!ids[0].crc() && ids[0].compression());
+        assert_eq!(ids[1].to_string(), "/iota-gossip/1/1.0.0");
+        assert!(!ids[1].crc() && !ids[1].compression());
+
+        let ids = protocol_ids(1, true, true);
+        assert_eq!(
+            ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "/iota-gossip/1/1.3.0",
+                "/iota-gossip/1/1.1.0",
+                "/iota-gossip/1/1.2.0",
+                "/iota-gossip/1/1.0.0"
+            ]
+        );
+        assert!(ids[0].crc() && ids[0].compression());
+        assert!(ids[1].crc() && !ids[1].compression());
+        assert!(!ids[2].crc() && ids[2].compression());
+    }
+}
@@ -43,6 +43,6 @@ async fn send_recv() {
     let (_, gossip_out1) = get_gossip_channels(&mut rx1).await;
     let (mut gossip_in2, _) = get_gossip_channels(&mut rx2).await;
 
-    gossip_out1.send(b"hello".to_vec()).unwrap();
+    gossip_out1.send(b"hello".to_vec()).await.unwrap();
     assert_eq!(gossip_in2.next().await.unwrap(), b"hello");
 }
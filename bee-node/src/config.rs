@@ -25,7 +25,11 @@ use serde::Deserialize;
 use crate::plugins::dashboard::config::{DashboardConfig, DashboardConfigBuilder};
 use crate::{
     cli::ClArgs,
-    plugins::mqtt::config::{MqttConfig, MqttConfigBuilder},
+    plugins::{
+        mps::config::{MpsConfig, MpsConfigBuilder},
+        mqtt::config::{MqttConfig, MqttConfigBuilder},
+        version_checker::config::{VersionCheckerConfig, VersionCheckerConfigBuilder},
+    },
     storage::NodeStorageBackend,
     util, BECH32_HRP_DEFAULT, NETWORK_NAME_DEFAULT,
 };
@@ -59,6 +63,8 @@ pub struct NodeConfig<S: NodeStorageBackend> {
     pub(crate) storage: S::Config,
     pub(crate) tangle: TangleConfig,
     pub(crate) mqtt: MqttConfig,
+    pub(crate) mps: MpsConfig,
+    pub(crate) version_checker: VersionCheckerConfig,
     #[cfg(feature = "dashboard")]
     pub(crate) dashboard: DashboardConfig,
 }
@@ -106,6 +112,9 @@ pub struct NodeConfigBuilder<S: NodeStorageBackend> {
     pub(crate) storage: Option<S::ConfigBuilder>,
     pub(crate) tangle: Option<TangleConfigBuilder>,
     pub(crate) mqtt: Option<MqttConfigBuilder>,
+    pub(crate) mps: Option<MpsConfigBuilder>,
+    #[serde(alias = "versionChecker")]
+    pub(crate) version_checker: Option<VersionCheckerConfigBuilder>,
     #[cfg(feature = "dashboard")]
     pub(crate) dashboard: Option<DashboardConfigBuilder>,
 }
@@ -134,6 +143,8 @@ where
             storage: self_storage,
             tangle: self_tangle,
             mqtt: self_mqtt,
+            mps: self_mps,
+            version_checker: self_version_checker,
             #[cfg(feature = "dashboard")]
                 dashboard: self_dashboard,
         } = self;
@@ -151,7 +162,9 @@ where
             && (self_pruning == &other.pruning)
             && (self_storage == &other.storage)
             && (self_tangle == &other.tangle)
-            && (self_mqtt == &other.mqtt);
+            && (self_mqtt == &other.mqtt)
+            && (self_mps == &other.mps)
+            && (self_version_checker == &other.version_checker);
 
         #[cfg(feature = "dashboard")]
         return cmp && (self_dashboard == &other.dashboard);
@@ -237,6 +250,8 @@ where
                 storage: self.storage.unwrap_or_default().into(),
                 tangle: self.tangle.unwrap_or_default().finish(),
                 mqtt: self.mqtt.unwrap_or_default().finish(),
+                mps: self.mps.unwrap_or_default().finish(),
+                version_checker: self.version_checker.unwrap_or_default().finish(),
                 #[cfg(feature = "dashboard")]
                 dashboard: self.dashboard.unwrap_or_default().finish(),
             },
@@ -29,6 +29,10 @@ pub enum CoreError {
     StorageBackend(Box<dyn std::error::Error>),
     #[error("shutdown error")]
     Shutdown,
+    #[error("cyclic worker dependency: {}", .0.join(" -> "))]
+    CyclicDependency(Vec<&'static str>),
+    #[error("plugin `{0}` depends on a plugin that was not added")]
+    MissingPluginDependency(&'static str),
 }
 
 /// Encapsulates worker, resource, task management and shutdown logic.
@@ -78,46 +82,63 @@ impl<N: Node> Core<N> {
 }
 
 pub(crate) struct TopologicalOrder {
-    graph: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
+    graph: HashMap<TypeId, Vec<TypeId>, FxBuildHasher>,
     non_visited: HashSet<TypeId, FxBuildHasher>,
-    being_visited: HashSet<TypeId, FxBuildHasher>,
+    being_visited: Vec<TypeId>,
     order: Vec<TypeId>,
 }
 
 impl TopologicalOrder {
-    pub(crate) fn visit(&mut self, id: TypeId) {
+    /// Visits a worker and its dependencies, returning the offending cycle if one is found.
+    fn visit(&mut self, id: TypeId) -> Result<(), Vec<TypeId>> {
         if !self.non_visited.contains(&id) {
-            return;
+            return Ok(());
         }
 
-        if !self.being_visited.insert(id) {
-            panic!("Cyclic dependency detected.");
+        if let Some(position) = self.being_visited.iter().position(|visited| visited == &id) {
+            let mut cycle = self.being_visited[position..].to_vec();
+            cycle.push(id);
+            return Err(cycle);
         }
 
-        for &id in self.graph[&id] {
-            self.visit(id);
+        self.being_visited.push(id);
+
+        for id in self.graph[&id].clone() {
+            self.visit(id)?;
         }
 
-        self.being_visited.remove(&id);
+        self.being_visited.pop();
         self.non_visited.remove(&id);
         self.order.push(id);
+
+        Ok(())
     }
 
-    pub(crate) fn sort(graph: HashMap<TypeId, &'static [TypeId], FxBuildHasher>) -> Vec<TypeId> {
+    pub(crate) fn sort(
+        graph: HashMap<TypeId, Vec<TypeId>, FxBuildHasher>,
+        worker_names: &WorkerNameMap,
+    ) -> Result<Vec<TypeId>, CoreError> {
         let non_visited = graph.keys().copied().collect();
 
         let mut this = Self {
             graph,
             non_visited,
-            being_visited: HashSet::default(),
+            being_visited: Vec::new(),
             order: vec![],
         };
 
         while let Some(&id) = this.non_visited.iter().next() {
-            this.visit(id);
+            this.visit(id).map_err(|cycle| {
+                CoreError::CyclicDependency(
+                    cycle
+                        .iter()
+                        .map(|id| worker_names.get(id).copied().unwrap_or("unknown"))
+                        .collect(),
+                )
+            })?;
         }
 
-        this.order
+        Ok(this.order)
     }
 }
 
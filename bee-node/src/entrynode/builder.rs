@@ -33,7 +33,7 @@ use crate::{
 /// A builder to create a Bee entry node (autopeering).
 pub struct EntryNodeBuilder {
     config: EntryNodeConfig,
-    deps: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
+    deps: HashMap<TypeId, Vec<TypeId>, FxBuildHasher>,
     worker_starts: HashMap<TypeId, Box<WorkerStart<EntryNode>>>,
     worker_stops: HashMap<TypeId, Box<WorkerStop<EntryNode>>>,
     worker_names: HashMap<TypeId, &'static str>,
@@ -74,7 +74,7 @@ impl NodeBuilder<EntryNode> for EntryNodeBuilder {
 
     /// Adds a worker (with config) to the entry node builder.
     fn with_worker_cfg<W: Worker<EntryNode> + 'static>(mut self, config: W::Config) -> Self {
-        self.deps.insert(TypeId::of::<W>(), W::dependencies());
+        self.deps.insert(TypeId::of::<W>(), W::dependencies().to_vec());
         self.worker_starts.insert(
             TypeId::of::<W>(),
             Box::new(|node| {
@@ -140,7 +140,8 @@ impl NodeBuilder<EntryNode> for EntryNodeBuilder {
         let builder = initialize_api(builder);
 
         // Start the version checker.
-        let builder = builder.with_worker::<VersionChecker>();
+        let version_checker_cfg = builder.config().version_checker.clone();
+        let builder = builder.with_worker_cfg::<VersionChecker>(version_checker_cfg);
 
         // Start the autopeering event logger.
         let builder = builder.with_worker_cfg::<AutopeeringEventLogger>(autopeering_rx);
@@ -154,7 +155,7 @@ impl NodeBuilder<EntryNode> for EntryNodeBuilder {
             resource_registers,
         } = builder;
 
-        let worker_order = TopologicalOrder::sort(deps);
+        let worker_order = TopologicalOrder::sort(deps, &worker_names)?;
 
         let core = Core::new(worker_stops, worker_order, worker_names);
 
@@ -8,6 +8,7 @@ use fern_logger::LoggerConfig;
 use crate::{
     config::{NetworkSpec, NodeConfig},
     local::Local,
+    plugins::version_checker::config::VersionCheckerConfig,
     storage::NodeStorageBackend,
 };
 
@@ -24,6 +25,8 @@ pub struct EntryNodeConfig {
     pub autopeering: AutopeeringConfig,
     /// REST API.
     pub rest_api: RestApiConfig,
+    /// Version checker.
+    pub version_checker: VersionCheckerConfig,
 }
 
 impl EntryNodeConfig {
@@ -47,6 +50,7 @@ impl EntryNodeConfig {
             logger: node_cfg.logger,
             autopeering: node_cfg.autopeering,
             rest_api: node_cfg.rest_api,
+            version_checker: node_cfg.version_checker,
         }
     }
 }
@@ -26,7 +26,7 @@ use crate::plugins::Dashboard;
 use crate::{
     config::NetworkSpec,
     core::{Core, CoreError, ResourceRegister, TopologicalOrder, WorkerStart, WorkerStop},
-    plugins::{self, Mqtt, VersionChecker},
+    plugins::{self, Mps, Mqtt, PluginRegistry, VersionChecker},
     shutdown,
     storage::NodeStorageBackend,
     util, AUTOPEERING_VERSION,
@@ -36,11 +36,12 @@ use crate::{
 #[must_use]
 pub struct FullNodeBuilder<S: NodeStorageBackend> {
     config: FullNodeConfig<S>,
-    deps: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
+    deps: HashMap<TypeId, Vec<TypeId>, FxBuildHasher>,
     worker_starts: HashMap<TypeId, Box<WorkerStart<FullNode<S>>>>,
     worker_stops: HashMap<TypeId, Box<WorkerStop<FullNode<S>>>>,
     worker_names: HashMap<TypeId, &'static str>,
     resource_registers: Vec<Box<ResourceRegister<FullNode<S>>>>,
+    plugins: PluginRegistry,
 }
 
 impl<S: NodeStorageBackend> FullNodeBuilder<S> {
@@ -54,13 +55,46 @@ impl<S: NodeStorageBackend> FullNodeBuilder<S> {
     where
         P::Config: Default,
     {
-        self.with_worker::<plugins::PluginWorker<P>>()
+        self.with_plugin_cfg::<P>(P::Config::default())
     }
 
     /// Adds a plugin with config.
-    pub fn with_plugin_cfg<P: plugins::Plugin>(self, config: P::Config) -> Self {
+    pub fn with_plugin_cfg<P: plugins::Plugin>(mut self, config: P::Config) -> Self {
+        self.plugins.register::<P>();
         self.with_worker_cfg::<plugins::PluginWorker<P>>(config)
     }
+
+    /// Registers the added resources and starts the added workers and plugins in the order of their dependencies.
+    pub(crate) async fn start_workers(self) -> Result<FullNode<S>, FullNodeError> {
+        let FullNodeBuilder {
+            config,
+            mut deps,
+            mut worker_starts,
+            worker_stops,
+            worker_names,
+            resource_registers,
+            plugins,
+        } = self;
+
+        plugins.resolve(&mut deps)?;
+
+        let worker_order = TopologicalOrder::sort(deps, &worker_names)?;
+
+        let core = Core::new(worker_stops, worker_order, worker_names);
+
+        let mut full_node = FullNode { config, core };
+
+        for f in resource_registers {
+            f(&mut full_node);
+        }
+
+        // Start all workers in topological order.
+        for id in &full_node.core.worker_order.clone() {
+            worker_starts.remove(id).unwrap()(&mut full_node).await;
+        }
+
+        Ok(full_node)
+    }
 }
 
 #[async_trait(?Send)]
@@ -77,6 +111,7 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
             worker_stops: HashMap::default(),
             worker_names: HashMap::default(),
             resource_registers: Vec::default(),
+            plugins: PluginRegistry::default(),
         })
     }
 
@@ -90,7 +125,7 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
 
     /// Adds a worker (with config) to the full node.
     fn with_worker_cfg<W: Worker<FullNode<S>> + 'static>(mut self, config: W::Config) -> Self {
-        self.deps.insert(TypeId::of::<W>(), W::dependencies());
+        self.deps.insert(TypeId::of::<W>(), W::dependencies().to_vec());
         self.worker_starts.insert(
             TypeId::of::<W>(),
             Box::new(|node| {
@@ -154,11 +189,16 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
         let builder = initialize_tangle(builder);
 
         // Start the version checker.
-        let builder = builder.with_worker::<VersionChecker>();
+        let version_checker_cfg = builder.config().version_checker.clone();
+        let builder = builder.with_worker_cfg::<VersionChecker>(version_checker_cfg);
 
         // Start the MQTT broker.
         let mqtt_cfg = builder.config().mqtt.clone();
-        let builder = builder.with_worker_cfg::<Mqtt>(mqtt_cfg);
+        let builder = builder.with_plugin_cfg::<Mqtt>(mqtt_cfg);
+
+        // Start tracking the messages per second metrics.
+        let mps_cfg = builder.config().mps.clone();
+        let builder = builder.with_plugin_cfg::<Mps>(mps_cfg);
 
         // Start serving the dashboard (if enabled).
         #[cfg(feature = "dashboard")]
@@ -167,30 +207,7 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
             builder.with_worker_cfg::<Dashboard>(dashboard_cfg)
         };
 
-        let FullNodeBuilder {
-            config,
-            deps,
-            mut worker_starts,
-            worker_stops,
-            worker_names,
-            resource_registers,
-            ..
-        } = builder;
-
-        let worker_order = TopologicalOrder::sort(deps);
-
-        let core = Core::new(worker_stops, worker_order, worker_names);
-
-        let mut full_node = FullNode { config, core };
-
-        for f in resource_registers {
-            f(&mut full_node);
-        }
-
-        // Start all workers in topological order.
-        for id in &full_node.core.worker_order.clone() {
-            worker_starts.remove(id).unwrap()(&mut full_node).await;
-        }
+        let full_node = builder.start_workers().await?;
 
         log::info!("Initialized.");
 
@@ -255,7 +272,11 @@ fn initialize_gossip_layer<S: NodeStorageBackend>(
 
     let keypair = config.local().keypair().clone();
     let network_id = config.network_spec().id();
-    let gossip_cfg = config.network.clone();
+    let mut gossip_cfg = config.network.clone();
+    // CRC checksummed and compressed gossip are negotiated per peer, they are only used with peers that have them
+    // enabled as well.
+    gossip_cfg.set_crc(config.protocol.tlv_crc());
+    gossip_cfg.set_compression(config.protocol.tlv_compression());
 
     let (builder, network_events) =
         bee_gossip::integrated::init::<FullNode<S>>(gossip_cfg, keypair, network_id, builder)
@@ -12,7 +12,11 @@ use fern_logger::LoggerConfig;
 #[cfg(feature = "dashboard")]
 use crate::plugins::dashboard::config::DashboardConfig;
 use crate::{
-    config::NetworkSpec, local::Local, plugins::mqtt::config::MqttConfig, storage::NodeStorageBackend, NodeConfig,
+    config::NetworkSpec,
+    local::Local,
+    plugins::{mps::config::MpsConfig, mqtt::config::MqttConfig, version_checker::config::VersionCheckerConfig},
+    storage::NodeStorageBackend,
+    NodeConfig,
 };
 
 /// The config of a Bee full node.
@@ -43,6 +47,10 @@ pub struct FullNodeConfig<S: NodeStorageBackend> {
     pub tangle: TangleConfig,
     /// MQTT broker.
     pub mqtt: MqttConfig,
+    /// Messages per second metrics.
+    pub mps: MpsConfig,
+    /// Version checker.
+    pub version_checker: VersionCheckerConfig,
     /// Node dashboard.
     #[cfg(feature = "dashboard")]
     pub dashboard: DashboardConfig,
@@ -79,6 +87,8 @@ impl<S: NodeStorageBackend> FullNodeConfig<S> {
             storage: node_cfg.storage,
             tangle: node_cfg.tangle,
             mqtt: node_cfg.mqtt,
+            mps: node_cfg.mps,
+            version_checker: node_cfg.version_checker,
             #[cfg(feature = "dashboard")]
             dashboard: node_cfg.dashboard,
         }
@@ -101,6 +111,8 @@ impl<S: NodeStorageBackend> Clone for FullNodeConfig<S> {
             storage: self.storage.clone(),
             tangle: self.tangle.clone(),
             mqtt: self.mqtt.clone(),
+            mps: self.mps.clone(),
+            version_checker: self.version_checker.clone(),
             #[cfg(feature = "dashboard")]
             dashboard: self.dashboard.clone(),
         }
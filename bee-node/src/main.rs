@@ -5,7 +5,7 @@ use std::{error::Error, path::Path};
 
 use bee_gossip::Keypair;
 use bee_node::{
-    plugins, print_banner_and_version, read_keypair_from_pem_file,
+    print_banner_and_version, read_keypair_from_pem_file,
     tools::{self},
     write_keypair_to_pem_file, ClArgs, EntryNodeBuilder, EntryNodeConfig, FullNodeBuilder, FullNodeConfig, Local,
     NodeConfig, NodeConfigBuilder, PemFileError,
@@ -163,7 +163,7 @@ async fn start_fullnode(local: Local, config: NodeConfig<Storage>) {
     let node_builder = FullNodeBuilder::<Storage>::new(full_node_config);
 
     match node_builder {
-        Ok(builder) => match builder.with_plugin::<plugins::Mps>().finish().await {
+        Ok(builder) => match builder.finish().await {
             Ok(node) => {
                 if let Err(e) = node.run().await {
                     log::error!("Failed to run full node: {}", e);
@@ -1,7 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::{
+    net::{IpAddr, SocketAddr, ToSocketAddrs},
+    time::Duration,
+};
 
 use multiaddr::{Multiaddr, Protocol};
 use serde::Deserialize;
@@ -11,6 +14,7 @@ const DEFAULT_USER: &str = "admin";
 const DEFAULT_PASSWORD_SALT: &str = "0000000000000000000000000000000000000000000000000000000000000000";
 const DEFAULT_PASSWORD_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
 const DEFAULT_BIND_ADDRESS: &str = "/ip4/0.0.0.0/tcp/8081";
+const DEFAULT_SYNC_STATUS_INTERVAL: u64 = 250;
 
 #[derive(Default, Deserialize, PartialEq)]
 pub struct DashboardAuthConfigBuilder {
@@ -73,6 +77,8 @@ pub struct DashboardConfigBuilder {
     #[serde(alias = "bindAddress")]
     bind_address: Option<Multiaddr>,
     auth: Option<DashboardAuthConfigBuilder>,
+    #[serde(alias = "syncStatusInterval")]
+    sync_status_interval: Option<u64>,
 }
 
 impl DashboardConfigBuilder {
@@ -112,6 +118,9 @@ impl DashboardConfigBuilder {
         DashboardConfig {
             bind_socket_addr: SocketAddr::new(address, port),
             auth: self.auth.unwrap_or_default().finish(),
+            sync_status_interval: Duration::from_millis(
+                self.sync_status_interval.unwrap_or(DEFAULT_SYNC_STATUS_INTERVAL),
+            ),
         }
     }
 }
@@ -120,6 +129,7 @@ impl DashboardConfigBuilder {
 pub struct DashboardConfig {
     bind_socket_addr: SocketAddr,
     auth: DashboardAuthConfig,
+    sync_status_interval: Duration,
 }
 
 impl DashboardConfig {
@@ -134,4 +144,8 @@ impl DashboardConfig {
     pub fn auth(&self) -> &DashboardAuthConfig {
         &self.auth
     }
+
+    pub fn sync_status_interval(&self) -> Duration {
+        self.sync_status_interval
+    }
 }
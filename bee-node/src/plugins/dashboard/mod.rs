@@ -13,12 +13,14 @@ mod workers;
 use std::{
     any::{Any, TypeId},
     convert::Infallible,
+    sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
 use bee_ledger::workers::event::MilestoneConfirmed;
 use bee_protocol::workers::{
-    event::{MessageSolidified, MpsMetricsUpdated, TipAdded, TipRemoved, VertexCreated},
+    event::{MessageSolidified, MpsMetricsUpdated, PeerAdded, PeerRemoved, TipAdded, TipRemoved, VertexCreated},
     MetricsWorker, PeerManagerResWorker,
 };
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
@@ -27,20 +29,26 @@ use futures::stream::StreamExt;
 use log::{debug, error, info};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use warp::ws::Message;
 
 use crate::{
     fullnode::config::FullNodeConfig,
-    plugins::dashboard::{
-        config::DashboardConfig,
-        websocket::{
-            responses::{milestone, milestone_info, sync_status, WsEvent},
-            WsUsers,
-        },
-        workers::{
-            confirmed_ms_metrics::confirmed_ms_metrics_worker, db_size_metrics::db_size_metrics_worker,
-            node_status::node_status_worker, peer_metric::peer_metric_worker,
+    plugins::{
+        dashboard::{
+            config::DashboardConfig,
+            websocket::{
+                coalesce::coalesce,
+                responses::{milestone, milestone_info, sync_status, WsEvent},
+                WsUsers,
+            },
+            workers::{
+                confirmed_mps::confirmed_mps_worker, confirmed_ms_metrics::confirmed_ms_metrics_worker,
+                db_size_metrics::db_size_metrics_worker, node_status::node_status_worker,
+                peer_metric::peer_metric_worker,
+            },
         },
+        mps::MpsAverageUpdated,
+        version_checker::VersionCheckResult,
+        Mps, PluginWorker,
     },
     storage::NodeStorageBackend,
 };
@@ -50,41 +58,75 @@ const CONFIRMED_THRESHOLD: u32 = 5;
 #[derive(Default)]
 pub struct Dashboard {}
 
+// Builds the event of a topic, deferred to the topic worker so that dropped events are never built.
+type TopicForwarder = Box<dyn FnOnce() -> WsEvent + Send>;
+
 fn topic_handler<N, E, F>(node: &mut N, topic: &'static str, users: &WsUsers, require_node_confirmed: bool, f: F)
 where
     N: Node,
     N::Backend: NodeStorageBackend,
     E: Any + Clone + Send + Sync,
     F: 'static + Fn(E) -> WsEvent + Send + Sync,
+{
+    let tx = topic_worker(node, topic, users, require_node_confirmed, None);
+
+    topic_listener(node, &tx, f);
+}
+
+// Spawns the worker broadcasting the events of a topic, coalescing them to at most one per `interval` if provided.
+fn topic_worker<N>(
+    node: &mut N,
+    topic: &'static str,
+    users: &WsUsers,
+    require_node_confirmed: bool,
+    interval: Option<Duration>,
+) -> mpsc::UnboundedSender<TopicForwarder>
+where
+    N: Node,
+    N::Backend: NodeStorageBackend,
 {
     let tangle = node.resource::<Tangle<N::Backend>>();
-    let bus = node.bus();
     let users = users.clone();
-    let (tx, rx) = mpsc::unbounded_channel();
+    let (tx, rx) = mpsc::unbounded_channel::<TopicForwarder>();
 
     node.spawn::<Dashboard, _, _>(|shutdown| async move {
         debug!("Ws {} topic handler running.", topic);
 
-        let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+        let receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+        let mut receiver = match interval {
+            Some(interval) => coalesce(receiver, interval).boxed(),
+            None => receiver.boxed(),
+        };
 
-        while let Some(event) = receiver.next().await {
-            if require_node_confirmed {
-                if tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
-                    broadcast(f(event), &users).await;
-                }
-            } else {
-                broadcast(f(event), &users).await;
+        while let Some(forward) = receiver.next().await {
+            if !require_node_confirmed || tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
+                broadcast(forward(), &users).await;
             }
         }
 
         debug!("Ws {} topic handler stopped.", topic);
     });
 
-    bus.add_listener::<Dashboard, E, _>(move |event: &E| {
+    tx
+}
+
+// Forwards the events of type `E` to a topic worker.
+fn topic_listener<N, E, F>(node: &mut N, tx: &mpsc::UnboundedSender<TopicForwarder>, f: F)
+where
+    N: Node,
+    E: Any + Clone + Send + Sync,
+    F: 'static + Fn(E) -> WsEvent + Send + Sync,
+{
+    let f = Arc::new(f);
+    let tx = tx.clone();
+
+    node.bus().add_listener::<Dashboard, E, _>(move |event: &E| {
+        let f = f.clone();
+        let event = (*event).clone();
         // The lifetime of the listeners is tied to the lifetime of the Dashboard worker so they are removed together.
         // However, topic handlers are shutdown as soon as the signal is received, causing this send to potentially
         // fail and spam the output. The return is then ignored as not being essential.
-        let _ = tx.send((*event).clone());
+        let _ = tx.send(Box::new(move || f(event)));
     });
 }
 
@@ -101,6 +143,7 @@ where
             TypeId::of::<TangleWorker>(),
             TypeId::of::<MetricsWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
+            TypeId::of::<PluginWorker<Mps>>(),
         ]
         .leak()
     }
@@ -117,20 +160,19 @@ where
 
         // Register event handlers
         {
-            let tangle = tangle.clone();
-            topic_handler(
-                node,
-                "SyncStatus",
-                &users,
-                false,
-                move |event: LatestMilestoneChanged| sync_status::forward_latest_milestone_changed(event, &tangle),
-            );
-        }
-        {
-            let tangle = tangle.clone();
-            topic_handler(node, "SyncStatus", &users, false, move |event: MilestoneConfirmed| {
-                sync_status::forward_confirmed_milestone_changed(&event, &tangle)
-            });
+            let sync_status_tx = topic_worker(node, "SyncStatus", &users, false, Some(config.sync_status_interval()));
+            {
+                let tangle = tangle.clone();
+                topic_listener(node, &sync_status_tx, move |event: LatestMilestoneChanged| {
+                    sync_status::forward_latest_milestone_changed(event, &tangle)
+                });
+            }
+            {
+                let tangle = tangle.clone();
+                topic_listener(node, &sync_status_tx, move |event: MilestoneConfirmed| {
+                    sync_status::forward_confirmed_milestone_changed(&event, &tangle)
+                });
+            }
         }
         topic_handler(
             node,
@@ -158,9 +200,26 @@ where
         );
         topic_handler(node, "TipInfo", &users, true, <WsEvent as From<TipAdded>>::from);
         topic_handler(node, "TipInfo", &users, true, <WsEvent as From<TipRemoved>>::from);
+        topic_handler(
+            node,
+            "Version",
+            &users,
+            false,
+            <WsEvent as From<VersionCheckResult>>::from,
+        );
+        topic_handler(
+            node,
+            "MpsAverage",
+            &users,
+            false,
+            <WsEvent as From<MpsAverageUpdated>>::from,
+        );
+        topic_handler(node, "PeerList", &users, false, <WsEvent as From<PeerAdded>>::from);
+        topic_handler(node, "PeerList", &users, false, <WsEvent as From<PeerRemoved>>::from);
 
         // run sub-workers
         confirmed_ms_metrics_worker(node, &users);
+        confirmed_mps_worker(node, &users);
         db_size_metrics_worker(node, &users);
         node_status_worker(node, &users);
         peer_metric_worker(node, &users);
@@ -208,10 +267,7 @@ pub(crate) async fn broadcast(event: WsEvent, users: &WsUsers) {
         Ok(as_text) => {
             for (_, user) in users.read().await.iter() {
                 if user.topics.contains(&event.kind) {
-                    if let Err(_disconnected) = user.tx.send(Ok(Message::text(as_text.clone()))) {
-                        // The tx is disconnected, our `user_disconnected` code should be happening in another task,
-                        // nothing more to do here.
-                    }
+                    user.send_text(&event.kind, as_text.clone());
                 }
             }
         }
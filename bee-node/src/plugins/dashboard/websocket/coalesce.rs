@@ -0,0 +1,68 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use futures::{stream, Stream, StreamExt};
+use tokio::time::{timeout_at, Instant};
+
+/// Coalesces the items of a stream so that at most one item is yielded per `interval`.
+/// The first item is yielded right away, then the items received during the following `interval` are dropped in
+/// favour of the most recent one, which is yielded when the `interval` elapses.
+pub(crate) fn coalesce<S>(stream: S, interval: Duration) -> impl Stream<Item = S::Item>
+where
+    S: Stream + Unpin,
+{
+    stream::unfold(
+        (stream.fuse(), None::<Instant>),
+        move |(mut stream, last_yield)| async move {
+            let mut latest = stream.next().await?;
+
+            if let Some(last_yield) = last_yield {
+                let deadline = last_yield + interval;
+
+                while let Ok(Some(item)) = timeout_at(deadline, stream.next()).await {
+                    latest = item;
+                }
+            }
+
+            Some((latest, (stream, Some(Instant::now()))))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn coalesce_rapid_sequence() {
+        let items = coalesce(stream::iter(1..=10), Duration::from_millis(250))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(items, vec![1, 10]);
+    }
+
+    #[tokio::test]
+    async fn coalesce_spaced_sequence() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut items = Box::pin(coalesce(
+            tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
+            Duration::from_millis(50),
+        ));
+
+        tx.send(1).unwrap();
+        assert_eq!(items.next().await, Some(1));
+
+        tx.send(2).unwrap();
+        tx.send(3).unwrap();
+        assert_eq!(items.next().await, Some(3));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        tx.send(4).unwrap();
+        drop(tx);
+        assert_eq!(items.next().await, Some(4));
+        assert_eq!(items.next().await, None);
+    }
+}
@@ -1,7 +1,9 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod coalesce;
 mod commands;
+pub(crate) mod queue;
 pub(crate) mod responses;
 mod topics;
 
@@ -16,13 +18,16 @@ use std::{
 use auth_helper::jwt::JsonWebToken;
 use bee_runtime::{resource::ResourceHandle, shutdown_stream::ShutdownStream};
 use bee_tangle::Tangle;
-use futures::{channel::oneshot, FutureExt, StreamExt};
+use futures::{channel::oneshot, stream, FutureExt, StreamExt};
 use log::{debug, error};
-use tokio::sync::{mpsc, RwLock};
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio::sync::RwLock;
 use warp::ws::{Message, WebSocket};
 
-use self::{commands::WsCommand, topics::WsTopic};
+use self::{
+    commands::WsCommand,
+    queue::{QueueError, WsQueue},
+    topics::WsTopic,
+};
 use crate::{
     plugins::dashboard::{
         auth::AUDIENCE_CLAIM,
@@ -37,8 +42,11 @@ use crate::{
 /// Our global unique user id counter.
 static NEXT_USER_ID: AtomicUsize = AtomicUsize::new(1);
 
+/// Maximum number of messages waiting to be sent to a user.
+const WS_USER_QUEUE_CAPACITY: usize = 1024;
+
 pub(crate) struct WsUser {
-    pub(crate) tx: mpsc::UnboundedSender<Result<Message, warp::Error>>,
+    pub(crate) queue: Arc<WsQueue>,
     pub(crate) shutdown: Option<oneshot::Sender<()>>,
     pub(crate) topics: HashSet<WsTopic>,
     pub(crate) shutdown_ready: Option<oneshot::Receiver<()>>,
@@ -47,15 +55,24 @@ pub(crate) struct WsUser {
 impl WsUser {
     pub(crate) fn send(&self, event: WsEvent) {
         match serde_json::to_string(&event) {
-            Ok(as_text) => {
-                if self.tx.send(Ok(Message::text(as_text))).is_err() {
-                    // The tx is disconnected, our `user_disconnected` code should be happening in another task, nothing
-                    // more to do here.
-                }
-            }
+            Ok(as_text) => self.send_text(&event.kind, as_text),
             Err(e) => error!("can not convert event to string: {}", e),
         }
     }
+
+    pub(crate) fn send_text(&self, topic: &WsTopic, text: String) {
+        match self.queue.push(topic.overflow_policy(), Message::text(text)) {
+            Ok(()) => {}
+            Err(QueueError::Overflow) => {
+                // The queue is now closed, which tears the connection down and triggers `user_disconnected`.
+                debug!("Ws user too slow to receive {:?} events, disconnecting.", topic);
+            }
+            Err(QueueError::Closed) => {
+                // The user is disconnected, our `user_disconnected` code should be happening in another task, nothing
+                // more to do here.
+            }
+        }
+    }
 }
 
 pub(crate) type WsUsers = Arc<RwLock<HashMap<usize, WsUser>>>;
@@ -76,11 +93,14 @@ pub(crate) async fn user_connected<S: NodeStorageBackend>(
     // Split the socket into a sender and receive of messages.
     let (ws_tx, mut ws_rx) = ws.split();
 
-    // Use an unbounded channel to handle buffering and flushing of messages
+    // Use a bounded queue to handle buffering and flushing of messages
     // to the websocket...
-    let (tx, rx) = mpsc::unbounded_channel();
+    let queue = Arc::new(WsQueue::new(WS_USER_QUEUE_CAPACITY));
     let (shutdown_tx, shutdown_rx) = oneshot::channel();
-    let receiver = ShutdownStream::new(shutdown_rx, UnboundedReceiverStream::new(rx));
+    let messages = stream::unfold(queue.clone(), |queue| async move {
+        queue.pop().await.map(|message| (Ok(message), queue))
+    });
+    let receiver = ShutdownStream::new(shutdown_rx, Box::pin(messages));
 
     let task = tokio::spawn(receiver.forward(ws_tx).map(|result| {
         if let Err(e) = result {
@@ -93,7 +113,7 @@ pub(crate) async fn user_connected<S: NodeStorageBackend>(
     users.write().await.insert(
         user_id,
         WsUser {
-            tx,
+            queue,
             shutdown: Some(shutdown_tx),
             topics: HashSet::new(),
             shutdown_ready: Some(shutdown_ready_rx),
@@ -129,39 +149,20 @@ async fn user_message<S: NodeStorageBackend>(
     node_id: &str,
     auth_config: &DashboardAuthConfig,
 ) {
-    if !msg.is_binary() {
-        return;
-    }
-
-    let bytes = msg.as_bytes();
-
-    if bytes.len() < 2 {
-        return;
-    }
-
-    let command: WsCommand = match bytes[0].try_into() {
-        Ok(command) => command,
-        Err(e) => {
-            error!("Unknown websocket command: {}.", e);
-            return;
-        }
-    };
-    let topic: WsTopic = match bytes[1].try_into() {
-        Ok(topic) => topic,
-        Err(e) => {
-            error!("Unknown websocket topic: {}.", e);
-            return;
-        }
+    let (command, topic, jwt) = match parse_command(&msg) {
+        Some(parsed) => parsed,
+        None => return,
     };
 
     if let Some(user) = users.write().await.get_mut(&user_id) {
         match command {
             WsCommand::Register => {
                 if !topic.is_public() {
-                    if bytes.len() < 3 {
-                        return;
-                    }
-                    let jwt = JsonWebToken::from(match String::from_utf8(bytes[2..].to_vec()) {
+                    let jwt = match jwt {
+                        Some(jwt) => jwt,
+                        None => return,
+                    };
+                    let jwt = JsonWebToken::from(match String::from_utf8(jwt.to_vec()) {
                         Ok(jwt) => jwt,
                         Err(e) => {
                             error!("Invalid provided JWT: {}", e);
@@ -180,19 +181,77 @@ async fn user_message<S: NodeStorageBackend>(
                         return;
                     }
                 }
+                debug!("User {} registered to topic {}.", user_id, topic);
                 send_init_values(&topic, user, tangle, storage);
                 let _ = user.topics.insert(topic);
             }
             WsCommand::Unregister => {
+                debug!("User {} unregistered from topic {}.", user_id, topic);
                 let _ = user.topics.remove(&topic);
             }
         }
     }
 }
 
+/// Parses a command sent by a user along with its topic and optional JWT. A command is either binary, a command byte
+/// followed by a topic byte and the JWT, or text, `register <topic> [jwt]` or `unregister <topic>` with the topic name.
+fn parse_command(msg: &Message) -> Option<(WsCommand, WsTopic, Option<&[u8]>)> {
+    if msg.is_binary() {
+        let bytes = msg.as_bytes();
+
+        if bytes.len() < 2 {
+            return None;
+        }
+
+        let command = match bytes[0].try_into() {
+            Ok(command) => command,
+            Err(e) => {
+                error!("Unknown websocket command: {}.", e);
+                return None;
+            }
+        };
+        let topic = match bytes[1].try_into() {
+            Ok(topic) => topic,
+            Err(e) => {
+                error!("Unknown websocket topic: {}.", e);
+                return None;
+            }
+        };
+        let jwt = Some(&bytes[2..]).filter(|jwt| !jwt.is_empty());
+
+        Some((command, topic, jwt))
+    } else if let Ok(text) = msg.to_str() {
+        let mut parts = text.split_whitespace();
+
+        let command = match parts.next() {
+            Some("register") => WsCommand::Register,
+            Some("unregister") => WsCommand::Unregister,
+            command => {
+                error!("Unknown websocket command: {:?}.", command);
+                return None;
+            }
+        };
+        let topic = match parts.next()?.parse::<WsTopic>() {
+            Ok(topic) => topic,
+            Err(e) => {
+                error!("{}.", e);
+                return None;
+            }
+        };
+        let jwt = parts.next().map(str::as_bytes);
+
+        Some((command, topic, jwt))
+    } else {
+        None
+    }
+}
+
 async fn user_disconnected(user_id: usize, users: &WsUsers) {
     debug!("User {} disconnected.", user_id);
-    users.write().await.remove(&user_id);
+    if let Some(user) = users.write().await.remove(&user_id) {
+        // Closing the queue ends the task sending its messages to the websocket.
+        user.queue.close();
+    }
 }
 
 fn send_init_values<S: NodeStorageBackend>(topic: &WsTopic, user: &WsUser, tangle: &Tangle<S>, storage: &S) {
@@ -200,10 +259,11 @@ fn send_init_values<S: NodeStorageBackend>(topic: &WsTopic, user: &WsUser, tangl
         WsTopic::SyncStatus => {
             let event = WsEvent::new(
                 WsTopic::SyncStatus,
-                WsEventInner::SyncStatus(SyncStatusResponse {
-                    lmi: *tangle.get_latest_milestone_index(),
-                    cmi: *tangle.get_confirmed_milestone_index(),
-                }),
+                WsEventInner::SyncStatus(SyncStatusResponse::new(
+                    *tangle.get_latest_milestone_index(),
+                    *tangle.get_confirmed_milestone_index(),
+                    tangle,
+                )),
             );
             user.send(event);
         }
@@ -220,3 +280,41 @@ fn send_init_values<S: NodeStorageBackend>(topic: &WsTopic, user: &WsUser, tangl
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_binary_command() {
+        let msg = Message::binary(vec![0, 2, b'j', b'w', b't']);
+
+        assert!(matches!(
+            parse_command(&msg),
+            Some((WsCommand::Register, WsTopic::NodeStatus, Some(b"jwt")))
+        ));
+        assert!(matches!(
+            parse_command(&Message::binary(vec![1, 0])),
+            Some((WsCommand::Unregister, WsTopic::SyncStatus, None))
+        ));
+        assert!(parse_command(&Message::binary(vec![2, 0])).is_none());
+        assert!(parse_command(&Message::binary(vec![0])).is_none());
+    }
+
+    #[test]
+    fn parse_text_command() {
+        let msg = Message::text("register node_status jwt");
+
+        assert!(matches!(
+            parse_command(&msg),
+            Some((WsCommand::Register, WsTopic::NodeStatus, Some(b"jwt")))
+        ));
+        assert!(matches!(
+            parse_command(&Message::text("unregister sync_status")),
+            Some((WsCommand::Unregister, WsTopic::SyncStatus, None))
+        ));
+        assert!(parse_command(&Message::text("register sync_stats")).is_none());
+        assert!(parse_command(&Message::text("subscribe sync_status")).is_none());
+        assert!(parse_command(&Message::text("register")).is_none());
+    }
+}
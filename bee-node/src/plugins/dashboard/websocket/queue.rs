@@ -0,0 +1,188 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use tokio::sync::Notify;
+use warp::ws::Message;
+
+/// What to do with a message that doesn't fit in a full queue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum OverflowPolicy {
+    /// The message supersedes the previous ones of its kind: the oldest droppable message is dropped to make room.
+    DropOldest,
+    /// The message can't be lost: the client is disconnected if no droppable message can make room.
+    Disconnect,
+}
+
+/// Error returned when a message can't be queued.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum QueueError {
+    /// The queue was full of messages that can't be dropped, the client has to be disconnected.
+    Overflow,
+    /// The queue was closed.
+    Closed,
+}
+
+struct WsQueueInner {
+    messages: VecDeque<(OverflowPolicy, Message)>,
+    closed: bool,
+}
+
+/// A bounded queue of the messages waiting to be sent to a websocket client.
+pub(crate) struct WsQueue {
+    inner: Mutex<WsQueueInner>,
+    notify: Notify,
+    capacity: usize,
+}
+
+impl WsQueue {
+    /// Creates a new `WsQueue` holding at most `capacity` messages.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(WsQueueInner {
+                messages: VecDeque::with_capacity(capacity),
+                closed: false,
+            }),
+            notify: Notify::new(),
+            capacity,
+        }
+    }
+
+    /// Queues a message, applying its overflow policy if the queue is full.
+    /// The queue is closed if the message overflows it and can't be dropped.
+    pub(crate) fn push(&self, policy: OverflowPolicy, message: Message) -> Result<(), QueueError> {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.closed {
+            return Err(QueueError::Closed);
+        }
+
+        if inner.messages.len() >= self.capacity {
+            match inner
+                .messages
+                .iter()
+                .position(|(policy, _)| *policy == OverflowPolicy::DropOldest)
+            {
+                Some(oldest) => {
+                    inner.messages.remove(oldest);
+                }
+                None => match policy {
+                    // Every queued message is more important than this one, it is the one that gets dropped.
+                    OverflowPolicy::DropOldest => return Ok(()),
+                    OverflowPolicy::Disconnect => {
+                        inner.closed = true;
+                        inner.messages.clear();
+                        drop(inner);
+                        self.notify.notify_one();
+                        return Err(QueueError::Overflow);
+                    }
+                },
+            }
+        }
+
+        inner.messages.push_back((policy, message));
+        drop(inner);
+        self.notify.notify_one();
+
+        Ok(())
+    }
+
+    /// Waits for the next message, returning `None` once the queue is closed.
+    pub(crate) async fn pop(&self) -> Option<Message> {
+        loop {
+            {
+                // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+                let mut inner = self.inner.lock().unwrap();
+
+                if let Some((_, message)) = inner.messages.pop_front() {
+                    return Some(message);
+                }
+                if inner.closed {
+                    return None;
+                }
+            }
+
+            self.notify.notified().await;
+        }
+    }
+
+    /// Closes the queue, dropping the messages that were not sent yet.
+    pub(crate) fn close(&self) {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.closed = true;
+        inner.messages.clear();
+        drop(inner);
+        self.notify.notify_one();
+    }
+
+    /// Returns the number of queued messages.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        self.inner.lock().unwrap().messages.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stalled_consumer_drops_oldest_status_messages() {
+        let queue = WsQueue::new(4);
+
+        for i in 0..100 {
+            queue
+                .push(OverflowPolicy::DropOldest, Message::text(i.to_string()))
+                .unwrap();
+        }
+
+        assert_eq!(queue.len(), 4);
+        for i in 96..100 {
+            assert_eq!(queue.pop().await.unwrap().to_str().unwrap(), i.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn stalled_consumer_keeps_critical_messages() {
+        let queue = WsQueue::new(4);
+
+        queue
+            .push(OverflowPolicy::Disconnect, Message::text("critical"))
+            .unwrap();
+        for i in 0..10 {
+            queue
+                .push(OverflowPolicy::DropOldest, Message::text(i.to_string()))
+                .unwrap();
+        }
+
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.pop().await.unwrap().to_str().unwrap(), "critical");
+        for i in 7..10 {
+            assert_eq!(queue.pop().await.unwrap().to_str().unwrap(), i.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn stalled_consumer_is_disconnected_on_critical_overflow() {
+        let queue = WsQueue::new(2);
+
+        queue.push(OverflowPolicy::Disconnect, Message::text("0")).unwrap();
+        queue.push(OverflowPolicy::Disconnect, Message::text("1")).unwrap();
+        assert_eq!(queue.push(OverflowPolicy::DropOldest, Message::text("status")), Ok(()));
+        assert_eq!(
+            queue.push(OverflowPolicy::Disconnect, Message::text("2")),
+            Err(QueueError::Overflow)
+        );
+        assert_eq!(queue.len(), 0);
+        assert!(queue.pop().await.is_none());
+        assert_eq!(
+            queue.push(OverflowPolicy::Disconnect, Message::text("3")),
+            Err(QueueError::Closed)
+        );
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+use crate::plugins::dashboard::{
+    websocket::{
+        responses::{WsEvent, WsEventInner},
+        topics::WsTopic,
+    },
+    workers::confirmed_mps::ConfirmedMps,
+};
+
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct ConfirmedMpsResponse {
+    ms_index: u32,
+    current: f64,
+    average: f64,
+    peak: f64,
+}
+
+impl From<ConfirmedMps> for WsEvent {
+    fn from(val: ConfirmedMps) -> Self {
+        Self::new(WsTopic::ConfirmedMps, WsEventInner::ConfirmedMps(val.into()))
+    }
+}
+
+impl From<ConfirmedMps> for ConfirmedMpsResponse {
+    fn from(val: ConfirmedMps) -> Self {
+        Self {
+            ms_index: val.ms_index,
+            current: val.current,
+            average: val.average,
+            peak: val.peak,
+        }
+    }
+}
@@ -3,16 +3,20 @@
 
 pub(crate) mod confirmed_info;
 pub(crate) mod confirmed_milestone_metrics;
+pub(crate) mod confirmed_mps;
 pub(crate) mod database_size_metrics;
 pub(crate) mod milestone;
 pub(crate) mod milestone_info;
+pub(crate) mod mps_average;
 pub(crate) mod mps_metrics_updated;
 pub(crate) mod node_status;
+pub(crate) mod peer_list;
 pub(crate) mod peer_metric;
 pub(crate) mod public_node_status;
 pub(crate) mod solid_info;
 pub(crate) mod sync_status;
 pub(crate) mod tip_info;
+pub(crate) mod version;
 pub(crate) mod vertex;
 
 use bee_rest_api::types::responses::PeersResponse;
@@ -21,10 +25,11 @@ use serde::Serialize;
 use crate::plugins::dashboard::websocket::{
     responses::{
         confirmed_info::ConfirmedInfoResponse, confirmed_milestone_metrics::ConfirmedMilestoneMetricsResponse,
-        database_size_metrics::DatabaseSizeMetricsResponse, milestone::MilestoneResponse,
-        milestone_info::MilestoneInfoResponse, mps_metrics_updated::MpsMetricsUpdatedResponse,
-        node_status::NodeStatusResponse, public_node_status::PublicNodeStatusResponse, solid_info::SolidInfoResponse,
-        sync_status::SyncStatusResponse, tip_info::TipInfoResponse, vertex::VertexResponse,
+        confirmed_mps::ConfirmedMpsResponse, database_size_metrics::DatabaseSizeMetricsResponse,
+        milestone::MilestoneResponse, milestone_info::MilestoneInfoResponse, mps_average::MpsAverageResponse,
+        mps_metrics_updated::MpsMetricsUpdatedResponse, node_status::NodeStatusResponse, peer_list::PeerListResponse,
+        public_node_status::PublicNodeStatusResponse, solid_info::SolidInfoResponse, sync_status::SyncStatusResponse,
+        tip_info::TipInfoResponse, version::VersionResponse, vertex::VertexResponse,
     },
     topics::WsTopic,
 };
@@ -59,4 +64,8 @@ pub(crate) enum WsEventInner {
     PublicNodeStatus(PublicNodeStatusResponse),
     NodeStatus(Box<NodeStatusResponse>), // `NodeStatusResponse` is much larger than the rest.
     PeerMetric(PeersResponse),
+    Version(VersionResponse),
+    MpsAverage(MpsAverageResponse),
+    PeerList(PeerListResponse),
+    ConfirmedMps(ConfirmedMpsResponse),
 }
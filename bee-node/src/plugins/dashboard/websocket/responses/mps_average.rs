@@ -0,0 +1,62 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+use crate::plugins::{
+    dashboard::websocket::{
+        responses::{WsEvent, WsEventInner},
+        topics::WsTopic,
+    },
+    mps::MpsAverageUpdated,
+};
+
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct MpsAverageResponse {
+    current: u64,
+    average: f64,
+    peak: u64,
+}
+
+impl From<MpsAverageUpdated> for WsEvent {
+    fn from(event: MpsAverageUpdated) -> Self {
+        Self::new(WsTopic::MpsAverage, WsEventInner::MpsAverage(event.into()))
+    }
+}
+
+impl From<MpsAverageUpdated> for MpsAverageResponse {
+    fn from(event: MpsAverageUpdated) -> Self {
+        Self {
+            current: event.current,
+            average: event.average,
+            peak: event.peak,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mps_average_updated_to_ws_event() {
+        let event = WsEvent::from(MpsAverageUpdated {
+            current: 12,
+            average: 10.5,
+            peak: 20,
+        });
+
+        assert_eq!(event.kind, WsTopic::MpsAverage);
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "type": 18,
+                "data": {
+                    "current": 12,
+                    "average": 10.5,
+                    "peak": 20,
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_protocol::workers::event::{PeerAdded, PeerRemoved};
+use serde::Serialize;
+
+use crate::plugins::dashboard::websocket::{
+    responses::{WsEvent, WsEventInner},
+    topics::WsTopic,
+};
+
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct PeerListResponse {
+    peer_id: String,
+    address: String,
+    added: bool,
+}
+
+impl From<PeerAdded> for WsEvent {
+    fn from(event: PeerAdded) -> Self {
+        Self::new(
+            WsTopic::PeerList,
+            WsEventInner::PeerList(PeerListResponse {
+                peer_id: event.peer_id.to_string(),
+                address: event.address.to_string(),
+                added: true,
+            }),
+        )
+    }
+}
+
+impl From<PeerRemoved> for WsEvent {
+    fn from(event: PeerRemoved) -> Self {
+        Self::new(
+            WsTopic::PeerList,
+            WsEventInner::PeerList(PeerListResponse {
+                peer_id: event.peer_id.to_string(),
+                address: event.address.to_string(),
+                added: false,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_gossip::{Multiaddr, PeerId};
+
+    use super::*;
+
+    #[test]
+    fn peer_list_changes_to_ws_event() {
+        let peer_id = PeerId::random();
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/15600".parse().unwrap();
+
+        let added = WsEvent::from(PeerAdded {
+            peer_id,
+            address: address.clone(),
+        });
+        let removed = WsEvent::from(PeerRemoved { peer_id, address });
+
+        assert_eq!(added.kind, WsTopic::PeerList);
+        assert_eq!(
+            serde_json::to_value(&added).unwrap(),
+            serde_json::json!({
+                "type": 19,
+                "data": {
+                    "peer_id": peer_id.to_string(),
+                    "address": "/ip4/127.0.0.1/tcp/15600",
+                    "added": true,
+                }
+            })
+        );
+        assert_eq!(removed.kind, WsTopic::PeerList);
+        assert_eq!(
+            serde_json::to_value(&removed).unwrap(),
+            serde_json::json!({
+                "type": 19,
+                "data": {
+                    "peer_id": peer_id.to_string(),
+                    "address": "/ip4/127.0.0.1/tcp/15600",
+                    "added": false,
+                }
+            })
+        );
+    }
+}
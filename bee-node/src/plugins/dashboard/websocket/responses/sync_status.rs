@@ -17,6 +17,40 @@ use crate::{
 pub(crate) struct SyncStatusResponse {
     pub(crate) lmi: u32,
     pub(crate) cmi: u32,
+    pub(crate) sync_percentage: f32,
+    pub(crate) cmi_timestamp: u32,
+}
+
+impl SyncStatusResponse {
+    pub(crate) fn new<S: NodeStorageBackend>(lmi: u32, cmi: u32, tangle: &Tangle<S>) -> Self {
+        Self {
+            lmi,
+            cmi,
+            sync_percentage: sync_percentage(lmi, cmi, *tangle.get_pruning_index()),
+            cmi_timestamp: tangle
+                .get_milestone(cmi.into())
+                .map_or(0, |milestone| milestone.timestamp() as u32),
+        }
+    }
+}
+
+/// Computes the percentage of the milestones between the pruning index and the latest milestone index that are
+/// confirmed.
+fn sync_percentage(lmi: u32, cmi: u32, pruning_index: u32) -> f32 {
+    if lmi == 0 {
+        return 0.0;
+    }
+    if cmi >= lmi {
+        return 100.0;
+    }
+
+    let total = lmi.saturating_sub(pruning_index);
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    cmi.saturating_sub(pruning_index) as f32 / total as f32 * 100.0
 }
 
 pub(crate) fn forward_latest_milestone_changed<S: NodeStorageBackend>(
@@ -25,10 +59,11 @@ pub(crate) fn forward_latest_milestone_changed<S: NodeStorageBackend>(
 ) -> WsEvent {
     WsEvent::new(
         WsTopic::SyncStatus,
-        WsEventInner::SyncStatus(SyncStatusResponse {
-            lmi: *latest_milestone.index,
-            cmi: *tangle.get_confirmed_milestone_index(),
-        }),
+        WsEventInner::SyncStatus(SyncStatusResponse::new(
+            *latest_milestone.index,
+            *tangle.get_confirmed_milestone_index(),
+            tangle,
+        )),
     )
 }
 
@@ -38,9 +73,26 @@ pub(crate) fn forward_confirmed_milestone_changed<S: NodeStorageBackend>(
 ) -> WsEvent {
     WsEvent::new(
         WsTopic::SyncStatus,
-        WsEventInner::SyncStatus(SyncStatusResponse {
-            lmi: *tangle.get_latest_milestone_index(),
-            cmi: *event.index,
-        }),
+        WsEventInner::SyncStatus(SyncStatusResponse::new(
+            *tangle.get_latest_milestone_index(),
+            *event.index,
+            tangle,
+        )),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_percentage_of_index_pairs() {
+        assert_eq!(sync_percentage(0, 0, 0), 0.0);
+        assert_eq!(sync_percentage(0, 10, 0), 0.0);
+        assert_eq!(sync_percentage(100, 100, 0), 100.0);
+        assert_eq!(sync_percentage(100, 50, 0), 50.0);
+        assert_eq!(sync_percentage(150, 100, 50), 50.0);
+        assert_eq!(sync_percentage(150, 25, 50), 0.0);
+        assert_eq!(sync_percentage(50, 25, 50), 0.0);
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+use crate::plugins::{
+    dashboard::websocket::{
+        responses::{WsEvent, WsEventInner},
+        topics::WsTopic,
+    },
+    version_checker::VersionCheckResult,
+};
+
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct VersionResponse {
+    current: String,
+    latest: String,
+    is_outdated: bool,
+}
+
+impl From<VersionCheckResult> for WsEvent {
+    fn from(event: VersionCheckResult) -> Self {
+        Self::new(WsTopic::Version, WsEventInner::Version(event.into()))
+    }
+}
+
+impl From<VersionCheckResult> for VersionResponse {
+    fn from(event: VersionCheckResult) -> Self {
+        Self {
+            current: event.current.to_string(),
+            latest: event.latest.to_string(),
+            is_outdated: event.is_outdated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::*;
+
+    #[test]
+    fn version_check_result_to_ws_event() {
+        let event = WsEvent::from(VersionCheckResult {
+            current: Version::new(0, 3, 0),
+            latest: Version::new(0, 4, 0),
+            is_outdated: true,
+        });
+
+        assert_eq!(event.kind, WsTopic::Version);
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "type": 17,
+                "data": {
+                    "current": "0.3.0",
+                    "latest": "0.4.0",
+                    "is_outdated": true,
+                }
+            })
+        );
+    }
+}
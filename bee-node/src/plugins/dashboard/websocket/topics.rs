@@ -1,8 +1,12 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{fmt, str::FromStr};
+
 use serde_repr::Serialize_repr;
 
+use crate::plugins::dashboard::websocket::queue::OverflowPolicy;
+
 #[repr(u8)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize_repr)]
 pub(crate) enum WsTopic {
@@ -23,6 +27,10 @@ pub(crate) enum WsTopic {
     DatabaseCleanupEvent = 14,
     SpamMetrics = 15,
     AverageSpamMetrics = 16,
+    Version = 17,
+    MpsAverage = 18,
+    PeerList = 19,
+    ConfirmedMps = 20,
 }
 
 impl TryFrom<u8> for WsTopic {
@@ -47,12 +55,90 @@ impl TryFrom<u8> for WsTopic {
             14 => Ok(WsTopic::DatabaseCleanupEvent),
             15 => Ok(WsTopic::SpamMetrics),
             16 => Ok(WsTopic::AverageSpamMetrics),
+            17 => Ok(WsTopic::Version),
+            18 => Ok(WsTopic::MpsAverage),
+            19 => Ok(WsTopic::PeerList),
+            20 => Ok(WsTopic::ConfirmedMps),
             _ => Err(val),
         }
     }
 }
 
+/// Error returned when parsing an unknown `WsTopic`.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("unknown websocket topic `{0}`")]
+pub(crate) struct UnknownWsTopic(String);
+
+impl FromStr for WsTopic {
+    type Err = UnknownWsTopic;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WsTopic::ALL
+            .iter()
+            .find(|topic| topic.as_str() == s)
+            .cloned()
+            .ok_or_else(|| UnknownWsTopic(s.to_owned()))
+    }
+}
+
+impl fmt::Display for WsTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl WsTopic {
+    pub(crate) const ALL: [WsTopic; 21] = [
+        WsTopic::SyncStatus,
+        WsTopic::PublicNodeStatus,
+        WsTopic::NodeStatus,
+        WsTopic::MpsMetrics,
+        WsTopic::TipSelectionMetrics,
+        WsTopic::Milestone,
+        WsTopic::PeerMetrics,
+        WsTopic::ConfirmedMilestoneMetrics,
+        WsTopic::Vertex,
+        WsTopic::SolidInfo,
+        WsTopic::ConfirmedInfo,
+        WsTopic::MilestoneInfo,
+        WsTopic::TipInfo,
+        WsTopic::DatabaseSizeMetrics,
+        WsTopic::DatabaseCleanupEvent,
+        WsTopic::SpamMetrics,
+        WsTopic::AverageSpamMetrics,
+        WsTopic::Version,
+        WsTopic::MpsAverage,
+        WsTopic::PeerList,
+        WsTopic::ConfirmedMps,
+    ];
+
+    /// Returns the name clients use to subscribe to this topic.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            WsTopic::SyncStatus => "sync_status",
+            WsTopic::PublicNodeStatus => "public_node_status",
+            WsTopic::NodeStatus => "node_status",
+            WsTopic::MpsMetrics => "mps_metrics",
+            WsTopic::TipSelectionMetrics => "tip_selection_metrics",
+            WsTopic::Milestone => "milestone",
+            WsTopic::PeerMetrics => "peer_metrics",
+            WsTopic::ConfirmedMilestoneMetrics => "confirmed_milestone_metrics",
+            WsTopic::Vertex => "vertex",
+            WsTopic::SolidInfo => "solid_info",
+            WsTopic::ConfirmedInfo => "confirmed_info",
+            WsTopic::MilestoneInfo => "milestone_info",
+            WsTopic::TipInfo => "tip_info",
+            WsTopic::DatabaseSizeMetrics => "database_size_metrics",
+            WsTopic::DatabaseCleanupEvent => "database_cleanup_event",
+            WsTopic::SpamMetrics => "spam_metrics",
+            WsTopic::AverageSpamMetrics => "average_spam_metrics",
+            WsTopic::Version => "version",
+            WsTopic::MpsAverage => "mps_average",
+            WsTopic::PeerList => "peer_list",
+            WsTopic::ConfirmedMps => "confirmed_mps",
+        }
+    }
+
     pub fn is_public(&self) -> bool {
         matches!(
             self,
@@ -66,6 +152,59 @@ impl WsTopic {
                 | WsTopic::ConfirmedInfo
                 | WsTopic::MilestoneInfo
                 | WsTopic::TipInfo
+                | WsTopic::Version
+                | WsTopic::MpsAverage
+                | WsTopic::ConfirmedMps
         )
     }
+
+    /// Returns what to do with the events of this topic when a client can't keep up with them.
+    /// Status events are superseded by the next ones and can be dropped, the others can't be lost.
+    pub(crate) fn overflow_policy(&self) -> OverflowPolicy {
+        match self {
+            WsTopic::SyncStatus
+            | WsTopic::PublicNodeStatus
+            | WsTopic::NodeStatus
+            | WsTopic::MpsMetrics
+            | WsTopic::TipSelectionMetrics
+            | WsTopic::PeerMetrics
+            | WsTopic::ConfirmedMilestoneMetrics
+            | WsTopic::DatabaseSizeMetrics
+            | WsTopic::SpamMetrics
+            | WsTopic::AverageSpamMetrics
+            | WsTopic::MpsAverage
+            | WsTopic::ConfirmedMps => OverflowPolicy::DropOldest,
+            WsTopic::Milestone
+            | WsTopic::Vertex
+            | WsTopic::SolidInfo
+            | WsTopic::ConfirmedInfo
+            | WsTopic::MilestoneInfo
+            | WsTopic::TipInfo
+            | WsTopic::DatabaseCleanupEvent
+            | WsTopic::Version
+            | WsTopic::PeerList => OverflowPolicy::Disconnect,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_round_trip() {
+        for topic in WsTopic::ALL {
+            assert_eq!(topic.to_string().parse::<WsTopic>(), Ok(topic.clone()));
+            assert_eq!(WsTopic::try_from(topic.clone() as u8), Ok(topic));
+        }
+    }
+
+    #[test]
+    fn unknown_string() {
+        assert_eq!(
+            "sync_stats".parse::<WsTopic>(),
+            Err(UnknownWsTopic("sync_stats".to_owned()))
+        );
+        assert!("SyncStatus".parse::<WsTopic>().is_err());
+    }
 }
@@ -0,0 +1,156 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::workers::event::MilestoneConfirmed;
+use bee_runtime::{node::Node, shutdown_stream::ShutdownStream};
+use futures::StreamExt;
+use log::debug;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::{
+    plugins::{
+        dashboard::{broadcast, websocket::WsUsers, Dashboard},
+        mps::MpsWindow,
+    },
+    storage::NodeStorageBackend,
+};
+
+/// Number of milestones over which the confirmed messages per second are averaged.
+const CONFIRMED_MPS_WINDOW: usize = 10;
+
+pub(crate) fn confirmed_mps_worker<N>(node: &mut N, users: &WsUsers)
+where
+    N: Node,
+    N::Backend: NodeStorageBackend,
+{
+    let bus = node.bus();
+    let users = users.clone();
+    let (tx, rx) = mpsc::unbounded_channel::<MilestoneConfirmed>();
+
+    node.spawn::<Dashboard, _, _>(|shutdown| async move {
+        debug!("Ws ConfirmedMps topic handler running.");
+
+        let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+        let mut tracker = ConfirmedMpsTracker::new(CONFIRMED_MPS_WINDOW);
+
+        while let Some(event) = receiver.next().await {
+            if let Some(confirmed_mps) = tracker.update(&event) {
+                broadcast(confirmed_mps.into(), &users).await;
+            }
+        }
+
+        debug!("Ws ConfirmedMps topic handler stopped.");
+    });
+
+    bus.add_listener::<Dashboard, _, _>(move |event: &MilestoneConfirmed| {
+        // The lifetime of the listeners is tied to the lifetime of the Dashboard worker so they are removed together.
+        // However, topic handlers are shutdown as soon as the signal is received, causing this send to potentially
+        // fail and spam the output. The return is then ignored as not being essential.
+        let _ = tx.send((*event).clone());
+    });
+}
+
+/// Computes the rate of confirmed messages from the messages referenced by consecutive milestones.
+pub(crate) struct ConfirmedMpsTracker {
+    window: MpsWindow<f64>,
+    prev_timestamp: Option<u64>,
+}
+
+impl ConfirmedMpsTracker {
+    pub(crate) fn new(size: usize) -> Self {
+        Self {
+            window: MpsWindow::new(size),
+            prev_timestamp: None,
+        }
+    }
+
+    /// Accounts for a confirmed milestone, returning the updated rate unless it can't be computed, i.e. for the first
+    /// milestone or if it has the timestamp of the previous one.
+    pub(crate) fn update(&mut self, event: &MilestoneConfirmed) -> Option<ConfirmedMps> {
+        let prev_timestamp = self.prev_timestamp.replace(event.timestamp)?;
+        let time_diff = event.timestamp.checked_sub(prev_timestamp).filter(|diff| *diff > 0)?;
+
+        self.window.push(event.referenced_messages as f64 / time_diff as f64);
+
+        Some(ConfirmedMps {
+            ms_index: *event.index,
+            current: self.window.current(),
+            average: self.window.average(),
+            peak: self.window.peak(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfirmedMps {
+    pub ms_index: u32,
+    pub current: f64,
+    pub average: f64,
+    pub peak: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_message::{milestone::MilestoneIndex, MessageId};
+
+    use super::*;
+
+    fn milestone_confirmed(index: u32, timestamp: u64, referenced_messages: usize) -> MilestoneConfirmed {
+        MilestoneConfirmed {
+            message_id: MessageId::null(),
+            index: MilestoneIndex(index),
+            timestamp,
+            referenced_messages,
+            excluded_no_transaction_messages: Vec::new(),
+            excluded_conflicting_messages: Vec::new(),
+            included_messages: Vec::new(),
+            consumed_outputs: 0,
+            created_outputs: 0,
+            receipt: false,
+        }
+    }
+
+    #[test]
+    fn confirmed_mps_rate() {
+        let mut tracker = ConfirmedMpsTracker::new(2);
+
+        // The first milestone has no previous timestamp to compute a rate from.
+        assert_eq!(tracker.update(&milestone_confirmed(1, 100, 50)), None);
+
+        assert_eq!(
+            tracker.update(&milestone_confirmed(2, 110, 200)),
+            Some(ConfirmedMps {
+                ms_index: 2,
+                current: 20.0,
+                average: 20.0,
+                peak: 20.0,
+            })
+        );
+
+        // Milestones with the same timestamp are skipped.
+        assert_eq!(tracker.update(&milestone_confirmed(3, 110, 100)), None);
+
+        // The rate is not truncated to an integer.
+        assert_eq!(
+            tracker.update(&milestone_confirmed(4, 114, 42)),
+            Some(ConfirmedMps {
+                ms_index: 4,
+                current: 10.5,
+                average: 15.25,
+                peak: 20.0,
+            })
+        );
+
+        // The window is full, the oldest rate is evicted.
+        assert_eq!(
+            tracker.update(&milestone_confirmed(5, 124, 300)),
+            Some(ConfirmedMps {
+                ms_index: 5,
+                current: 30.0,
+                average: 20.25,
+                peak: 30.0,
+            })
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod confirmed_mps;
 pub(crate) mod confirmed_ms_metrics;
 pub(crate) mod db_size_metrics;
 pub(crate) mod node_status;
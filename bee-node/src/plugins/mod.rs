@@ -5,34 +5,221 @@
 pub mod dashboard;
 pub mod mps;
 pub mod mqtt;
+pub mod supervisor;
 pub mod version_checker;
 
-use std::{any::type_name, error::Error, fmt};
+use std::{
+    any::{type_name, TypeId},
+    collections::HashMap,
+    convert::Infallible,
+    error::Error,
+    fmt,
+    sync::Arc,
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use bee_runtime::{event::Bus, node::Node, worker::Worker};
+use bee_runtime::{event::Bus, node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use futures::{channel::oneshot, Stream, StreamExt};
+use fxhash::FxBuildHasher;
+use log::{debug, warn};
+use tokio::sync::{mpsc, Mutex};
+use tokio_stream::wrappers::UnboundedReceiverStream;
 
 #[cfg(feature = "dashboard")]
 pub use self::dashboard::Dashboard;
-pub use self::{mps::Mps, mqtt::Mqtt, version_checker::VersionChecker};
+pub use self::{
+    mps::Mps,
+    mqtt::Mqtt,
+    supervisor::{spawn_supervised, RestartPolicy},
+    version_checker::VersionChecker,
+};
+use crate::core::CoreError;
+
+/// Time given to a plugin to stop before the node shuts down without waiting for it anymore.
+const PLUGIN_STOP_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[async_trait]
 pub trait Plugin: Sized + Send + Sync + 'static {
     type Config: Send;
-    type Error: Error;
+    type Error: Error + PluginErrorKind + Send + 'static;
+
+    /// Returns the `TypeId`s of the plugins this plugin depends on, which are started before it.
+    fn dependencies() -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    /// Returns how the tasks spawned through `spawn_supervised` are restarted when they fail or panic. They are never
+    /// restarted by default.
+    fn restart_policy() -> RestartPolicy {
+        RestartPolicy::never()
+    }
 
     async fn start(config: Self::Config, bus: &Bus<'_>) -> Result<Self, Self::Error>;
+    /// Registers the resources and spawns the background tasks of a started plugin. The tasks are spawned on behalf
+    /// of the plugin worker and are therefore shut down with it.
+    fn register<N: Node>(&mut self, _node: &mut N) {}
     async fn stop(self) -> Result<(), Self::Error> {
         Ok(())
     }
+    /// Applies a new configuration to a running plugin, without restarting it.
+    async fn reload(&mut self, _config: Self::Config) -> Result<(), ReloadError<Self::Error>> {
+        Err(ReloadError::Unsupported)
+    }
+}
+
+/// Distinguishes the errors caused by an invalid configuration of a plugin from its runtime failures.
+pub trait PluginErrorKind {
+    /// Returns whether the error is caused by an invalid configuration. Such an error is fatal: retrying with the same
+    /// configuration fails the same way.
+    fn is_config_error(&self) -> bool {
+        false
+    }
+}
+
+impl PluginErrorKind for Infallible {}
+
+/// Errors that may occur when reloading a plugin.
+#[derive(Debug)]
+pub enum ReloadError<E> {
+    /// The plugin does not support being reloaded.
+    Unsupported,
+    /// The plugin is not running anymore.
+    Stopped,
+    /// The plugin failed to apply the new configuration.
+    Plugin(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ReloadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "reloading is not supported"),
+            Self::Stopped => write!(f, "the plugin is not running"),
+            Self::Plugin(e) => write!(f, "reloading failed: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ReloadError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Plugin(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+enum PluginCommand<P: Plugin> {
+    Reload(P::Config, oneshot::Sender<Result<(), ReloadError<P::Error>>>),
+}
+
+/// A handle to a running plugin, registered as a node resource by its `PluginWorker`.
+pub struct PluginHandle<P: Plugin> {
+    tx: mpsc::UnboundedSender<PluginCommand<P>>,
+}
+
+impl<P: Plugin> PluginHandle<P> {
+    /// Reloads the plugin with a new configuration.
+    pub async fn reload(&self, config: P::Config) -> Result<(), ReloadError<P::Error>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx
+            .send(PluginCommand::Reload(config, tx))
+            .map_err(|_| ReloadError::Stopped)?;
+
+        rx.await.map_err(|_| ReloadError::Stopped)?
+    }
+}
+
+/// Keeps track of the plugins added to a node so that they are started according to their dependencies.
+#[derive(Default)]
+pub(crate) struct PluginRegistry {
+    // Maps the id of a plugin to the id of its worker.
+    workers: HashMap<TypeId, TypeId>,
+    // Maps the id of a plugin worker to the name of its plugin and the ids of the plugins it depends on.
+    dependencies: HashMap<TypeId, (&'static str, Vec<TypeId>)>,
+}
+
+impl PluginRegistry {
+    /// Registers a plugin and its dependencies.
+    pub(crate) fn register<P: Plugin>(&mut self) {
+        self.workers.insert(TypeId::of::<P>(), TypeId::of::<PluginWorker<P>>());
+        self.dependencies
+            .insert(TypeId::of::<PluginWorker<P>>(), (type_name::<P>(), P::dependencies()));
+    }
+
+    /// Adds the dependencies between plugins to the dependencies of their workers.
+    pub(crate) fn resolve(self, deps: &mut HashMap<TypeId, Vec<TypeId>, FxBuildHasher>) -> Result<(), CoreError> {
+        for (worker, (name, plugin_deps)) in self.dependencies {
+            if plugin_deps.is_empty() {
+                continue;
+            }
+
+            let worker_deps = deps.entry(worker).or_default();
+
+            for dep in plugin_deps {
+                worker_deps.push(*self.workers.get(&dep).ok_or(CoreError::MissingPluginDependency(name))?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Handles the commands sent through a `PluginHandle` until the command stream ends.
+async fn command_handler<P: Plugin>(
+    plugin: Arc<Mutex<Option<P>>>,
+    mut commands: impl Stream<Item = PluginCommand<P>> + Unpin,
+) {
+    while let Some(command) = commands.next().await {
+        match command {
+            PluginCommand::Reload(config, tx) => {
+                let res = match plugin.lock().await.as_mut() {
+                    Some(plugin) => plugin.reload(config).await,
+                    None => Err(ReloadError::Stopped),
+                };
+
+                if let Err(e) = &res {
+                    warn!("Reloading plugin `{}` failed: {}.", type_name::<P>(), e);
+                }
+                // The requester may have given up waiting, the result is then simply dropped.
+                let _ = tx.send(res);
+            }
+        }
+    }
+}
+
+/// Stops a plugin, giving up on it if it doesn't stop within `timeout` so that it can't block the shutdown.
+async fn stop_plugin<P: Plugin>(plugin: P, timeout: Duration) -> Result<(), PluginError<P>> {
+    match tokio::time::timeout(timeout, plugin.stop()).await {
+        Ok(res) => res.map_err(PluginError),
+        Err(_) => {
+            warn!("Plugin `{}` did not stop within {:?}.", type_name::<P>(), timeout);
+            Ok(())
+        }
+    }
 }
 
 pub struct PluginWorker<P: Plugin> {
-    plugin: P,
+    // The plugin is shared with the command task and taken back when the worker stops.
+    plugin: Arc<Mutex<Option<P>>>,
 }
 
 pub struct PluginError<P: Plugin>(P::Error);
 
+impl<P: Plugin> PluginError<P> {
+    /// Returns the error emitted by the plugin.
+    pub fn inner(&self) -> &P::Error {
+        &self.0
+    }
+
+    /// Returns whether the plugin failed because of an invalid configuration, in which case it must not be restarted
+    /// with the same configuration.
+    pub fn is_config_error(&self) -> bool {
+        self.0.is_config_error()
+    }
+}
+
 impl<P: Plugin> fmt::Debug for PluginError<P> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Plugin `{}` error: {:?}", type_name::<P>(), self.0)
@@ -45,7 +232,11 @@ impl<P: Plugin> fmt::Display for PluginError<P> {
     }
 }
 
-impl<P: Plugin> Error for PluginError<P> {}
+impl<P: Plugin> Error for PluginError<P> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
 
 #[async_trait]
 impl<P: Plugin, N: Node> Worker<N> for PluginWorker<P> {
@@ -54,13 +245,387 @@ impl<P: Plugin, N: Node> Worker<N> for PluginWorker<P> {
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let bus = node.bus();
-        Ok(Self {
-            plugin: P::start(config, &bus).await.map_err(PluginError)?,
-        })
+        let mut plugin = P::start(config, &bus).await.map_err(PluginError)?;
+
+        plugin.register(node);
+
+        let plugin = Arc::new(Mutex::new(Some(plugin)));
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        node.register_resource(PluginHandle::<P> { tx });
+
+        let task_plugin = plugin.clone();
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            debug!("Plugin `{}` command handler running.", type_name::<P>());
+
+            command_handler(
+                task_plugin,
+                ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx)),
+            )
+            .await;
+
+            debug!("Plugin `{}` command handler stopped.", type_name::<P>());
+        });
+
+        Ok(Self { plugin })
     }
 
     async fn stop(self, _node: &mut N) -> Result<(), Self::Error> {
-        self.plugin.stop().await.map_err(PluginError)?;
+        // The command handler has already been stopped so the plugin can not be reloaded anymore.
+        let plugin = self.plugin.lock().await.take();
+
+        // Workers are stopped in the reverse order of their start, a plugin is therefore stopped before the plugins it
+        // depends on.
+        if let Some(plugin) = plugin {
+            stop_plugin(plugin, PLUGIN_STOP_TIMEOUT).await?;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Instant,
+    };
+
+    use bee_gossip::Keypair;
+    use bee_runtime::node::NodeBuilder;
+    use bee_storage_null::Storage as NullStorage;
+
+    use super::{mps::config::MpsConfig, *};
+    use crate::{
+        core::{TopologicalOrder, WorkerNameMap},
+        FullNodeBuilder, FullNodeConfig, Local, NodeConfigBuilder,
+    };
+
+    struct ReloadableConfig {
+        value: u32,
+        // Counts the number of dropped plugin instances.
+        dropped: Arc<AtomicUsize>,
+    }
+
+    struct Reloadable {
+        value: u32,
+        dropped: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Reloadable {
+        fn drop(&mut self) {
+            self.dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[async_trait]
+    impl Plugin for Reloadable {
+        type Config = ReloadableConfig;
+        type Error = Infallible;
+
+        async fn start(config: Self::Config, _bus: &Bus<'_>) -> Result<Self, Self::Error> {
+            Ok(Self {
+                value: config.value,
+                dropped: config.dropped,
+            })
+        }
+
+        async fn reload(&mut self, config: Self::Config) -> Result<(), ReloadError<Self::Error>> {
+            self.value = config.value;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn reload_swaps_config() {
+        let bus = Bus::default();
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let config = |value| ReloadableConfig {
+            value,
+            dropped: dropped.clone(),
+        };
+        let plugin = Arc::new(Mutex::new(Some(Reloadable::start(config(1), &bus).await.unwrap())));
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = PluginHandle::<Reloadable> { tx };
+        let handler = tokio::spawn(command_handler(plugin.clone(), UnboundedReceiverStream::new(rx)));
+
+        handle.reload(config(2)).await.unwrap();
+
+        assert_eq!(plugin.lock().await.as_ref().unwrap().value, 2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 0);
+
+        // Reloading a stopped plugin fails.
+        let stopped = plugin.lock().await.take().unwrap();
+        assert!(matches!(handle.reload(config(3)).await, Err(ReloadError::Stopped)));
+
+        stopped.stop().await.unwrap();
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+        // Dropping the handle ends the command handler.
+        drop(handle);
+        handler.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reload_unsupported() {
+        let bus = Bus::default();
+        let mut plugin = Mps::start(MpsConfig::default(), &bus).await.unwrap();
+
+        assert!(matches!(
+            plugin.reload(MpsConfig::default()).await,
+            Err(ReloadError::Unsupported)
+        ));
+    }
+
+    macro_rules! dependent_plugin {
+        ($name:ident $(, $dep:ident)*) => {
+            struct $name;
+
+            #[async_trait]
+            impl Plugin for $name {
+                type Config = ();
+                type Error = Infallible;
+
+                fn dependencies() -> Vec<TypeId> {
+                    vec![$(TypeId::of::<$dep>()),*]
+                }
+
+                async fn start(_: Self::Config, _bus: &Bus<'_>) -> Result<Self, Self::Error> {
+                    Ok(Self)
+                }
+            }
+        };
+    }
+
+    dependent_plugin!(A, B, C);
+    dependent_plugin!(B, C);
+    dependent_plugin!(C);
+    dependent_plugin!(D, E);
+    dependent_plugin!(E, D);
+
+    #[derive(Default)]
+    struct Graph {
+        registry: PluginRegistry,
+        deps: HashMap<TypeId, Vec<TypeId>, FxBuildHasher>,
+        names: WorkerNameMap,
+    }
+
+    impl Graph {
+        fn with_plugin<P: Plugin>(mut self) -> Self {
+            self.registry.register::<P>();
+            self.deps.insert(TypeId::of::<PluginWorker<P>>(), Vec::new());
+            self.names
+                .insert(TypeId::of::<PluginWorker<P>>(), type_name::<PluginWorker<P>>());
+            self
+        }
+
+        fn sort(self) -> Result<Vec<TypeId>, CoreError> {
+            let Graph {
+                registry,
+                mut deps,
+                names,
+            } = self;
+
+            registry.resolve(&mut deps)?;
+            TopologicalOrder::sort(deps, &names)
+        }
+    }
+
+    #[test]
+    fn plugin_dependency_order() {
+        let order = Graph::default()
+            .with_plugin::<A>()
+            .with_plugin::<B>()
+            .with_plugin::<C>()
+            .sort()
+            .unwrap();
+
+        assert_eq!(
+            order,
+            vec![
+                TypeId::of::<PluginWorker<C>>(),
+                TypeId::of::<PluginWorker<B>>(),
+                TypeId::of::<PluginWorker<A>>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn plugin_dependency_cycle() {
+        let res = Graph::default()
+            .with_plugin::<C>()
+            .with_plugin::<D>()
+            .with_plugin::<E>()
+            .sort();
+
+        match res {
+            Err(CoreError::CyclicDependency(cycle)) => {
+                assert_eq!(cycle.len(), 3);
+                assert_eq!(cycle.first(), cycle.last());
+                assert!(cycle.iter().all(|name| name.ends_with("D>") || name.ends_with("E>")));
+            }
+            _ => panic!("expected a cyclic dependency error"),
+        }
+    }
+
+    #[test]
+    fn plugin_dependency_missing() {
+        let res = Graph::default().with_plugin::<A>().with_plugin::<C>().sort();
+
+        assert!(matches!(res, Err(CoreError::MissingPluginDependency(name)) if name.ends_with("A")));
+    }
+
+    type Log = Arc<std::sync::Mutex<Vec<String>>>;
+
+    macro_rules! recording_plugin {
+        ($name:ident $(, $dep:ident)*) => {
+            struct $name(Log);
+
+            #[async_trait]
+            impl Plugin for $name {
+                type Config = Log;
+                type Error = Infallible;
+
+                fn dependencies() -> Vec<TypeId> {
+                    vec![$(TypeId::of::<$dep>()),*]
+                }
+
+                async fn start(log: Self::Config, _bus: &Bus<'_>) -> Result<Self, Self::Error> {
+                    log.lock().unwrap().push(format!("start {}", stringify!($name)));
+                    Ok(Self(log))
+                }
+
+                async fn stop(self) -> Result<(), Self::Error> {
+                    self.0.lock().unwrap().push(format!("stop {}", stringify!($name)));
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    recording_plugin!(X, Y, Z);
+    recording_plugin!(Y, Z);
+    recording_plugin!(Z);
+
+    #[tokio::test]
+    async fn plugin_stop_order() {
+        let log = Log::default();
+        let (_, node_config) = NodeConfigBuilder::<NullStorage>::default().finish();
+        let config = FullNodeConfig::from(Local::from_keypair(Keypair::generate()), node_config);
+
+        // The plugins are added in an order that differs from their dependency order on purpose.
+        let node = FullNodeBuilder::new(config)
+            .unwrap()
+            .with_resource(Bus::<TypeId>::default())
+            .with_plugin_cfg::<X>(log.clone())
+            .with_plugin_cfg::<Z>(log.clone())
+            .with_plugin_cfg::<Y>(log.clone())
+            .start_workers()
+            .await
+            .unwrap();
+
+        node.stop().await.unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["start Z", "start Y", "start X", "stop X", "stop Y", "stop Z"]
+        );
+    }
+
+    struct Hanging;
+
+    #[async_trait]
+    impl Plugin for Hanging {
+        type Config = ();
+        type Error = Infallible;
+
+        async fn start(_: Self::Config, _bus: &Bus<'_>) -> Result<Self, Self::Error> {
+            Ok(Self)
+        }
+
+        async fn stop(self) -> Result<(), Self::Error> {
+            futures::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn plugin_stop_timeout() {
+        let bus = Bus::default();
+        let timeout = Duration::from_millis(50);
+        let plugin = Hanging::start((), &bus).await.unwrap();
+        let start = Instant::now();
+
+        // The hanging plugin is given up on once the timeout elapsed, without an error.
+        assert!(stop_plugin(plugin, timeout).await.is_ok());
+        assert!(start.elapsed() >= timeout);
+        assert!(start.elapsed() < PLUGIN_STOP_TIMEOUT);
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("failing plugin error")]
+    struct FailingError;
+
+    impl PluginErrorKind for FailingError {}
+
+    struct Failing;
+
+    #[async_trait]
+    impl Plugin for Failing {
+        type Config = ();
+        type Error = FailingError;
+
+        async fn start(_: Self::Config, _bus: &Bus<'_>) -> Result<Self, Self::Error> {
+            Err(FailingError)
+        }
+    }
+
+    #[tokio::test]
+    async fn plugin_error_source() {
+        let bus = Bus::default();
+        let error = PluginError::<Failing>(Failing::start((), &bus).await.err().unwrap());
+
+        assert!(matches!(error.inner(), FailingError));
+        assert!(!error.is_config_error());
+        assert!(error.source().unwrap().downcast_ref::<FailingError>().is_some());
+        assert!(error.to_string().ends_with("error: failing plugin error"));
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("invalid threshold {0}")]
+    struct InvalidThreshold(u8);
+
+    impl PluginErrorKind for InvalidThreshold {
+        fn is_config_error(&self) -> bool {
+            true
+        }
+    }
+
+    struct Thresholded;
+
+    #[async_trait]
+    impl Plugin for Thresholded {
+        type Config = u8;
+        type Error = InvalidThreshold;
+
+        async fn start(threshold: Self::Config, _bus: &Bus<'_>) -> Result<Self, Self::Error> {
+            if threshold > 100 {
+                return Err(InvalidThreshold(threshold));
+            }
+
+            Ok(Self)
+        }
+    }
+
+    #[tokio::test]
+    async fn plugin_config_error() {
+        let bus = Bus::default();
+
+        assert!(Thresholded::start(50, &bus).await.is_ok());
+
+        let error = PluginError::<Thresholded>(Thresholded::start(200, &bus).await.err().unwrap());
+
+        assert!(error.is_config_error());
+        assert!(error.to_string().ends_with("error: invalid threshold 200"));
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+const DEFAULT_WINDOW: usize = 10;
+
+#[derive(Default, Deserialize, PartialEq)]
+pub struct MpsConfigBuilder {
+    window: Option<usize>,
+}
+
+impl MpsConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size of the window, in seconds.
+    pub fn window(mut self, window: usize) -> Self {
+        self.window.replace(window);
+        self
+    }
+
+    pub fn finish(self) -> MpsConfig {
+        MpsConfig::new(self.window.unwrap_or(DEFAULT_WINDOW))
+    }
+}
+
+/// The configuration of the `Mps` plugin.
+#[derive(Clone)]
+pub struct MpsConfig {
+    window: usize,
+}
+
+impl MpsConfig {
+    /// Creates a new `MpsConfig` averaging over a window of `window` seconds, at least one.
+    pub fn new(window: usize) -> Self {
+        Self { window: window.max(1) }
+    }
+
+    /// Returns the size of the window, in seconds.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+}
+
+impl Default for MpsConfig {
+    fn default() -> Self {
+        MpsConfigBuilder::new().finish()
+    }
+}
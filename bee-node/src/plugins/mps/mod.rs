@@ -0,0 +1,331 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod config;
+
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use bee_protocol::workers::event::MpsMetricsUpdated;
+use bee_runtime::{event::Bus, node::Node, resource::ResourceHandle, shutdown_stream::ShutdownStream};
+use futures::{future::BoxFuture, stream, FutureExt, Stream, StreamExt};
+use log::{debug, info};
+use tokio::sync::mpsc;
+
+use self::config::MpsConfig;
+use crate::{
+    plugins::{spawn_supervised, Plugin, PluginWorker, RestartPolicy},
+    shutdown::ShutdownRx,
+};
+
+/// A sample of an `MpsWindow`.
+pub trait MpsSample: Copy + Default + PartialOrd {
+    /// Converts the sample to a float to average it.
+    fn as_f64(self) -> f64;
+}
+
+impl MpsSample for u64 {
+    fn as_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl MpsSample for f64 {
+    fn as_f64(self) -> f64 {
+        self
+    }
+}
+
+/// A rolling window over the last messages-per-second samples, backed by a ring buffer.
+pub struct MpsWindow<T = u64> {
+    samples: Vec<T>,
+    next: usize,
+    len: usize,
+}
+
+impl<T: MpsSample> MpsWindow<T> {
+    /// Creates a new `MpsWindow` holding at most `size` samples, at least one.
+    pub fn new(size: usize) -> Self {
+        Self {
+            samples: vec![T::default(); size.max(1)],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds a sample to the window, evicting the oldest one if the window is full.
+    pub fn push(&mut self, sample: T) {
+        self.samples[self.next] = sample;
+        self.next = (self.next + 1) % self.samples.len();
+        self.len = (self.len + 1).min(self.samples.len());
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        self.samples.iter().take(self.len)
+    }
+
+    /// Returns the latest sample.
+    pub fn current(&self) -> T {
+        if self.len == 0 {
+            T::default()
+        } else {
+            self.samples[(self.next + self.samples.len() - 1) % self.samples.len()]
+        }
+    }
+
+    /// Returns the average of the samples of the window.
+    pub fn average(&self) -> f64 {
+        if self.len == 0 {
+            0.0
+        } else {
+            self.iter().map(|sample| sample.as_f64()).sum::<f64>() / self.len as f64
+        }
+    }
+
+    /// Returns the highest sample of the window.
+    pub fn peak(&self) -> T {
+        // Samples are rates and therefore never lower than the default.
+        self.iter()
+            .copied()
+            .fold(T::default(), |peak, sample| if sample > peak { sample } else { peak })
+    }
+}
+
+/// An event that indicates that the rolling MPS metrics were updated.
+#[derive(Clone, Debug)]
+pub struct MpsAverageUpdated {
+    /// Latest number of incoming messages per second.
+    pub current: u64,
+    /// Average number of incoming messages per second over the window.
+    pub average: f64,
+    /// Highest number of incoming messages per second over the window.
+    pub peak: u64,
+}
+
+impl From<&MpsWindow> for MpsAverageUpdated {
+    fn from(window: &MpsWindow) -> Self {
+        Self {
+            current: window.current(),
+            average: window.average(),
+            peak: window.peak(),
+        }
+    }
+}
+
+pub struct Mps {
+    // Registered as a node resource so that the rolling metrics can be queried.
+    window: Arc<Mutex<MpsWindow>>,
+    // Taken when the plugin is registered to the node.
+    events: Option<mpsc::UnboundedReceiver<MpsAverageUpdated>>,
+}
+
+// Events can't be dispatched from within a listener, they are dispatched from a separate task instead.
+async fn dispatch_averages(
+    bus: ResourceHandle<Bus<'static>>,
+    mut events: impl Stream<Item = MpsAverageUpdated> + Unpin,
+) {
+    while let Some(event) = events.next().await {
+        bus.dispatch::<MpsAverageUpdated>(event);
+    }
+}
+
+/// Builds the dispatching task. A restarted task resumes receiving the events where the previous one stopped.
+fn dispatcher(
+    bus: ResourceHandle<Bus<'static>>,
+    events: mpsc::UnboundedReceiver<MpsAverageUpdated>,
+) -> impl FnMut(ShutdownRx) -> BoxFuture<'static, Result<(), Infallible>> + Send + 'static {
+    let events = Arc::new(tokio::sync::Mutex::new(events));
+
+    move |shutdown| {
+        let (bus, events) = (bus.clone(), events.clone());
+
+        async move {
+            debug!("Mps dispatcher running.");
+
+            let mut events = events.lock().await;
+            let events = stream::poll_fn(|cx| events.poll_recv(cx));
+
+            dispatch_averages(bus, ShutdownStream::new(shutdown, events)).await;
+
+            debug!("Mps dispatcher stopped.");
+
+            Ok::<_, Infallible>(())
+        }
+        .boxed()
+    }
+}
+
+#[async_trait]
+impl Plugin for Mps {
+    type Config = MpsConfig;
+    type Error = Infallible;
+
+    fn restart_policy() -> RestartPolicy {
+        // A listener of the averages panicking takes the dispatcher down with it.
+        RestartPolicy::new(3, Duration::from_secs(1))
+    }
+
+    async fn start(config: Self::Config, bus: &Bus<'_>) -> Result<Self, Self::Error> {
+        let window = Arc::new(Mutex::new(MpsWindow::new(config.window())));
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let listener_window = window.clone();
+        bus.add_listener::<PluginWorker<Mps>, MpsMetricsUpdated, _>(move |metrics| {
+            info!(
+                "Mps: incoming {} new {} known {} invalid {} outgoing {}",
+                metrics.incoming, metrics.new, metrics.known, metrics.invalid, metrics.outgoing
+            );
+
+            let event = {
+                // Panic: the lock is never poisoned as its holders don't panic.
+                let mut window = listener_window.lock().unwrap();
+                window.push(metrics.incoming);
+                MpsAverageUpdated::from(&*window)
+            };
+
+            // The dispatching task may already have been shut down, the event is then simply dropped.
+            let _ = tx.send(event);
+        });
+
+        Ok(Self {
+            window,
+            events: Some(rx),
+        })
+    }
+
+    fn register<N: Node>(&mut self, node: &mut N) {
+        node.register_resource(self.window.clone());
+
+        if let Some(events) = self.events.take() {
+            let bus = node.bus();
+
+            spawn_supervised::<Self, _, _, _, _>(node, dispatcher(bus, events));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::TypeId,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use futures::channel::oneshot;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    use super::*;
+    use crate::plugins::supervisor::supervise;
+
+    fn assert_window(window: &MpsWindow, current: u64, average: f64, peak: u64) {
+        assert_eq!(window.current(), current);
+        assert!((window.average() - average).abs() < f64::EPSILON);
+        assert_eq!(window.peak(), peak);
+    }
+
+    #[test]
+    fn rolling_window() {
+        let mut window = MpsWindow::new(3);
+
+        assert_window(&window, 0, 0.0, 0);
+
+        window.push(4);
+        assert_window(&window, 4, 4.0, 4);
+
+        window.push(8);
+        window.push(3);
+        assert_window(&window, 3, 5.0, 8);
+
+        // The window is full, the oldest samples are evicted.
+        window.push(1);
+        assert_window(&window, 1, 4.0, 8);
+
+        window.push(2);
+        window.push(6);
+        assert_window(&window, 6, 3.0, 6);
+    }
+
+    #[tokio::test]
+    async fn average_event() {
+        let bus = ResourceHandle::new(Bus::default());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        bus.add_listener::<(), MpsAverageUpdated, _>(move |event| {
+            let _ = tx.send(event.clone());
+        });
+
+        let mut mps = Mps::start(MpsConfig::new(2), &bus).await.unwrap();
+        let events = UnboundedReceiverStream::new(mps.events.take().unwrap());
+        let dispatcher = tokio::spawn(dispatch_averages(bus.clone(), events));
+
+        for incoming in [10, 20, 60] {
+            bus.dispatch(MpsMetricsUpdated {
+                incoming,
+                new: 0,
+                known: 0,
+                invalid: 0,
+                outgoing: 0,
+            });
+        }
+
+        let averages = [
+            rx.recv().await.unwrap().average,
+            rx.recv().await.unwrap().average,
+            rx.recv().await.unwrap().average,
+        ];
+
+        assert_eq!(averages, [10.0, 15.0, 40.0]);
+
+        {
+            // Panic: the lock is never poisoned as its holders don't panic.
+            let window = mps.window.lock().unwrap();
+            assert_eq!(window.current(), 60);
+            assert_eq!(window.peak(), 60);
+        }
+
+        // The dispatcher stops once the listener, and therefore the sender, is dropped.
+        bus.remove_listeners_by_id(TypeId::of::<PluginWorker<Mps>>());
+        dispatcher.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn dispatcher_restarts_after_panic() {
+        let bus = ResourceHandle::new(Bus::default());
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (dispatched_tx, mut dispatched_rx) = mpsc::unbounded_channel();
+        let panicked = AtomicBool::new(false);
+
+        // The first average makes the dispatcher panic.
+        bus.add_listener::<(), MpsAverageUpdated, _>(move |event| {
+            if !panicked.swap(true, Ordering::SeqCst) {
+                panic!("deliberate panic");
+            }
+            let _ = dispatched_tx.send(event.current);
+        });
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let supervisor = tokio::spawn(supervise(
+            "mps",
+            RestartPolicy::new(3, Duration::from_millis(1)),
+            shutdown_rx,
+            dispatcher(bus.clone(), rx),
+        ));
+
+        for current in [1, 2] {
+            let mut window = MpsWindow::new(1);
+            window.push(current);
+            tx.send(MpsAverageUpdated::from(&window)).unwrap();
+        }
+
+        // The restarted dispatcher goes on with the next average.
+        assert_eq!(dispatched_rx.recv().await, Some(2));
+
+        shutdown_tx.send(()).unwrap();
+
+        assert_eq!(supervisor.await.unwrap(), 1);
+    }
+}
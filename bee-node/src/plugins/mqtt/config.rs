@@ -3,11 +3,15 @@
 
 use serde::Deserialize;
 
+use crate::plugins::mqtt::topics::TOPICS;
+
 const DEFAULT_ADDRESS: &str = "tcp://localhost:1883";
+const DEFAULT_QOS: u8 = 0;
 
 #[derive(Default, Deserialize, PartialEq)]
 pub struct MqttConfigBuilder {
     address: Option<String>,
+    topics: Option<Vec<MqttTopicConfigBuilder>>,
 }
 
 impl MqttConfigBuilder {
@@ -15,9 +19,48 @@ impl MqttConfigBuilder {
         Self::default()
     }
 
+    pub fn address(mut self, address: String) -> Self {
+        self.address.replace(address);
+        self
+    }
+
+    /// Enables a topic, only the enabled topics are published if any is set.
+    pub fn topic(mut self, name: String, qos: u8) -> Self {
+        self.topics
+            .get_or_insert_with(Vec::new)
+            .push(MqttTopicConfigBuilder { name, qos: Some(qos) });
+        self
+    }
+
     pub fn finish(self) -> MqttConfig {
         MqttConfig {
             address: self.address.unwrap_or_else(|| DEFAULT_ADDRESS.to_owned()),
+            topics: match self.topics {
+                Some(topics) => topics.into_iter().map(MqttTopicConfigBuilder::finish).collect(),
+                // All supported topics are published by default.
+                None => TOPICS
+                    .iter()
+                    .map(|name| MqttTopicConfig {
+                        name: (*name).to_owned(),
+                        qos: DEFAULT_QOS,
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, PartialEq)]
+pub struct MqttTopicConfigBuilder {
+    name: String,
+    qos: Option<u8>,
+}
+
+impl MqttTopicConfigBuilder {
+    pub fn finish(self) -> MqttTopicConfig {
+        MqttTopicConfig {
+            name: self.name,
+            qos: self.qos.unwrap_or(DEFAULT_QOS),
         }
     }
 }
@@ -25,10 +68,31 @@ impl MqttConfigBuilder {
 #[derive(Clone)]
 pub struct MqttConfig {
     address: String,
+    topics: Vec<MqttTopicConfig>,
 }
 
 impl MqttConfig {
     pub fn address(&self) -> &String {
         &self.address
     }
+
+    pub fn topics(&self) -> &[MqttTopicConfig] {
+        &self.topics
+    }
+}
+
+#[derive(Clone)]
+pub struct MqttTopicConfig {
+    name: String,
+    qos: u8,
+}
+
+impl MqttTopicConfig {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn qos(&self) -> u8 {
+        self.qos
+    }
 }
@@ -7,12 +7,22 @@ use log::{error, warn};
 use paho_mqtt as mqtt;
 use thiserror::Error;
 
-use crate::plugins::mqtt::config::MqttConfig;
+use crate::plugins::{mqtt::config::MqttConfig, PluginErrorKind};
 
 #[derive(Error, Debug)]
-pub(crate) enum Error {
+pub enum Error {
     #[error("Mqtt operation failed: {0}.")]
     Mqtt(#[from] mqtt::errors::Error),
+    #[error("Unknown mqtt topic: {0}.")]
+    UnknownTopic(String),
+    #[error("Invalid QoS {qos} for mqtt topic {topic}, expected 0, 1 or 2.")]
+    InvalidQos { topic: String, qos: u8 },
+}
+
+impl PluginErrorKind for Error {
+    fn is_config_error(&self) -> bool {
+        matches!(self, Self::UnknownTopic(_) | Self::InvalidQos { .. })
+    }
 }
 
 pub(crate) struct MqttManager {
@@ -43,13 +53,13 @@ impl MqttManager {
         Ok(manager)
     }
 
-    pub(crate) async fn send<T, P>(&self, topic: T, payload: P)
+    pub(crate) async fn send<T, P>(&self, topic: T, payload: P, qos: i32)
     where
         T: Into<String>,
         P: Into<Vec<u8>>,
     {
         // TODO Send to all that registered to this topic
-        if let Err(e) = self.client.publish(mqtt::Message::new(topic, payload, 0)).await {
+        if let Err(e) = self.client.publish(mqtt::Message::new(topic, payload, qos)).await {
             warn!("Publishing mqtt message failed: {:?}.", e);
         }
     }
@@ -6,93 +6,248 @@ mod topics;
 
 pub mod config;
 
-use std::{any::Any, convert::Infallible};
+use std::{any::Any, collections::HashMap, mem, sync::Arc};
 
 use async_trait::async_trait;
-use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_runtime::{event::Bus, node::Node, shutdown_stream::ShutdownStream};
 use bee_tangle::event::{LatestMilestoneChanged, SolidMilestoneChanged};
-use futures::stream::StreamExt;
+use futures::{Stream, StreamExt};
 use log::{debug, warn};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, RwLock};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
+pub use self::manager::Error;
 use self::{config::MqttConfig, manager::MqttManager, topics::*};
+use crate::plugins::{Plugin, PluginWorker, ReloadError};
 
+// A message to publish: the configured topic it belongs to, its topic name and its payload.
+type Publication = (&'static str, String, Vec<u8>);
+
+// The connection to the broker and the topics published to it, both swapped when the plugin is reloaded.
 #[derive(Default)]
-pub struct Mqtt;
+struct MqttState {
+    // `None` if the broker could not be reached.
+    manager: Option<MqttManager>,
+    // The enabled topics along with their QoS.
+    topics: HashMap<&'static str, i32>,
+}
+
+pub struct Mqtt {
+    state: Arc<RwLock<MqttState>>,
+    // Taken when the plugin is registered to the node.
+    publications: Option<mpsc::UnboundedReceiver<Publication>>,
+}
+
+/// Validates the configured topics, returning the enabled ones along with their QoS.
+fn enabled_topics(config: &MqttConfig) -> Result<Vec<(&'static str, i32)>, Error> {
+    config
+        .topics()
+        .iter()
+        .map(|topic| {
+            let name = TOPICS
+                .iter()
+                .find(|name| *name == topic.name())
+                .ok_or_else(|| Error::UnknownTopic(topic.name().clone()))?;
+
+            if topic.qos() > 2 {
+                return Err(Error::InvalidQos {
+                    topic: topic.name().clone(),
+                    qos: topic.qos(),
+                });
+            }
+
+            Ok((*name, i32::from(topic.qos())))
+        })
+        .collect()
+}
 
-fn topic_handler<N, E, T, P, F>(node: &mut N, topic: &'static str, f: F)
+/// Connects to the broker without blocking the runtime, the client only offering a blocking connection.
+async fn connect(config: MqttConfig) -> Result<MqttManager, Error> {
+    tokio::task::spawn_blocking(move || MqttManager::new(config))
+        .await
+        // Panic: the blocking task is never cancelled, it only fails if connecting panicked.
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+}
+
+fn topic_handler<E, T, P, F>(bus: &Bus<'_>, tx: &mpsc::UnboundedSender<Publication>, topic: &'static str, f: F)
 where
-    N: Node,
     E: Any + Clone + Send + Sync,
-    T: Into<String> + Send,
-    P: Into<Vec<u8>> + Send,
+    T: Into<String>,
+    P: Into<Vec<u8>>,
     F: 'static + Fn(&E) -> (T, P) + Send + Sync,
 {
-    let bus = node.bus();
-    let manager = node.resource::<MqttManager>();
-    let (tx, rx) = mpsc::unbounded_channel();
-
-    node.spawn::<Mqtt, _, _>(|shutdown| async move {
-        debug!("Mqtt {} topic handler running.", topic);
+    let tx = tx.clone();
 
-        let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+    bus.add_listener::<PluginWorker<Mqtt>, _, _>(move |event: &E| {
+        let (topic_name, payload) = f(event);
 
-        while let Some(event) = receiver.next().await {
-            let (topic, payload) = f(&event);
-            manager.send(topic, payload).await;
+        if tx.send((topic, topic_name.into(), payload.into())).is_err() {
+            warn!("Sending event to mqtt {} topic publisher failed.", topic)
         }
-
-        debug!("Mqtt {} topic handler stopped.", topic);
     });
+}
+
+/// Publishes messages of the enabled topics to the broker, if reachable, until the publication stream ends.
+async fn publisher(state: Arc<RwLock<MqttState>>, mut publications: impl Stream<Item = Publication> + Unpin) {
+    while let Some((topic, topic_name, payload)) = publications.next().await {
+        let state = state.read().await;
 
-    bus.add_listener::<Mqtt, _, _>(move |event: &E| {
-        if tx.send((*event).clone()).is_err() {
-            warn!("Sending event to mqtt {} topic handler failed.", topic)
+        if let (Some(manager), Some(qos)) = (state.manager.as_ref(), state.topics.get(topic)) {
+            manager.send(topic_name, payload, *qos).await;
         }
-    });
+    }
 }
 
 #[async_trait]
-impl<N: Node> Worker<N> for Mqtt {
+impl Plugin for Mqtt {
     type Config = MqttConfig;
-    type Error = Infallible;
+    type Error = Error;
 
-    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        match MqttManager::new(config) {
-            Ok(manager) => {
-                // TODO log connected
-                node.register_resource(manager);
+    async fn start(config: Self::Config, bus: &Bus<'_>) -> Result<Self, Self::Error> {
+        let topics = enabled_topics(&config)?.into_iter().collect();
 
-                topic_handler(node, TOPIC_MILESTONES_LATEST, |_event: &LatestMilestoneChanged| {
-                    (TOPIC_MILESTONES_LATEST, "")
-                });
-                topic_handler(node, TOPIC_MILESTONES_SOLID, |_event: &SolidMilestoneChanged| {
-                    (TOPIC_MILESTONES_SOLID, "")
-                });
-                // topic_handler(node, _TOPIC_MESSAGES, |_event: &_| (_TOPIC_MESSAGES, ""));
-                // topic_handler(node, _TOPIC_MESSAGES_REFERENCED, |_event: &_| {
-                //     (_TOPIC_MESSAGES_REFERENCED, "")
-                // });
-                // topic_handler(node, _TOPIC_MESSAGES_INDEXATION, |_event: &_| {
-                //     (_TOPIC_MESSAGES_INDEXATION, "")
-                // });
-                // topic_handler(node, _TOPIC_MESSAGES_METADATA, |_event: &_| {
-                //     (_TOPIC_MESSAGES_METADATA, "")
-                // });
-                // topic_handler(node, _TOPIC_OUTPUTS, |_event: &_| (_TOPIC_OUTPUTS, ""));
-                // topic_handler(node, _TOPIC_ADDRESSES_OUTPUTS, |_event: &_| {
-                //     (_TOPIC_ADDRESSES_OUTPUTS, "")
-                // });
-                // topic_handler(node, _TOPIC_ADDRESSES_ED25519_OUTPUT, |_event: &_| {
-                //     (_TOPIC_ADDRESSES_ED25519_OUTPUT, "")
-                // });
-            }
-            Err(_e) => {
-                // error!("Creating mqtt manager failed {:?}.", e);
+        let manager = match connect(config).await {
+            // TODO log connected
+            Ok(manager) => Some(manager),
+            Err(e) => {
+                warn!("Creating mqtt manager failed: {:?}.", e);
+                None
             }
+        };
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        // Every supported topic is listened to so that the enabled ones can change when the plugin is reloaded.
+        topic_handler(bus, &tx, TOPIC_MILESTONES_LATEST, |_event: &LatestMilestoneChanged| {
+            (TOPIC_MILESTONES_LATEST, "")
+        });
+        topic_handler(bus, &tx, TOPIC_MILESTONES_SOLID, |_event: &SolidMilestoneChanged| {
+            (TOPIC_MILESTONES_SOLID, "")
+        });
+
+        Ok(Self {
+            state: Arc::new(RwLock::new(MqttState { manager, topics })),
+            publications: Some(rx),
+        })
+    }
+
+    fn register<N: Node>(&mut self, node: &mut N) {
+        if let Some(publications) = self.publications.take() {
+            let state = self.state.clone();
+
+            node.spawn::<PluginWorker<Self>, _, _>(|shutdown| async move {
+                debug!("Mqtt publisher running.");
+
+                publisher(
+                    state,
+                    ShutdownStream::new(shutdown, UnboundedReceiverStream::new(publications)),
+                )
+                .await;
+
+                debug!("Mqtt publisher stopped.");
+            });
         }
+    }
+
+    async fn reload(&mut self, config: Self::Config) -> Result<(), ReloadError<Self::Error>> {
+        // Validate the topics and connect to the new broker first so that a failure leaves the current state untouched.
+        let topics = enabled_topics(&config)
+            .map_err(ReloadError::Plugin)?
+            .into_iter()
+            .collect();
+        let manager = connect(config).await.map_err(ReloadError::Plugin)?;
+
+        let previous = mem::replace(
+            &mut *self.state.write().await,
+            MqttState {
+                manager: Some(manager),
+                topics,
+            },
+        );
+
+        // The previous manager disconnects, blocking, when dropped.
+        let _ = tokio::task::spawn_blocking(move || drop(previous)).await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{config::MqttConfigBuilder, *};
+    use crate::plugins::PluginError;
+
+    #[test]
+    fn enabled_topics_subset() {
+        let config = MqttConfigBuilder::new()
+            .topic(TOPIC_MILESTONES_SOLID.to_owned(), 2)
+            .finish();
+
+        assert_eq!(enabled_topics(&config).unwrap(), vec![(TOPIC_MILESTONES_SOLID, 2)]);
+    }
+
+    #[test]
+    fn enabled_topics_default() {
+        let config = MqttConfigBuilder::new().finish();
+
+        assert_eq!(
+            enabled_topics(&config).unwrap(),
+            vec![(TOPIC_MILESTONES_LATEST, 0), (TOPIC_MILESTONES_SOLID, 0)]
+        );
+    }
+
+    #[test]
+    fn enabled_topics_invalid() {
+        let config = MqttConfigBuilder::new()
+            .topic(TOPIC_MILESTONES_LATEST.to_owned(), 0)
+            .topic("milestones/unknown".to_owned(), 0)
+            .finish();
+
+        assert!(matches!(enabled_topics(&config), Err(Error::UnknownTopic(topic)) if topic == "milestones/unknown"));
+
+        let config = MqttConfigBuilder::new()
+            .topic(TOPIC_MILESTONES_LATEST.to_owned(), 3)
+            .finish();
+
+        assert!(matches!(enabled_topics(&config), Err(Error::InvalidQos { qos: 3, .. })));
+    }
+
+    #[tokio::test]
+    async fn start_invalid_config() {
+        let bus = Bus::default();
+        let config = MqttConfigBuilder::new()
+            .topic(TOPIC_MILESTONES_LATEST.to_owned(), 3)
+            .finish();
+
+        let error = PluginError::<Mqtt>(Mqtt::start(config, &bus).await.err().unwrap());
+
+        assert!(error.is_config_error());
+    }
 
-        Ok(Self::default())
+    #[tokio::test]
+    async fn reload_invalid_config() {
+        let bus = Bus::default();
+        // Nothing listens on this port, the broker is unreachable.
+        let config = |qos| {
+            MqttConfigBuilder::new()
+                .address("tcp://127.0.0.1:1".to_owned())
+                .topic(TOPIC_MILESTONES_LATEST.to_owned(), qos)
+                .finish()
+        };
+        let mut mqtt = Mqtt::start(config(1), &bus).await.unwrap();
+
+        assert!(matches!(
+            mqtt.reload(config(3)).await,
+            Err(ReloadError::Plugin(Error::InvalidQos { qos: 3, .. }))
+        ));
+        assert!(matches!(
+            mqtt.reload(config(2)).await,
+            Err(ReloadError::Plugin(Error::Mqtt(_)))
+        ));
+
+        // The failed reloads leave the topics untouched.
+        let state = mqtt.state.read().await;
+
+        assert!(state.manager.is_none());
+        assert_eq!(state.topics, HashMap::from([(TOPIC_MILESTONES_LATEST, 1)]));
     }
 }
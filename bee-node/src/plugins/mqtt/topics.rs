@@ -10,3 +10,7 @@ pub(crate) const _TOPIC_MESSAGES_METADATA: &str = "messages/{messageId}/metadata
 pub(crate) const _TOPIC_OUTPUTS: &str = "outputs/{outputId}";
 pub(crate) const _TOPIC_ADDRESSES_OUTPUTS: &str = "addresses/{address}/outputs";
 pub(crate) const _TOPIC_ADDRESSES_ED25519_OUTPUT: &str = "addresses/ed25519/{address}/outputs";
+
+/// The topics that can currently be published.
+// TODO add the remaining topics once their events are available.
+pub(crate) const TOPICS: &[&str] = &[TOPIC_MILESTONES_LATEST, TOPIC_MILESTONES_SOLID];
@@ -0,0 +1,245 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Supervision of the tasks spawned by the plugins, restarting them when they fail or panic.
+//!
+//! A panic is caught through the join handle of the task, which requires unwinding: with `panic = "abort"`, as the
+//! workspace profiles build the node, a panic still aborts the process.
+
+use std::{
+    any::{type_name, Any},
+    fmt,
+    future::Future,
+    time::Duration,
+};
+
+use bee_runtime::node::Node;
+use futures::channel::oneshot;
+use log::{error, warn};
+
+use crate::{
+    plugins::{Plugin, PluginErrorKind, PluginWorker},
+    shutdown::ShutdownRx,
+};
+
+/// Determines whether and how often a failing plugin task is restarted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RestartPolicy {
+    max_restarts: u32,
+    backoff: Duration,
+}
+
+impl RestartPolicy {
+    /// Creates a policy restarting a task at most `max_restarts` times. The first restart happens after `backoff` and
+    /// the wait doubles before each of the next ones.
+    pub const fn new(max_restarts: u32, backoff: Duration) -> Self {
+        Self { max_restarts, backoff }
+    }
+
+    /// Creates a policy never restarting a task.
+    pub const fn never() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    /// Returns the wait before the restart following `restarts` previous ones.
+    fn backoff(&self, restarts: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(restarts))
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self::never()
+    }
+}
+
+/// Spawns a task on behalf of the worker of plugin `P`, restarting it according to `P::restart_policy`.
+///
+/// The task is built by `g` from a shutdown receiver each time it is started.
+pub fn spawn_supervised<P, N, G, F, E>(node: &mut N, g: G)
+where
+    P: Plugin,
+    N: Node,
+    G: FnMut(ShutdownRx) -> F + Send + 'static,
+    F: Future<Output = Result<(), E>> + Send + 'static,
+    E: fmt::Display + PluginErrorKind + Send + 'static,
+{
+    node.spawn::<PluginWorker<P>, _, _>(|shutdown| async move {
+        supervise(type_name::<P>(), P::restart_policy(), shutdown, g).await;
+    });
+}
+
+/// Runs the task built by `g` until it completes or `shutdown` is triggered, restarting it according to `policy` if it
+/// fails or panics. A task failing because of its configuration is never restarted as it would fail the same way.
+///
+/// Returns the number of times the task was restarted.
+pub(crate) async fn supervise<G, F, E>(name: &str, policy: RestartPolicy, mut shutdown: ShutdownRx, mut g: G) -> u32
+where
+    G: FnMut(ShutdownRx) -> F,
+    F: Future<Output = Result<(), E>> + Send + 'static,
+    E: fmt::Display + PluginErrorKind + Send + 'static,
+{
+    let mut restarts = 0;
+
+    loop {
+        let (task_shutdown_tx, task_shutdown_rx) = oneshot::channel();
+        let mut task = tokio::spawn(g(task_shutdown_rx));
+
+        let res = tokio::select! {
+            res = &mut task => res,
+            _ = &mut shutdown => {
+                // The task may already be stopping on its own, it then doesn't need the signal anymore.
+                let _ = task_shutdown_tx.send(());
+                let _ = task.await;
+
+                return restarts;
+            }
+        };
+
+        match res {
+            Ok(Ok(())) => return restarts,
+            Ok(Err(e)) if e.is_config_error() => {
+                error!("Task of `{}` failed because of its configuration: {}.", name, e);
+                return restarts;
+            }
+            Ok(Err(e)) => error!("Task of `{}` failed: {}.", name, e),
+            Err(e) if e.is_panic() => error!("Task of `{}` panicked: {}.", name, panic_message(e.into_panic())),
+            // Tasks are never aborted, they are only cancelled when the runtime shuts down.
+            Err(_) => return restarts,
+        }
+
+        if restarts == policy.max_restarts {
+            if restarts > 0 {
+                error!(
+                    "Task of `{}` is not restarted anymore after {} restarts.",
+                    name, restarts
+                );
+            }
+            return restarts;
+        }
+
+        let backoff = policy.backoff(restarts);
+
+        warn!(
+            "Restarting task of `{}` in {:?} ({}/{}).",
+            name,
+            backoff,
+            restarts + 1,
+            policy.max_restarts
+        );
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => restarts += 1,
+            _ = &mut shutdown => return restarts,
+        }
+    }
+}
+
+/// Extracts the message of a panic payload, when it has one.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| "unknown panic".to_owned(), |message| (*message).to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("task error, config: {0}")]
+    struct TaskError(bool);
+
+    impl PluginErrorKind for TaskError {
+        fn is_config_error(&self) -> bool {
+            self.0
+        }
+    }
+
+    const POLICY: RestartPolicy = RestartPolicy::new(3, Duration::from_millis(1));
+
+    #[test]
+    fn backoff_doubles() {
+        let policy = RestartPolicy::new(3, Duration::from_millis(10));
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(10));
+        assert_eq!(policy.backoff(1), Duration::from_millis(20));
+        assert_eq!(policy.backoff(2), Duration::from_millis(40));
+        assert_eq!(RestartPolicy::new(1, Duration::MAX).backoff(1), Duration::MAX);
+    }
+
+    #[tokio::test]
+    async fn failing_task_restarts_unless_config_error() {
+        for (config, expected) in [(false, 3), (true, 0)] {
+            let (_shutdown_tx, shutdown_rx) = oneshot::channel();
+
+            let restarts = supervise(
+                "failing",
+                POLICY,
+                shutdown_rx,
+                |_| async move { Err(TaskError(config)) },
+            )
+            .await;
+
+            assert_eq!(restarts, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn panicking_task_restarts_then_gives_up() {
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel();
+        let started = Arc::new(AtomicUsize::new(0));
+        let task_started = started.clone();
+
+        let restarts = supervise("panicking", POLICY, shutdown_rx, move |_| {
+            task_started.fetch_add(1, Ordering::SeqCst);
+            async move {
+                panic!("deliberate panic");
+                #[allow(unreachable_code)]
+                Ok::<_, TaskError>(())
+            }
+        })
+        .await;
+
+        assert_eq!(restarts, 3);
+        assert_eq!(started.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn completed_task_is_not_restarted() {
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        let restarts = supervise("completed", POLICY, shutdown_rx, |_| async { Ok::<_, TaskError>(()) }).await;
+
+        assert_eq!(restarts, 0);
+    }
+
+    #[tokio::test]
+    async fn shutdown_stops_task() {
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let task_stopped = stopped.clone();
+
+        let supervisor = tokio::spawn(supervise("running", POLICY, shutdown_rx, move |shutdown| {
+            let stopped = task_stopped.clone();
+            async move {
+                let _ = shutdown.await;
+                stopped.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, TaskError>(())
+            }
+        }));
+
+        shutdown_tx.send(()).unwrap();
+
+        assert_eq!(supervisor.await.unwrap(), 0);
+        assert_eq!(stopped.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+const DEFAULT_RELEASES_URL: &str = "https://api.github.com/repos/iotaledger/bee/releases/latest";
+const DEFAULT_CHECK_INTERVAL_SEC: u64 = 3600;
+
+#[derive(Default, Deserialize, PartialEq)]
+pub struct VersionCheckerConfigBuilder {
+    #[serde(alias = "releasesUrl")]
+    releases_url: Option<String>,
+    #[serde(alias = "checkInterval")]
+    check_interval: Option<u64>,
+}
+
+impl VersionCheckerConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> VersionCheckerConfig {
+        VersionCheckerConfig {
+            releases_url: self.releases_url.unwrap_or_else(|| DEFAULT_RELEASES_URL.to_owned()),
+            // A zero interval would make the ticker panic.
+            check_interval: self.check_interval.unwrap_or(DEFAULT_CHECK_INTERVAL_SEC).max(1),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct VersionCheckerConfig {
+    releases_url: String,
+    check_interval: u64,
+}
+
+impl VersionCheckerConfig {
+    /// Returns the URL of the endpoint providing the latest release.
+    pub fn releases_url(&self) -> &str {
+        &self.releases_url
+    }
+
+    /// Returns the interval between two checks, in seconds, at least one.
+    pub fn check_interval(&self) -> u64 {
+        self.check_interval
+    }
+}
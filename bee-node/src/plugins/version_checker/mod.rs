@@ -0,0 +1,193 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod config;
+
+use std::{convert::Infallible, time::Duration};
+
+use async_trait::async_trait;
+use bee_runtime::{event::Bus, node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use futures::StreamExt;
+use log::{debug, info, warn};
+use semver::Version;
+use serde::Deserialize;
+use tokio::time::interval;
+use tokio_stream::wrappers::IntervalStream;
+
+use self::config::VersionCheckerConfig;
+
+const REQUEST_TIMEOUT_SEC: u64 = 30;
+
+/// An event signaling that a newer version of the node has been released.
+#[derive(Clone, Debug)]
+pub struct NewVersionAvailable {
+    /// The version of the running node.
+    pub current: Version,
+    /// The latest released version.
+    pub latest: Version,
+}
+
+/// An event carrying the result of a successful version check.
+#[derive(Clone, Debug)]
+pub struct VersionCheckResult {
+    /// The version of the running node.
+    pub current: Version,
+    /// The latest released version.
+    pub latest: Version,
+    /// Whether the latest released version is newer than the running one.
+    pub is_outdated: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("requesting the latest release failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("parsing the latest release version failed: {0}")]
+    Version(#[from] semver::Error),
+}
+
+#[derive(Deserialize)]
+struct Release {
+    tag_name: String,
+}
+
+async fn fetch_latest_version(client: &reqwest::Client, url: &str) -> Result<Version, Error> {
+    let release = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Release>()
+        .await?;
+
+    Ok(Version::parse(release.tag_name.trim_start_matches('v'))?)
+}
+
+/// Returns whether the `latest` released version is newer than the `current` one, a pre-release being older than the
+/// release it precedes.
+fn is_outdated(current: &Version, latest: &Version) -> bool {
+    latest > current
+}
+
+/// Fetches the latest released version and dispatches the result of the check.
+async fn check_version(client: &reqwest::Client, url: &str, current: &Version, bus: &Bus<'static>) {
+    match fetch_latest_version(client, url).await {
+        Ok(latest) => {
+            let is_outdated = is_outdated(current, &latest);
+
+            if is_outdated {
+                warn!("A new version of Bee is available: {} (current: {}).", latest, current);
+
+                bus.dispatch(NewVersionAvailable {
+                    current: current.clone(),
+                    latest: latest.clone(),
+                });
+            } else {
+                debug!("Bee is up to date: {} (latest: {}).", current, latest);
+            }
+
+            bus.dispatch(VersionCheckResult {
+                current: current.clone(),
+                latest,
+                is_outdated,
+            });
+        }
+        // Failures are expected when the node is offline, the check is simply retried on the next tick.
+        Err(e) => debug!("Checking for a new version failed: {}.", e),
+    }
+}
+
+#[derive(Default)]
+pub struct VersionChecker {}
+
+#[async_trait]
+impl<N: Node> Worker<N> for VersionChecker {
+    type Config = VersionCheckerConfig;
+    type Error = Infallible;
+
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let bus = node.bus();
+        // Panic: the crate version is always a valid semantic version.
+        let current = Version::parse(env!("CARGO_PKG_VERSION")).unwrap();
+
+        let client = match reqwest::Client::builder()
+            // The GitHub API rejects requests without a user agent.
+            .user_agent(concat!("bee/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SEC))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                warn!("Creating the HTTP client failed: {}.", e);
+                return Ok(Self::default());
+            }
+        };
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut ticker = ShutdownStream::new(
+                shutdown,
+                IntervalStream::new(interval(Duration::from_secs(config.check_interval()))),
+            );
+
+            while ticker.next().await.is_some() {
+                check_version(&client, config.releases_url(), &current, &bus).await;
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    fn version(version: &str) -> Version {
+        Version::parse(version).unwrap()
+    }
+
+    #[test]
+    fn outdated_versions() {
+        assert!(is_outdated(&version("0.3.0"), &version("0.3.1")));
+        assert!(is_outdated(&version("0.3.0"), &version("1.0.0")));
+        assert!(!is_outdated(&version("0.3.0"), &version("0.3.0")));
+        assert!(!is_outdated(&version("0.3.1"), &version("0.3.0")));
+        // A pre-release precedes its release.
+        assert!(is_outdated(&version("0.3.0-rc.1"), &version("0.3.0")));
+        assert!(is_outdated(&version("0.3.0-rc.1"), &version("0.3.0-rc.2")));
+        assert!(!is_outdated(&version("0.3.0"), &version("0.3.0-rc.1")));
+    }
+
+    #[tokio::test]
+    async fn failed_check_dispatches_nothing() {
+        let bus = Bus::default();
+        let dispatched = Arc::new(AtomicUsize::new(0));
+
+        let counter = dispatched.clone();
+        bus.add_listener::<(), VersionCheckResult, _>(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let counter = dispatched.clone();
+        bus.add_listener::<(), NewVersionAvailable, _>(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // Nothing listens on the port, the request fails.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        drop(listener);
+
+        check_version(&reqwest::Client::new(), &url, &version("0.3.0"), &bus).await;
+
+        assert_eq!(dispatched.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A module that provides a type describing the capabilities negotiated with peers.
+
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// A set of optional protocol features, both offered by the node and agreed on with a peer at connection time.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Capabilities(u8);
+
+impl Capabilities {
+    /// No capability.
+    pub const NONE: Self = Self(0);
+    /// Packets carry a CRC32 checksum.
+    pub const CRC: Self = Self(1 << 0);
+    /// Packets may be compressed.
+    pub const COMPRESSION: Self = Self(1 << 1);
+
+    /// Creates `Capabilities` from their bits, ignoring the unknown ones.
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & (Self::CRC.0 | Self::COMPRESSION.0))
+    }
+
+    /// Returns the bits of the `Capabilities`.
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether all the given capabilities are part of the `Capabilities`.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the capabilities both sides of a connection offered, the only ones that can be used.
+    pub fn negotiate(&self, other: Self) -> Self {
+        *self & other
+    }
+
+    /// Returns whether packets carry a CRC32 checksum.
+    pub fn supports_crc(&self) -> bool {
+        self.contains(Self::CRC)
+    }
+
+    /// Returns whether packets may be compressed.
+    pub fn supports_compression(&self) -> bool {
+        self.contains(Self::COMPRESSION)
+    }
+}
+
+impl BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Capabilities {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAnd for Capabilities {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate() {
+        let offered = Capabilities::CRC | Capabilities::COMPRESSION;
+        let negotiated = offered.negotiate(Capabilities::COMPRESSION);
+
+        assert!(offered.supports_crc() && offered.supports_compression());
+        assert!(!negotiated.supports_crc());
+        assert!(negotiated.supports_compression());
+        assert_eq!(Capabilities::default(), Capabilities::NONE);
+        assert_eq!(Capabilities::from_bits(0xff), offered);
+    }
+}
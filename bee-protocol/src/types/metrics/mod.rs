@@ -6,4 +6,7 @@
 pub mod node;
 pub mod peer;
 
-pub use self::{node::NodeMetrics, peer::PeerMetrics};
+pub use self::{
+    node::NodeMetrics,
+    peer::{PeerMetrics, PeerMetricsSnapshot},
+};
@@ -20,6 +20,47 @@ pub struct PeerMetrics {
     messages_sent: AtomicU64,
     message_requests_sent: AtomicU64,
     heartbeats_sent: AtomicU64,
+    packets_received: AtomicU64,
+    bytes_received: AtomicU64,
+    packets_sent: AtomicU64,
+    bytes_sent: AtomicU64,
+}
+
+/// A point-in-time copy of the metrics related to a peer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PeerMetricsSnapshot {
+    /// The number of invalid messages.
+    pub invalid_messages: u64,
+    /// The number of new messages.
+    pub new_messages: u64,
+    /// The number of known messages.
+    pub known_messages: u64,
+    /// The number of invalid packets.
+    pub invalid_packets: u64,
+    /// The number of received milestone requests.
+    pub milestone_requests_received: u64,
+    /// The number of received messages.
+    pub messages_received: u64,
+    /// The number of received message requests.
+    pub message_requests_received: u64,
+    /// The number of received heartbeats.
+    pub heartbeats_received: u64,
+    /// The number of sent milestone requests.
+    pub milestone_requests_sent: u64,
+    /// The number of sent messages.
+    pub messages_sent: u64,
+    /// The number of sent message requests.
+    pub message_requests_sent: u64,
+    /// The number of sent heartbeats.
+    pub heartbeats_sent: u64,
+    /// The number of received packets.
+    pub packets_received: u64,
+    /// The number of received bytes.
+    pub bytes_received: u64,
+    /// The number of sent packets.
+    pub packets_sent: u64,
+    /// The number of sent bytes.
+    pub bytes_sent: u64,
 }
 
 impl PeerMetrics {
@@ -142,6 +183,60 @@ impl PeerMetrics {
     pub fn heartbeats_sent_inc(&self) -> u64 {
         self.heartbeats_sent.fetch_add(1, Ordering::SeqCst)
     }
+
+    /// Returns the number of received packets of the `PeerMetrics`.
+    pub fn packets_received(&self) -> u64 {
+        self.packets_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of received bytes of the `PeerMetrics`.
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of received packets and adds to the number of received bytes of the `PeerMetrics`.
+    pub fn packet_received(&self, bytes: usize) {
+        self.packets_received.fetch_add(1, Ordering::SeqCst);
+        self.bytes_received.fetch_add(bytes as u64, Ordering::SeqCst);
+    }
+
+    /// Returns the number of sent packets of the `PeerMetrics`.
+    pub fn packets_sent(&self) -> u64 {
+        self.packets_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of sent bytes of the `PeerMetrics`.
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of sent packets and adds to the number of sent bytes of the `PeerMetrics`.
+    pub fn packet_sent(&self, bytes: usize) {
+        self.packets_sent.fetch_add(1, Ordering::SeqCst);
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::SeqCst);
+    }
+
+    /// Returns a snapshot of the `PeerMetrics`.
+    pub fn snapshot(&self) -> PeerMetricsSnapshot {
+        PeerMetricsSnapshot {
+            invalid_messages: self.invalid_messages(),
+            new_messages: self.new_messages(),
+            known_messages: self.known_messages(),
+            invalid_packets: self.invalid_packets(),
+            milestone_requests_received: self.milestone_requests_received(),
+            messages_received: self.messages_received(),
+            message_requests_received: self.message_requests_received(),
+            heartbeats_received: self.heartbeats_received(),
+            milestone_requests_sent: self.milestone_requests_sent(),
+            messages_sent: self.messages_sent(),
+            message_requests_sent: self.message_requests_sent(),
+            heartbeats_sent: self.heartbeats_sent(),
+            packets_received: self.packets_received(),
+            bytes_received: self.bytes_received(),
+            packets_sent: self.packets_sent(),
+            bytes_sent: self.bytes_sent(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -208,4 +303,27 @@ mod tests {
         assert_eq!(metrics.message_requests_sent(), 1);
         assert_eq!(metrics.heartbeats_sent(), 1);
     }
+
+    #[test]
+    fn peer_metrics_throughput() {
+        let metrics = PeerMetrics::default();
+
+        assert_eq!(metrics.snapshot(), PeerMetricsSnapshot::default());
+
+        metrics.packet_received(10);
+        metrics.packet_received(5);
+        metrics.packet_sent(7);
+
+        assert_eq!(metrics.packets_received(), 2);
+        assert_eq!(metrics.bytes_received(), 15);
+        assert_eq!(metrics.packets_sent(), 1);
+        assert_eq!(metrics.bytes_sent(), 7);
+
+        let snapshot = metrics.snapshot();
+        metrics.packet_sent(7);
+
+        assert_eq!(snapshot.packets_sent, 1);
+        assert_eq!(snapshot.bytes_sent, 7);
+        assert_eq!(metrics.snapshot().bytes_sent, 14);
+    }
 }
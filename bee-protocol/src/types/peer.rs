@@ -11,7 +11,7 @@ use std::{
 use bee_gossip::{Multiaddr, PeerId, PeerInfo, PeerRelation};
 use bee_message::milestone::MilestoneIndex;
 
-use crate::types::metrics::PeerMetrics;
+use crate::types::{capabilities::Capabilities, metrics::PeerMetrics};
 
 const SYNCED_THRESHOLD: u32 = 2;
 
@@ -20,6 +20,7 @@ pub struct Peer {
     id: PeerId,
     info: PeerInfo,
     connected: AtomicBool,
+    capabilities: AtomicU8,
     metrics: PeerMetrics,
     solid_milestone_index: AtomicU32,
     pruned_index: AtomicU32,
@@ -37,6 +38,7 @@ impl Peer {
             id,
             info,
             connected: AtomicBool::new(false),
+            capabilities: AtomicU8::new(Capabilities::NONE.bits()),
             metrics: PeerMetrics::default(),
             solid_milestone_index: AtomicU32::new(0),
             pruned_index: AtomicU32::new(0),
@@ -78,6 +80,16 @@ impl Peer {
         self.connected.load(Ordering::Relaxed)
     }
 
+    /// Sets the capabilities negotiated with the `Peer`.
+    pub fn set_capabilities(&self, capabilities: Capabilities) {
+        self.capabilities.store(capabilities.bits(), Ordering::Relaxed);
+    }
+
+    /// Returns the capabilities negotiated with the `Peer`.
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::from_bits(self.capabilities.load(Ordering::Relaxed))
+    }
+
     /// Returns the metrics of the `Peer`.
     pub fn metrics(&self) -> &PeerMetrics {
         &self.metrics
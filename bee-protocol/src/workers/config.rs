@@ -1,10 +1,12 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use bee_message::milestone::MilestoneIndex;
 use serde::Deserialize;
 
-use crate::types::milestone_key_range::MilestoneKeyRange;
+use crate::{types::milestone_key_range::MilestoneKeyRange, workers::peer::FullQueuePolicy};
 
 const DEFAULT_MINIMUM_POW_SCORE: f64 = 4000.0;
 const DEFAULT_COO_PUBLIC_KEY_COUNT: usize = 2;
@@ -12,6 +14,10 @@ const DEFAULT_COO_PUBLIC_KEY_RANGES: [(&str, MilestoneIndex, MilestoneIndex); 0]
 const DEFAULT_MESSAGE_WORKER_CACHE: usize = 10000;
 const DEFAULT_STATUS_INTERVAL: u64 = 10;
 const DEFAULT_MILESTONE_SYNC_COUNT: u32 = 200;
+const DEFAULT_MAX_PEERS: usize = 16;
+const DEFAULT_TLV_CRC: bool = false;
+const DEFAULT_TLV_COMPRESSION: bool = false;
+const DEFAULT_MESSAGE_REQUEST_DEDUP_TTL: u64 = 1000;
 
 #[derive(Default, Deserialize, PartialEq)]
 #[must_use]
@@ -31,6 +37,16 @@ struct ProtocolWorkersConfigBuilder {
     status_interval: Option<u64>,
     #[serde(alias = "milestoneSyncCount")]
     milestone_sync_count: Option<u32>,
+    #[serde(alias = "maxPeers")]
+    max_peers: Option<usize>,
+    #[serde(alias = "tlvCrc")]
+    tlv_crc: Option<bool>,
+    #[serde(alias = "tlvCompression")]
+    tlv_compression: Option<bool>,
+    #[serde(alias = "messageRequestDedupTtl")]
+    message_request_dedup_ttl: Option<u64>,
+    #[serde(alias = "fullQueuePolicy")]
+    full_queue_policy: Option<FullQueuePolicy>,
 }
 
 /// Builder for a `ProtocolConfig`.
@@ -85,6 +101,43 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    /// Sets the maximum number of peers of the `ProtocolConfigBuilder`.
+    /// Manually configured peers are always accepted, regardless of this limit.
+    pub fn max_peers(mut self, max_peers: usize) -> Self {
+        self.workers.max_peers.replace(max_peers);
+        self
+    }
+
+    /// Sets whether packets carrying a trailing CRC32 checksum are offered to peers in the `ProtocolConfigBuilder`.
+    /// Checksums are negotiated per peer and only used with peers that enable them too.
+    pub fn tlv_crc(mut self, tlv_crc: bool) -> Self {
+        self.workers.tlv_crc.replace(tlv_crc);
+        self
+    }
+
+    /// Sets whether compressed message packets are offered to peers in the `ProtocolConfigBuilder`.
+    /// Compression is negotiated per peer and only used with peers that enable it too.
+    pub fn tlv_compression(mut self, tlv_compression: bool) -> Self {
+        self.workers.tlv_compression.replace(tlv_compression);
+        self
+    }
+
+    /// Sets the time, in milliseconds, during which a message is not requested again once it has been requested in the
+    /// `ProtocolConfigBuilder`.
+    pub fn message_request_dedup_ttl(mut self, message_request_dedup_ttl: u64) -> Self {
+        self.workers
+            .message_request_dedup_ttl
+            .replace(message_request_dedup_ttl);
+        self
+    }
+
+    /// Sets what to do with the peers whose gossip queue is full in the `ProtocolConfigBuilder`.
+    /// The capacity of the queues is set in the network configuration.
+    pub fn full_queue_policy(mut self, full_queue_policy: FullQueuePolicy) -> Self {
+        self.workers.full_queue_policy.replace(full_queue_policy);
+        self
+    }
+
     /// Finishes the `ProtocolConfigBuilder` into a `ProtocolConfig`.
     #[must_use]
     pub fn finish(self) -> ProtocolConfig {
@@ -112,6 +165,15 @@ impl ProtocolConfigBuilder {
                     .workers
                     .milestone_sync_count
                     .unwrap_or(DEFAULT_MILESTONE_SYNC_COUNT),
+                max_peers: self.workers.max_peers.unwrap_or(DEFAULT_MAX_PEERS),
+                tlv_crc: self.workers.tlv_crc.unwrap_or(DEFAULT_TLV_CRC),
+                tlv_compression: self.workers.tlv_compression.unwrap_or(DEFAULT_TLV_COMPRESSION),
+                message_request_dedup_ttl: Duration::from_millis(
+                    self.workers
+                        .message_request_dedup_ttl
+                        .unwrap_or(DEFAULT_MESSAGE_REQUEST_DEDUP_TTL),
+                ),
+                full_queue_policy: self.workers.full_queue_policy.unwrap_or_default(),
             },
         }
     }
@@ -130,6 +192,11 @@ pub struct ProtocolWorkersConfig {
     pub(crate) message_worker_cache: usize,
     pub(crate) status_interval: u64,
     pub(crate) milestone_sync_count: u32,
+    pub(crate) max_peers: usize,
+    pub(crate) tlv_crc: bool,
+    pub(crate) tlv_compression: bool,
+    pub(crate) message_request_dedup_ttl: Duration,
+    pub(crate) full_queue_policy: FullQueuePolicy,
 }
 
 /// Configuration for the protocol.
@@ -155,4 +222,14 @@ impl ProtocolConfig {
     pub fn coordinator(&self) -> &ProtocolCoordinatorConfig {
         &self.coordinator
     }
+
+    /// Returns whether CRC32 checksummed TLV packets are offered to peers by the `ProtocolConfig`.
+    pub fn tlv_crc(&self) -> bool {
+        self.workers.tlv_crc
+    }
+
+    /// Returns whether compressed message packets are offered to peers by the `ProtocolConfig`.
+    pub fn tlv_compression(&self) -> bool {
+        self.workers.tlv_compression
+    }
 }
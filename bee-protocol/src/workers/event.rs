@@ -1,6 +1,7 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use bee_gossip::{Multiaddr, PeerId};
 use bee_message::MessageId;
 
 /// An event that indicates that a message was processed.
@@ -53,6 +54,24 @@ pub struct VertexCreated {
     pub is_selected: bool,
 }
 
+/// An event that indicates that a peer was added.
+#[derive(Clone)]
+pub struct PeerAdded {
+    /// Identifier of the added peer.
+    pub peer_id: PeerId,
+    /// Address of the added peer.
+    pub address: Multiaddr,
+}
+
+/// An event that indicates that a peer was removed.
+#[derive(Clone)]
+pub struct PeerRemoved {
+    /// Identifier of the removed peer.
+    pub peer_id: PeerId,
+    /// Address of the removed peer.
+    pub address: Multiaddr,
+}
+
 /// An event that indicates that a tip was added.
 #[derive(Clone)]
 pub struct TipAdded {
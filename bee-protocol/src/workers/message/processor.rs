@@ -21,7 +21,7 @@ use crate::{
         message::submitter::{notify_invalid_message, notify_message},
         packets::MessagePacket,
         peer::PeerManager,
-        requester::request_message,
+        requester::request_messages,
         storage::StorageBackend,
         BroadcasterWorker, BroadcasterWorkerEvent, MessageRequesterWorker, MessageSubmitterError, MetricsWorker,
         PayloadWorker, PayloadWorkerEvent, PeerManagerResWorker, PropagatorWorker, PropagatorWorkerEvent,
@@ -161,10 +161,14 @@ where
                                 latency_sum += (Instant::now() - instant).as_millis() as u64;
                                 metrics.messages_average_latency_set(latency_sum / latency_num);
 
-                                for parent in message.parents().iter() {
-                                    request_message(&tangle, &message_requester, &*requested_messages, *parent, index)
-                                        .await;
-                                }
+                                request_messages(
+                                    &tangle,
+                                    &message_requester,
+                                    &requested_messages,
+                                    message.parents(),
+                                    index,
+                                )
+                                .await;
                             }
                             // Message was not requested.
                             None => {
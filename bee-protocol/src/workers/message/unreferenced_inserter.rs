@@ -8,7 +8,7 @@ use bee_runtime::{
     shutdown_stream::ShutdownStream,
     worker::{Error as WorkerError, Worker},
 };
-use bee_storage::access::{Batch, BatchBuilder, Insert};
+use bee_storage::access::{Batch, BatchBuilder, Durability, Insert};
 use bee_tangle::unreferenced_message::UnreferencedMessage;
 use futures::{future::FutureExt, stream::StreamExt};
 use log::{debug, error, info};
@@ -57,7 +57,7 @@ where
 
                 counter += 1;
                 if counter == UNREFERENCED_MESSAGE_BATCH_SIZE {
-                    if let Err(e) = storage.batch_commit(batch, true) {
+                    if let Err(e) = storage.batch_commit_with(batch, Durability::Async) {
                         error!("Committing unreferenced message batch failed: {:?}.", e);
                     }
                     batch = N::Backend::batch_begin();
@@ -65,7 +65,7 @@ where
                 }
             }
 
-            if let Err(e) = storage.batch_commit(batch, true) {
+            if let Err(e) = storage.batch_commit_with(batch, Durability::Async) {
                 error!("Committing unreferenced message batch failed: {:?}.", e);
             }
 
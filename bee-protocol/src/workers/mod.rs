@@ -19,6 +19,8 @@ mod responder;
 mod sender;
 mod solidifier;
 mod status;
+#[cfg(test)]
+mod test_util;
 
 use bee_autopeering::event::EventRx as AutopeeringEventRx;
 use bee_gossip::NetworkEventReceiver as NetworkEventRx;
@@ -47,8 +49,8 @@ pub(crate) use self::{
 pub use self::{
     message::{MessageSubmitterError, MessageSubmitterWorker, MessageSubmitterWorkerEvent},
     metrics::MetricsWorker,
-    peer::{PeerManager, PeerManagerResWorker},
-    requester::{request_message, MessageRequesterWorker, RequestedMessages, RequestedMilestones},
+    peer::{FullQueuePolicy, PeerEvent, PeerManager, PeerManagerResWorker, PeerSummary},
+    requester::{request_message, request_messages, MessageRequesterWorker, RequestedMessages, RequestedMilestones},
 };
 
 pub fn init<N: Node>(
@@ -63,7 +65,7 @@ where
 {
     node_builder
         .with_worker::<MetricsWorker>()
-        .with_worker::<PeerManagerResWorker>()
+        .with_worker_cfg::<PeerManagerResWorker>((config.workers.max_peers, config.workers.full_queue_policy))
         .with_worker_cfg::<PeerManagerWorker>(PeerManagerConfig {
             network_rx: network_events,
             peering_rx: autopeering_events,
@@ -73,7 +75,7 @@ where
         .with_worker_cfg::<ProcessorWorker>(network_id.1)
         .with_worker::<MessageResponderWorker>()
         .with_worker::<MilestoneResponderWorker>()
-        .with_worker::<MessageRequesterWorker>()
+        .with_worker_cfg::<MessageRequesterWorker>(config.workers.message_request_dedup_ttl)
         .with_worker::<MilestoneRequesterWorker>()
         .with_worker::<PayloadWorker>()
         .with_worker::<TransactionPayloadWorker>()
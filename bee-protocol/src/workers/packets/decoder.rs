@@ -0,0 +1,177 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Streaming decoder of the type-length-value encoding.
+
+use crate::workers::packets::{HeaderPacket, CHECKSUM_SIZE, HEADER_SIZE};
+
+/// The read state of the decoder.
+enum ReadState {
+    /// The decoder should read a header.
+    Header,
+    /// The decoder should read a payload based on a header.
+    Payload(HeaderPacket),
+}
+
+/// A stateful decoder of TLV encoded packets.
+///
+/// Bytes are fed as they arrive, in chunks of arbitrary sizes, and packets are yielded once they have been fully
+/// received.
+pub(crate) struct TlvDecoder {
+    buffer: Vec<u8>,
+    offset: usize,
+    state: ReadState,
+    with_crc: bool,
+}
+
+impl TlvDecoder {
+    /// Creates a new `TlvDecoder`, expecting payloads to be followed by a CRC32 checksum or not.
+    pub(crate) fn new(with_crc: bool) -> Self {
+        Self {
+            buffer: Vec::new(),
+            offset: 0,
+            state: ReadState::Header,
+            with_crc,
+        }
+    }
+
+    /// Feeds bytes to the `TlvDecoder`.
+    pub(crate) fn push(&mut self, buf: &[u8]) {
+        // Remove the already decoded bytes from the buffer.
+        self.buffer.drain(..self.offset);
+        self.offset = 0;
+        self.buffer.extend_from_slice(buf);
+    }
+
+    fn payload_len(&self, header: &HeaderPacket) -> usize {
+        usize::from(header.packet_length) + if self.with_crc { CHECKSUM_SIZE } else { 0 }
+    }
+
+    /// Returns whether a complete packet is available, reading its header if needed.
+    pub(crate) fn has_frame(&mut self) -> bool {
+        if let ReadState::Header = self.state {
+            if self.buffer.len() - self.offset < HEADER_SIZE {
+                return false;
+            }
+
+            // This never panics because there are at least `HEADER_SIZE` bytes available.
+            let header = HeaderPacket::from_bytes(self.buffer[self.offset..][..HEADER_SIZE].try_into().unwrap());
+            self.offset += HEADER_SIZE;
+            self.state = ReadState::Payload(header);
+        }
+
+        match &self.state {
+            ReadState::Payload(header) => self.buffer.len() - self.offset >= self.payload_len(header),
+            ReadState::Header => false,
+        }
+    }
+
+    /// Returns the header and the payload of the next complete packet, if any.
+    ///
+    /// The payload includes the trailing checksum if the decoder expects one.
+    pub(crate) fn next_frame(&mut self) -> Option<(HeaderPacket, &[u8])> {
+        if !self.has_frame() {
+            return None;
+        }
+
+        match std::mem::replace(&mut self.state, ReadState::Header) {
+            ReadState::Payload(header) => {
+                let len = self.payload_len(&header);
+                let bytes = &self.buffer[self.offset..][..len];
+                self.offset += len;
+
+                Some((header, bytes))
+            }
+            ReadState::Header => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workers::packets::{tlv_from_bytes, tlv_to_bytes, HeartbeatPacket, MessagePacket, Packet, TlvError};
+
+    impl TlvDecoder {
+        /// Decodes the next complete packet, if any, as a packet of type `P`, like the packet handler does.
+        fn decode<P: Packet>(&mut self) -> Option<Result<P, TlvError>> {
+            let with_crc = self.with_crc;
+
+            self.next_frame()
+                .map(|(header, bytes)| tlv_from_bytes(&header, bytes, with_crc))
+        }
+    }
+
+    fn decode_in_chunks(bytes: &[u8], splits: &[usize], with_crc: bool) -> Vec<u8> {
+        let mut decoder = TlvDecoder::new(with_crc);
+        let mut start = 0;
+
+        for &end in splits {
+            decoder.push(&bytes[start..end]);
+            assert!(decoder.decode::<MessagePacket>().is_none());
+            start = end;
+        }
+
+        decoder.push(&bytes[start..]);
+        let packet = decoder.decode::<MessagePacket>().unwrap().unwrap();
+        assert!(decoder.decode::<MessagePacket>().is_none());
+
+        packet.bytes
+    }
+
+    #[test]
+    fn split_packet() {
+        let message = (0..200).map(|i| i as u8).collect::<Vec<_>>();
+
+        for with_crc in [false, true] {
+            let bytes = tlv_to_bytes(&MessagePacket::new(message.clone()), with_crc, false);
+
+            assert_eq!(decode_in_chunks(&bytes, &[], with_crc), message);
+            // Split within the header and within the payload.
+            assert_eq!(decode_in_chunks(&bytes, &[2], with_crc), message);
+            assert_eq!(decode_in_chunks(&bytes, &[100], with_crc), message);
+            assert_eq!(decode_in_chunks(&bytes, &[1, 100], with_crc), message);
+            assert_eq!(
+                decode_in_chunks(&bytes, &[HEADER_SIZE, bytes.len() - 1], with_crc),
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn consecutive_packets() {
+        let mut decoder = TlvDecoder::new(false);
+        let mut bytes = tlv_to_bytes(&HeartbeatPacket::new(1, 2, 3, 4, 5), false, false);
+        bytes.extend(tlv_to_bytes(&HeartbeatPacket::new(6, 7, 8, 9, 10), false, false));
+
+        let (first, second) = bytes.split_at(20);
+        decoder.push(first);
+
+        let packet = decoder.decode::<HeartbeatPacket>().unwrap().unwrap();
+        assert_eq!(packet.solid_milestone_index, 1);
+        assert!(decoder.decode::<HeartbeatPacket>().is_none());
+
+        decoder.push(second);
+
+        let packet = decoder.decode::<HeartbeatPacket>().unwrap().unwrap();
+        assert_eq!(packet.solid_milestone_index, 6);
+        assert!(decoder.decode::<HeartbeatPacket>().is_none());
+    }
+
+    #[test]
+    fn invalid_packet() {
+        let mut decoder = TlvDecoder::new(false);
+        let mut bytes = tlv_to_bytes(&HeartbeatPacket::new(1, 2, 3, 4, 5), false, false);
+        bytes.extend(tlv_to_bytes(&HeartbeatPacket::new(6, 7, 8, 9, 10), false, false));
+
+        decoder.push(&bytes);
+
+        assert!(matches!(
+            decoder.decode::<MessagePacket>(),
+            Some(Err(TlvError::InvalidAdvertisedType { .. }))
+        ));
+
+        let packet = decoder.decode::<HeartbeatPacket>().unwrap().unwrap();
+        assert_eq!(packet.solid_milestone_index, 6);
+    }
+}
@@ -7,21 +7,49 @@ const HEADER_TYPE_SIZE: usize = 1;
 const HEADER_LENGTH_SIZE: usize = 2;
 pub(crate) const HEADER_SIZE: usize = HEADER_TYPE_SIZE + HEADER_LENGTH_SIZE;
 
+// The type byte holds the packet type in its low nibble, the protocol version in the next three bits and whether the
+// packet is compressed in its most significant bit.
+const HEADER_TYPE_MASK: u8 = 0x0f;
+const HEADER_VERSION_SHIFT: u8 = 4;
+const HEADER_VERSION_MASK: u8 = 0x07;
+const HEADER_COMPRESSED_FLAG: u8 = 0x80;
+
+/// Current version of the gossip protocol.
+///
+/// Peers unaware of the versioning always leave the high bits of the type byte empty, which reads as version 0.
+pub(crate) const PROTOCOL_VERSION: u8 = 0;
+
 /// A header for the type-length-value encoding.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct HeaderPacket {
+    /// Version of the protocol the packet is encoded with.
+    pub(crate) version: u8,
     /// Type of the packet.
     pub(crate) packet_type: u8,
-    /// Length of the packet.
+    /// Whether the packet is compressed.
+    pub(crate) compressed: bool,
+    /// Length of the packet, as sent on the wire.
     pub(crate) packet_length: u16,
 }
 
 impl HeaderPacket {
+    /// Creates a new `HeaderPacket` of an uncompressed packet with the current protocol version.
+    pub(crate) fn new(packet_type: u8, packet_length: u16) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            packet_type,
+            compressed: false,
+            packet_length,
+        }
+    }
+
     pub(crate) fn from_bytes(bytes: &[u8; HEADER_SIZE]) -> Self {
         // This never panics because `HEADER_TYPE_SIZE < HEADER_SIZE`.
         let (packet_type_bytes, packet_length_bytes) = bytes.split_at(HEADER_TYPE_SIZE);
         Self {
-            packet_type: packet_type_bytes[0],
+            version: (packet_type_bytes[0] >> HEADER_VERSION_SHIFT) & HEADER_VERSION_MASK,
+            packet_type: packet_type_bytes[0] & HEADER_TYPE_MASK,
+            compressed: packet_type_bytes[0] & HEADER_COMPRESSED_FLAG != 0,
             // This never panics because `packet_length_bytes` has exactly
             // `HEADER_SIZE - HEADER_TYPE_SIZE` bytes by construction.
             packet_length: u16::from_le_bytes(packet_length_bytes.try_into().unwrap()),
@@ -29,7 +57,11 @@ impl HeaderPacket {
     }
 
     pub(crate) fn to_bytes(&self, bytes: &mut [u8]) {
-        bytes[0] = self.packet_type;
+        bytes[0] =
+            ((self.version & HEADER_VERSION_MASK) << HEADER_VERSION_SHIFT) | (self.packet_type & HEADER_TYPE_MASK);
+        if self.compressed {
+            bytes[0] |= HEADER_COMPRESSED_FLAG;
+        }
         bytes[1..].copy_from_slice(&self.packet_length.to_le_bytes());
     }
 }
@@ -3,7 +3,10 @@
 
 //! Heartbeat packet of the protocol.
 
-use std::ops::Range;
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
 
 use crate::workers::packets::Packet;
 
@@ -104,6 +107,12 @@ impl Packet for HeartbeatPacket {
         let (bytes, _) = next.split_at_mut(SYNCED_PEERS_SIZE);
         bytes.copy_from_slice(&self.synced_peers.to_le_bytes());
     }
+
+    fn to_writer<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut bytes = [0u8; CONSTANT_SIZE];
+        self.to_bytes(&mut bytes);
+        writer.write_all(&bytes)
+    }
 }
 
 #[cfg(test)]
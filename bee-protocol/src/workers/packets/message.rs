@@ -3,9 +3,13 @@
 
 //! Message packet of the protocol.
 
-use std::ops::Range;
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
 
-use bee_message::{MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN};
+use bee_common::packable::Packable;
+use bee_message::{Message, MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN};
 
 use crate::workers::packets::Packet;
 
@@ -20,11 +24,23 @@ impl MessagePacket {
     pub(crate) fn new(bytes: Vec<u8>) -> Self {
         Self { bytes }
     }
+
+    /// Creates a packet from a borrowed message, packing it in a buffer allocated once to its packed length.
+    pub(crate) fn from_message_ref(message: &Message) -> Self {
+        let mut bytes = Vec::with_capacity(message.packed_len());
+        // Panic: packing to a `Vec` can't fail.
+        message.pack(&mut bytes).unwrap();
+
+        Self { bytes }
+    }
 }
 
 impl Packet for MessagePacket {
     const ID: u8 = 0x02;
 
+    // Message payloads are large enough for compression to pay off.
+    const COMPRESSIBLE: bool = true;
+
     fn size_range() -> Range<usize> {
         (MESSAGE_LENGTH_MIN)..(MESSAGE_LENGTH_MAX + 1)
     }
@@ -40,6 +56,10 @@ impl Packet for MessagePacket {
     fn to_bytes(&self, bytes: &mut [u8]) {
         bytes.copy_from_slice(&self.bytes)
     }
+
+    fn to_writer<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.bytes)
+    }
 }
 
 #[cfg(test)]
@@ -103,4 +123,17 @@ mod tests {
 
         assert!(packet_to.bytes.eq(&MESSAGE));
     }
+
+    #[test]
+    fn from_message_ref() {
+        // Network id 0, the null parent, no payload and nonce 0.
+        let mut bytes = [0u8; MESSAGE_LENGTH_MIN];
+        bytes[8] = 1;
+        let message = Message::unpack(&mut bytes.as_slice()).unwrap();
+        let packet = MessagePacket::from_message_ref(&message);
+
+        assert_eq!(packet.size(), message.packed_len());
+        assert_eq!(packet.bytes.capacity(), message.packed_len());
+        assert_eq!(packet.bytes, message.pack_new());
+    }
 }
@@ -3,7 +3,10 @@
 
 //! MessageRequest packet of the protocol.
 
-use std::ops::Range;
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
 
 use bee_message::MessageId;
 
@@ -49,6 +52,10 @@ impl Packet for MessageRequestPacket {
     fn to_bytes(&self, bytes: &mut [u8]) {
         bytes.copy_from_slice(self.message_id.as_ref())
     }
+
+    fn to_writer<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.message_id.as_ref())
+    }
 }
 
 #[cfg(test)]
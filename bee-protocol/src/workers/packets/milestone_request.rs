@@ -3,7 +3,10 @@
 
 //! MilestoneRequest packet of the protocol.
 
-use std::ops::Range;
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
 
 use crate::workers::packets::Packet;
 
@@ -43,6 +46,10 @@ impl Packet for MilestoneRequestPacket {
     fn to_bytes(&self, bytes: &mut [u8]) {
         bytes.copy_from_slice(&self.index.to_le_bytes())
     }
+
+    fn to_writer<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.index.to_le_bytes())
+    }
 }
 
 #[cfg(test)]
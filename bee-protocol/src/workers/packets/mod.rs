@@ -3,6 +3,7 @@
 
 // TODO document
 
+mod decoder;
 mod header;
 mod heartbeat;
 mod message;
@@ -10,15 +11,19 @@ mod message_request;
 mod milestone_request;
 mod tlv;
 
-use std::ops::Range;
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
 
 pub(crate) use self::{
-    header::{HeaderPacket, HEADER_SIZE},
+    decoder::TlvDecoder,
+    header::{HeaderPacket, HEADER_SIZE, PROTOCOL_VERSION},
     heartbeat::HeartbeatPacket,
     message::MessagePacket,
     message_request::MessageRequestPacket,
     milestone_request::MilestoneRequestPacket,
-    tlv::{tlv_from_bytes, tlv_to_bytes, Error as TlvError},
+    tlv::{tlv_from_bytes, tlv_to_bytes, Error as TlvError, CHECKSUM_SIZE},
 };
 
 /// A trait describing the behavior of a packet.
@@ -30,7 +35,10 @@ pub(crate) trait Packet {
     /// The unique identifier of the packet within the protocol.
     const ID: u8;
 
-    /// Returns the size range of the packet as it can be compressed.
+    /// Whether the packet may be compressed on the wire.
+    const COMPRESSIBLE: bool = false;
+
+    /// Returns the size range of the packet, once decompressed.
     fn size_range() -> Range<usize>;
 
     /// Deserializes a byte buffer into a packet.
@@ -59,4 +67,11 @@ pub(crate) trait Packet {
     /// Panics if the provided buffer has an invalid size.
     /// The size of the buffer should be equal to the one returned by the `size` method.
     fn to_bytes(&self, bytes: &mut [u8]);
+
+    /// Serializes a packet to a writer, without going through an intermediate heap allocated buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`  -   The writer to serialize into.
+    fn to_writer<W: Write>(&self, writer: &mut W) -> io::Result<()>;
 }
@@ -3,12 +3,26 @@
 
 //! Type-length-value encoding on top of the packets.
 
-use crate::workers::packets::{HeaderPacket, Packet, HEADER_SIZE};
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+use crate::workers::packets::{HeaderPacket, Packet, HEADER_SIZE, PROTOCOL_VERSION};
+
+/// Size of the optional CRC32 checksum trailing a TLV encoded packet.
+pub(crate) const CHECKSUM_SIZE: usize = 4;
+
+/// Zstd compression level of the compressed packets, the fastest one since packets are compressed on the send path.
+const COMPRESSION_LEVEL: i32 = 1;
 
 #[allow(clippy::enum_variant_names)]
 #[allow(dead_code)] // TODO
 #[derive(Debug)]
 pub(crate) enum Error {
+    UnsupportedVersion {
+        version: u8,
+    },
     InvalidAdvertisedType {
         found: u8,
         advertised: u8,
@@ -22,21 +36,91 @@ pub(crate) enum Error {
         type_id: u8,
         len: usize,
     },
+    InvalidChecksum {
+        expected: u32,
+        found: u32,
+    },
+    InvalidCompression {
+        type_id: u8,
+    },
+}
+
+/// A writer computing the CRC32 checksum of everything written through it.
+struct ChecksumWriter<'a, W: Write> {
+    writer: &'a mut W,
+    hasher: crc32fast::Hasher,
+}
+
+impl<W: Write> Write for ChecksumWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn checksum(header: &HeaderPacket, payload: &[u8]) -> u32 {
+    let mut header_bytes = [0u8; HEADER_SIZE];
+    header.to_bytes(&mut header_bytes);
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&header_bytes);
+    hasher.update(payload);
+    hasher.finalize()
 }
 
 /// Deserializes a TLV header and a byte buffer into a packet.
 ///
 /// # Arguments
 ///
-/// * `header`  -   The TLV header to deserialize from.
-/// * `bytes`   -   The byte buffer to deserialize from.
+/// * `header`      -   The TLV header to deserialize from.
+/// * `bytes`       -   The byte buffer to deserialize from.
+/// * `with_crc`    -   Whether the byte buffer ends with a CRC32 checksum of the header and the packet.
+///
+/// Compressed packets, as advertised by the header, are transparently decompressed.
 ///
 /// # Errors
 ///
+/// * The packet is encoded with a newer protocol version than the supported one.
 /// * The advertised packet type does not match the required packet type.
 /// * The advertised packet length does not match the buffer length.
-/// * The buffer length is not within the allowed size range of the required packet type.
-pub(crate) fn tlv_from_bytes<P: Packet>(header: &HeaderPacket, bytes: &[u8]) -> Result<P, Error> {
+/// * The packet is compressed while the required packet type can't be, or can't be decompressed.
+/// * The buffer length, once decompressed, is not within the allowed size range of the required packet type.
+/// * The checksum does not match the header and the packet.
+pub(crate) fn tlv_from_bytes<P: Packet>(header: &HeaderPacket, bytes: &[u8], with_crc: bool) -> Result<P, Error> {
+    if header.version > PROTOCOL_VERSION {
+        return Err(Error::UnsupportedVersion {
+            version: header.version,
+        });
+    }
+
+    let bytes = if with_crc {
+        if bytes.len() < CHECKSUM_SIZE {
+            return Err(Error::InvalidAdvertisedLength {
+                type_id: header.packet_type,
+                advertised: header.packet_length as usize + CHECKSUM_SIZE,
+                found: bytes.len(),
+            });
+        }
+
+        let (bytes, crc) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        // This never panics because `crc` has exactly `CHECKSUM_SIZE` bytes by construction.
+        let found = u32::from_le_bytes(crc.try_into().unwrap());
+        let expected = checksum(header, bytes);
+
+        if expected != found {
+            return Err(Error::InvalidChecksum { expected, found });
+        }
+
+        bytes
+    } else {
+        bytes
+    };
+
     if header.packet_type != P::ID {
         return Err(Error::InvalidAdvertisedType {
             found: P::ID,
@@ -52,6 +136,23 @@ pub(crate) fn tlv_from_bytes<P: Packet>(header: &HeaderPacket, bytes: &[u8]) ->
         });
     }
 
+    let bytes = if header.compressed {
+        if !P::COMPRESSIBLE {
+            return Err(Error::InvalidCompression {
+                type_id: header.packet_type,
+            });
+        }
+
+        // The decompressed size is bounded by the maximum packet size, decompressing anything bigger fails.
+        Cow::Owned(
+            zstd::block::decompress(bytes, P::size_range().end - 1).map_err(|_| Error::InvalidCompression {
+                type_id: header.packet_type,
+            })?,
+        )
+    } else {
+        Cow::Borrowed(bytes)
+    };
+
     if !P::size_range().contains(&bytes.len()) {
         return Err(Error::InvalidLength {
             type_id: header.packet_type,
@@ -59,25 +160,89 @@ pub(crate) fn tlv_from_bytes<P: Packet>(header: &HeaderPacket, bytes: &[u8]) ->
         });
     }
 
-    Ok(P::from_bytes(bytes))
+    Ok(P::from_bytes(&bytes))
 }
 
-/// Serializes a TLV header and a packet to a byte buffer.
+fn tlv_size<P: Packet>(packet: &P, with_crc: bool) -> usize {
+    HEADER_SIZE + packet.size() + if with_crc { CHECKSUM_SIZE } else { 0 }
+}
+
+/// Serializes a TLV header and a packet to a writer.
 ///
 /// # Arguments
 ///
-/// * `packet` -   The packet to serialize.
-pub(crate) fn tlv_to_bytes<P: Packet>(packet: &P) -> Vec<u8> {
-    let size = packet.size();
-    let mut bytes = vec![0u8; HEADER_SIZE + size];
-    let (header, payload) = bytes.split_at_mut(HEADER_SIZE);
+/// * `packet`      -   The packet to serialize.
+/// * `writer`      -   The writer to serialize into.
+/// * `with_crc`    -   Whether to append a CRC32 checksum of the header and the packet.
+pub(crate) fn tlv_to_writer<P: Packet, W: Write>(packet: &P, writer: &mut W, with_crc: bool) -> io::Result<()> {
+    let mut header_bytes = [0u8; HEADER_SIZE];
 
-    HeaderPacket {
-        packet_type: P::ID,
-        packet_length: size as u16,
+    HeaderPacket::new(P::ID, packet.size() as u16).to_bytes(&mut header_bytes);
+
+    if with_crc {
+        let mut writer = ChecksumWriter {
+            writer,
+            hasher: crc32fast::Hasher::new(),
+        };
+
+        writer.write_all(&header_bytes)?;
+        packet.to_writer(&mut writer)?;
+
+        let crc = writer.hasher.finalize();
+        writer.writer.write_all(&crc.to_le_bytes())
+    } else {
+        writer.write_all(&header_bytes)?;
+        packet.to_writer(writer)
     }
-    .to_bytes(header);
-    packet.to_bytes(payload);
+}
+
+/// Serializes a TLV header and a compressed packet to a byte buffer, unless compression doesn't shrink the packet.
+fn tlv_to_compressed_bytes<P: Packet>(packet: &P, with_crc: bool) -> Option<Vec<u8>> {
+    let mut payload = vec![0u8; packet.size()];
+    packet.to_bytes(&mut payload);
+
+    let compressed = zstd::block::compress(&payload, COMPRESSION_LEVEL).ok()?;
+
+    if compressed.len() >= payload.len() {
+        return None;
+    }
+
+    let mut header = HeaderPacket::new(P::ID, compressed.len() as u16);
+    header.compressed = true;
+
+    let mut bytes = vec![0u8; HEADER_SIZE];
+    bytes.reserve(compressed.len() + if with_crc { CHECKSUM_SIZE } else { 0 });
+    header.to_bytes(&mut bytes);
+    bytes.extend_from_slice(&compressed);
+
+    if with_crc {
+        bytes.extend_from_slice(&checksum(&header, &compressed).to_le_bytes());
+    }
+
+    Some(bytes)
+}
+
+/// Serializes a TLV header and a packet to a byte buffer.
+///
+/// The buffer is allocated once with the exact required capacity and the packet is streamed into it, unless the
+/// packet is compressed.
+///
+/// # Arguments
+///
+/// * `packet`              -   The packet to serialize.
+/// * `with_crc`            -   Whether to append a CRC32 checksum of the header and the packet.
+/// * `with_compression`    -   Whether to compress the packet, only if its type allows it and it shrinks it.
+pub(crate) fn tlv_to_bytes<P: Packet>(packet: &P, with_crc: bool, with_compression: bool) -> Vec<u8> {
+    if with_compression && P::COMPRESSIBLE {
+        if let Some(bytes) = tlv_to_compressed_bytes(packet, with_crc) {
+            return bytes;
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(tlv_size(packet, with_crc));
+
+    // Panic: writing to a `Vec` never fails.
+    tlv_to_writer(packet, &mut bytes, with_crc).unwrap();
 
     bytes
 }
@@ -85,6 +250,7 @@ pub(crate) fn tlv_to_bytes<P: Packet>(packet: &P) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
 
+    use bee_message::{MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN};
     use rand::Rng;
 
     use super::*;
@@ -94,11 +260,9 @@ mod tests {
 
     fn invalid_advertised_type<P: Packet>() {
         match tlv_from_bytes::<P>(
-            &HeaderPacket {
-                packet_type: P::ID + 1,
-                packet_length: P::size_range().start as u16,
-            },
+            &HeaderPacket::new(P::ID + 1, P::size_range().start as u16),
             &Vec::with_capacity(P::size_range().start),
+            false,
         ) {
             Err(Error::InvalidAdvertisedType { advertised, found }) => {
                 assert_eq!(advertised, P::ID + 1);
@@ -110,11 +274,9 @@ mod tests {
 
     fn invalid_advertised_length<P: Packet>() {
         match tlv_from_bytes::<P>(
-            &HeaderPacket {
-                packet_type: P::ID,
-                packet_length: P::size_range().start as u16,
-            },
+            &HeaderPacket::new(P::ID, P::size_range().start as u16),
             &vec![0u8; P::size_range().start + 1],
+            false,
         ) {
             Err(Error::InvalidAdvertisedLength {
                 type_id,
@@ -131,11 +293,9 @@ mod tests {
 
     fn length_out_of_range<P: Packet>() {
         match tlv_from_bytes::<P>(
-            &HeaderPacket {
-                packet_type: P::ID,
-                packet_length: P::size_range().start as u16 - 1,
-            },
+            &HeaderPacket::new(P::ID, P::size_range().start as u16 - 1),
             &vec![0u8; P::size_range().start - 1],
+            false,
         ) {
             Err(Error::InvalidLength { type_id, len }) => {
                 assert_eq!(type_id, P::ID);
@@ -145,11 +305,9 @@ mod tests {
         }
 
         match tlv_from_bytes::<P>(
-            &HeaderPacket {
-                packet_type: P::ID,
-                packet_length: P::size_range().end as u16,
-            },
+            &HeaderPacket::new(P::ID, P::size_range().end as u16),
             &vec![0u8; P::size_range().end],
+            false,
         ) {
             Err(Error::InvalidLength { type_id, len }) => {
                 assert_eq!(type_id, P::ID);
@@ -165,24 +323,67 @@ mod tests {
         for _ in 0..1000 {
             let length = rng.gen_range(P::size_range());
             let bytes_from: Vec<u8> = (0..length).map(|_| rand::random::<u8>()).collect();
-            let packet = tlv_from_bytes::<P>(
-                &HeaderPacket {
-                    packet_type: P::ID,
-                    packet_length: length as u16,
-                },
-                &bytes_from,
-            )
-            .unwrap();
-            let bytes_to = tlv_to_bytes(&packet);
+            let packet = tlv_from_bytes::<P>(&HeaderPacket::new(P::ID, length as u16), &bytes_from, false).unwrap();
+            let bytes_to = tlv_to_bytes(&packet, false, false);
 
             assert_eq!(bytes_to[0], P::ID);
             assert_eq!(u16::from_le_bytes(bytes_to[1..3].try_into().unwrap()), length as u16);
             assert!(bytes_from.eq(&bytes_to[3..].to_vec()));
+
+            let mut bytes_to = tlv_to_bytes(&packet, true, false);
+            let header = HeaderPacket::from_bytes(bytes_to[..HEADER_SIZE].try_into().unwrap());
+
+            assert_eq!(bytes_to.len(), HEADER_SIZE + length + CHECKSUM_SIZE);
+            assert!(tlv_from_bytes::<P>(&header, &bytes_to[HEADER_SIZE..], true).is_ok());
+
+            // Flipping any byte after the header must be caught by the checksum.
+            let index = rng.gen_range(HEADER_SIZE..bytes_to.len());
+            bytes_to[index] ^= 1 << rng.gen_range(0..8);
+
+            assert!(matches!(
+                tlv_from_bytes::<P>(&header, &bytes_to[HEADER_SIZE..], true),
+                Err(Error::InvalidChecksum { .. })
+            ));
+
+            // The version survives the header encoding and packets of newer versions are rejected.
+            let mut header = HeaderPacket::new(P::ID, length as u16);
+            header.version = rng.gen_range(0..8);
+            let mut header_bytes = [0u8; HEADER_SIZE];
+            header.to_bytes(&mut header_bytes);
+
+            assert_eq!(HeaderPacket::from_bytes(&header_bytes), header);
+
+            if header.version > PROTOCOL_VERSION {
+                assert!(matches!(
+                    tlv_from_bytes::<P>(&header, &bytes_from, false),
+                    Err(Error::UnsupportedVersion { version }) if version == header.version
+                ));
+            } else {
+                assert!(tlv_from_bytes::<P>(&header, &bytes_from, false).is_ok());
+            }
+        }
+    }
+
+    fn serialization_consistency<P: Packet>() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let length = rng.gen_range(P::size_range());
+            let bytes_from: Vec<u8> = (0..length).map(|_| rand::random::<u8>()).collect();
+            let packet = P::from_bytes(&bytes_from);
+
+            for with_crc in [false, true] {
+                let bytes = tlv_to_bytes(&packet, with_crc, false);
+
+                let mut writer = Vec::new();
+                tlv_to_writer(&packet, &mut writer, with_crc).unwrap();
+                assert_eq!(writer, bytes);
+            }
         }
     }
 
     macro_rules! implement_tlv_tests {
-        ($type:ty, $iat:tt, $ial:tt, $loor:tt, $fuzz:tt) => {
+        ($type:ty, $iat:tt, $ial:tt, $loor:tt, $fuzz:tt, $sc:tt) => {
             #[test]
             fn $iat() {
                 invalid_advertised_type::<$type>();
@@ -202,6 +403,11 @@ mod tests {
             fn $fuzz() {
                 fuzz::<$type>();
             }
+
+            #[test]
+            fn $sc() {
+                serialization_consistency::<$type>();
+            }
         };
     }
 
@@ -210,7 +416,8 @@ mod tests {
         invalid_advertised_type_milestone_request,
         invalid_advertised_length_milestone_request,
         length_out_of_range_milestone_request,
-        fuzz_milestone_request
+        fuzz_milestone_request,
+        serialization_consistency_milestone_request
     );
 
     implement_tlv_tests!(
@@ -218,7 +425,8 @@ mod tests {
         invalid_advertised_type_message,
         invalid_advertised_length_message,
         length_out_of_range_message,
-        fuzz_message
+        fuzz_message,
+        serialization_consistency_message
     );
 
     implement_tlv_tests!(
@@ -226,7 +434,8 @@ mod tests {
         invalid_advertised_type_message_request,
         invalid_advertised_length_message_request,
         length_out_of_range_message_request,
-        fuzz_message_request
+        fuzz_message_request,
+        serialization_consistency_message_request
     );
 
     implement_tlv_tests!(
@@ -234,6 +443,92 @@ mod tests {
         invalid_advertised_type_heartbeat,
         invalid_advertised_length_heartbeat,
         length_out_of_range_heartbeat,
-        fuzz_range_heartbeat
+        fuzz_range_heartbeat,
+        serialization_consistency_heartbeat
     );
+
+    #[test]
+    fn heartbeat_fields_round_trip() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let packet_from = HeartbeatPacket::new(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen());
+
+            for with_crc in [false, true] {
+                let bytes = tlv_to_bytes(&packet_from, with_crc, false);
+                let header = HeaderPacket::from_bytes(bytes[..HEADER_SIZE].try_into().unwrap());
+                let packet_to = tlv_from_bytes::<HeartbeatPacket>(&header, &bytes[HEADER_SIZE..], with_crc).unwrap();
+
+                assert_eq!(packet_to.solid_milestone_index, packet_from.solid_milestone_index);
+                assert_eq!(packet_to.pruned_index, packet_from.pruned_index);
+                assert_eq!(packet_to.latest_milestone_index, packet_from.latest_milestone_index);
+                assert_eq!(packet_to.connected_peers, packet_from.connected_peers);
+                assert_eq!(packet_to.synced_peers, packet_from.synced_peers);
+            }
+        }
+    }
+
+    #[test]
+    fn compression_round_trip() {
+        let message = b"compressible".repeat(1000);
+        let packet_from = MessagePacket::new(message.clone());
+
+        for with_crc in [false, true] {
+            let uncompressed = tlv_to_bytes(&packet_from, with_crc, false);
+            let compressed = tlv_to_bytes(&packet_from, with_crc, true);
+            let header = HeaderPacket::from_bytes(compressed[..HEADER_SIZE].try_into().unwrap());
+
+            assert!(header.compressed);
+            assert!(compressed.len() < uncompressed.len());
+
+            let packet_to = tlv_from_bytes::<MessagePacket>(&header, &compressed[HEADER_SIZE..], with_crc).unwrap();
+
+            assert_eq!(packet_to.bytes, message);
+        }
+    }
+
+    #[test]
+    fn compression_only_if_smaller() {
+        let message: Vec<u8> = (0..MESSAGE_LENGTH_MAX).map(|_| rand::random::<u8>()).collect();
+        let packet = MessagePacket::new(message);
+
+        assert_eq!(tlv_to_bytes(&packet, false, true), tlv_to_bytes(&packet, false, false));
+    }
+
+    #[test]
+    fn compression_only_if_compressible() {
+        let packet = HeartbeatPacket::new(0, 0, 0, 0, 0);
+
+        assert_eq!(tlv_to_bytes(&packet, false, true), tlv_to_bytes(&packet, false, false));
+
+        let mut header = HeaderPacket::new(HeartbeatPacket::ID, HeartbeatPacket::size_range().start as u16);
+        header.compressed = true;
+
+        assert!(matches!(
+            tlv_from_bytes::<HeartbeatPacket>(&header, &vec![0u8; HeartbeatPacket::size_range().start], false),
+            Err(Error::InvalidCompression { type_id }) if type_id == HeartbeatPacket::ID
+        ));
+    }
+
+    #[test]
+    fn compression_size_range() {
+        for (len, valid) in [
+            (MESSAGE_LENGTH_MIN - 1, false),
+            (MESSAGE_LENGTH_MIN, true),
+            (MESSAGE_LENGTH_MAX, true),
+            (MESSAGE_LENGTH_MAX + 1, false),
+        ] {
+            let compressed = zstd::block::compress(&vec![0u8; len], COMPRESSION_LEVEL).unwrap();
+            let mut header = HeaderPacket::new(MessagePacket::ID, compressed.len() as u16);
+            header.compressed = true;
+
+            // The size range applies to the decompressed packet, not to the compressed one.
+            match tlv_from_bytes::<MessagePacket>(&header, &compressed, false) {
+                Ok(packet) => assert!(valid && packet.bytes.len() == len),
+                Err(Error::InvalidLength { len: found, .. }) => assert!(!valid && found == len),
+                Err(Error::InvalidCompression { .. }) => assert!(!valid && len > MESSAGE_LENGTH_MAX),
+                Err(_) => unreachable!(),
+            }
+        }
+    }
 }
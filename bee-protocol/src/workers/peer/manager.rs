@@ -16,8 +16,9 @@ use log::{info, trace, warn};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::{
-    types::{metrics::NodeMetrics, peer::Peer},
+    types::{capabilities::Capabilities, metrics::NodeMetrics, peer::Peer},
     workers::{
+        event::{PeerAdded, PeerRemoved},
         heartbeater::{new_heartbeat, send_heartbeat},
         peer::PeerManager,
         storage::StorageBackend,
@@ -103,6 +104,7 @@ where
         }
 
         let gossip_command_tx = node.resource::<NetworkCommandSender>();
+        let bus = node.bus();
 
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Network handler running.");
@@ -115,14 +117,34 @@ where
                 match event {
                     NetworkEvent::PeerAdded { peer_id, info } => {
                         // TODO check if not already added ?
-                        info!("Added peer {}.", info.alias);
-
+                        let alias = info.alias.clone();
+                        let address = info.address.clone();
                         let peer = Arc::new(Peer::new(peer_id, info));
-                        peer_manager.add(peer);
+
+                        match peer_manager.add(peer) {
+                            Ok(()) => {
+                                info!("Added peer {}.", alias);
+                                bus.dispatch(PeerAdded { peer_id, address });
+                            }
+                            Err(e) => {
+                                warn!("Rejected peer {}: {}", alias, e);
+
+                                // Since the peer is not known to the peer manager, its gossip channels will be dropped
+                                // if it connects anyway, tearing the connection down.
+                                // Panic: sending commands cannot fail: same explanation as in other sender usages.
+                                gossip_command_tx
+                                    .send(Command::RemovePeer { peer_id })
+                                    .expect("send gossip command");
+                            }
+                        }
                     }
                     NetworkEvent::PeerRemoved { peer_id } => {
                         if let Some(peer) = peer_manager.remove(&peer_id) {
                             info!("Removed peer {}.", peer.0.alias());
+                            bus.dispatch(PeerRemoved {
+                                peer_id,
+                                address: peer.0.address().clone(),
+                            });
                         }
                     }
                     NetworkEvent::PeerConnected {
@@ -130,6 +152,8 @@ where
                         info: _,
                         gossip_in: receiver,
                         gossip_out: sender,
+                        crc,
+                        compression,
                     } => {
                         {
                             let metrics = metrics.clone();
@@ -144,6 +168,15 @@ where
                                 .get_mut_map(&peer_id, move |peer| {
                                     let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
+                                    // Only enabled if both sides offered them during the gossip protocol negotiation.
+                                    let mut capabilities = Capabilities::NONE;
+                                    if crc {
+                                        capabilities |= Capabilities::CRC;
+                                    }
+                                    if compression {
+                                        capabilities |= Capabilities::COMPRESSION;
+                                    }
+                                    peer.0.set_capabilities(capabilities);
                                     peer.0.set_connected(true);
                                     peer.1 = Some((sender, shutdown_tx));
 
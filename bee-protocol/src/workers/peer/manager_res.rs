@@ -4,31 +4,65 @@
 // TODO This exist to avoid a cyclic dependency, there has to be another way.
 
 use std::{
+    collections::HashMap,
     convert::Infallible,
+    future::Future,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
-use bee_gossip::{GossipSender, PeerId};
-use bee_runtime::{node::Node, worker::Worker};
-use futures::channel::oneshot;
-use log::debug;
+use bee_gossip::{GossipSender, Multiaddr, PeerId};
+use bee_message::milestone::MilestoneIndex;
+use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use futures::{channel::oneshot, Stream, StreamExt};
+use log::{debug, info, warn};
 use parking_lot::RwLock;
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::{
+    sync::{
+        broadcast::{self, error::RecvError},
+        mpsc::error::TrySendError,
+    },
+    time::interval,
+};
+use tokio_stream::wrappers::IntervalStream;
 
 use crate::types::peer::Peer;
 
+const BAN_CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+// Number of events a subscriber can lag behind before missing the oldest ones.
+const PEER_EVENT_CAPACITY: usize = 64;
+
 pub struct PeerManagerResWorker {}
 
 #[async_trait]
 impl<N: Node> Worker<N> for PeerManagerResWorker {
-    type Config = ();
+    type Config = (usize, FullQueuePolicy);
     type Error = Infallible;
 
-    async fn start(node: &mut N, _config: Self::Config) -> Result<Self, Self::Error> {
-        node.register_resource(PeerManager::new());
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let (max_peers, full_queue_policy) = config;
+
+        node.register_resource(PeerManager::new(max_peers).with_full_queue_policy(full_queue_policy));
+
+        let peer_manager = node.resource::<PeerManager>();
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut ticker = ShutdownStream::new(shutdown, IntervalStream::new(interval(BAN_CLEANUP_INTERVAL)));
+
+            while ticker.next().await.is_some() {
+                peer_manager.remove_expired_bans();
+            }
+
+            info!("Stopped.");
+        });
 
         Ok(Self {})
     }
@@ -47,14 +81,118 @@ impl<N: Node> Worker<N> for PeerManagerResWorker {
     }
 }
 
+/// Errors that can occur when adding a peer to the `PeerManager`.
+#[derive(Debug, Error)]
+pub(crate) enum PeerManagerError {
+    /// The maximum number of peers has been reached.
+    #[error("Maximum number of peers {0} reached.")]
+    MaxPeersReached(usize),
+    /// The peer is banned.
+    #[error("Peer is banned for another {0:?}.")]
+    Banned(Duration),
+}
+
+/// What to do with a peer whose gossip queue is full, i.e. that doesn't read the packets sent to it fast enough.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FullQueuePolicy {
+    /// The packets that don't fit in the queue are dropped.
+    Drop,
+    /// The peer is disconnected: its packets are no longer processed nor sent to.
+    Disconnect,
+}
+
+impl Default for FullQueuePolicy {
+    fn default() -> Self {
+        Self::Drop
+    }
+}
+
+/// An event emitted by the `PeerManager`, see `PeerManager::subscribe`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PeerEvent {
+    /// A peer was added.
+    Added(PeerId),
+    /// A peer was removed.
+    Removed(PeerId),
+    /// A peer was banned.
+    Banned(PeerId),
+}
+
+/// A snapshot of the metadata of a peer, independent of the `PeerManager` lock.
+#[derive(Clone, Debug)]
+pub struct PeerSummary {
+    /// The identifier of the peer.
+    pub id: PeerId,
+    /// The address of the peer.
+    pub address: Multiaddr,
+    /// Whether the peer is connected or not.
+    pub connected: bool,
+    /// Whether the peer is synced or not.
+    pub synced: bool,
+    /// The solid milestone index of the peer.
+    pub solid_milestone_index: MilestoneIndex,
+    /// The latest milestone index of the peer.
+    pub latest_milestone_index: MilestoneIndex,
+    /// The pruned index of the peer.
+    pub pruned_index: MilestoneIndex,
+    /// The number of messages received from the peer.
+    pub messages_received: u64,
+    /// The number of messages sent to the peer.
+    pub messages_sent: u64,
+    /// The number of heartbeats received from the peer.
+    pub heartbeats_received: u64,
+    /// The number of heartbeats sent to the peer.
+    pub heartbeats_sent: u64,
+}
+
+/// Sends `bytes` to a peer through its gossip sender and, on success, updates its packet and byte counters.
+/// Sending never waits: it fails if the gossip queue of the peer is full, see `PeerManager::handle_send_error`.
+pub(crate) fn send_bytes(peer: &Peer, sender: &GossipSender, bytes: Vec<u8>) -> Result<(), TrySendError<Vec<u8>>> {
+    let len = bytes.len();
+
+    sender.try_send(bytes)?;
+    peer.metrics().packet_sent(len);
+
+    Ok(())
+}
+
+impl From<&Peer> for PeerSummary {
+    fn from(peer: &Peer) -> Self {
+        Self {
+            id: *peer.id(),
+            address: peer.address().clone(),
+            connected: peer.is_connected(),
+            synced: peer.is_synced(),
+            solid_milestone_index: peer.solid_milestone_index(),
+            latest_milestone_index: peer.latest_milestone_index(),
+            pruned_index: peer.pruned_index(),
+            messages_received: peer.metrics().messages_received(),
+            messages_sent: peer.metrics().messages_sent(),
+            heartbeats_received: peer.metrics().heartbeats_received(),
+            heartbeats_sent: peer.metrics().heartbeats_sent(),
+        }
+    }
+}
+
 type PeerTuple = (Arc<Peer>, Option<(GossipSender, oneshot::Sender<()>)>);
 
 #[derive(Default)]
 struct PeerManagerInner {
     peers: Vec<(PeerId, PeerTuple)>,
+    // Maps the banned peers to the expiry of their ban.
+    bans: HashMap<PeerId, Instant>,
 }
 
 impl PeerManagerInner {
+    // Returns the remaining duration of the ban of a peer, if any.
+    fn ban_remaining(&self, id: &PeerId, now: Instant) -> Option<Duration> {
+        self.bans
+            .get(id)
+            .map(|expiry| expiry.saturating_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
     fn get(&self, id: &PeerId) -> Option<&PeerTuple> {
         self.peers
             .binary_search_by_key(id, |(id, _)| *id)
@@ -85,15 +223,48 @@ impl PeerManagerInner {
     }
 }
 
-#[derive(Default)]
 pub struct PeerManager {
     inner: RwLock<PeerManagerInner>,
     counter: AtomicUsize,
+    max_peers: usize,
+    full_queue_policy: FullQueuePolicy,
+    events: broadcast::Sender<PeerEvent>,
 }
 
 impl PeerManager {
-    pub(crate) fn new() -> Self {
-        Self::default()
+    pub(crate) fn new(max_peers: usize) -> Self {
+        Self {
+            inner: RwLock::default(),
+            counter: AtomicUsize::default(),
+            max_peers,
+            full_queue_policy: FullQueuePolicy::default(),
+            events: broadcast::channel(PEER_EVENT_CAPACITY).0,
+        }
+    }
+
+    /// Sets what to do with the peers whose gossip queue is full.
+    pub(crate) fn with_full_queue_policy(mut self, full_queue_policy: FullQueuePolicy) -> Self {
+        self.full_queue_policy = full_queue_policy;
+        self
+    }
+
+    /// Returns a stream of the peers being added, removed or banned from now on.
+    /// Producers never wait for subscribers: a subscriber lagging behind misses the oldest events.
+    pub fn subscribe(&self) -> impl Stream<Item = PeerEvent> {
+        futures::stream::unfold(self.events.subscribe(), |mut events| async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => return Some((event, events)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    fn emit(&self, event: PeerEvent) {
+        // Sending only fails if there is no subscriber, in which case the event is simply dropped.
+        let _ = self.events.send(event);
     }
 
     pub fn is_empty(&self) -> bool {
@@ -114,6 +285,12 @@ impl PeerManager {
         output
     }
 
+    /// Returns a snapshot of the metadata of a peer.
+    /// Unlike `get_map`, the lock is released before returning, so the snapshot can be held across await points.
+    pub fn peer_summary(&self, id: &PeerId) -> Option<PeerSummary> {
+        self.get_map(id, |(peer, _)| PeerSummary::from(peer.as_ref()))
+    }
+
     pub fn get_all(&self) -> Vec<Arc<Peer>> {
         self.inner
             .read()
@@ -124,20 +301,120 @@ impl PeerManager {
             .collect()
     }
 
-    pub(crate) fn add(&self, peer: Arc<Peer>) {
-        debug!("Added peer {}.", peer.id());
+    /// Returns the id of a peer given its alias. If several peers share the alias, the first one is returned.
+    pub fn get_by_alias(&self, alias: &str) -> Option<PeerId> {
+        self.inner
+            .read()
+            .peers
+            .iter()
+            .find(|(_, (peer, _))| peer.alias() == alias)
+            .map(|(id, _)| *id)
+    }
+
+    pub fn peers_keys(&self) -> Vec<PeerId> {
+        self.inner.read().peers.iter().map(|(id, _)| *id).collect()
+    }
+
+    /// Awaits `f` on every peer, one after the other.
+    /// The peers are collected beforehand so that the lock is not held across await points.
+    pub async fn for_each_peer_async<F, Fut>(&self, f: F)
+    where
+        F: Fn(PeerId, Arc<Peer>) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let peers = self
+            .inner
+            .read()
+            .peers
+            .iter()
+            .map(|(id, (peer, _))| (*id, peer.clone()))
+            .collect::<Vec<_>>();
+
+        for (id, peer) in peers {
+            f(id, peer).await;
+        }
+    }
+
+    /// Bans a peer for a given duration, during which it can't be added. An existing ban is replaced.
+    /// Banning a peer doesn't remove it if it has already been added.
+    pub fn ban(&self, id: PeerId, duration: Duration) {
+        debug!("Banned peer {} for {:?}.", id, duration);
+        self.inner.write().bans.insert(id, Instant::now() + duration);
+        self.emit(PeerEvent::Banned(id));
+    }
+
+    /// Lifts the ban of a peer and returns whether it was banned.
+    pub fn unban(&self, id: &PeerId) -> bool {
         let mut lock = self.inner.write();
-        lock.insert(*peer.id(), (peer, None));
+        let banned = lock.ban_remaining(id, Instant::now()).is_some();
+
+        lock.bans.remove(id);
+
+        banned
     }
 
-    pub(crate) fn remove(&self, id: &PeerId) -> Option<PeerTuple> {
-        debug!("Removed peer {}.", id);
+    /// Returns whether a peer is currently banned.
+    pub fn is_banned(&self, id: &PeerId) -> bool {
+        self.inner.read().ban_remaining(id, Instant::now()).is_some()
+    }
+
+    /// Forgets the bans that have expired.
+    pub(crate) fn remove_expired_bans(&self) {
+        let now = Instant::now();
+
+        self.inner.write().bans.retain(|_, expiry| *expiry > now);
+    }
+
+    /// Adds a peer, unless it is banned or the maximum number of peers has been reached.
+    /// Manually configured peers are always accepted and don't count towards the maximum, unless they are banned.
+    pub(crate) fn add(&self, peer: Arc<Peer>) -> Result<(), PeerManagerError> {
         let mut lock = self.inner.write();
-        lock.remove(id)
+
+        if let Some(remaining) = lock.ban_remaining(peer.id(), Instant::now()) {
+            return Err(PeerManagerError::Banned(remaining));
+        }
+
+        if !peer.relation().is_known() && lock.get(peer.id()).is_none() {
+            let unknown_peers = lock
+                .peers
+                .iter()
+                .filter(|(_, (peer, _))| !peer.relation().is_known())
+                .count();
+
+            if unknown_peers >= self.max_peers {
+                return Err(PeerManagerError::MaxPeersReached(self.max_peers));
+            }
+        }
+
+        debug!("Added peer {} ({}).", peer.alias(), peer.id());
+        let id = *peer.id();
+        lock.insert(id, (peer, None));
+        drop(lock);
+
+        self.emit(PeerEvent::Added(id));
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, id: &PeerId) -> Option<PeerTuple> {
+        let peer = self.inner.write().remove(id);
+
+        if let Some((peer, _)) = &peer {
+            debug!("Removed peer {} ({}).", peer.alias(), id);
+            self.emit(PeerEvent::Removed(*id));
+        }
+
+        peer
     }
 
-    pub(crate) fn for_each<F: Fn(&PeerId, &Peer)>(&self, f: F) {
-        self.inner.read().peers.iter().for_each(|(id, (peer, _))| f(id, peer));
+    /// Calls `f` on every peer while holding the read lock once.
+    /// The lock is taken recursively so that `f` can itself read from the `PeerManager` without deadlocking.
+    pub fn for_each<F: Fn(&PeerId, &Peer)>(&self, f: F) {
+        self.inner
+            .read_recursive()
+            .peers
+            .iter()
+            .for_each(|(id, (peer, _))| f(id, peer));
     }
 
     /// Find one peer that satisfies a condition. If more than one peer satisfies this condition,
@@ -159,29 +436,476 @@ impl PeerManager {
         None
     }
 
+    /// Applies the full queue policy to a peer that could not be sent to.
+    /// It must be called without holding a lock on the `PeerManager`.
+    pub(crate) fn handle_send_error(&self, id: &PeerId, error: &TrySendError<Vec<u8>>) {
+        if !matches!(error, TrySendError::Full(_)) || self.full_queue_policy == FullQueuePolicy::Drop {
+            return;
+        }
+
+        let ctx = self
+            .get_mut_map(id, |(peer, ctx)| {
+                peer.set_connected(false);
+                ctx.take()
+            })
+            .flatten();
+
+        // Stopping the peer worker drops the gossip receiver of the peer, which ends the inbound gossip.
+        if let Some((_, shutdown)) = ctx {
+            warn!("Disconnected peer {} as its gossip queue is full.", id);
+            let _ = shutdown.send(());
+        }
+    }
+
+    /// Sends `bytes` to all connected peers and returns the number of peers that were reached.
+    /// Peers whose channel is closed or full are skipped, the latter according to the full queue policy.
+    pub fn broadcast(&self, bytes: Vec<u8>) -> usize {
+        let mut errors = Vec::new();
+        let reached = self
+            .inner
+            .read()
+            .peers
+            .iter()
+            .filter_map(|(id, (peer, ctx))| ctx.as_ref().map(|(sender, _)| (id, peer, sender)))
+            .filter(|(id, peer, sender)| match send_bytes(peer, sender, bytes.clone()) {
+                Ok(()) => true,
+                Err(e) => {
+                    errors.push((**id, e));
+                    false
+                }
+            })
+            .count();
+
+        for (id, e) in errors {
+            self.handle_send_error(&id, &e);
+        }
+
+        reached
+    }
+
+    /// Sends `bytes` to a connected peer and returns whether it was reached.
+    pub fn send_to(&self, id: &PeerId, bytes: Vec<u8>) -> bool {
+        let result = self
+            .inner
+            .read()
+            .get(id)
+            .and_then(|(peer, ctx)| ctx.as_ref().map(|(sender, _)| send_bytes(peer, sender, bytes)));
+
+        match result {
+            Some(Ok(())) => true,
+            Some(Err(e)) => {
+                self.handle_send_error(id, &e);
+                false
+            }
+            None => false,
+        }
+    }
+
     pub fn is_connected(&self, id: &PeerId) -> bool {
         self.inner.read().get(id).map_or(false, |p| p.1.is_some())
     }
 
+    /// Returns the number of connected peers, saturating at `u8::MAX`.
     pub fn connected_peers(&self) -> u8 {
-        self.inner
+        let count = self
+            .inner
             .read()
             .peers
             .iter()
             .filter(|(_, (_, ctx))| ctx.is_some())
-            .count() as u8
+            .count();
+
+        u8::try_from(count).unwrap_or(u8::MAX)
     }
 
+    /// Returns the number of connected peers that are synced, saturating at `u8::MAX`.
     pub fn synced_peers(&self) -> u8 {
-        self.inner
+        let count = self
+            .inner
             .read()
             .peers
             .iter()
             .filter(|(_, (peer, ctx))| (ctx.is_some() && peer.is_synced()))
-            .count() as u8
+            .count();
+
+        u8::try_from(count).unwrap_or(u8::MAX)
     }
 
     pub fn len(&self) -> usize {
         self.inner.read().peers.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bee_gossip::{PeerInfo, PeerRelation};
+
+    use super::*;
+    use crate::workers::test_util::{add_peer, new_peer};
+
+    fn new_aliased_peer(relation: PeerRelation, alias: String) -> Peer {
+        Peer::new(
+            PeerId::random(),
+            PeerInfo {
+                address: Multiaddr::empty(),
+                alias,
+                relation,
+            },
+        )
+    }
+
+    fn indexed_peer(solid_index: u32, latest_index: u32) -> Peer {
+        let peer = new_peer(PeerRelation::Known);
+
+        peer.set_solid_milestone_index(MilestoneIndex(solid_index));
+        peer.set_latest_milestone_index(MilestoneIndex(latest_index));
+
+        peer
+    }
+
+    #[test]
+    fn connected_and_synced_peers() {
+        let peer_manager = PeerManager::new(8);
+
+        assert_eq!(peer_manager.connected_peers(), 0);
+        assert_eq!(peer_manager.synced_peers(), 0);
+
+        // Connected and synced.
+        add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        add_peer(&peer_manager, indexed_peer(99, 100), Some(8));
+        // Connected but not synced.
+        let unsynced = add_peer(&peer_manager, indexed_peer(50, 100), Some(8)).0;
+        // Synced but not connected.
+        add_peer(&peer_manager, indexed_peer(100, 100), None);
+
+        assert_eq!(peer_manager.len(), 4);
+        assert_eq!(peer_manager.connected_peers(), 3);
+        assert_eq!(peer_manager.synced_peers(), 2);
+
+        peer_manager.get_map(&unsynced, |peer| peer.0.set_solid_milestone_index(MilestoneIndex(100)));
+
+        assert_eq!(peer_manager.synced_peers(), 3);
+
+        peer_manager.remove(&unsynced);
+
+        assert_eq!(peer_manager.len(), 3);
+        assert_eq!(peer_manager.connected_peers(), 2);
+        assert_eq!(peer_manager.synced_peers(), 2);
+    }
+
+    #[test]
+    fn for_each() {
+        let peer_manager = PeerManager::new(8);
+
+        for _ in 0..5 {
+            add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        }
+
+        let ids = parking_lot::Mutex::new(Vec::new());
+
+        peer_manager.for_each(|id, peer| {
+            // Reading from the manager within the closure must not deadlock.
+            assert!(peer_manager.is_connected(peer.id()));
+            ids.lock().push(*id);
+        });
+
+        assert_eq!(ids.into_inner(), peer_manager.peers_keys());
+    }
+
+    #[tokio::test]
+    async fn for_each_peer_async() {
+        let peer_manager = PeerManager::new(8);
+
+        for _ in 0..5 {
+            add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        }
+
+        let ids = parking_lot::Mutex::new(Vec::new());
+
+        peer_manager
+            .for_each_peer_async(|id, peer| {
+                let ids = &ids;
+                let peer_manager = &peer_manager;
+
+                async move {
+                    tokio::task::yield_now().await;
+                    assert!(peer_manager.is_connected(peer.id()));
+                    ids.lock().push(id);
+                }
+            })
+            .await;
+
+        assert_eq!(ids.into_inner(), peer_manager.peers_keys());
+    }
+
+    #[test]
+    fn broadcast_and_send_to() {
+        let peer_manager = PeerManager::new(8);
+
+        let (id_1, receiver_1) = add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        let (_, receiver_2) = add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        let (id_3, receiver_3) = add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        let (id_4, _) = add_peer(&peer_manager, indexed_peer(100, 100), None);
+        let (mut receiver_1, mut receiver_2) = (receiver_1.unwrap(), receiver_2.unwrap());
+
+        // The channel of the third peer is closed.
+        drop(receiver_3);
+
+        assert_eq!(peer_manager.broadcast(vec![1, 2, 3]), 2);
+        assert_eq!(receiver_1.try_recv().unwrap(), vec![1, 2, 3]);
+        assert_eq!(receiver_2.try_recv().unwrap(), vec![1, 2, 3]);
+
+        assert!(peer_manager.send_to(&id_1, vec![4, 5]));
+        assert_eq!(receiver_1.try_recv().unwrap(), vec![4, 5]);
+        assert!(receiver_2.try_recv().is_err());
+
+        let metrics = peer_manager
+            .get_map(&id_1, |(peer, _)| peer.metrics().snapshot())
+            .unwrap();
+        assert_eq!(metrics.packets_sent, 2);
+        assert_eq!(metrics.bytes_sent, 5);
+
+        assert!(!peer_manager.send_to(&id_3, vec![4, 5]));
+        assert_eq!(
+            peer_manager.get_map(&id_3, |(peer, _)| peer.metrics().packets_sent()),
+            Some(0)
+        );
+        assert!(!peer_manager.send_to(&id_4, vec![4, 5]));
+        assert!(!peer_manager.send_to(&PeerId::random(), vec![4, 5]));
+    }
+
+    #[test]
+    fn full_queue_drop() {
+        let peer_manager = PeerManager::new(8);
+        let (id, receiver) = add_peer(&peer_manager, indexed_peer(100, 100), Some(1));
+        let mut receiver = receiver.unwrap();
+
+        assert!(peer_manager.send_to(&id, vec![1]));
+        assert!(!peer_manager.send_to(&id, vec![2]));
+        assert_eq!(peer_manager.broadcast(vec![3]), 0);
+
+        // The packets that didn't fit are dropped but the peer stays connected.
+        assert!(peer_manager.is_connected(&id));
+        assert_eq!(receiver.try_recv().unwrap(), vec![1]);
+        assert!(receiver.try_recv().is_err());
+
+        assert!(peer_manager.send_to(&id, vec![4]));
+        assert_eq!(receiver.try_recv().unwrap(), vec![4]);
+    }
+
+    #[test]
+    fn full_queue_disconnect() {
+        let peer_manager = PeerManager::new(8).with_full_queue_policy(FullQueuePolicy::Disconnect);
+        let (id_1, receiver_1) = add_peer(&peer_manager, indexed_peer(100, 100), Some(1));
+        let (id_2, receiver_2) = add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+        let (mut receiver_1, mut receiver_2) = (receiver_1.unwrap(), receiver_2.unwrap());
+
+        assert_eq!(peer_manager.broadcast(vec![1]), 2);
+        assert_eq!(peer_manager.broadcast(vec![2]), 1);
+
+        // Only the peer whose queue is full is disconnected.
+        assert!(!peer_manager.is_connected(&id_1));
+        assert!(!peer_manager.get_map(&id_1, |(peer, _)| peer.is_connected()).unwrap());
+        assert!(peer_manager.is_connected(&id_2));
+        assert!(!peer_manager.send_to(&id_1, vec![3]));
+
+        assert_eq!(receiver_1.try_recv().unwrap(), vec![1]);
+        assert!(receiver_1.try_recv().is_err());
+        assert_eq!(receiver_2.try_recv().unwrap(), vec![1]);
+        assert_eq!(receiver_2.try_recv().unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn max_peers() {
+        let peer_manager = PeerManager::new(2);
+        let unknown = Arc::new(new_peer(PeerRelation::Unknown));
+
+        assert!(peer_manager.add(unknown.clone()).is_ok());
+        assert!(peer_manager.add(Arc::new(new_peer(PeerRelation::Discovered))).is_ok());
+
+        assert!(matches!(
+            peer_manager.add(Arc::new(new_peer(PeerRelation::Unknown))),
+            Err(PeerManagerError::MaxPeersReached(2))
+        ));
+        assert!(matches!(
+            peer_manager.add(Arc::new(new_peer(PeerRelation::Discovered))),
+            Err(PeerManagerError::MaxPeersReached(2))
+        ));
+        assert_eq!(peer_manager.len(), 2);
+
+        // Replacing an existing peer doesn't count as a new peer.
+        assert!(peer_manager.add(unknown).is_ok());
+        assert_eq!(peer_manager.len(), 2);
+
+        // Manually configured peers are always accepted.
+        assert!(peer_manager.add(Arc::new(new_peer(PeerRelation::Known))).is_ok());
+        assert_eq!(peer_manager.len(), 3);
+    }
+
+    #[test]
+    fn max_peers_ignores_known_peers() {
+        let peer_manager = PeerManager::new(1);
+
+        // Manually configured peers don't count towards the maximum.
+        assert!(peer_manager.add(Arc::new(new_peer(PeerRelation::Known))).is_ok());
+        assert!(peer_manager.add(Arc::new(new_peer(PeerRelation::Known))).is_ok());
+        assert!(peer_manager.add(Arc::new(new_peer(PeerRelation::Unknown))).is_ok());
+
+        assert!(matches!(
+            peer_manager.add(Arc::new(new_peer(PeerRelation::Discovered))),
+            Err(PeerManagerError::MaxPeersReached(1))
+        ));
+        assert_eq!(peer_manager.len(), 3);
+    }
+
+    #[test]
+    fn ban() {
+        let peer_manager = PeerManager::new(8);
+        let peer = Arc::new(new_peer(PeerRelation::Known));
+        let id = *peer.id();
+
+        assert!(!peer_manager.is_banned(&id));
+
+        peer_manager.ban(id, Duration::from_secs(60));
+
+        assert!(peer_manager.is_banned(&id));
+        assert!(matches!(
+            peer_manager.add(peer.clone()),
+            Err(PeerManagerError::Banned(remaining)) if remaining <= Duration::from_secs(60)
+        ));
+        assert!(peer_manager.is_empty());
+
+        // Other peers are not affected.
+        assert!(peer_manager.add(Arc::new(new_peer(PeerRelation::Known))).is_ok());
+        assert_eq!(peer_manager.len(), 1);
+    }
+
+    #[test]
+    fn ban_expiry() {
+        let peer_manager = PeerManager::new(8);
+        let peer = Arc::new(new_peer(PeerRelation::Unknown));
+        let id = *peer.id();
+
+        peer_manager.ban(id, Duration::from_millis(10));
+        assert!(peer_manager.add(peer.clone()).is_err());
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(!peer_manager.is_banned(&id));
+        assert!(peer_manager.add(peer).is_ok());
+
+        // Expired bans are forgotten by the cleanup, not the others.
+        let banned = PeerId::random();
+
+        peer_manager.ban(banned, Duration::from_secs(60));
+        peer_manager.remove_expired_bans();
+
+        assert_eq!(peer_manager.inner.read().bans.len(), 1);
+        assert!(peer_manager.is_banned(&banned));
+    }
+
+    #[test]
+    fn unban() {
+        let peer_manager = PeerManager::new(8);
+        let peer = Arc::new(new_peer(PeerRelation::Known));
+        let id = *peer.id();
+
+        assert!(!peer_manager.unban(&id));
+
+        peer_manager.ban(id, Duration::from_secs(60));
+
+        assert!(peer_manager.unban(&id));
+        assert!(!peer_manager.is_banned(&id));
+        assert!(peer_manager.add(peer).is_ok());
+    }
+
+    #[test]
+    fn get_by_alias() {
+        let peer_manager = PeerManager::new(8);
+        let alice = Arc::new(new_aliased_peer(PeerRelation::Known, "alice".to_owned()));
+        let bob = Arc::new(new_aliased_peer(PeerRelation::Known, "bob".to_owned()));
+        let (alice_id, bob_id) = (*alice.id(), *bob.id());
+
+        peer_manager.add(alice).unwrap();
+        peer_manager.add(bob).unwrap();
+        add_peer(&peer_manager, indexed_peer(100, 100), Some(8));
+
+        assert_eq!(peer_manager.get_by_alias("alice"), Some(alice_id));
+        assert_eq!(peer_manager.get_by_alias("bob"), Some(bob_id));
+        assert_eq!(peer_manager.get_by_alias("carol"), None);
+
+        let (removed, _) = peer_manager.remove(&alice_id).unwrap();
+
+        assert_eq!(removed.alias(), "alice");
+        assert_eq!(peer_manager.get_by_alias("alice"), None);
+        assert!(peer_manager.remove(&alice_id).is_none());
+    }
+
+    #[tokio::test]
+    async fn subscribe() {
+        let peer_manager = PeerManager::new(8);
+        let mut events = Box::pin(peer_manager.subscribe());
+        let peer = Arc::new(new_peer(PeerRelation::Known));
+        let id = *peer.id();
+
+        peer_manager.add(peer).unwrap();
+        peer_manager.remove(&id);
+        // Removing an unknown peer emits nothing.
+        peer_manager.remove(&id);
+        peer_manager.ban(id, Duration::from_secs(60));
+
+        assert_eq!(events.next().await, Some(PeerEvent::Added(id)));
+        assert_eq!(events.next().await, Some(PeerEvent::Removed(id)));
+        assert_eq!(events.next().await, Some(PeerEvent::Banned(id)));
+
+        // The stream ends with the manager.
+        drop(peer_manager);
+        assert_eq!(events.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn subscribe_lagging() {
+        let peer_manager = PeerManager::new(8);
+        let mut events = Box::pin(peer_manager.subscribe());
+        let ids = (0..PEER_EVENT_CAPACITY + 8)
+            .map(|_| PeerId::random())
+            .collect::<Vec<_>>();
+
+        // The subscriber doesn't consume anything, producers are not blocked.
+        for id in &ids {
+            peer_manager.ban(*id, Duration::from_secs(60));
+        }
+
+        // The oldest events are missed.
+        assert_eq!(events.next().await, Some(PeerEvent::Banned(ids[8])));
+    }
+
+    #[test]
+    fn peer_summary() {
+        let peer_manager = PeerManager::new(8);
+        let id = add_peer(&peer_manager, indexed_peer(100, 100), Some(8)).0;
+
+        assert!(peer_manager.peer_summary(&PeerId::random()).is_none());
+
+        let summary = peer_manager.peer_summary(&id).unwrap();
+
+        peer_manager.get_mut_map(&id, |peer| {
+            peer.0.set_connected(false);
+            peer.0.set_solid_milestone_index(MilestoneIndex(50));
+            peer.0.metrics().messages_received_inc();
+        });
+
+        assert_eq!(summary.id, id);
+        assert!(summary.connected);
+        assert!(summary.synced);
+        assert_eq!(summary.solid_milestone_index, MilestoneIndex(100));
+        assert_eq!(summary.messages_received, 0);
+
+        let summary = peer_manager.peer_summary(&id).unwrap();
+
+        assert!(!summary.connected);
+        assert!(!summary.synced);
+        assert_eq!(summary.solid_milestone_index, MilestoneIndex(50));
+        assert_eq!(summary.messages_received, 1);
+    }
+}
@@ -13,16 +13,17 @@ use bee_tangle::Tangle;
 use futures::{channel::oneshot, future::FutureExt};
 use log::{debug, error, info, trace};
 use tokio::sync::mpsc;
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::ReceiverStream;
 
 pub(crate) use self::manager::{PeerManagerConfig, PeerManagerWorker};
-pub use self::manager_res::{PeerManager, PeerManagerResWorker};
+pub(crate) use self::manager_res::send_bytes;
+pub use self::manager_res::{FullQueuePolicy, PeerEvent, PeerManager, PeerManagerResWorker, PeerSummary};
 use crate::{
     types::{metrics::NodeMetrics, peer::Peer},
     workers::{
         packets::{
             tlv_from_bytes, HeaderPacket, HeartbeatPacket, MessagePacket, MessageRequestPacket, MilestoneRequestPacket,
-            Packet, TlvError,
+            Packet, TlvError, HEADER_SIZE,
         },
         peer::packet_handler::PacketHandler,
         requester::request_latest_milestone,
@@ -76,14 +77,19 @@ impl PeerWorker {
         mut self,
         tangle: ResourceHandle<Tangle<B>>,
         requested_milestones: ResourceHandle<RequestedMilestones>,
-        receiver: UnboundedReceiverStream<Vec<u8>>,
+        receiver: ReceiverStream<Vec<u8>>,
         shutdown: oneshot::Receiver<()>,
     ) {
         info!("[{}] Running.", self.peer.alias());
 
         let shutdown_fused = shutdown.fuse();
 
-        let mut packet_handler = PacketHandler::new(receiver, shutdown_fused, self.peer.address().clone());
+        let mut packet_handler = PacketHandler::new(
+            receiver,
+            shutdown_fused,
+            self.peer.address().clone(),
+            self.peer.capabilities().supports_crc(),
+        );
 
         request_latest_milestone(
             &*tangle,
@@ -96,6 +102,8 @@ impl PeerWorker {
         let tangle = tangle.into_weak();
 
         while let Some((header, bytes)) = packet_handler.fetch_packet().await {
+            self.peer.metrics().packet_received(HEADER_SIZE + bytes.len());
+
             let tangle = tangle.upgrade().expect("Needed Tangle resource but it was removed");
 
             if let Err(e) = self.process_packet(&tangle, &header, bytes) {
@@ -118,7 +126,7 @@ impl PeerWorker {
             MilestoneRequestPacket::ID => {
                 trace!("[{}] Reading MilestoneRequestPacket...", self.peer.alias());
 
-                let packet = tlv_from_bytes::<MilestoneRequestPacket>(header, bytes)?;
+                let packet = tlv_from_bytes::<MilestoneRequestPacket>(header, bytes, self.peer.capabilities().supports_crc())?;
 
                 let _ = self.milestone_responder.send(MilestoneResponderWorkerEvent {
                     peer_id: *self.peer.id(),
@@ -131,7 +139,7 @@ impl PeerWorker {
             MessagePacket::ID => {
                 trace!("[{}] Reading MessagePacket...", self.peer.alias());
 
-                let packet = tlv_from_bytes::<MessagePacket>(header, bytes)?;
+                let packet = tlv_from_bytes::<MessagePacket>(header, bytes, self.peer.capabilities().supports_crc())?;
 
                 let _ = self.hasher.send(HasherWorkerEvent {
                     from: Some(*self.peer.id()),
@@ -145,7 +153,7 @@ impl PeerWorker {
             MessageRequestPacket::ID => {
                 trace!("[{}] Reading MessageRequestPacket...", self.peer.alias());
 
-                let packet = tlv_from_bytes::<MessageRequestPacket>(header, bytes)?;
+                let packet = tlv_from_bytes::<MessageRequestPacket>(header, bytes, self.peer.capabilities().supports_crc())?;
 
                 let _ = self.message_responder.send(MessageResponderWorkerEvent {
                     peer_id: *self.peer.id(),
@@ -158,7 +166,7 @@ impl PeerWorker {
             HeartbeatPacket::ID => {
                 trace!("[{}] Reading HeartbeatPacket...", self.peer.alias());
 
-                let packet = tlv_from_bytes::<HeartbeatPacket>(header, bytes)?;
+                let packet = tlv_from_bytes::<HeartbeatPacket>(header, bytes, self.peer.capabilities().supports_crc())?;
 
                 self.peer.set_solid_milestone_index(packet.solid_milestone_index.into());
                 self.peer.set_pruned_index(packet.pruned_index.into());
@@ -2,159 +2,61 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_gossip::Multiaddr;
-use futures::{
-    channel::oneshot,
-    future::{self, FutureExt},
-    stream::StreamExt,
-};
+use futures::{channel::oneshot, future, stream::StreamExt};
 use log::trace;
 use tokio::select;
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::ReceiverStream;
 
-use crate::workers::packets::{HeaderPacket, HEADER_SIZE};
+use crate::workers::packets::{HeaderPacket, TlvDecoder};
 
-type EventRecv = UnboundedReceiverStream<Vec<u8>>;
+type EventRecv = ReceiverStream<Vec<u8>>;
 type ShutdownRecv = future::Fuse<oneshot::Receiver<()>>;
 
-/// The read state of the packet handler.
-///
-/// This type is used by `PacketHandler` to decide what should be read next when handling an
-/// event.
-enum ReadState {
-    /// `PacketHandler` should read a header.
-    Header,
-    /// `PacketHandler` should read a payload based on a header.
-    Payload(HeaderPacket),
-}
-
 /// A packet handler.
 ///
 /// It takes care of processing events into packets that can be processed by the workers.
 pub(super) struct PacketHandler {
-    events: EventHandler,
+    receiver: EventRecv,
+    decoder: TlvDecoder,
     // FIXME: see if we can implement `Stream` for the `PacketHandler` and use the
     // `ShutdownStream` type instead.
     shutdown: ShutdownRecv,
-    state: ReadState,
     /// The address of the peer. This field is only here for logging purposes.
     address: Multiaddr,
 }
 
 impl PacketHandler {
-    /// Create a new packet handler from an event receiver, a shutdown receiver and the peer's
-    /// address.
-    pub(super) fn new(receiver: EventRecv, shutdown: ShutdownRecv, address: Multiaddr) -> Self {
+    /// Create a new packet handler from an event receiver, a shutdown receiver, the peer's
+    /// address and whether payloads are followed by a CRC32 checksum.
+    pub(super) fn new(receiver: EventRecv, shutdown: ShutdownRecv, address: Multiaddr, crc: bool) -> Self {
         Self {
-            events: EventHandler::new(receiver),
+            receiver,
+            decoder: TlvDecoder::new(crc),
             shutdown,
-            // The handler should read a header first.
-            state: ReadState::Header,
             address,
         }
     }
+
     /// Fetch the header and payload of a packet.
     ///
-    /// This method only returns `None` if a shutdown signal is received.
+    /// This method only returns `None` if a shutdown signal is received or if the event receiver
+    /// is closed.
     pub(super) async fn fetch_packet(&mut self) -> Option<(HeaderPacket, &[u8])> {
-        // loop until we can return the header and payload
-        loop {
-            match &self.state {
-                // Read a header.
-                ReadState::Header => {
-                    // We need `HEADER_SIZE` bytes to read a header.
-                    let bytes = self
-                        .events
-                        .fetch_bytes_or_shutdown(&mut self.shutdown, HEADER_SIZE)
-                        .await?;
-                    trace!("[{}] Reading Header...", self.address);
-                    // This never panics because we fetch exactly `HEADER_SIZE` bytes.
-                    let header = HeaderPacket::from_bytes(bytes.try_into().unwrap());
-                    // Now we are ready to read a payload.
-                    self.state = ReadState::Payload(header);
-                }
-                // Read a payload.
-                ReadState::Payload(header) => {
-                    // We read the quantity of bytes stated by the header.
-                    let bytes = self
-                        .events
-                        .fetch_bytes_or_shutdown(&mut self.shutdown, header.packet_length.into())
-                        .await?;
-                    // FIXME: Avoid this clone
-                    let header = header.clone();
-                    // Now we are ready to read the next packet's header.
-                    self.state = ReadState::Header;
-                    // We return the current packet's header and payload.
-                    return Some((header, bytes));
-                }
-            }
+        // Receive events until the decoder has a complete packet.
+        while !self.decoder.has_frame() {
+            let event = select! {
+                // Always select `shutdown` first, otherwise you can end with an infinite loop.
+                biased;
+                _ = &mut self.shutdown => None,
+                event = self.receiver.next() => event,
+            }?;
+
+            self.decoder.push(&event);
         }
-    }
-}
 
-// An event handler.
-//
-// This type takes care of actually receiving the events and appending them to an inner buffer so
-// they can be used seamlessly by the `PacketHandler`.
-struct EventHandler {
-    receiver: EventRecv,
-    buffer: Vec<u8>,
-    offset: usize,
-}
+        trace!("[{}] Read packet.", self.address);
 
-impl EventHandler {
-    /// Create a new event handler from an event receiver.
-    fn new(receiver: EventRecv) -> Self {
-        Self {
-            receiver,
-            buffer: vec![],
-            offset: 0,
-        }
-    }
-
-    /// Push a new event into the buffer.
-    ///
-    /// This method also removes the `..self.offset` range from the buffer and sets the offset back
-    /// to zero. Which means that this should only be called when the buffer is empty or when there
-    /// are not enough bytes to read a new header or payload.
-    fn push_event(&mut self, mut bytes: Vec<u8>) {
-        // Remove the already read bytes from the buffer.
-        self.buffer = self.buffer.split_off(self.offset);
-        // Reset the offset.
-        self.offset = 0;
-        // Append the bytes of the new event
-        self.buffer.append(&mut bytes);
-    }
-    /// Fetch a slice of bytes of a determined length.
-    ///
-    /// The future returned by this method will be ready until there are enough bytes to fullfill
-    /// the request.
-    async fn fetch_bytes(&mut self, len: usize) -> &[u8] {
-        // We need to be sure that we have enough bytes in the buffer.
-        while self.offset + len > self.buffer.len() {
-            // If there are not enough bytes in the buffer, we must receive new events
-            if let Some(event) = self.receiver.next().await {
-                // If we received an event, we push it to the buffer.
-                self.push_event(event);
-            }
-        }
-        // Get the requested bytes. This will not panic because the loop above only exists if we
-        // have enough bytes to do this step.
-        let bytes = &self.buffer[self.offset..][..len];
-        // Increase the offset by the length of the byte slice.
-        self.offset += len;
-        bytes
-    }
-
-    /// Helper method to be able to shutdown when fetching bytes for a packet.
-    ///
-    /// This method returns `None` if a shutdown signal is received, otherwise it returns the
-    /// requested bytes.
-    async fn fetch_bytes_or_shutdown(&mut self, shutdown: &mut ShutdownRecv, len: usize) -> Option<&'_ [u8]> {
-        select! {
-            // Always select `shutdown` first, otherwise you can end with an infinite loop.
-            _ = shutdown => None,
-            bytes = self.fetch_bytes(len).fuse() => Some(bytes),
-        }
+        self.decoder.next_frame()
     }
 }
 
@@ -164,7 +66,7 @@ mod tests {
 
     use futures::{channel::oneshot, future::FutureExt};
     use tokio::{spawn, sync::mpsc, time::sleep};
-    use tokio_stream::wrappers::UnboundedReceiverStream;
+    use tokio_stream::wrappers::ReceiverStream;
 
     use super::*;
 
@@ -194,24 +96,19 @@ mod tests {
         let events = gen_events(event_size, msg_size, msg_count);
         // Create a new packet handler
         let (sender_shutdown, receiver_shutdown) = oneshot::channel::<()>();
-        let (sender, receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+        let (sender, receiver) = mpsc::channel::<Vec<u8>>(64);
         let mut msg_handler = PacketHandler::new(
-            UnboundedReceiverStream::new(receiver),
+            ReceiverStream::new(receiver),
             receiver_shutdown.fuse(),
             "/ip4/0.0.0.0/tcp/8080".parse().unwrap(),
+            false,
         );
         // Create the task that does the checks of the test.
         let handle = spawn(async move {
             // The packets are expected to be filled with zeroes except for the packet length
             // field of the header.
             let expected_bytes = vec![0u8; msg_len];
-            let expected_msg = (
-                HeaderPacket {
-                    packet_type: 0,
-                    packet_length: msg_len as u16,
-                },
-                expected_bytes.as_slice(),
-            );
+            let expected_msg = (HeaderPacket::new(0, msg_len as u16), expected_bytes.as_slice());
             // Count how many packets can be fetched.
             let mut counter = 0;
             while let Some(msg) = msg_handler.fetch_packet().await {
@@ -226,7 +123,7 @@ mod tests {
         });
         // Send all the events to the packet handler.
         for event in events {
-            sender.send(event).unwrap();
+            sender.send(event).await.unwrap();
             sleep(Duration::from_millis(1)).await;
         }
         // Sleep to be sure the handler had time to produce all the packets.
@@ -287,23 +184,18 @@ mod tests {
         let last_event = events.pop().unwrap();
 
         let (sender_shutdown, receiver_shutdown) = oneshot::channel::<()>();
-        let (sender, receiver) = mpsc::unbounded_channel::<Vec<u8>>();
+        let (sender, receiver) = mpsc::channel::<Vec<u8>>(64);
 
         let mut msg_handler = PacketHandler::new(
-            UnboundedReceiverStream::new(receiver),
+            ReceiverStream::new(receiver),
             receiver_shutdown.fuse(),
             "/ip4/0.0.0.0/tcp/8080".parse().unwrap(),
+            false,
         );
 
         let handle = spawn(async move {
             let expected_bytes = vec![0u8; msg_len];
-            let expected_msg = (
-                HeaderPacket {
-                    packet_type: 0,
-                    packet_length: msg_len as u16,
-                },
-                expected_bytes.as_slice(),
-            );
+            let expected_msg = (HeaderPacket::new(0, msg_len as u16), expected_bytes.as_slice());
 
             let mut counter = 0;
             while let Some(msg) = msg_handler.fetch_packet().await {
@@ -317,14 +209,14 @@ mod tests {
         });
 
         for event in events {
-            sender.send(event).unwrap();
+            sender.send(event).await.unwrap();
             sleep(Duration::from_millis(1)).await;
         }
 
         sender_shutdown.send(()).unwrap();
         sleep(Duration::from_millis(1)).await;
         // Send the last event after the shutdown signal
-        sender.send(last_event).unwrap();
+        sender.send(last_event).await.unwrap();
 
         assert!(handle.await.is_ok());
     }
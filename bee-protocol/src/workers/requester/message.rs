@@ -4,7 +4,7 @@
 use std::{
     any::TypeId,
     cmp::{Ord, Ordering, PartialOrd},
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     convert::Infallible,
     sync::Arc,
     time::{Duration, Instant},
@@ -43,7 +43,32 @@ pub async fn request_message<B: StorageBackend>(
         && !tangle.is_solid_entry_point(&message_id).await
         && !requested_messages.contains(&message_id)
     {
-        message_requester.request(MessageRequesterWorkerEvent(message_id, index));
+        message_requester.request(MessageRequesterWorkerEvent(vec![message_id], index));
+    }
+}
+
+/// Requests the messages that are neither in the tangle, solid entry points nor already requested, the requests being
+/// sent to the same peer in as few frames as possible.
+pub async fn request_messages<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    message_requester: &MessageRequesterWorker,
+    requested_messages: &RequestedMessages,
+    message_ids: &[MessageId],
+    index: MilestoneIndex,
+) {
+    let mut missing = Vec::with_capacity(message_ids.len());
+
+    for message_id in message_ids {
+        if !tangle.contains(message_id)
+            && !tangle.is_solid_entry_point(message_id).await
+            && !requested_messages.contains(message_id)
+        {
+            missing.push(*message_id);
+        }
+    }
+
+    if !missing.is_empty() {
+        message_requester.request(MessageRequesterWorkerEvent(missing, index));
     }
 }
 
@@ -74,8 +99,48 @@ impl RequestedMessages {
     }
 }
 
+/// Messages recently requested, used to suppress identical requests within a time-to-live.
+///
+/// Unlike `RequestedMessages`, entries are kept after the message is received so that it is not requested again while
+/// it is still being processed.
+pub(crate) struct RecentMessageRequests {
+    ttl: Duration,
+    requests: HashMap<MessageId, Instant, FxBuildHasher>,
+    order: VecDeque<(Instant, MessageId)>,
+}
+
+impl RecentMessageRequests {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            requests: Default::default(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Records a request of a message at `now` and returns whether it was not already requested within the TTL.
+    pub(crate) fn insert(&mut self, message_id: MessageId, now: Instant) -> bool {
+        while let Some((instant, expired)) = self.order.front() {
+            if now.saturating_duration_since(*instant) < self.ttl {
+                break;
+            }
+            self.requests.remove(expired);
+            self.order.pop_front();
+        }
+
+        if self.requests.contains_key(&message_id) {
+            return false;
+        }
+
+        self.requests.insert(message_id, now);
+        self.order.push_back((now, message_id));
+
+        true
+    }
+}
+
 #[derive(Eq, PartialEq)]
-pub struct MessageRequesterWorkerEvent(pub(crate) MessageId, pub(crate) MilestoneIndex);
+pub struct MessageRequesterWorkerEvent(pub(crate) Vec<MessageId>, pub(crate) MilestoneIndex);
 
 impl Ord for MessageRequesterWorkerEvent {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -100,24 +165,63 @@ impl MessageRequesterWorker {
     }
 }
 
-fn process_request(
-    message_id: MessageId,
+fn process_requests(
+    message_ids: &[MessageId],
     index: MilestoneIndex,
     peer_manager: &PeerManager,
     metrics: &NodeMetrics,
     requested_messages: &RequestedMessages,
+    recent_requests: &mut RecentMessageRequests,
 ) {
-    if requested_messages.contains(&message_id) {
+    if peer_manager.is_empty() {
         return;
     }
 
-    if peer_manager.is_empty() {
+    let now = Instant::now();
+    let message_ids = message_ids
+        .iter()
+        .copied()
+        .filter(|message_id| {
+            if requested_messages.contains(message_id) {
+                return false;
+            }
+
+            if !recent_requests.insert(*message_id, now) {
+                trace!("Message {} was requested recently, skipping.", message_id);
+                return false;
+            }
+
+            requested_messages.insert(*message_id, index);
+
+            true
+        })
+        .collect::<Vec<_>>();
+
+    process_requests_unchecked(&message_ids, index, peer_manager, metrics);
+}
+
+fn process_requests_unchecked(
+    message_ids: &[MessageId],
+    index: MilestoneIndex,
+    peer_manager: &PeerManager,
+    metrics: &NodeMetrics,
+) {
+    if message_ids.is_empty() {
         return;
     }
 
-    requested_messages.insert(message_id, index);
+    let message_requests = message_ids
+        .iter()
+        .copied()
+        .map(MessageRequestPacket::new)
+        .collect::<Vec<_>>();
 
-    process_request_unchecked(message_id, index, peer_manager, metrics);
+    if let Some(peer_id) = peer_manager
+        .fair_find(|peer| peer.has_data(index))
+        .or_else(|| peer_manager.fair_find(|peer| peer.maybe_has_data(index)))
+    {
+        Sender::<MessageRequestPacket>::send_batch(&message_requests, &peer_id, peer_manager, metrics)
+    }
 }
 
 fn process_request_unchecked(
@@ -179,7 +283,7 @@ impl<N: Node> Worker<N> for MessageRequesterWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = ();
+    type Config = Duration;
     type Error = Infallible;
 
     fn dependencies() -> &'static [TypeId] {
@@ -191,8 +295,9 @@ where
         .leak()
     }
 
-    async fn start(node: &mut N, _config: Self::Config) -> Result<Self, Self::Error> {
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let req_queue = Arc::new(PriorityQueue::new());
+        let mut recent_requests = RecentMessageRequests::new(config);
 
         let requested_messages: RequestedMessages = Default::default();
         node.register_resource(requested_messages);
@@ -208,10 +313,17 @@ where
 
                 let mut receiver = ShutdownStream::new(shutdown, req_queue.incoming());
 
-                while let Some(MessageRequesterWorkerEvent(message_id, index)) = receiver.next().await {
-                    trace!("Requesting message {}.", message_id);
-
-                    process_request(message_id, index, &peer_manager, &metrics, &requested_messages);
+                while let Some(MessageRequesterWorkerEvent(message_ids, index)) = receiver.next().await {
+                    trace!("Requesting {} message(s).", message_ids.len());
+
+                    process_requests(
+                        &message_ids,
+                        index,
+                        &peer_manager,
+                        &metrics,
+                        &requested_messages,
+                        &mut recent_requests,
+                    );
                 }
 
                 info!("Requester stopped.");
@@ -238,3 +350,119 @@ where
         Ok(Self { req_queue })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bee_gossip::PeerRelation;
+
+    use super::*;
+    use crate::workers::{
+        packets::tlv_to_bytes,
+        test_util::{self, new_peer},
+    };
+
+    const TTL: Duration = Duration::from_millis(50);
+
+    fn add_peer(peer_manager: &PeerManager) -> tokio::sync::mpsc::Receiver<Vec<u8>> {
+        let peer = new_peer(PeerRelation::Known);
+
+        peer.set_solid_milestone_index(MilestoneIndex(100));
+
+        test_util::add_peer(peer_manager, peer, Some(16)).1.unwrap()
+    }
+
+    fn sent_packets(receiver: &mut tokio::sync::mpsc::Receiver<Vec<u8>>) -> usize {
+        std::iter::from_fn(|| receiver.try_recv().ok()).count()
+    }
+
+    #[test]
+    fn recent_message_requests() {
+        let mut recent_requests = RecentMessageRequests::new(TTL);
+        let (first, second) = (MessageId::new([1; 32]), MessageId::new([2; 32]));
+        let now = Instant::now();
+
+        assert!(recent_requests.insert(first, now));
+        assert!(!recent_requests.insert(first, now + TTL / 2));
+        assert!(recent_requests.insert(second, now + TTL / 2));
+        assert!(recent_requests.insert(first, now + TTL));
+        assert!(!recent_requests.insert(second, now + TTL));
+    }
+
+    #[test]
+    fn back_to_back_requests_are_deduplicated() {
+        let peer_manager = PeerManager::new(8);
+        let metrics = NodeMetrics::default();
+        let requested_messages = RequestedMessages::default();
+        let mut recent_requests = RecentMessageRequests::new(TTL);
+        let mut receiver = add_peer(&peer_manager);
+        let (message_id, index) = (MessageId::new([1; 32]), MilestoneIndex(50));
+
+        process_requests(
+            &[message_id],
+            index,
+            &peer_manager,
+            &metrics,
+            &requested_messages,
+            &mut recent_requests,
+        );
+        // The message is received but requested again before it is processed.
+        requested_messages.remove(&message_id);
+        process_requests(
+            &[message_id],
+            index,
+            &peer_manager,
+            &metrics,
+            &requested_messages,
+            &mut recent_requests,
+        );
+
+        assert_eq!(sent_packets(&mut receiver), 1);
+        assert_eq!(metrics.message_requests_sent(), 1);
+
+        std::thread::sleep(TTL);
+        requested_messages.remove(&message_id);
+        process_requests(
+            &[message_id],
+            index,
+            &peer_manager,
+            &metrics,
+            &requested_messages,
+            &mut recent_requests,
+        );
+
+        assert_eq!(sent_packets(&mut receiver), 1);
+    }
+
+    #[test]
+    fn batched_requests_are_coalesced() {
+        let peer_manager = PeerManager::new(8);
+        let metrics = NodeMetrics::default();
+        let requested_messages = RequestedMessages::default();
+        let mut recent_requests = RecentMessageRequests::new(TTL);
+        let mut receiver = add_peer(&peer_manager);
+        let message_ids = (0..100u8).map(|i| MessageId::new([i; 32])).collect::<Vec<_>>();
+
+        process_requests(
+            &message_ids,
+            MilestoneIndex(50),
+            &peer_manager,
+            &metrics,
+            &requested_messages,
+            &mut recent_requests,
+        );
+
+        assert_eq!(requested_messages.len(), 100);
+        assert!(
+            message_ids
+                .iter()
+                .all(|message_id| requested_messages.contains(message_id))
+        );
+        assert_eq!(metrics.message_requests_sent(), 100);
+
+        // A single frame holding the 100 request packets.
+        let frame = receiver.try_recv().unwrap();
+        let packet_len = tlv_to_bytes(&MessageRequestPacket::new(message_ids[0]), false, false).len();
+        assert_eq!(frame.len(), 100 * packet_len);
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -8,6 +8,6 @@ pub(crate) use self::milestone::{
     request_latest_milestone, request_milestone, MilestoneRequesterWorker, MilestoneRequesterWorkerEvent,
 };
 pub use self::{
-    message::{request_message, MessageRequesterWorker, MessageRequesterWorkerEvent, RequestedMessages},
+    message::{request_message, request_messages, MessageRequesterWorker, MessageRequesterWorkerEvent, RequestedMessages},
     milestone::RequestedMilestones,
 };
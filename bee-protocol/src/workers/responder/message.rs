@@ -4,7 +4,6 @@
 use std::{any::TypeId, convert::Infallible};
 
 use async_trait::async_trait;
-use bee_common::packable::Packable;
 use bee_gossip::PeerId;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{Tangle, TangleWorker};
@@ -65,7 +64,7 @@ where
             while let Some(MessageResponderWorkerEvent { peer_id, request }) = receiver.next().await {
                 if let Some(message) = tangle.get(&request.message_id) {
                     Sender::<MessagePacket>::send(
-                        &MessagePacket::new(message.pack_new()),
+                        &MessagePacket::from_message_ref(&message),
                         &peer_id,
                         &peer_manager,
                         &metrics,
@@ -4,7 +4,6 @@
 use std::{any::TypeId, convert::Infallible};
 
 use async_trait::async_trait;
-use bee_common::packable::Packable;
 use bee_gossip::PeerId;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{Tangle, TangleWorker};
@@ -71,7 +70,7 @@ where
 
                 if let Some(message) = tangle.get_milestone_message(index) {
                     Sender::<MessagePacket>::send(
-                        &MessagePacket::new(message.pack_new()),
+                        &MessagePacket::from_message_ref(&message),
                         &peer_id,
                         &peer_manager,
                         &metrics,
@@ -1,16 +1,17 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::marker::PhantomData;
+use std::{cell::Cell, marker::PhantomData};
 
 use bee_gossip::PeerId;
+use bee_message::MESSAGE_LENGTH_MAX;
 use log::warn;
 
 use crate::{
-    types::metrics::NodeMetrics,
+    types::{capabilities::Capabilities, metrics::NodeMetrics, peer::Peer},
     workers::{
         packets::{tlv_to_bytes, HeartbeatPacket, MessagePacket, MessageRequestPacket, MilestoneRequestPacket, Packet},
-        peer::PeerManager,
+        peer::{send_bytes, PeerManager},
     },
 };
 
@@ -18,6 +19,55 @@ pub(crate) struct Sender<P: Packet> {
     marker: PhantomData<P>,
 }
 
+// Frames of coalesced packets are kept below the size of the largest single packet.
+const MAX_FRAME_LEN: usize = MESSAGE_LENGTH_MAX;
+
+// Sends a packet to a connected peer, calling `on_sent` on success, then applies the full queue policy on failure.
+fn send_packet<P: Packet>(
+    packet: &P,
+    name: &str,
+    id: &PeerId,
+    peer_manager: &PeerManager,
+    on_sent: impl FnOnce(&Peer),
+) {
+    send_frame(
+        |capabilities| tlv_to_bytes(packet, capabilities.supports_crc(), capabilities.supports_compression()),
+        name,
+        id,
+        peer_manager,
+        on_sent,
+    )
+}
+
+// Sends the bytes built for the capabilities of a connected peer, calling `on_sent` on success, then applies the full
+// queue policy on failure.
+fn send_frame(
+    to_bytes: impl FnOnce(Capabilities) -> Vec<u8>,
+    name: &str,
+    id: &PeerId,
+    peer_manager: &PeerManager,
+    on_sent: impl FnOnce(&Peer),
+) {
+    let result = peer_manager
+        .get_map(id, |peer| {
+            peer.1.as_ref().map(|sender| {
+                let result = send_bytes(&peer.0, &sender.0, to_bytes(peer.0.capabilities()));
+
+                match &result {
+                    Ok(_) => on_sent(&peer.0),
+                    Err(e) => warn!("Sending {} to {} failed: {:?}.", name, id, e),
+                }
+
+                result
+            })
+        })
+        .flatten();
+
+    if let Some(Err(e)) = result {
+        peer_manager.handle_send_error(id, &e);
+    }
+}
+
 impl Sender<MilestoneRequestPacket> {
     pub(crate) fn send(
         packet: &MilestoneRequestPacket,
@@ -25,81 +75,136 @@ impl Sender<MilestoneRequestPacket> {
         peer_manager: &PeerManager,
         metrics: &NodeMetrics,
     ) {
-        peer_manager
-            .get_map(id, |peer| {
-                if let Some(ref sender) = peer.1 {
-                    match sender.0.send(tlv_to_bytes(packet)) {
-                        Ok(_) => {
-                            peer.0.metrics().milestone_requests_sent_inc();
-                            metrics.milestone_requests_sent_inc();
-                        }
-                        Err(e) => {
-                            warn!("Sending MilestoneRequestPacket to {} failed: {:?}.", id, e);
-                        }
-                    }
-                }
-            })
-            .unwrap_or_default()
+        send_packet(packet, "MilestoneRequestPacket", id, peer_manager, |peer| {
+            peer.metrics().milestone_requests_sent_inc();
+            metrics.milestone_requests_sent_inc();
+        })
     }
 }
 
 impl Sender<MessagePacket> {
     pub(crate) fn send(packet: &MessagePacket, id: &PeerId, peer_manager: &PeerManager, metrics: &NodeMetrics) {
-        peer_manager
-            .get_map(id, |peer| {
-                if let Some(ref sender) = peer.1 {
-                    match sender.0.send(tlv_to_bytes(packet)) {
-                        Ok(_) => {
-                            peer.0.metrics().messages_sent_inc();
-                            metrics.messages_sent_inc();
-                        }
-                        Err(e) => {
-                            warn!("Sending MessagePacket to {} failed: {:?}.", id, e);
-                        }
-                    }
-                }
-            })
-            .unwrap_or_default()
+        send_packet(packet, "MessagePacket", id, peer_manager, |peer| {
+            peer.metrics().messages_sent_inc();
+            metrics.messages_sent_inc();
+        })
     }
 }
 
 impl Sender<MessageRequestPacket> {
     pub(crate) fn send(packet: &MessageRequestPacket, id: &PeerId, peer_manager: &PeerManager, metrics: &NodeMetrics) {
-        peer_manager
-            .get_map(id, |peer| {
-                if let Some(ref sender) = peer.1 {
-                    match sender.0.send(tlv_to_bytes(packet)) {
-                        Ok(_) => {
-                            peer.0.metrics().message_requests_sent_inc();
-                            metrics.message_requests_sent_inc();
-                        }
-                        Err(e) => {
-                            warn!("Sending MessageRequestPacket to {} failed: {:?}.", id, e);
+        send_packet(packet, "MessageRequestPacket", id, peer_manager, |peer| {
+            peer.metrics().message_requests_sent_inc();
+            metrics.message_requests_sent_inc();
+        })
+    }
+}
+
+impl Sender<MessageRequestPacket> {
+    /// Sends several requests to a peer, coalesced into as few frames as possible.
+    ///
+    /// Each request stays a packet of its own, the receiving decoder reads them one after the other.
+    pub(crate) fn send_batch(
+        packets: &[MessageRequestPacket],
+        id: &PeerId,
+        peer_manager: &PeerManager,
+        metrics: &NodeMetrics,
+    ) {
+        let mut packets = packets.iter().peekable();
+
+        while packets.peek().is_some() {
+            let count = Cell::new(0);
+
+            send_frame(
+                |capabilities| {
+                    let mut frame = Vec::new();
+
+                    while let Some(packet) = packets.peek() {
+                        let bytes = tlv_to_bytes(
+                            *packet,
+                            capabilities.supports_crc(),
+                            capabilities.supports_compression(),
+                        );
+
+                        if !frame.is_empty() && frame.len() + bytes.len() > MAX_FRAME_LEN {
+                            break;
                         }
+
+                        frame.extend_from_slice(&bytes);
+                        packets.next();
+                        count.set(count.get() + 1);
                     }
-                }
-            })
-            .unwrap_or_default()
+
+                    frame
+                },
+                "MessageRequestPacket",
+                id,
+                peer_manager,
+                |peer| {
+                    for _ in 0..count.get() {
+                        peer.metrics().message_requests_sent_inc();
+                        metrics.message_requests_sent_inc();
+                    }
+                },
+            );
+
+            // The peer is not connected anymore, nothing was framed.
+            if count.get() == 0 {
+                break;
+            }
+        }
     }
 }
 
 impl Sender<HeartbeatPacket> {
     pub(crate) fn send(packet: &HeartbeatPacket, id: &PeerId, peer_manager: &PeerManager, metrics: &NodeMetrics) {
-        peer_manager
-            .get_map(id, |peer| {
-                if let Some(ref sender) = peer.1 {
-                    match sender.0.send(tlv_to_bytes(packet)) {
-                        Ok(_) => {
-                            peer.0.metrics().heartbeats_sent_inc();
-                            peer.0.set_heartbeat_sent_timestamp();
-                            metrics.heartbeats_sent_inc();
-                        }
-                        Err(e) => {
-                            warn!("Sending HeartbeatPacket to {} failed: {:?}.", id, e);
-                        }
-                    }
-                }
-            })
-            .unwrap_or_default();
+        send_packet(packet, "HeartbeatPacket", id, peer_manager, |peer| {
+            peer.metrics().heartbeats_sent_inc();
+            peer.set_heartbeat_sent_timestamp();
+            metrics.heartbeats_sent_inc();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_gossip::PeerRelation;
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::{
+        types::capabilities::Capabilities,
+        workers::test_util::{add_peer, new_peer},
+    };
+
+    fn add_capable_peer(peer_manager: &PeerManager, capabilities: Capabilities) -> (PeerId, mpsc::Receiver<Vec<u8>>) {
+        let peer = new_peer(PeerRelation::Known);
+
+        peer.set_capabilities(capabilities);
+
+        let (id, receiver) = add_peer(peer_manager, peer, Some(8));
+
+        (id, receiver.unwrap())
+    }
+
+    #[test]
+    fn send_negotiated_form() {
+        let peer_manager = PeerManager::new(8);
+        let metrics = NodeMetrics::default();
+        // Repetitive enough to be shrunk by compression.
+        let packet = MessagePacket::new(vec![42; 1000]);
+
+        let (plain_id, mut plain_receiver) = add_capable_peer(&peer_manager, Capabilities::NONE);
+        let (compressed_id, mut compressed_receiver) = add_capable_peer(&peer_manager, Capabilities::COMPRESSION);
+
+        Sender::<MessagePacket>::send(&packet, &plain_id, &peer_manager, &metrics);
+        Sender::<MessagePacket>::send(&packet, &compressed_id, &peer_manager, &metrics);
+
+        let plain = plain_receiver.try_recv().unwrap();
+        let compressed = compressed_receiver.try_recv().unwrap();
+
+        assert_eq!(plain, tlv_to_bytes(&packet, false, false));
+        assert_eq!(compressed, tlv_to_bytes(&packet, false, true));
+        assert!(compressed.len() < plain.len());
     }
 }
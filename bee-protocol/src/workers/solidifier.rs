@@ -21,7 +21,7 @@ use crate::{
     workers::{
         heartbeater::broadcast_heartbeat,
         peer::PeerManager,
-        requester::{request_message, request_milestone},
+        requester::{request_message, request_messages, request_milestone},
         storage::StorageBackend,
         IndexUpdaterWorker, IndexUpdaterWorkerEvent, MessageRequesterWorker, MetricsWorker, MilestoneRequesterWorker,
         PeerManagerResWorker, RequestedMessages, RequestedMilestones,
@@ -159,9 +159,14 @@ where
                                 *smi + 1,
                                 *next - 1
                             );
-                            for parent in message.parents().iter() {
-                                request_message(&tangle, &message_requester, &requested_messages, *parent, index).await;
-                            }
+                            request_messages(
+                                &tangle,
+                                &message_requester,
+                                &requested_messages,
+                                message.parents(),
+                                index,
+                            )
+                            .await;
                         } else {
                             error!("Requested milestone {} message not present in the tangle.", index)
                         }
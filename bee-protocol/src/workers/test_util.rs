@@ -0,0 +1,52 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers shared by the tests of the workers.
+
+use std::sync::Arc;
+
+use bee_gossip::{Multiaddr, PeerId, PeerInfo, PeerRelation};
+use futures::channel::oneshot;
+use tokio::sync::mpsc;
+
+use crate::{types::peer::Peer, workers::PeerManager};
+
+/// Creates a peer with the given relation, aliased by its id.
+pub(crate) fn new_peer(relation: PeerRelation) -> Peer {
+    let id = PeerId::random();
+
+    Peer::new(
+        id,
+        PeerInfo {
+            address: Multiaddr::empty(),
+            alias: id.to_string(),
+            relation,
+        },
+    )
+}
+
+/// Adds a peer to a peer manager and returns its id. If a queue capacity is given, the peer is also connected through
+/// a queue of that capacity whose receiving end is returned.
+pub(crate) fn add_peer(
+    peer_manager: &PeerManager,
+    peer: Peer,
+    capacity: Option<usize>,
+) -> (PeerId, Option<mpsc::Receiver<Vec<u8>>>) {
+    let id = *peer.id();
+
+    peer_manager.add(Arc::new(peer)).unwrap();
+
+    let receiver = capacity.map(|capacity| {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let (shutdown_tx, _) = oneshot::channel();
+
+        peer_manager.get_mut_map(&id, |peer| {
+            peer.0.set_connected(true);
+            peer.1 = Some((sender, shutdown_tx));
+        });
+
+        receiver
+    });
+
+    (id, receiver)
+}
@@ -38,9 +38,14 @@ type FusedShutdown = future::Fuse<Shutdown>;
 ///
 /// This type wraps a shutdown receiver and a stream to produce a new stream that ends when the
 /// shutdown receiver is triggered or when the stream ends.
+///
+/// In drain mode, the stream keeps yielding the items that are already queued when the shutdown receiver is
+/// triggered and only ends once the wrapped stream has no item ready.
 pub struct ShutdownStream<S> {
     shutdown: FusedShutdown,
     stream: S,
+    drain: bool,
+    drained: bool,
 }
 
 impl<S: Stream> ShutdownStream<stream::Fuse<S>> {
@@ -53,6 +58,19 @@ impl<S: Stream> ShutdownStream<stream::Fuse<S>> {
         Self {
             shutdown: shutdown.fuse(),
             stream: stream.fuse(),
+            drain: false,
+            drained: false,
+        }
+    }
+
+    /// Create a new `ShutdownStream` in drain mode from a shutdown receiver and an unfused stream.
+    ///
+    /// Unlike with `new`, the items already queued in the stream when the shutdown receiver is triggered are
+    /// still yielded so that none of the accepted items are dropped.
+    pub fn with_drain(shutdown: Shutdown, stream: S) -> Self {
+        Self {
+            drain: true,
+            ..Self::new(shutdown, stream)
         }
     }
 
@@ -60,7 +78,12 @@ impl<S: Stream> ShutdownStream<stream::Fuse<S>> {
     ///
     /// This method receives the fused stream to be wrapped and a fused `oneshot::Receiver` for the shutdown.
     pub fn from_fused(shutdown: FusedShutdown, stream: stream::Fuse<S>) -> Self {
-        Self { shutdown, stream }
+        Self {
+            shutdown,
+            stream,
+            drain: false,
+            drained: false,
+        }
     }
 
     /// Consume and split the `ShutdownStream` into its shutdown receiver and stream.
@@ -76,12 +99,21 @@ impl<S: Stream<Item = T> + FusedStream + Unpin, T> Stream for ShutdownStream<S>
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
         if !self.shutdown.is_terminated() {
             if self.shutdown.poll_unpin(cx).is_ready() {
-                return Poll::Ready(None);
+                if !self.drain {
+                    return Poll::Ready(None);
+                }
+            } else if !self.stream.is_terminated() {
+                return self.stream.poll_next_unpin(cx);
             }
+        }
 
-            if !self.stream.is_terminated() {
-                return self.stream.poll_next_unpin(cx);
+        if self.drain && !self.drained && !self.stream.is_terminated() {
+            // Only the items that are ready are yielded, waiting for new ones would delay the shutdown.
+            if let Poll::Ready(Some(item)) = self.stream.poll_next_unpin(cx) {
+                return Poll::Ready(Some(item));
             }
+
+            self.drained = true;
         }
 
         Poll::Ready(None)
@@ -90,6 +122,10 @@ impl<S: Stream<Item = T> + FusedStream + Unpin, T> Stream for ShutdownStream<S>
 
 impl<S: Stream<Item = T> + FusedStream + Unpin, T> FusedStream for ShutdownStream<S> {
     fn is_terminated(&self) -> bool {
-        self.shutdown.is_terminated() || self.stream.is_terminated()
+        if self.drain {
+            self.drained || self.stream.is_terminated()
+        } else {
+            self.shutdown.is_terminated() || self.stream.is_terminated()
+        }
     }
 }
@@ -6,6 +6,7 @@ use std::time::Duration;
 use bee_runtime::shutdown_stream::ShutdownStream;
 use futures::{
     channel::{mpsc, oneshot},
+    stream::FusedStream,
     SinkExt, StreamExt,
 };
 use tokio::{task::spawn, time::sleep};
@@ -95,3 +96,30 @@ async fn early_shutdown_split_from_fused() {
 
     assert!(handle.await.unwrap() < 5050);
 }
+
+#[tokio::test]
+async fn shutdown_with_drain() {
+    let (sender, receiver) = mpsc::unbounded::<usize>();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+
+    for i in 0..=100 {
+        assert!(sender.unbounded_send(i).is_ok());
+    }
+
+    assert!(shutdown_sender.send(()).is_ok());
+
+    let mut shutdown_stream = ShutdownStream::with_drain(shutdown_receiver, receiver);
+
+    let mut acc = 0;
+
+    while let Some(item) = shutdown_stream.next().await {
+        acc += item;
+    }
+
+    assert_eq!(acc, 5050);
+    assert!(shutdown_stream.is_terminated());
+
+    // The sender is still connected but items sent after the drain are not yielded anymore.
+    assert!(sender.unbounded_send(1).is_ok());
+    assert!(shutdown_stream.next().await.is_none());
+}
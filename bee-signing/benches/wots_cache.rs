@@ -0,0 +1,42 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(deprecated)]
+
+use bee_crypto::ternary::sponge::Kerl;
+use bee_signing::ternary::{
+    wots::{cache, WotsSecurityLevel, WotsSpongePrivateKeyGeneratorBuilder},
+    PrivateKey, PrivateKeyGenerator, PublicKey,
+};
+use bee_ternary::{T1B1Buf, TryteBuf};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const ENTROPY: &str = "CEFLDDLMF9TO9ZLLTYXIPVFIJKAOFRIQLGNYIDZCTDYSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD";
+const MESSAGE: &str = "CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD";
+
+fn wots_cache(c: &mut Criterion) {
+    let entropy = TryteBuf::try_from_str(ENTROPY).unwrap().as_trits().encode::<T1B1Buf>();
+    let message = TryteBuf::try_from_str(MESSAGE).unwrap().as_trits().encode::<T1B1Buf>();
+    let mut private_key = WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+        .with_security_level(WotsSecurityLevel::High)
+        .build()
+        .unwrap()
+        .generate_from_entropy(&entropy)
+        .unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+    let signature = private_key.sign(&message).unwrap();
+
+    c.bench_function("wots verify uncached", |b| {
+        b.iter(|| {
+            cache::clear();
+            assert!(public_key.verify(&message, &signature).unwrap());
+        })
+    });
+
+    c.bench_function("wots verify cached", |b| {
+        b.iter(|| assert!(public_key.verify(&message, &signature).unwrap()))
+    });
+}
+
+criterion_group!(benches, wots_cache);
+criterion_main!(benches);
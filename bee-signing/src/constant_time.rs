@@ -0,0 +1,19 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ternary::{Trits, T1B1};
+
+/// Compares two trit slices in constant time.
+///
+/// The time taken only depends on the lengths of the slices, which are not considered secret, and not on their
+/// content. This prevents timing attacks on comparisons involving secret-dependent data, like recovered public keys.
+pub fn constant_time_eq(a: &Trits<T1B1>, b: &Trits<T1B1>) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0, |acc, (a, b)| acc | (i8::from(a) ^ i8::from(b)))
+        == 0
+}
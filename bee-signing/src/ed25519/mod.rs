@@ -0,0 +1,204 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Ed25519 signing scheme primitives.
+//!
+//! Private keys are derived from a seed following SLIP-0010, along the `m/44'/4218'/account'/0'/index'` path.
+//! <https://github.com/satoshilabs/slips/blob/master/slip-0010.md>.
+
+mod multisig;
+mod scheme;
+
+pub mod seed;
+
+use bee_common_derive::{SecretDebug, SecretDisplay, SecretDrop};
+use crypto::{
+    keys::slip10::{Chain, Curve, Seed as Slip10Seed, Segment},
+    signatures::ed25519::{
+        PublicKey as CryptoPublicKey, SecretKey, Signature as CryptoSignature, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH,
+        SIGNATURE_LENGTH,
+    },
+};
+use thiserror::Error;
+use zeroize::Zeroize;
+
+use self::seed::Seed;
+pub use self::{
+    multisig::verify_multisig,
+    scheme::{PrivateKey, PrivateKeyGenerator, PublicKey, Signature},
+};
+
+/// BIP44 purpose of the derivation path.
+const BIP44_PURPOSE: u32 = 44;
+/// SLIP-0044 coin type of IOTA.
+const IOTA_COIN_TYPE: u32 = 4218;
+
+/// Errors occuring during Ed25519 operations.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// Invalid derivation index, hardened derivation only allows indexes below 2^31.
+    #[error("Invalid derivation index {0}, should be below 2^31.")]
+    InvalidIndex(u32),
+    /// Failed key derivation.
+    #[error("Failed key derivation.")]
+    FailedDerivation,
+    /// Invalid private key length.
+    #[error("Invalid private key length, should be {SECRET_KEY_LENGTH} bytes, was {0}.")]
+    InvalidPrivateKeyLength(usize),
+    /// Invalid public key length.
+    #[error("Invalid public key length, should be {PUBLIC_KEY_LENGTH} bytes, was {0}.")]
+    InvalidPublicKeyLength(usize),
+    /// Invalid public key.
+    #[error("Invalid public key.")]
+    InvalidPublicKey,
+    /// Invalid signature length.
+    #[error("Invalid signature length, should be {SIGNATURE_LENGTH} bytes, was {0}.")]
+    InvalidSignatureLength(usize),
+    /// Mismatched numbers of signatures and public keys.
+    #[error("Mismatched numbers of signatures ({signatures}) and public keys ({public_keys}).")]
+    SignatureCountMismatch {
+        /// Number of signatures.
+        signatures: usize,
+        /// Number of public keys.
+        public_keys: usize,
+    },
+    /// Public key signing more than once.
+    #[error("Duplicate signer.")]
+    DuplicateSigner,
+    /// Multisig threshold of zero, which would accept any message.
+    #[error("Invalid multisig threshold 0, should be at least 1.")]
+    InvalidThreshold,
+}
+
+/// Ed25519 private key generator, deriving keys of a given account.
+#[derive(Default)]
+pub struct Ed25519PrivateKeyGenerator {
+    account: u32,
+}
+
+impl Ed25519PrivateKeyGenerator {
+    /// Sets the account the private keys are derived from.
+    #[must_use]
+    pub fn with_account(mut self, account: u32) -> Self {
+        self.account = account;
+        self
+    }
+}
+
+impl PrivateKeyGenerator for Ed25519PrivateKeyGenerator {
+    type PrivateKey = Ed25519PrivateKey;
+    type Error = Error;
+
+    fn generate_from_seed(&self, seed: &Seed, index: u32) -> Result<Self::PrivateKey, Self::Error> {
+        for segment in [self.account, index] {
+            if segment >= Segment::HARDEN_MASK {
+                return Err(Error::InvalidIndex(segment));
+            }
+        }
+
+        let chain = Chain::from_u32_hardened([BIP44_PURPOSE, IOTA_COIN_TYPE, self.account, 0, index]);
+        let key = Slip10Seed::from_bytes(seed.as_bytes())
+            .derive(Curve::Ed25519, &chain)
+            .map_err(|_| Error::FailedDerivation)?;
+
+        Ok(Ed25519PrivateKey(key.secret_key().to_bytes()))
+    }
+}
+
+/// Ed25519 private key.
+#[derive(SecretDebug, SecretDisplay, SecretDrop)]
+pub struct Ed25519PrivateKey([u8; SECRET_KEY_LENGTH]);
+
+impl Zeroize for Ed25519PrivateKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Ed25519PrivateKey {
+    // Only the secret bytes are kept around, the secret key is built whenever it is needed.
+    fn secret_key(&self) -> SecretKey {
+        SecretKey::from_bytes(self.0)
+    }
+}
+
+impl PrivateKey for Ed25519PrivateKey {
+    type PublicKey = Ed25519PublicKey;
+    type Signature = Ed25519Signature;
+    type Error = Error;
+
+    fn generate_public_key(&self) -> Result<Self::PublicKey, Self::Error> {
+        Ok(Ed25519PublicKey(self.secret_key().public_key()))
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Self::Signature, Self::Error> {
+        Ok(Ed25519Signature(self.secret_key().sign(message).to_bytes()))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(
+            bytes
+                .try_into()
+                .map_err(|_| Error::InvalidPrivateKeyLength(bytes.len()))?,
+        ))
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Ed25519 public key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519PublicKey(CryptoPublicKey);
+
+impl PublicKey for Ed25519PublicKey {
+    type Signature = Ed25519Signature;
+    type Error = Error;
+
+    fn verify(&self, message: &[u8], signature: &Self::Signature) -> Result<bool, Self::Error> {
+        Ok(self.0.verify(&CryptoSignature::from_bytes(signature.0), message))
+    }
+
+    fn size(&self) -> usize {
+        PUBLIC_KEY_LENGTH
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| Error::InvalidPublicKeyLength(bytes.len()))?;
+
+        Ok(Self(
+            CryptoPublicKey::try_from_bytes(bytes).map_err(|_| Error::InvalidPublicKey)?,
+        ))
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Ed25519 signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519Signature([u8; SIGNATURE_LENGTH]);
+
+impl Signature for Ed25519Signature {
+    type Error = Error;
+
+    fn size(&self) -> usize {
+        SIGNATURE_LENGTH
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(
+            bytes
+                .try_into()
+                .map_err(|_| Error::InvalidSignatureLength(bytes.len()))?,
+        ))
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
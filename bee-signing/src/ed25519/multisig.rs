@@ -0,0 +1,42 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::ed25519::{Ed25519PublicKey, Ed25519Signature, Error, PublicKey};
+
+/// Verifies that `message` is signed by at least `threshold` distinct signers, as required to validate milestones.
+///
+/// `signatures[i]` is expected to be made by `public_keys[i]`; invalid signatures are not counted. Signers appearing
+/// more than once are rejected rather than counted several times, as is a `threshold` of 0.
+pub fn verify_multisig(
+    message: &[u8],
+    signatures: &[Ed25519Signature],
+    public_keys: &[Ed25519PublicKey],
+    threshold: usize,
+) -> Result<bool, Error> {
+    if threshold == 0 {
+        return Err(Error::InvalidThreshold);
+    }
+
+    if signatures.len() != public_keys.len() {
+        return Err(Error::SignatureCountMismatch {
+            signatures: signatures.len(),
+            public_keys: public_keys.len(),
+        });
+    }
+
+    let mut signers = HashSet::with_capacity(public_keys.len());
+    let mut valid = 0;
+
+    for (signature, public_key) in signatures.iter().zip(public_keys) {
+        if !signers.insert(public_key.as_bytes()) {
+            return Err(Error::DuplicateSigner);
+        }
+        if public_key.verify(message, signature)? {
+            valid += 1;
+        }
+    }
+
+    Ok(valid >= threshold)
+}
@@ -0,0 +1,139 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use zeroize::Zeroize;
+
+use crate::ed25519::seed::Seed;
+
+/// Generates an Ed25519 private key.
+pub trait PrivateKeyGenerator {
+    /// Generated private keys type.
+    type PrivateKey: PrivateKey;
+    /// Errors occuring while generating private keys.
+    type Error;
+
+    /// Deterministically generates and returns a private key from a seed and an index.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed`    A seed to deterministically derive a private key from.
+    /// * `index`   An index to deterministically derive a private key from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bee_signing::ed25519::{seed::Seed, Ed25519PrivateKeyGenerator, PrivateKeyGenerator};
+    ///
+    /// let seed = Seed::from_bytes(&[0x2a; 32]).unwrap();
+    /// let private_key_generator = Ed25519PrivateKeyGenerator::default();
+    /// let private_key = private_key_generator.generate_from_seed(&seed, 0).unwrap();
+    /// ```
+    fn generate_from_seed(&self, seed: &Seed, index: u32) -> Result<Self::PrivateKey, Self::Error>;
+}
+
+/// An Ed25519 private key.
+pub trait PrivateKey: Zeroize {
+    /// Matching public key type.
+    type PublicKey: PublicKey;
+    /// Generated signatures type.
+    type Signature: Signature;
+    /// Errors occuring while handling private keys.
+    type Error;
+
+    /// Returns the public counterpart of a private key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bee_signing::ed25519::{seed::Seed, Ed25519PrivateKeyGenerator, PrivateKeyGenerator};
+    /// use bee_signing::ed25519::PrivateKey;
+    ///
+    /// # let seed = Seed::from_bytes(&[0x2a; 32]).unwrap();
+    /// # let private_key = Ed25519PrivateKeyGenerator::default().generate_from_seed(&seed, 0).unwrap();
+    /// let public_key = private_key.generate_public_key().unwrap();
+    /// ```
+    fn generate_public_key(&self) -> Result<Self::PublicKey, Self::Error>;
+
+    /// Generates and returns a signature for a given message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` A slice that holds a message to be signed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bee_signing::ed25519::{seed::Seed, Ed25519PrivateKeyGenerator, PrivateKeyGenerator};
+    /// use bee_signing::ed25519::PrivateKey;
+    ///
+    /// # let seed = Seed::from_bytes(&[0x2a; 32]).unwrap();
+    /// # let private_key = Ed25519PrivateKeyGenerator::default().generate_from_seed(&seed, 0).unwrap();
+    /// let signature = private_key.sign(b"bee").unwrap();
+    /// ```
+    fn sign(&self, message: &[u8]) -> Result<Self::Signature, Self::Error>;
+
+    /// Creates a private key from bytes.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+
+    /// Interprets the private key as bytes.
+    fn as_bytes(&self) -> &[u8];
+}
+
+/// An Ed25519 public key.
+pub trait PublicKey {
+    /// Matching signature type.
+    type Signature: Signature;
+    /// Errors occuring while handling public keys.
+    type Error;
+
+    /// Verifies a signature for a given message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message`     A slice that holds a message to verify a signature for.
+    /// * `signature`   The signature to verify.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bee_signing::ed25519::{seed::Seed, Ed25519PrivateKeyGenerator, PrivateKey, PrivateKeyGenerator};
+    /// use bee_signing::ed25519::PublicKey;
+    ///
+    /// # let seed = Seed::from_bytes(&[0x2a; 32]).unwrap();
+    /// # let private_key = Ed25519PrivateKeyGenerator::default().generate_from_seed(&seed, 0).unwrap();
+    /// # let public_key = private_key.generate_public_key().unwrap();
+    /// # let signature = private_key.sign(b"bee").unwrap();
+    /// let valid = public_key.verify(b"bee", &signature).unwrap();
+    /// ```
+    fn verify(&self, message: &[u8], signature: &Self::Signature) -> Result<bool, Self::Error>;
+
+    /// Returns the size of the public key.
+    fn size(&self) -> usize;
+
+    /// Creates a public key from bytes.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+
+    /// Interprets the public key as bytes.
+    fn as_bytes(&self) -> &[u8];
+}
+
+/// An Ed25519 signature.
+pub trait Signature {
+    /// Errors occuring while handling signatures.
+    type Error;
+
+    /// Returns the size of the signature.
+    fn size(&self) -> usize;
+
+    /// Creates a signature from bytes.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+
+    /// Interprets the signature as bytes.
+    fn as_bytes(&self) -> &[u8];
+}
@@ -0,0 +1,58 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Binary seed to derive Ed25519 private keys, public keys and signatures from.
+
+use bee_common_derive::{SecretDebug, SecretDisplay, SecretDrop};
+use rand::RngCore;
+use thiserror::Error;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Length of a `Seed`, in bytes.
+pub const SEED_LENGTH: usize = 32;
+
+/// Errors occuring when handling a `Seed`.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// Invalid seed length.
+    #[error("Invalid seed length, should be {SEED_LENGTH} bytes, was {0}.")]
+    InvalidLength(usize),
+}
+
+/// Binary `Seed` to derive Ed25519 private keys, public keys and signatures from.
+#[derive(SecretDebug, SecretDisplay, SecretDrop)]
+pub struct Seed([u8; SEED_LENGTH]);
+
+impl Zeroize for Seed {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Seed {
+    /// Creates a new random `Seed`.
+    pub fn rand() -> Self {
+        // `ThreadRng` implements `CryptoRng` so it is safe to use in cryptographic contexts.
+        // https://rust-random.github.io/rand/rand/trait.CryptoRng.html
+        let mut seed = [0; SEED_LENGTH];
+
+        rand::thread_rng().fill_bytes(&mut seed);
+
+        Self(seed)
+    }
+
+    /// Creates a `Seed` from bytes, e.g. read from an external key store.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(bytes.try_into().map_err(|_| Error::InvalidLength(bytes.len()))?))
+    }
+
+    /// Returns the inner bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns a copy of the inner bytes, zeroized on drop like the `Seed` itself.
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.0.to_vec())
+    }
+}
@@ -12,5 +12,10 @@
     clippy::cast_possible_wrap
 )]
 
+mod constant_time;
+
+pub mod ed25519;
 #[deprecated(note = "`bee-signing` will no longer be supported.")]
 pub mod ternary;
+
+pub use self::constant_time::constant_time_eq;
@@ -12,5 +12,5 @@ pub mod wots;
 
 pub use self::{
     constants::SIGNATURE_FRAGMENT_LENGTH,
-    scheme::{PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature, Signature},
+    scheme::{PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature, Signature, TrytesError},
 };
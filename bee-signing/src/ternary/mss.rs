@@ -11,8 +11,12 @@ use bee_ternary::{T1B1Buf, TritBuf, Trits, T1B1};
 use thiserror::Error;
 use zeroize::Zeroize;
 
-use crate::ternary::{
-    seed::Seed, PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature, Signature, SIGNATURE_FRAGMENT_LENGTH,
+use crate::{
+    constant_time_eq,
+    ternary::{
+        seed::Seed, PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature, Signature,
+        SIGNATURE_FRAGMENT_LENGTH,
+    },
 };
 
 const MAX_MSS_DEPTH: u8 = 20;
@@ -334,7 +338,7 @@ where
             j <<= 1;
         }
 
-        Ok(hash == self.state)
+        Ok(constant_time_eq(&hash, &self.state))
     }
 
     fn size(&self) -> usize {
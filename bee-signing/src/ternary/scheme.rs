@@ -1,11 +1,31 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_ternary::{T1B1Buf, TritBuf, Trits, T1B1};
+use bee_ternary::{T1B1Buf, TritBuf, Trits, TryteBuf, T1B1};
+use thiserror::Error;
 use zeroize::Zeroize;
 
 use crate::ternary::seed::Seed;
 
+/// Errors occuring when decoding a public key or a signature from trytes.
+#[derive(Debug, Error, PartialEq)]
+pub enum TrytesError<E> {
+    /// Invalid trytes.
+    #[error("Invalid trytes.")]
+    InvalidTrytes,
+    /// Trits that are not a valid public key or signature.
+    #[error("Invalid trits: {0}")]
+    InvalidTrits(E),
+}
+
+// Decodes trytes to trits, used by the default `from_trytes` implementations.
+fn trytes_to_trits<E>(trytes: &str) -> Result<TritBuf<T1B1Buf>, TrytesError<E>> {
+    Ok(TryteBuf::try_from_str(trytes)
+        .map_err(|_| TrytesError::InvalidTrytes)?
+        .as_trits()
+        .encode::<T1B1Buf>())
+}
+
 /// Generates a ternary private key.
 pub trait PrivateKeyGenerator {
     /// Generated private keys type.
@@ -139,6 +159,18 @@ pub trait PrivateKey: Zeroize {
     /// let signature = private_key.sign(&message_trits).unwrap();
     /// ```
     fn sign(&mut self, message: &Trits<T1B1>) -> Result<Self::Signature, Self::Error>;
+
+    /// Generates and returns signatures for a batch of messages, in order.
+    ///
+    /// The default implementation signs the messages one after the other. Schemes forbidding key reuse, like one-time
+    /// signature schemes, must override it to reject batches of more than one message.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages`    A slice of messages to be signed.
+    fn sign_batch(&mut self, messages: &[&Trits<T1B1>]) -> Result<Vec<Self::Signature>, Self::Error> {
+        messages.iter().map(|message| self.sign(message)).collect()
+    }
 }
 
 /// A ternary public key.
@@ -150,6 +182,9 @@ pub trait PublicKey {
 
     /// Verifies a signature for a given message.
     ///
+    /// The recovered key material is compared to the public key in constant time, see [`crate::constant_time_eq`], so
+    /// that the time taken does not leak how close a forged signature is to a valid one.
+    ///
     /// # Arguments
     ///
     /// * `message`     A slice that holds a message to verify a signature for.
@@ -196,6 +231,23 @@ pub trait PublicKey {
 
     /// Interprets the public key as trits.
     fn as_trits(&self) -> &Trits<T1B1>;
+
+    /// Creates a public key from trytes.
+    fn from_trytes(trytes: &str) -> Result<Self, TrytesError<Self::Error>>
+    where
+        Self: Sized,
+    {
+        Self::from_trits(trytes_to_trits(trytes)?).map_err(TrytesError::InvalidTrits)
+    }
+
+    /// Encodes the public key as trytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the public key is not a multiple of 3 trits.
+    fn to_trytes(&self) -> String {
+        self.as_trits().iter_trytes().map(char::from).collect()
+    }
 }
 
 /// A ternary signature.
@@ -213,6 +265,23 @@ pub trait Signature {
 
     /// Interprets the signature as trits.
     fn as_trits(&self) -> &Trits<T1B1>;
+
+    /// Creates a signature from trytes.
+    fn from_trytes(trytes: &str) -> Result<Self, TrytesError<Self::Error>>
+    where
+        Self: Sized,
+    {
+        Self::from_trits(trytes_to_trits(trytes)?).map_err(TrytesError::InvalidTrits)
+    }
+
+    /// Encodes the signature as trytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the signature is not a multiple of 3 trits.
+    fn to_trytes(&self) -> String {
+        self.as_trits().iter_trytes().map(char::from).collect()
+    }
 }
 
 /// A ternary signature from which a public key can be recovered.
@@ -224,6 +293,9 @@ pub trait RecoverableSignature: Signature {
 
     /// Recovers a public key from a signature.
     ///
+    /// The intermediate buffers holding partially recovered key material are zeroized before returning, whether the
+    /// recovery succeeds or not.
+    ///
     /// # Arguments
     ///
     /// * `message` A slice that holds a message to recover the public key from.
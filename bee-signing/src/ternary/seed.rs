@@ -10,10 +10,13 @@ use bee_crypto::ternary::{
     sponge::{Kerl, Sponge},
     HASH_LENGTH,
 };
-use bee_ternary::{Btrit, T1B1Buf, Trit, TritBuf, Trits, TryteBuf, T1B1};
+use bee_ternary::{Btrit, T1B1Buf, T5B1Buf, Trit, TritBuf, Trits, TryteBuf, T1B1, T5B1};
 use rand::distributions::{Distribution, Uniform};
 use thiserror::Error;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Length of a `Seed` packed into bytes, 5 trits per byte.
+pub const SEED_BYTES_LENGTH: usize = HASH_LENGTH.div_ceil(5);
 
 /// Errors occuring when handling a `Seed`.
 #[derive(Debug, Error, PartialEq)]
@@ -24,6 +27,12 @@ pub enum Error {
     /// Invalid seed trytes.
     #[error("Invalid seed trytes.")]
     InvalidTrytes,
+    /// Invalid seed bytes length.
+    #[error("Invalid seed bytes length, should be {SEED_BYTES_LENGTH} bytes, was {0}.")]
+    InvalidBytesLength(usize),
+    /// Invalid seed bytes.
+    #[error("Invalid seed bytes.")]
+    InvalidBytes,
     /// Failed sponge operation.
     #[error("Failed sponge operation.")]
     FailedSpongeOperation,
@@ -57,9 +66,17 @@ impl Seed {
         Self(<&Trits>::from(&seed as &[_]).to_buf())
     }
 
-    /// Creates a new `Seed` from the current `Seed` and an index.
+    /// Creates a new `Seed` from the current `Seed` and an index, hashing with `Kerl`.
     #[must_use]
     pub fn subseed(&self, index: usize) -> Self {
+        self.subseed_with::<Kerl>(index)
+    }
+
+    /// Creates a new `Seed` from the current `Seed` and an index, hashing with the given sponge.
+    ///
+    /// Deriving subseeds with another sponge than `Kerl` provides compatibility with legacy tools.
+    #[must_use]
+    pub fn subseed_with<S: Sponge + Default>(&self, index: usize) -> Self {
         let mut subseed = self.0.clone();
 
         for _ in 0..index {
@@ -74,7 +91,7 @@ impl Seed {
         }
 
         // Safe to unwrap since the size is known to be valid.
-        Self(Kerl::default().digest(&subseed).unwrap())
+        Self(S::default().digest(&subseed).unwrap_or_else(|_| unreachable!()))
     }
 
     /// Creates a `Seed` from trits.
@@ -90,6 +107,42 @@ impl Seed {
     pub fn as_trits(&self) -> &Trits<T1B1> {
         &self.0
     }
+
+    /// Creates a `Seed` from its trits packed into bytes, 5 trits per byte, as returned by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != SEED_BYTES_LENGTH {
+            return Err(Error::InvalidBytesLength(bytes.len()));
+        }
+
+        let mut raw = [0i8; SEED_BYTES_LENGTH];
+
+        for (raw, byte) in raw.iter_mut().zip(bytes) {
+            *raw = i8::from_le_bytes([*byte]);
+        }
+
+        let seed = Trits::<T5B1>::try_from_raw(&raw, HASH_LENGTH)
+            .map(|trits| Self(trits.encode::<T1B1Buf>()))
+            .map_err(|_| Error::InvalidBytes);
+
+        raw.zeroize();
+
+        seed
+    }
+
+    /// Returns the inner trits packed into bytes, 5 trits per byte, zeroized on drop like the `Seed` itself.
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut packed = self.0.encode::<T5B1Buf>();
+        let bytes = packed
+            .as_i8_slice()
+            .iter()
+            .map(|trits| trits.to_le_bytes()[0])
+            .collect();
+
+        // This unsafe is fine since we only reset the whole buffer with zeros, there is no alignement issues.
+        unsafe { packed.as_i8_slice_mut().zeroize() }
+
+        Zeroizing::new(bytes)
+    }
 }
 
 impl FromStr for Seed {
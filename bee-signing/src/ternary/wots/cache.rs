@@ -0,0 +1,71 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Memoization of the public keys recovered from WOTS signatures.
+//!
+//! Validating milestones verifies the same signatures repeatedly, the recovered public keys are therefore kept in a
+//! bounded LRU cache keyed by a hash of the sponge, the signature and the message.
+
+use std::{any::type_name, sync::Mutex};
+
+use bee_ternary::{T1B1Buf, TritBuf, Trits, T1B1};
+use lru::LruCache;
+use once_cell::sync::Lazy;
+use sha3::{Digest, Sha3_256};
+
+/// Maximum number of recovered public keys kept in the cache.
+pub const CACHE_CAPACITY: usize = 1024;
+
+type Key = [u8; 32];
+
+static CACHE: Lazy<Mutex<LruCache<Key, TritBuf<T1B1Buf>>>> = Lazy::new(|| Mutex::new(LruCache::new(CACHE_CAPACITY)));
+
+/// Computes the cache key of the public key recovered by sponge `S` from `signature` and `message`.
+pub(crate) fn key<S>(signature: &Trits<T1B1>, message: &Trits<T1B1>) -> Key {
+    let mut hasher = Sha3_256::new();
+
+    hasher.update(type_name::<S>());
+    for trits in [signature, message] {
+        hasher.update((trits.len() as u64).to_le_bytes());
+        hasher.update(
+            trits
+                .as_i8_slice()
+                .iter()
+                .map(|trit| trit.to_le_bytes()[0])
+                .collect::<Vec<u8>>(),
+        );
+    }
+
+    hasher.finalize().into()
+}
+
+/// Returns the cached public key state of `key`, if any.
+pub(crate) fn get(key: &Key) -> Option<TritBuf<T1B1Buf>> {
+    // Panic: the lock is never poisoned as its holders don't panic.
+    CACHE.lock().unwrap().get(key).cloned()
+}
+
+/// Caches the public key state of `key`, evicting the least recently used one if the cache is full.
+pub(crate) fn insert(key: Key, state: TritBuf<T1B1Buf>) {
+    // Panic: the lock is never poisoned as its holders don't panic.
+    CACHE.lock().unwrap().put(key, state);
+}
+
+/// Returns whether the public key recovered by sponge `S` from `signature` and `message` is cached, without marking it
+/// as recently used.
+pub fn contains<S>(signature: &Trits<T1B1>, message: &Trits<T1B1>) -> bool {
+    // Panic: the lock is never poisoned as its holders don't panic.
+    CACHE.lock().unwrap().contains(&key::<S>(signature, message))
+}
+
+/// Removes all the cached public keys.
+pub fn clear() {
+    // Panic: the lock is never poisoned as its holders don't panic.
+    CACHE.lock().unwrap().clear();
+}
+
+/// Returns the number of cached public keys.
+pub fn len() -> usize {
+    // Panic: the lock is never poisoned as its holders don't panic.
+    CACHE.lock().unwrap().len()
+}
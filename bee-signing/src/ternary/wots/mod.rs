@@ -4,6 +4,8 @@
 //! Winternitz One Time Signature scheme.
 //! <https://eprint.iacr.org/2011/191.pdf>.
 
+#[cfg(feature = "wots-cache")]
+pub mod cache;
 mod normalize;
 mod shake;
 mod sponge;
@@ -14,7 +16,10 @@ use std::{
 };
 
 use bee_common_derive::{SecretDebug, SecretDisplay, SecretDrop};
-use bee_crypto::ternary::{sponge::Sponge, HASH_LENGTH};
+use bee_crypto::ternary::{
+    sponge::{CurlP27, CurlP81, Kerl, Sponge, SpongeKind, UnrolledCurlP81},
+    HASH_LENGTH,
+};
 use bee_ternary::{T1B1Buf, TritBuf, Trits, Tryte, T1B1};
 use thiserror::Error;
 use zeroize::Zeroize;
@@ -24,7 +29,10 @@ pub use self::{
     shake::{WotsShakePrivateKeyGenerator, WotsShakePrivateKeyGeneratorBuilder},
     sponge::{WotsSpongePrivateKeyGenerator, WotsSpongePrivateKeyGeneratorBuilder},
 };
-use crate::ternary::{PrivateKey, PublicKey, RecoverableSignature, Signature, SIGNATURE_FRAGMENT_LENGTH};
+use crate::{
+    constant_time_eq,
+    ternary::{seed::Seed, PrivateKey, PublicKey, RecoverableSignature, Signature, SIGNATURE_FRAGMENT_LENGTH},
+};
 
 /// Errors occuring during WOTS operations.
 #[derive(Debug, Error, PartialEq)]
@@ -50,10 +58,16 @@ pub enum Error {
     /// Last trit of the entropy is not null.
     #[error("Last trit of the entropy is not null.")]
     NonNullEntropyLastTrit,
+    /// Forbidden key reuse, a WOTS private key can only sign a single message.
+    #[error("Forbidden key reuse, a WOTS private key can only sign a single message, was given {0}.")]
+    ForbiddenKeyReuse(usize),
 }
 
 /// Available WOTS security levels.
-#[derive(Clone, Copy)]
+///
+/// With the `serde` feature, a security level is serialized as its name and deserialized from either its name or its
+/// number, e.g. `"medium"` or `2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum WotsSecurityLevel {
     /// Low security.
@@ -70,6 +84,75 @@ impl Default for WotsSecurityLevel {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for WotsSecurityLevel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            WotsSecurityLevel::Low => "low",
+            WotsSecurityLevel::Medium => "medium",
+            WotsSecurityLevel::High => "high",
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for WotsSecurityLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected, Visitor};
+
+        struct SecurityLevelVisitor;
+
+        impl<'de> Visitor<'de> for SecurityLevelVisitor {
+            type Value = WotsSecurityLevel;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "a WOTS security level, either low, medium, high or 1, 2, 3")
+            }
+
+            fn visit_str<E: Error>(self, name: &str) -> Result<Self::Value, E> {
+                if name.eq_ignore_ascii_case("low") {
+                    Ok(WotsSecurityLevel::Low)
+                } else if name.eq_ignore_ascii_case("medium") {
+                    Ok(WotsSecurityLevel::Medium)
+                } else if name.eq_ignore_ascii_case("high") {
+                    Ok(WotsSecurityLevel::High)
+                } else {
+                    Err(E::invalid_value(Unexpected::Str(name), &self))
+                }
+            }
+
+            fn visit_u64<E: Error>(self, level: u64) -> Result<Self::Value, E> {
+                match level {
+                    1 => Ok(WotsSecurityLevel::Low),
+                    2 => Ok(WotsSecurityLevel::Medium),
+                    3 => Ok(WotsSecurityLevel::High),
+                    _ => Err(E::invalid_value(Unexpected::Unsigned(level), &self)),
+                }
+            }
+
+            // Some formats, e.g. TOML, only have signed integers.
+            fn visit_i64<E: Error>(self, level: i64) -> Result<Self::Value, E> {
+                match u64::try_from(level) {
+                    Ok(level) => self.visit_u64(level),
+                    Err(_) => Err(E::invalid_value(Unexpected::Signed(level), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(SecurityLevelVisitor)
+    }
+}
+
+// Derives the subseed of a seed at a given index, hashing with the given sponge.
+fn subseed(seed: &Seed, index: usize, sponge: SpongeKind) -> Seed {
+    match sponge {
+        SpongeKind::Kerl => seed.subseed_with::<Kerl>(index),
+        SpongeKind::CurlP27 => seed.subseed_with::<CurlP27>(index),
+        SpongeKind::CurlP81 => seed.subseed_with::<CurlP81>(index),
+        SpongeKind::UnrolledCurlP81 => seed.subseed_with::<UnrolledCurlP81>(index),
+    }
+}
+
 /// Winternitz One Time Signature private key.
 #[derive(SecretDebug, SecretDisplay, SecretDrop)]
 pub struct WotsPrivateKey<S> {
@@ -77,10 +160,15 @@ pub struct WotsPrivateKey<S> {
     pub(crate) marker: PhantomData<S>,
 }
 
+// Resets a trit buffer to zeros.
+fn zeroize_trits(buf: &mut Trits<T1B1>) {
+    // This unsafe is fine since we only reset the whole buffer with zeros, there is no alignement issues.
+    unsafe { buf.as_i8_slice_mut().zeroize() }
+}
+
 impl<S> Zeroize for WotsPrivateKey<S> {
     fn zeroize(&mut self) {
-        // This unsafe is fine since we only reset the whole buffer with zeros, there is no alignement issues.
-        unsafe { self.state.as_i8_slice_mut().zeroize() }
+        zeroize_trits(&mut self.state)
     }
 }
 
@@ -152,6 +240,16 @@ impl<S: Sponge + Default> PrivateKey for WotsPrivateKey<S> {
             marker: PhantomData,
         })
     }
+
+    /// WOTS is a one-time signature scheme: each signature reveals part of the private key and signing more than one
+    /// message with the same key weakens its security. Batches of more than one message are therefore rejected.
+    fn sign_batch(&mut self, messages: &[&Trits<T1B1>]) -> Result<Vec<Self::Signature>, Self::Error> {
+        if messages.len() > 1 {
+            return Err(Error::ForbiddenKeyReuse(messages.len()));
+        }
+
+        messages.iter().map(|message| self.sign(message)).collect()
+    }
 }
 
 impl<S: Sponge + Default> WotsPrivateKey<S> {
@@ -172,7 +270,9 @@ impl<S: Sponge + Default> PublicKey for WotsPublicKey<S> {
     type Error = Error;
 
     fn verify(&self, message: &Trits<T1B1>, signature: &Self::Signature) -> Result<bool, Self::Error> {
-        Ok(signature.recover_public_key(message)?.state == self.state)
+        let public_key = signature.recover_public_key(message)?;
+
+        Ok(constant_time_eq(&public_key.state, &self.state))
     }
 
     fn size(&self) -> usize {
@@ -197,11 +297,7 @@ impl<S: Sponge + Default> PublicKey for WotsPublicKey<S> {
 
 impl<S: Sponge + Default> Display for WotsPublicKey<S> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.as_trits().iter_trytes().map(char::from).collect::<String>()
-        )
+        write!(f, "{}", self.to_trytes())
     }
 }
 
@@ -234,23 +330,16 @@ impl<S: Sponge + Default> Signature for WotsSignature<S> {
     }
 }
 
-impl<S: Sponge + Default> RecoverableSignature for WotsSignature<S> {
-    type PublicKey = WotsPublicKey<S>;
-    type Error = Error;
-
-    fn recover_public_key(
+impl<S: Sponge + Default> WotsSignature<S> {
+    // Hashes the signature chains and fragments into the given buffers to recover the public key.
+    fn recover_public_key_into(
         &self,
         message: &Trits<T1B1>,
-    ) -> Result<Self::PublicKey, <Self as RecoverableSignature>::Error> {
-        if message.len() != HASH_LENGTH {
-            return Err(Error::InvalidMessageLength(message.len()));
-        }
-
+        hashed_signature: &mut Trits<T1B1>,
+        digests: &mut Trits<T1B1>,
+        public_key_state: &mut Trits<T1B1>,
+    ) -> Result<(), Error> {
         let mut sponge = S::default();
-        let mut public_key_state = TritBuf::<T1B1Buf>::zeros(HASH_LENGTH);
-        let security = self.state.len() / SIGNATURE_FRAGMENT_LENGTH;
-        let mut digests = TritBuf::<T1B1Buf>::zeros(security * HASH_LENGTH);
-        let mut hashed_signature = self.state.clone();
 
         for (i, chunk) in hashed_signature.chunks_mut(HASH_LENGTH).enumerate() {
             // Safe to unwrap because 3 trits can't underflow/overflow an i8.
@@ -261,7 +350,7 @@ impl<S: Sponge + Default> RecoverableSignature for WotsSignature<S> {
                 sponge
                     .absorb(chunk)
                     .and_then(|_| sponge.squeeze_into(chunk))
-                    .map_err(|_| <Self as RecoverableSignature>::Error::FailedSpongeOperation)?;
+                    .map_err(|_| Error::FailedSpongeOperation)?;
                 sponge.reset();
             }
         }
@@ -270,13 +359,57 @@ impl<S: Sponge + Default> RecoverableSignature for WotsSignature<S> {
         for (i, chunk) in hashed_signature.chunks(SIGNATURE_FRAGMENT_LENGTH).enumerate() {
             sponge
                 .digest_into(chunk, &mut digests[i * HASH_LENGTH..(i + 1) * HASH_LENGTH])
-                .map_err(|_| <Self as RecoverableSignature>::Error::FailedSpongeOperation)?;
+                .map_err(|_| Error::FailedSpongeOperation)?;
         }
 
         // Hash the digests together to recover the public key.
         sponge
-            .digest_into(&digests, &mut public_key_state)
-            .map_err(|_| <Self as RecoverableSignature>::Error::FailedSpongeOperation)?;
+            .digest_into(digests, public_key_state)
+            .map_err(|_| Error::FailedSpongeOperation)
+    }
+}
+
+impl<S: Sponge + Default> RecoverableSignature for WotsSignature<S> {
+    type PublicKey = WotsPublicKey<S>;
+    type Error = Error;
+
+    fn recover_public_key(
+        &self,
+        message: &Trits<T1B1>,
+    ) -> Result<Self::PublicKey, <Self as RecoverableSignature>::Error> {
+        if message.len() != HASH_LENGTH {
+            return Err(Error::InvalidMessageLength(message.len()));
+        }
+
+        #[cfg(feature = "wots-cache")]
+        let key = cache::key::<S>(&self.state, message);
+
+        #[cfg(feature = "wots-cache")]
+        if let Some(state) = cache::get(&key) {
+            return Ok(Self::PublicKey {
+                state,
+                marker: PhantomData,
+            });
+        }
+
+        let mut public_key_state = TritBuf::<T1B1Buf>::zeros(HASH_LENGTH);
+        let security = self.state.len() / SIGNATURE_FRAGMENT_LENGTH;
+        let mut digests = TritBuf::<T1B1Buf>::zeros(security * HASH_LENGTH);
+        let mut hashed_signature = self.state.clone();
+
+        let res = self.recover_public_key_into(message, &mut hashed_signature, &mut digests, &mut public_key_state);
+
+        // The intermediate buffers hold partially recovered key material, they are cleared whatever the outcome.
+        zeroize_trits(&mut hashed_signature);
+        zeroize_trits(&mut digests);
+
+        if let Err(e) = res {
+            zeroize_trits(&mut public_key_state);
+            return Err(e);
+        }
+
+        #[cfg(feature = "wots-cache")]
+        cache::insert(key, public_key_state.clone());
 
         Ok(Self::PublicKey {
             state: public_key_state,
@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 use bee_crypto::ternary::{
     bigint::{binary_representation::U8Repr, endianness::BigEndian, I384, T242, T243},
-    sponge::Sponge,
+    sponge::{Sponge, SpongeKind},
     HASH_LENGTH,
 };
 use bee_ternary::{Btrit, T1B1Buf, TritBuf, Trits, T1B1};
@@ -15,7 +15,8 @@ use sha3::{
 };
 
 use crate::ternary::{
-    wots::{Error as WotsError, WotsPrivateKey, WotsSecurityLevel},
+    seed::Seed,
+    wots::{subseed, Error as WotsError, WotsPrivateKey, WotsSecurityLevel},
     PrivateKeyGenerator, SIGNATURE_FRAGMENT_LENGTH,
 };
 
@@ -24,6 +25,7 @@ use crate::ternary::{
 #[must_use]
 pub struct WotsShakePrivateKeyGeneratorBuilder<S> {
     security_level: Option<WotsSecurityLevel>,
+    subseed_sponge: Option<SpongeKind>,
     marker: PhantomData<S>,
 }
 
@@ -34,10 +36,19 @@ impl<S: Sponge + Default> WotsShakePrivateKeyGeneratorBuilder<S> {
         self
     }
 
+    /// Sets the sponge subseeds are derived with when generating private keys from a seed, `Kerl` by default.
+    ///
+    /// The private key generation constraints on the entropy still apply to subseeds derived with another sponge.
+    pub fn with_subseed_sponge(mut self, subseed_sponge: SpongeKind) -> Self {
+        self.subseed_sponge.replace(subseed_sponge);
+        self
+    }
+
     /// Builds the private key generator.
     pub fn build(self) -> Result<WotsShakePrivateKeyGenerator<S>, WotsError> {
         Ok(WotsShakePrivateKeyGenerator {
             security_level: self.security_level.ok_or(WotsError::MissingSecurityLevel)?,
+            subseed_sponge: self.subseed_sponge.unwrap_or(SpongeKind::Kerl),
             marker: PhantomData,
         })
     }
@@ -46,6 +57,7 @@ impl<S: Sponge + Default> WotsShakePrivateKeyGeneratorBuilder<S> {
 /// Shake-based Winternitz One Time Signature private key generator.
 pub struct WotsShakePrivateKeyGenerator<S> {
     security_level: WotsSecurityLevel,
+    subseed_sponge: SpongeKind,
     marker: PhantomData<S>,
 }
 
@@ -53,6 +65,10 @@ impl<S: Sponge + Default> PrivateKeyGenerator for WotsShakePrivateKeyGenerator<S
     type PrivateKey = WotsPrivateKey<S>;
     type Error = WotsError;
 
+    fn generate_from_seed(&self, seed: &Seed, index: usize) -> Result<Self::PrivateKey, Self::Error> {
+        self.generate_from_entropy(subseed(seed, index, self.subseed_sponge).as_trits())
+    }
+
     /// Derives a private key from entropy using the SHAKE256 extendable-output function.
     /// The entropy must be a slice of exactly 243 trits where the last trit is zero.
     /// Derives its security assumptions from the properties of the underlying SHAKE function.
@@ -3,11 +3,15 @@
 
 use std::marker::PhantomData;
 
-use bee_crypto::ternary::{sponge::Sponge, HASH_LENGTH};
+use bee_crypto::ternary::{
+    sponge::{Sponge, SpongeKind},
+    HASH_LENGTH,
+};
 use bee_ternary::{Btrit, T1B1Buf, TritBuf, Trits, T1B1};
 
 use crate::ternary::{
-    wots::{Error as WotsError, WotsPrivateKey, WotsSecurityLevel},
+    seed::Seed,
+    wots::{subseed, Error as WotsError, WotsPrivateKey, WotsSecurityLevel},
     PrivateKeyGenerator, SIGNATURE_FRAGMENT_LENGTH,
 };
 
@@ -16,6 +20,7 @@ use crate::ternary::{
 #[must_use]
 pub struct WotsSpongePrivateKeyGeneratorBuilder<S> {
     security_level: Option<WotsSecurityLevel>,
+    subseed_sponge: Option<SpongeKind>,
     marker: PhantomData<S>,
 }
 
@@ -26,10 +31,19 @@ impl<S: Sponge + Default> WotsSpongePrivateKeyGeneratorBuilder<S> {
         self
     }
 
+    /// Sets the sponge subseeds are derived with when generating private keys from a seed, `Kerl` by default.
+    ///
+    /// The private key generation constraints on the entropy still apply to subseeds derived with another sponge.
+    pub fn with_subseed_sponge(mut self, subseed_sponge: SpongeKind) -> Self {
+        self.subseed_sponge.replace(subseed_sponge);
+        self
+    }
+
     /// Builds the private key generator.
     pub fn build(self) -> Result<WotsSpongePrivateKeyGenerator<S>, WotsError> {
         Ok(WotsSpongePrivateKeyGenerator {
             security_level: self.security_level.ok_or(WotsError::MissingSecurityLevel)?,
+            subseed_sponge: self.subseed_sponge.unwrap_or(SpongeKind::Kerl),
             marker: PhantomData,
         })
     }
@@ -38,6 +52,7 @@ impl<S: Sponge + Default> WotsSpongePrivateKeyGeneratorBuilder<S> {
 /// Sponge-based Winternitz One Time Signature private key generator.
 pub struct WotsSpongePrivateKeyGenerator<S> {
     security_level: WotsSecurityLevel,
+    subseed_sponge: SpongeKind,
     marker: PhantomData<S>,
 }
 
@@ -45,6 +60,10 @@ impl<S: Sponge + Default> PrivateKeyGenerator for WotsSpongePrivateKeyGenerator<
     type PrivateKey = WotsPrivateKey<S>;
     type Error = WotsError;
 
+    fn generate_from_seed(&self, seed: &Seed, index: usize) -> Result<Self::PrivateKey, Self::Error> {
+        self.generate_from_entropy(subseed(seed, index, self.subseed_sponge).as_trits())
+    }
+
     /// Derives a private key from entropy using the provided ternary sponge construction.
     /// The entropy must be a slice of exactly 243 trits where the last trit is zero.
     //
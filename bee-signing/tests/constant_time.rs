@@ -0,0 +1,22 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_signing::constant_time_eq;
+use bee_ternary::{T1B1Buf, TryteBuf};
+
+#[test]
+fn constant_time_eq_trits() {
+    let a = TryteBuf::try_from_str("CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD")
+        .unwrap()
+        .as_trits()
+        .encode::<T1B1Buf>();
+    let b = TryteBuf::try_from_str("CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDE")
+        .unwrap()
+        .as_trits()
+        .encode::<T1B1Buf>();
+
+    assert!(constant_time_eq(&a, &a));
+    assert!(!constant_time_eq(&a, &b));
+    assert!(!constant_time_eq(&a, &a[..a.len() - 1]));
+    assert!(constant_time_eq(&a[..0], &b[..0]));
+}
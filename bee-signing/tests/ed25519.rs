@@ -0,0 +1,182 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_signing::ed25519::{
+    seed::{Error as SeedError, Seed},
+    verify_multisig, Ed25519PrivateKey, Ed25519PrivateKeyGenerator, Ed25519PublicKey, Ed25519Signature, Error,
+    PrivateKey, PrivateKeyGenerator, PublicKey, Signature,
+};
+
+fn seed() -> Seed {
+    Seed::from_bytes(&[0x2a; 32]).unwrap()
+}
+
+#[test]
+fn invalid_seed_length() {
+    assert_eq!(Seed::from_bytes(&[0; 31]).err(), Some(SeedError::InvalidLength(31)));
+}
+
+#[test]
+fn seed_bytes_round_trip() {
+    let seed_1 = Seed::rand();
+    let seed_2 = Seed::from_bytes(&seed_1.to_bytes()).unwrap();
+
+    assert_eq!(seed_1.as_bytes(), seed_2.as_bytes());
+}
+
+#[test]
+fn deterministic_derivation() {
+    let generator = Ed25519PrivateKeyGenerator::default();
+
+    let private_key_1 = generator.generate_from_seed(&seed(), 0).unwrap();
+    let private_key_2 = generator.generate_from_seed(&seed(), 0).unwrap();
+    let private_key_3 = generator.generate_from_seed(&seed(), 1).unwrap();
+    let private_key_4 = Ed25519PrivateKeyGenerator::default()
+        .with_account(1)
+        .generate_from_seed(&seed(), 0)
+        .unwrap();
+
+    assert_eq!(private_key_1.as_bytes(), private_key_2.as_bytes());
+    assert_ne!(private_key_1.as_bytes(), private_key_3.as_bytes());
+    assert_ne!(private_key_1.as_bytes(), private_key_4.as_bytes());
+}
+
+#[test]
+fn invalid_index() {
+    let generator = Ed25519PrivateKeyGenerator::default();
+
+    assert_eq!(
+        generator.generate_from_seed(&seed(), 1 << 31).err(),
+        Some(Error::InvalidIndex(1 << 31))
+    );
+}
+
+#[test]
+fn sign_verify() {
+    let private_key = Ed25519PrivateKeyGenerator::default()
+        .generate_from_seed(&seed(), 0)
+        .unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+    let signature = private_key.sign(b"bee").unwrap();
+
+    assert!(public_key.verify(b"bee", &signature).unwrap());
+    assert!(!public_key.verify(b"wasp", &signature).unwrap());
+
+    let other_public_key = Ed25519PrivateKeyGenerator::default()
+        .generate_from_seed(&seed(), 1)
+        .unwrap()
+        .generate_public_key()
+        .unwrap();
+
+    assert!(!other_public_key.verify(b"bee", &signature).unwrap());
+}
+
+#[test]
+fn bytes_round_trip() {
+    let private_key = Ed25519PrivateKeyGenerator::default()
+        .generate_from_seed(&seed(), 0)
+        .unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+    let signature = private_key.sign(b"bee").unwrap();
+
+    let private_key = Ed25519PrivateKey::from_bytes(private_key.as_bytes()).unwrap();
+    let public_key_from_bytes = Ed25519PublicKey::from_bytes(public_key.as_bytes()).unwrap();
+    let signature_from_bytes = Ed25519Signature::from_bytes(signature.as_bytes()).unwrap();
+
+    assert_eq!(private_key.generate_public_key().unwrap(), public_key);
+    assert_eq!(public_key_from_bytes, public_key);
+    assert_eq!(signature_from_bytes, signature);
+    assert_eq!(public_key.size(), public_key.as_bytes().len());
+    assert_eq!(signature.size(), signature.as_bytes().len());
+    assert!(public_key_from_bytes.verify(b"bee", &signature_from_bytes).unwrap());
+}
+
+#[test]
+fn invalid_lengths() {
+    assert_eq!(
+        Ed25519PrivateKey::from_bytes(&[0; 31]).err(),
+        Some(Error::InvalidPrivateKeyLength(31))
+    );
+    assert_eq!(
+        Ed25519PublicKey::from_bytes(&[0; 33]).err(),
+        Some(Error::InvalidPublicKeyLength(33))
+    );
+    assert_eq!(
+        Ed25519Signature::from_bytes(&[0; 63]).err(),
+        Some(Error::InvalidSignatureLength(63))
+    );
+}
+
+fn multisig(message: &[u8], indexes: &[u32]) -> (Vec<Ed25519Signature>, Vec<Ed25519PublicKey>) {
+    indexes
+        .iter()
+        .map(|index| {
+            let private_key = Ed25519PrivateKeyGenerator::default()
+                .generate_from_seed(&seed(), *index)
+                .unwrap();
+
+            (
+                private_key.sign(message).unwrap(),
+                private_key.generate_public_key().unwrap(),
+            )
+        })
+        .unzip()
+}
+
+#[test]
+fn multisig_threshold_met() {
+    let (signatures, public_keys) = multisig(b"milestone", &[0, 1, 2]);
+
+    assert!(verify_multisig(b"milestone", &signatures, &public_keys, 2).unwrap());
+    assert!(verify_multisig(b"milestone", &signatures, &public_keys, 3).unwrap());
+}
+
+#[test]
+fn multisig_threshold_not_met() {
+    let (mut signatures, public_keys) = multisig(b"milestone", &[0, 1, 2]);
+
+    assert!(!verify_multisig(b"milestone", &signatures, &public_keys, 4).unwrap());
+    assert!(!verify_multisig(b"other milestone", &signatures, &public_keys, 1).unwrap());
+
+    signatures[2] = signatures[0].clone();
+
+    assert!(verify_multisig(b"milestone", &signatures, &public_keys, 2).unwrap());
+    assert!(!verify_multisig(b"milestone", &signatures, &public_keys, 3).unwrap());
+}
+
+#[test]
+fn multisig_duplicate_signer() {
+    let (signatures, public_keys) = multisig(b"milestone", &[0, 1, 0]);
+
+    assert_eq!(
+        verify_multisig(b"milestone", &signatures, &public_keys, 2).err(),
+        Some(Error::DuplicateSigner)
+    );
+}
+
+#[test]
+fn multisig_count_mismatch() {
+    let (signatures, public_keys) = multisig(b"milestone", &[0, 1]);
+
+    assert_eq!(
+        verify_multisig(b"milestone", &signatures[..1], &public_keys, 1).err(),
+        Some(Error::SignatureCountMismatch {
+            signatures: 1,
+            public_keys: 2
+        })
+    );
+}
+
+#[test]
+fn multisig_zero_threshold() {
+    let (signatures, public_keys) = multisig(b"milestone", &[0, 1]);
+
+    assert_eq!(
+        verify_multisig(b"milestone", &signatures, &public_keys, 0).err(),
+        Some(Error::InvalidThreshold)
+    );
+    assert_eq!(
+        verify_multisig(b"milestone", &[], &[], 0).err(),
+        Some(Error::InvalidThreshold)
+    );
+}
@@ -264,3 +264,50 @@ fn signatures_exhausted() {
         Some(MssError::SignaturesExhausted)
     );
 }
+
+#[test]
+fn sign_batch() {
+    const SEED: &str = "NNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNN";
+    const MESSAGES: [&str; 3] = [
+        "CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD",
+        "KEWPSJHHGOICFXVGNUNRUDSKDUKNWGADKUFOFYVTZVGBVLWGIQBOICNNZIMWAXMV9RRMWSYGIABIBZUZ9",
+        "NNZLXQKRAQBEUKNGVTKAHIIJUGSNNNNCASGGPNBJHKGTH9EGEAJZPKYL9WTNVYHFKDSQYERI9AYUFHYB9",
+    ];
+    const DEPTH: u8 = 2;
+
+    let seed = Seed::from_str(SEED).unwrap();
+    let messages_trits = MESSAGES
+        .iter()
+        .map(|message| TryteBuf::try_from_str(message).unwrap().as_trits().encode::<T1B1Buf>())
+        .collect::<Vec<_>>();
+    let messages = messages_trits
+        .iter()
+        .map(|message| message.as_slice())
+        .collect::<Vec<_>>();
+
+    let wots_private_key_generator = WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+        .with_security_level(WotsSecurityLevel::Low)
+        .build()
+        .unwrap();
+    let private_key_generator = MssPrivateKeyGeneratorBuilder::<Kerl, WotsSpongePrivateKeyGenerator<Kerl>>::default()
+        .with_depth(DEPTH)
+        .with_generator(wots_private_key_generator)
+        .build()
+        .unwrap();
+    let mut private_key = private_key_generator.generate_from_seed(&seed, 0).unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+
+    // MSS allows signing several messages, each with its own leaf.
+    let signatures = private_key.sign_batch(&messages).unwrap();
+
+    assert_eq!(signatures.len(), messages.len());
+    for (message, signature) in messages.iter().zip(signatures.iter()) {
+        assert!(public_key.verify(message, signature).unwrap());
+    }
+
+    // Only a single leaf is left.
+    assert_eq!(
+        private_key.sign_batch(&messages[..2]).err(),
+        Some(MssError::SignaturesExhausted)
+    );
+}
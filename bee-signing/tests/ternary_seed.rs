@@ -5,8 +5,8 @@
 
 use std::str::FromStr;
 
-use bee_crypto::ternary::sponge::{Kerl, Sponge};
-use bee_signing::ternary::seed::{Error, Seed};
+use bee_crypto::ternary::sponge::{CurlP27, CurlP81, Kerl, Sponge};
+use bee_signing::ternary::seed::{Error, Seed, SEED_BYTES_LENGTH};
 use bee_ternary::{T1B1Buf, TritBuf, TryteBuf};
 
 const SEED: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ9ABCDEFGHIJKLMNOPQRSTUVWXYZ9ABCDEFGHIJKLMNOPQRSTUVWXYZ9";
@@ -15,7 +15,7 @@ fn subseed_generic<S: Sponge + Default>(seed_string: &str, subseed_strings: &[&s
     let seed = Seed::from_str(seed_string).unwrap();
 
     for (i, subseed_string) in subseed_strings.iter().enumerate() {
-        let subseed = seed.subseed(i);
+        let subseed = seed.subseed_with::<S>(i);
         let subseed_trits = TryteBuf::try_from_str(subseed_string)
             .unwrap()
             .as_trits()
@@ -44,6 +44,44 @@ fn subseed_kerl() {
     );
 }
 
+#[test]
+fn subseed_curlp27() {
+    subseed_generic::<CurlP27>(
+        SEED,
+        &[
+            "ITTFAEIWTRSFQGZGLGUMLUTHFXYSCLXTFYMGVTTDSNNWFUCKBRPSOBERNLXIYCNCEBKUV9QIXI9BDCKSM",
+            "W9YWLOQQJMENWCDBLBKYBNJJDGFKFBGYEBSIBPKUAGNIV9TJWRRAQPAEKBLIYVLGHPIIDYQYP9QNSPFTY",
+            "X9WMLHFSJYEWNLVSGTVGWMAPNUSFMXQPTMCPUML9RCMAJQVUYMTJJHKT9HO9NSNGAEMKGDBHE9KZNMBPZ",
+            "YNTUYQNJWJPK99YE9NOMGNKF9YRBJX9EH9UZWLMISXQRQLLZRKHFOPTW9PIERIPXK9ZDUPLSLZOEFUWXF",
+            "URBRFVWBAGHM9WTWSZZLRBMNGMNNRJRBGBLDEBBSZTGMWELW9JHXFSFNLRKPI9MLYELEZEDYIPKGE9CRO",
+            "XMGTGBZBINHC9ZPKRBHZFLUP9CEWULNCMVUAVVUXRDHU9OILDOORKPLRIWZQDNRFGSWMJAVYZWGDXMZNW",
+            "KFEGWPGWLAHWQXGCHKHDDVAZEISLYMGQLRRZBCJWXWKK9JIJKHXRDV9NMYIFTAGKXU9GLACAQUCXBLMH9",
+            "BMUAOOZBHPUOVHRWPX9KWUCZSXWXWPMKOMGNAZOXLDMAHBBVMDLXQ9IVPOPIOFPWHZSMRKBOBLCUEVUXX",
+            "GLVXLLOFYERJWBECYRXVPCFXK9GUDCHBEZYMTPMUDOYEQCIAPCAACKSOL9ADEGSTBQRIBJIWTCJYVUIRW",
+            "FOPHLVKCYHZLLCCOUWBPMQQAWHVRBGJBKQGPQXOTOEWTOCVZQCJXDCBLG9SEZBUVYPIIRTTP9CJPXWKKW",
+        ],
+    );
+}
+
+#[test]
+fn subseed_curlp81() {
+    subseed_generic::<CurlP81>(
+        SEED,
+        &[
+            "PKKJZREHPYHNIBWAPYEXHXEAFZCI99UWZNKBOCCECFTDUXG9YGYDAGRLUBJVKMYNWPRCPYENACHOYSHJO",
+            "EM9CGOOPJNDODXNHATOQTKLPV9SCMMDHMZIBQUZJCUBCPVAGP9AIEAKYAXOYTEUXRKZACVXRHGWNW9TNC",
+            "RRJNNVVOJEGYSXWUDUBVZSYSSWXLIAYUPIEAFSWUDDDEFCTRBBTMODUSXASEONBJOAREKLARUOUDHWKZF",
+            "XNW9XBGHM9ZVPSV9BXMFRB9MKODAXKEPPSTGX9PFEDNTVZPJUQGGQ9JCOZRMABQQNQBAURFKVJUZTYUQV",
+            "MMJRVEANOJUYWEGF9NNJUJVVZTGXKRWGXGVXRNRNDHPNMWVDGRHRH9FGODYVYWSVABUYZEVCJXUZZLYQB",
+            "PCOAKZFKIWGDTTQSBWZABUCIIEFADQQFHCJYTOFVEURSEQZHQCORMMBDKVRGNATYINDDWMGZBUGKLUZOR",
+            "CMDZYS9GCHCFFOHPMIPDKRASMFSUXJPDWUWYNMHLHBXUPUPPLEKCSBWSKUG9TKTCRXHJHIA9BVWKAGEHG",
+            "TAIMONWQMIXTMCGYMBGIDOZF9FOUPBIEIYYPQZYNMORHGNNLAPWCSMAKVLREZLGDS9XGTXNYYYQYUWRPM",
+            "VTKERDSFSJGLZF9UJHXJKFXIXFYSPNVSBHBMAZXXCJCBJHLDEEDMNPBRFJ9PCLNNSZYFLMRJQAYRMHVWL",
+            "YVGEVYOLICOIDRYBHP99JQZZJKVYZDPHFCQKJAN9BCEZCMWIEUJIRZWNAZNUMNDMT9JUCDGBSGXDUYQJC",
+        ],
+    );
+}
+
 #[test]
 fn from_str_invalid_length() {
     let trytes = "VBAZOIZIWGBRAXMFDUBLP";
@@ -80,3 +118,33 @@ fn to_trits_from_trits() {
         assert_eq!(seed_1.as_trits(), seed_2.as_trits());
     }
 }
+
+#[test]
+fn to_bytes_from_bytes() {
+    for _ in 0..10 {
+        let seed_1 = Seed::rand();
+        let bytes = seed_1.to_bytes();
+        let seed_2 = Seed::from_bytes(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), SEED_BYTES_LENGTH);
+        assert_eq!(seed_1.as_trits(), seed_2.as_trits());
+    }
+}
+
+#[test]
+fn from_bytes_invalid_length() {
+    let bytes = [0; 32];
+
+    assert_eq!(
+        Seed::from_bytes(&bytes).err(),
+        Some(Error::InvalidBytesLength(bytes.len()))
+    );
+}
+
+#[test]
+fn from_bytes_invalid_bytes() {
+    // 5 balanced trits range from -121 to 121.
+    let bytes = [122; SEED_BYTES_LENGTH];
+
+    assert_eq!(Seed::from_bytes(&bytes).err(), Some(Error::InvalidBytes));
+}
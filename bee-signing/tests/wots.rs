@@ -6,7 +6,7 @@
 use bee_crypto::ternary::sponge::Kerl;
 use bee_signing::ternary::{
     wots::{Error as WotsError, WotsPublicKey, WotsSecurityLevel, WotsSignature, WotsSpongePrivateKeyGeneratorBuilder},
-    PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature, Signature,
+    PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature, Signature, TrytesError,
 };
 use bee_ternary::{T1B1Buf, TryteBuf};
 
@@ -72,3 +72,119 @@ fn invalid_signature_length() {
         Some(WotsError::InvalidSignatureLength(entropy.len()))
     );
 }
+
+#[test]
+fn trytes_round_trip() {
+    let message =
+        TryteBuf::try_from_str("CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let entropy =
+        TryteBuf::try_from_str("CEFLDDLMF9TO9ZLLTYXIPVFIJKAOFRIQLGNYIDZCTDYSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let private_key_generator = WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+        .with_security_level(WotsSecurityLevel::Medium)
+        .build()
+        .unwrap();
+    let mut private_key = private_key_generator.generate_from_entropy(&entropy).unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+    let signature = private_key.sign(&message).unwrap();
+
+    let public_key_trytes = public_key.to_trytes();
+    let signature_trytes = signature.to_trytes();
+
+    assert_eq!(public_key_trytes.len(), public_key.size() / 3);
+    assert_eq!(signature_trytes.len(), signature.size() / 3);
+
+    let decoded_public_key = WotsPublicKey::<Kerl>::from_trytes(&public_key_trytes).unwrap();
+    let decoded_signature = WotsSignature::<Kerl>::from_trytes(&signature_trytes).unwrap();
+
+    assert_eq!(decoded_public_key.as_trits(), public_key.as_trits());
+    assert_eq!(decoded_signature.as_trits(), signature.as_trits());
+    assert!(decoded_public_key.verify(&message, &decoded_signature).unwrap());
+}
+
+#[test]
+fn from_trytes_invalid() {
+    let trytes = "CEFLDDLMF9TO9ZLLTYX@PVFIJKAOFRIQLGNYIDZCTDYSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD";
+
+    assert_eq!(
+        WotsPublicKey::<Kerl>::from_trytes(trytes).err(),
+        Some(TrytesError::InvalidTrytes)
+    );
+    assert_eq!(
+        WotsSignature::<Kerl>::from_trytes("cefldd").err(),
+        Some(TrytesError::InvalidTrytes)
+    );
+    assert_eq!(
+        WotsPublicKey::<Kerl>::from_trytes("YSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD").err(),
+        Some(TrytesError::InvalidTrits(WotsError::InvalidPublicKeyLength(117)))
+    );
+    assert_eq!(
+        WotsSignature::<Kerl>::from_trytes("YSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD").err(),
+        Some(TrytesError::InvalidTrits(WotsError::InvalidSignatureLength(117)))
+    );
+}
+
+#[test]
+fn sign_batch() {
+    let message =
+        TryteBuf::try_from_str("CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let entropy =
+        TryteBuf::try_from_str("CEFLDDLMF9TO9ZLLTYXIPVFIJKAOFRIQLGNYIDZCTDYSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let private_key_generator = WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+        .with_security_level(WotsSecurityLevel::Medium)
+        .build()
+        .unwrap();
+    let mut private_key = private_key_generator.generate_from_entropy(&entropy).unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+
+    // A one-time signature key can't sign several messages.
+    assert_eq!(
+        private_key.sign_batch(&[&message, &message]).err(),
+        Some(WotsError::ForbiddenKeyReuse(2))
+    );
+
+    let signatures = private_key.sign_batch(&[&message]).unwrap();
+
+    assert_eq!(signatures.len(), 1);
+    assert!(public_key.verify(&message, &signatures[0]).unwrap());
+}
+
+#[test]
+fn verify_valid_and_invalid() {
+    let message =
+        TryteBuf::try_from_str("CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let tampered_message =
+        TryteBuf::try_from_str("DHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let entropy =
+        TryteBuf::try_from_str("CEFLDDLMF9TO9ZLLTYXIPVFIJKAOFRIQLGNYIDZCTDYSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD")
+            .unwrap()
+            .as_trits()
+            .encode::<T1B1Buf>();
+    let private_key_generator = WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+        .with_security_level(WotsSecurityLevel::Medium)
+        .build()
+        .unwrap();
+    let mut private_key = private_key_generator.generate_from_entropy(&entropy).unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+    let signature = private_key.sign(&message).unwrap();
+
+    assert!(public_key.verify(&message, &signature).unwrap());
+    assert!(!public_key.verify(&tampered_message, &signature).unwrap());
+}
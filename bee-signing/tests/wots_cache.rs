@@ -0,0 +1,70 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "wots-cache")]
+#![allow(deprecated)]
+
+use bee_crypto::ternary::sponge::{CurlP27, CurlP81, Kerl, Sponge};
+use bee_signing::ternary::{
+    wots::{cache, WotsSecurityLevel, WotsSpongePrivateKeyGeneratorBuilder},
+    PrivateKey, PrivateKeyGenerator, PublicKey, RecoverableSignature,
+};
+use bee_ternary::{T1B1Buf, TryteBuf};
+
+const ENTROPY: &str = "CEFLDDLMF9TO9ZLLTYXIPVFIJKAOFRIQLGNYIDZCTDYSWMNXPYNGFAKHQDY9ABGGQZHEFTXKWKWZXEIUD";
+const MESSAGES: [&str; 2] = [
+    "CHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD",
+    "DHXHLHQLOPYP9NSUXTMWWABIBSBLUFXFRNWOZXJPVJPBCIDI99YBSCFYILCHPXHTSEYSYWIGQFERCRVDD",
+];
+
+fn cached_matches_uncached<S: Sponge + Default>() {
+    let entropy = TryteBuf::try_from_str(ENTROPY).unwrap().as_trits().encode::<T1B1Buf>();
+    let mut private_key = WotsSpongePrivateKeyGeneratorBuilder::<S>::default()
+        .with_security_level(WotsSecurityLevel::Medium)
+        .build()
+        .unwrap()
+        .generate_from_entropy(&entropy)
+        .unwrap();
+    let public_key = private_key.generate_public_key().unwrap();
+
+    for message in MESSAGES {
+        let message = TryteBuf::try_from_str(message).unwrap().as_trits().encode::<T1B1Buf>();
+        let signature = private_key.sign(&message).unwrap();
+
+        cache::clear();
+        let uncached = signature.recover_public_key(&message).unwrap();
+        let cached = signature.recover_public_key(&message).unwrap();
+
+        assert_eq!(uncached.as_trits(), cached.as_trits());
+        assert_eq!(uncached.as_trits(), public_key.as_trits());
+        assert!(public_key.verify(&message, &signature).unwrap());
+    }
+
+    // Other messages must not hit the entries cached for the signed ones.
+    let entropy_signature = private_key.sign(&entropy).unwrap();
+    let recovered = entropy_signature
+        .recover_public_key(
+            &TryteBuf::try_from_str(MESSAGES[0])
+                .unwrap()
+                .as_trits()
+                .encode::<T1B1Buf>(),
+        )
+        .unwrap();
+
+    assert_ne!(recovered.as_trits(), public_key.as_trits());
+}
+
+#[test]
+fn cached_matches_uncached_kerl() {
+    cached_matches_uncached::<Kerl>();
+}
+
+#[test]
+fn cached_matches_uncached_curl27() {
+    cached_matches_uncached::<CurlP27>();
+}
+
+#[test]
+fn cached_matches_uncached_curl81() {
+    cached_matches_uncached::<CurlP81>();
+}
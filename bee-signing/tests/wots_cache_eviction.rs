@@ -0,0 +1,50 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// The cache is global, the eviction is tested in its own binary so that no other test touches it meanwhile.
+
+#![cfg(feature = "wots-cache")]
+#![allow(deprecated)]
+
+use bee_crypto::ternary::sponge::CurlP27;
+use bee_signing::ternary::{
+    wots::{cache, WotsSignature},
+    RecoverableSignature, Signature,
+};
+use bee_ternary::{Btrit, T1B1Buf, TritBuf};
+
+#[test]
+fn least_recently_used_is_evicted() {
+    // A message of minimal trytes skips the hash chains, which keeps the many recoveries cheap.
+    let message = TritBuf::<T1B1Buf>::filled(243, Btrit::NegOne);
+
+    // One signature per distinct seed, one more than the cache can hold.
+    let signatures = (0..=cache::CACHE_CAPACITY)
+        .map(|seed| {
+            let mut state = TritBuf::<T1B1Buf>::zeros(6561);
+            // The seed is written in base 3 in the first trits, 3^7 seeds are enough to overflow the cache.
+            for i in 0..7 {
+                state.set(i, ((seed / 3usize.pow(i as u32) % 3) as i8 - 1).try_into().unwrap());
+            }
+
+            WotsSignature::<CurlP27>::from_trits(state).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for signature in &signatures[..cache::CACHE_CAPACITY] {
+        signature.recover_public_key(&message).unwrap();
+    }
+    assert_eq!(cache::len(), cache::CACHE_CAPACITY);
+
+    // Recovering the oldest entry again makes the second one the least recently used.
+    signatures[0].recover_public_key(&message).unwrap();
+    signatures[cache::CACHE_CAPACITY].recover_public_key(&message).unwrap();
+
+    assert_eq!(cache::len(), cache::CACHE_CAPACITY);
+    assert!(cache::contains::<CurlP27>(signatures[0].as_trits(), &message));
+    assert!(!cache::contains::<CurlP27>(signatures[1].as_trits(), &message));
+    assert!(cache::contains::<CurlP27>(
+        signatures[cache::CACHE_CAPACITY].as_trits(),
+        &message
+    ));
+}
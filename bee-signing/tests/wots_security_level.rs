@@ -0,0 +1,52 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "serde")]
+#![allow(deprecated)]
+
+use bee_signing::ternary::wots::WotsSecurityLevel;
+
+#[test]
+fn deserialize_name() {
+    assert_eq!(
+        serde_json::from_str::<WotsSecurityLevel>(r#""medium""#).unwrap(),
+        WotsSecurityLevel::Medium
+    );
+    assert_eq!(
+        serde_json::from_str::<WotsSecurityLevel>(r#""High""#).unwrap(),
+        WotsSecurityLevel::High
+    );
+}
+
+#[test]
+fn deserialize_number() {
+    assert_eq!(
+        serde_json::from_str::<WotsSecurityLevel>("2").unwrap(),
+        WotsSecurityLevel::Medium
+    );
+    assert_eq!(
+        serde_json::from_str::<WotsSecurityLevel>("1").unwrap(),
+        WotsSecurityLevel::Low
+    );
+}
+
+#[test]
+fn deserialize_invalid() {
+    assert!(serde_json::from_str::<WotsSecurityLevel>(r#""extreme""#).is_err());
+    assert!(serde_json::from_str::<WotsSecurityLevel>("4").is_err());
+    assert!(serde_json::from_str::<WotsSecurityLevel>("-1").is_err());
+}
+
+#[test]
+fn serialize_round_trip() {
+    for level in [
+        WotsSecurityLevel::Low,
+        WotsSecurityLevel::Medium,
+        WotsSecurityLevel::High,
+    ] {
+        let serialized = serde_json::to_string(&level).unwrap();
+
+        assert_eq!(serde_json::from_str::<WotsSecurityLevel>(&serialized).unwrap(), level);
+    }
+    assert_eq!(serde_json::to_string(&WotsSecurityLevel::Low).unwrap(), r#""low""#);
+}
@@ -5,7 +5,7 @@
 
 use std::str::FromStr;
 
-use bee_crypto::ternary::sponge::{CurlP27, CurlP81, Kerl, Sponge};
+use bee_crypto::ternary::sponge::{CurlP27, CurlP81, Kerl, Sponge, SpongeKind};
 use bee_signing::ternary::{
     seed::Seed,
     wots::{Error as WotsError, WotsSecurityLevel, WotsSpongePrivateKeyGeneratorBuilder},
@@ -41,6 +41,41 @@ fn generator_valid() {
     }
 }
 
+#[test]
+fn generator_subseed_sponge() {
+    let seed = Seed::from_str(SEED).unwrap();
+    let private_key_generator = |subseed_sponge| {
+        WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+            .with_security_level(WotsSecurityLevel::Low)
+            .with_subseed_sponge(subseed_sponge)
+            .build()
+            .unwrap()
+    };
+
+    for index in 0..3 {
+        let kerl = private_key_generator(SpongeKind::Kerl)
+            .generate_from_seed(&seed, index)
+            .unwrap();
+        let default = WotsSpongePrivateKeyGeneratorBuilder::<Kerl>::default()
+            .with_security_level(WotsSecurityLevel::Low)
+            .build()
+            .unwrap()
+            .generate_from_seed(&seed, index)
+            .unwrap();
+
+        assert_eq!(kerl.as_trits(), default.as_trits());
+
+        let curlp81 = private_key_generator(SpongeKind::CurlP81)
+            .generate_from_seed(&seed, index)
+            .map(|private_key| private_key.as_trits().to_buf::<T1B1Buf>());
+        let expected = private_key_generator(SpongeKind::CurlP81)
+            .generate_from_entropy(seed.subseed_with::<CurlP81>(index).as_trits())
+            .map(|private_key| private_key.as_trits().to_buf::<T1B1Buf>());
+
+        assert_eq!(curlp81, expected);
+    }
+}
+
 fn roundtrip<S: Sponge + Default>() {
     let message_trits = TryteBuf::try_from_str(MESSAGE).unwrap().as_trits().encode::<T1B1Buf>();
     let seed = Seed::from_str(SEED).unwrap();
@@ -15,7 +15,7 @@ use bee_message::{
     Message, MessageId,
 };
 use bee_storage::{
-    access::{Batch, BatchBuilder},
+    access::{Batch, BatchBuilder, Durability},
     backend::StorageBackend,
 };
 use bee_tangle::{
@@ -53,7 +53,11 @@ impl BatchBuilder for Storage {
         Self::Batch::default()
     }
 
-    fn batch_commit(&self, batch: Self::Batch, _durability: bool) -> Result<(), <Self as StorageBackend>::Error> {
+    fn batch_commit_with(
+        &self,
+        batch: Self::Batch,
+        _durability: Durability,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
         let mut inner = self.inner.write()?;
 
         macro_rules! apply_batch {
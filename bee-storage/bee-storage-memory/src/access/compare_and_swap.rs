@@ -0,0 +1,31 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compare and swap access operations.
+
+use bee_ledger::types::LedgerIndex;
+use bee_storage::{access::CompareAndSwap, backend::StorageBackend};
+
+use crate::storage::Storage;
+
+impl CompareAndSwap<(), LedgerIndex> for Storage {
+    fn compare_and_swap(
+        &self,
+        (): &(),
+        old: Option<&LedgerIndex>,
+        new: Option<&LedgerIndex>,
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut guard = self.inner.write()?;
+
+        if guard.ledger_index.fetch(&()).as_ref() != old {
+            return Ok(false);
+        }
+
+        match new {
+            Some(index) => guard.ledger_index.insert(&(), index),
+            None => guard.ledger_index.delete(&()),
+        }
+
+        Ok(true)
+    }
+}
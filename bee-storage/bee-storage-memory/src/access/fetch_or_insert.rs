@@ -0,0 +1,31 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fetch or insert access operations.
+
+use bee_message::MessageId;
+use bee_storage::{access::FetchOrInsert, backend::StorageBackend};
+use bee_tangle::metadata::MessageMetadata;
+
+use crate::storage::Storage;
+
+impl FetchOrInsert<MessageId, MessageMetadata> for Storage {
+    fn fetch_or_insert_with<F: FnOnce() -> MessageMetadata>(
+        &self,
+        k: &MessageId,
+        f: F,
+    ) -> Result<MessageMetadata, <Self as StorageBackend>::Error> {
+        let mut guard = self.inner.write()?;
+
+        if let Some(v) = guard.message_id_to_metadata.fetch(k) {
+            return Ok(v);
+        }
+
+        let v = f();
+        guard.message_id_to_metadata.insert(k, &v);
+
+        drop(guard);
+
+        Ok(v)
+    }
+}
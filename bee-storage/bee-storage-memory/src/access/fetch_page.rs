@@ -0,0 +1,20 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fetch page access operations.
+
+use bee_message::{payload::indexation::PaddedIndex, MessageId};
+use bee_storage::{access::FetchPage, backend::StorageBackend};
+
+use crate::storage::Storage;
+
+impl FetchPage<PaddedIndex, MessageId> for Storage {
+    fn fetch_page(
+        &self,
+        index: &PaddedIndex,
+        cursor: Option<&MessageId>,
+        limit: usize,
+    ) -> Result<(Vec<MessageId>, Option<MessageId>), <Self as StorageBackend>::Error> {
+        Ok(self.inner.read()?.index_to_message_id.fetch_page(index, cursor, limit))
+    }
+}
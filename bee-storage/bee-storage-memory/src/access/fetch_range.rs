@@ -0,0 +1,24 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fetch range access operations.
+
+use bee_ledger::types::OutputDiff;
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::{access::FetchRange, backend::StorageBackend};
+
+use crate::storage::Storage;
+
+impl FetchRange<MilestoneIndex, OutputDiff> for Storage {
+    fn fetch_range(
+        &self,
+        start: &MilestoneIndex,
+        end: &MilestoneIndex,
+    ) -> Result<Vec<(MilestoneIndex, OutputDiff)>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .read()?
+            .milestone_index_to_output_diff
+            .fetch_range(start, end))
+    }
+}
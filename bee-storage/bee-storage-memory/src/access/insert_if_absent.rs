@@ -0,0 +1,25 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Insert if absent access operations.
+
+use bee_message::{Message, MessageId};
+use bee_storage::{access::InsertIfAbsent, backend::StorageBackend};
+
+use crate::storage::Storage;
+
+impl InsertIfAbsent<MessageId, Message> for Storage {
+    fn insert_if_absent(&self, k: &MessageId, v: &Message) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut guard = self.inner.write()?;
+
+        if guard.message_id_to_message.exist(k) {
+            return Ok(false);
+        }
+
+        guard.message_id_to_message.insert(k, v);
+
+        drop(guard);
+
+        Ok(true)
+    }
+}
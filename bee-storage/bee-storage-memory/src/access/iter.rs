@@ -14,7 +14,11 @@ use bee_message::{
     payload::indexation::PaddedIndex,
     Message, MessageId,
 };
-use bee_storage::{access::AsIterator, backend::StorageBackend, system::System};
+use bee_storage::{
+    access::{AsIterator, Keys, Values},
+    backend::StorageBackend,
+    system::System,
+};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
@@ -28,29 +32,59 @@ macro_rules! impl_iter {
     (($key:ty, $value:ty), (), $field:ident) => {
         impl<'a> AsIterator<'a, ($key, $value), ()> for Storage {
             type AsIter = VecTableIter<$key, $value>;
+            type AsKeysIter = Keys<VecTableIter<$key, $value>>;
+            type AsValuesIter = Values<VecTableIter<$key, $value>>;
 
             fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
                 Ok(self.inner.read()?.$field.iter())
             }
+
+            fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+                Ok(Keys::new(self.inner.read()?.$field.iter()))
+            }
+
+            fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+                Ok(Values::new(self.inner.read()?.$field.iter()))
+            }
         }
     };
 
     ((), $value:ty, $field:ident) => {
         impl<'a> AsIterator<'a, (), $value> for Storage {
             type AsIter = SingletonTableIter<$value>;
+            type AsKeysIter = Keys<SingletonTableIter<$value>>;
+            type AsValuesIter = Values<SingletonTableIter<$value>>;
 
             fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
                 Ok(self.inner.read()?.$field.iter())
             }
+
+            fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+                Ok(Keys::new(self.inner.read()?.$field.iter()))
+            }
+
+            fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+                Ok(Values::new(self.inner.read()?.$field.iter()))
+            }
         }
     };
     ($key:ty, $value:ty, $field:ident) => {
         impl<'a> AsIterator<'a, $key, $value> for Storage {
             type AsIter = TableIter<$key, $value>;
+            type AsKeysIter = Keys<TableIter<$key, $value>>;
+            type AsValuesIter = Values<TableIter<$key, $value>>;
 
             fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
                 Ok(self.inner.read()?.$field.iter())
             }
+
+            fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+                Ok(Keys::new(self.inner.read()?.$field.iter()))
+            }
+
+            fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+                Ok(Values::new(self.inner.read()?.$field.iter()))
+            }
         }
     };
 }
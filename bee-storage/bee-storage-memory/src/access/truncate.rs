@@ -24,10 +24,8 @@ use crate::storage::Storage;
 macro_rules! impl_truncate {
     ($key:ty, $value:ty, $field:ident) => {
         impl Truncate<$key, $value> for Storage {
-            fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
-                self.inner.write()?.$field.truncate();
-
-                Ok(())
+            fn truncate(&self) -> Result<usize, <Self as StorageBackend>::Error> {
+                Ok(self.inner.write()?.$field.truncate())
             }
         }
     };
@@ -43,8 +43,12 @@ impl<K: Hash + Eq + Clone, V: Clone> Table<K, V> {
         self.inner.remove(k);
     }
 
-    pub(crate) fn truncate(&mut self) {
+    pub(crate) fn truncate(&mut self) -> usize {
+        let count = self.inner.len();
+
         self.inner.clear();
+
+        count
     }
 
     pub(crate) fn update(&mut self, k: &K, f: impl FnOnce(&mut V)) {
@@ -76,6 +80,21 @@ impl<K: Hash + Eq + Clone, V: Clone> Table<K, V> {
     }
 }
 
+impl<K: Hash + Ord + Clone, V: Clone> Table<K, V> {
+    pub(crate) fn fetch_range(&self, start: &K, end: &K) -> Vec<(K, V)> {
+        let mut pairs = self
+            .inner
+            .iter()
+            .filter(|(k, _)| (start..=end).contains(k))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+
+        pairs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        pairs
+    }
+}
+
 /// An iterator over the elements of a `VecTable` or `VecBinTable`.
 pub struct VecTableIter<K, V> {
     head: Option<(K, Vec<V>)>,
@@ -147,8 +166,12 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq> VecTable<K, V> {
         }
     }
 
-    pub(crate) fn truncate(&mut self) {
+    pub(crate) fn truncate(&mut self) -> usize {
+        let count = self.inner.values().map(Vec::len).sum();
+
         self.inner.clear();
+
+        count
     }
 
     pub(crate) fn iter(&self) -> VecTableIter<K, V> {
@@ -197,6 +220,23 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
         self.inner.get(k).cloned().or_else(|| Some(vec![]))
     }
 
+    pub(crate) fn fetch_page(&self, k: &K, cursor: Option<&V>, limit: usize) -> (Vec<V>, Option<V>) {
+        let vs = self.inner.get(k).map_or(&[][..], Vec::as_slice);
+        // The cursor is the last value of the previous page, it is excluded to avoid duplicates.
+        let start = cursor.map_or(0, |cursor| match vs.binary_search(cursor) {
+            Ok(i) => i + 1,
+            Err(i) => i,
+        });
+        let end = start.saturating_add(limit).min(vs.len());
+        let next = if end < vs.len() {
+            vs[start..end].last().cloned()
+        } else {
+            None
+        };
+
+        (vs[start..end].to_vec(), next)
+    }
+
     pub(crate) fn exist(&self, (k, v): &(K, V)) -> bool {
         self.inner.get(k).map_or(false, |vs| vs.binary_search(v).is_ok())
     }
@@ -217,8 +257,12 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
         }
     }
 
-    pub(crate) fn truncate(&mut self) {
+    pub(crate) fn truncate(&mut self) -> usize {
+        let count = self.inner.values().map(Vec::len).sum();
+
         self.inner.clear();
+
+        count
     }
 
     pub(crate) fn iter(&self) -> VecTableIter<K, V> {
@@ -278,8 +322,8 @@ impl<V: Clone> SingletonTable<V> {
         self.inner = None;
     }
 
-    pub(crate) fn truncate(&mut self) {
-        self.inner = None;
+    pub(crate) fn truncate(&mut self) -> usize {
+        usize::from(self.inner.take().is_some())
     }
 
     pub(crate) fn iter(&self) -> SingletonTableIter<V> {
@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_storage::access::{Batch, BatchBuilder};
+use bee_storage::access::{Batch, BatchBuilder, Durability};
 
 use crate::Storage;
 
@@ -11,7 +11,7 @@ pub struct StorageBatch;
 impl BatchBuilder for Storage {
     type Batch = StorageBatch;
 
-    fn batch_commit(&self, _batch: Self::Batch, _durability: bool) -> Result<(), Self::Error> {
+    fn batch_commit_with(&self, _batch: Self::Batch, _durability: Durability) -> Result<(), Self::Error> {
         Ok(())
     }
 }
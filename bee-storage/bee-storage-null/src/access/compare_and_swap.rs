@@ -0,0 +1,13 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::access::CompareAndSwap;
+
+use crate::Storage;
+
+impl<K, V> CompareAndSwap<K, V> for Storage {
+    fn compare_and_swap(&self, _key: &K, old: Option<&V>, _new: Option<&V>) -> Result<bool, Self::Error> {
+        // Nothing is ever stored, only an absent value can be swapped.
+        Ok(old.is_none())
+    }
+}
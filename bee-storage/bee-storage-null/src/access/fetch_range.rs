@@ -0,0 +1,12 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::access::FetchRange;
+
+use crate::Storage;
+
+impl<K, V> FetchRange<K, V> for Storage {
+    fn fetch_range(&self, _start: &K, _end: &K) -> Result<Vec<(K, V)>, Self::Error> {
+        Ok(Vec::new())
+    }
+}
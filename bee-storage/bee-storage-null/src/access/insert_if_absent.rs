@@ -0,0 +1,8 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::access::InsertIfAbsent;
+
+use crate::Storage;
+
+impl<K, V> InsertIfAbsent<K, V> for Storage {}
@@ -3,7 +3,10 @@
 
 use std::marker::PhantomData;
 
-use bee_storage::{access::AsIterator, backend::StorageBackend};
+use bee_storage::{
+    access::{AsIterator, Keys, Values},
+    backend::StorageBackend,
+};
 
 use crate::Storage;
 
@@ -27,8 +30,18 @@ impl<K, V> Iterator for StorageIterator<K, V> {
 
 impl<'a, K, V> AsIterator<'a, K, V> for Storage {
     type AsIter = StorageIterator<K, V>;
+    type AsKeysIter = Keys<StorageIterator<K, V>>;
+    type AsValuesIter = Values<StorageIterator<K, V>>;
 
     fn iter(&'a self) -> Result<Self::AsIter, Self::Error> {
         Ok(StorageIterator::new())
     }
+
+    fn iter_keys(&'a self) -> Result<Self::AsKeysIter, Self::Error> {
+        Ok(Keys::new(StorageIterator::new()))
+    }
+
+    fn iter_values(&'a self) -> Result<Self::AsValuesIter, Self::Error> {
+        Ok(Values::new(StorageIterator::new()))
+    }
 }
@@ -13,7 +13,7 @@ use bee_message::{
     payload::indexation::PaddedIndex,
     Message, MessageId,
 };
-use bee_storage::access::{Batch, BatchBuilder};
+use bee_storage::access::{Batch, BatchBuilder, Durability};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
@@ -35,10 +35,10 @@ pub struct StorageBatch {
 impl BatchBuilder for Storage {
     type Batch = StorageBatch;
 
-    fn batch_commit(&self, batch: Self::Batch, durability: bool) -> Result<(), <Self as StorageBackend>::Error> {
+    fn batch_commit_with(&self, batch: Self::Batch, durability: Durability) -> Result<(), <Self as StorageBackend>::Error> {
         let mut write_options = WriteOptions::default();
-        write_options.set_sync(false);
-        write_options.disable_wal(!durability);
+        write_options.set_sync(durability == Durability::Sync);
+        write_options.disable_wal(durability == Durability::None);
 
         let guard = batch.should_lock.then(|| self.locks.message_id_to_metadata.read());
 
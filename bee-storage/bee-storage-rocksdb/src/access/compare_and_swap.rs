@@ -0,0 +1,35 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_ledger::types::LedgerIndex;
+use bee_storage::access::CompareAndSwap;
+
+use crate::{column_families::*, storage::Storage};
+
+impl CompareAndSwap<(), LedgerIndex> for Storage {
+    fn compare_and_swap(
+        &self,
+        (): &(),
+        old: Option<&LedgerIndex>,
+        new: Option<&LedgerIndex>,
+    ) -> Result<bool, Self::Error> {
+        let cf_handle = self.cf_handle(CF_LEDGER_INDEX)?;
+
+        let guard = self.locks.ledger_index.write();
+
+        let current = self.inner.get_pinned_cf(cf_handle, [0x00u8])?;
+        let swapped = current.as_deref() == old.map(Packable::pack_new).as_deref();
+
+        if swapped {
+            match new {
+                Some(index) => self.inner.put_cf(cf_handle, [0x00u8], index.pack_new())?,
+                None => self.inner.delete_cf(cf_handle, [0x00u8])?,
+            }
+        }
+
+        drop(guard);
+
+        Ok(swapped)
+    }
+}
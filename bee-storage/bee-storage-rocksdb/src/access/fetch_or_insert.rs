@@ -0,0 +1,37 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_message::MessageId;
+use bee_storage::access::FetchOrInsert;
+use bee_tangle::metadata::MessageMetadata;
+
+use crate::{column_families::*, storage::Storage};
+
+impl FetchOrInsert<MessageId, MessageMetadata> for Storage {
+    fn fetch_or_insert_with<F: FnOnce() -> MessageMetadata>(
+        &self,
+        message_id: &MessageId,
+        f: F,
+    ) -> Result<MessageMetadata, Self::Error> {
+        let cf_handle = self.cf_handle(CF_MESSAGE_ID_TO_METADATA)?;
+
+        let guard = self.locks.message_id_to_metadata.write();
+
+        let metadata = match self.inner.get_pinned_cf(cf_handle, message_id)? {
+            // Unpacking from storage is fine.
+            Some(v) => MessageMetadata::unpack_unchecked(&mut &*v).unwrap(),
+            None => {
+                let metadata = f();
+
+                self.inner.put_cf(cf_handle, message_id, metadata.pack_new())?;
+
+                metadata
+            }
+        };
+
+        drop(guard);
+
+        Ok(metadata)
+    }
+}
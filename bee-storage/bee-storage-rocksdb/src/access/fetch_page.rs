@@ -0,0 +1,52 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{
+    payload::indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
+    MessageId, MESSAGE_ID_LENGTH,
+};
+use bee_storage::access::FetchPage;
+
+use crate::{
+    column_families::*,
+    storage::{Storage, StorageBackend},
+};
+
+impl FetchPage<PaddedIndex, MessageId> for Storage {
+    fn fetch_page(
+        &self,
+        index: &PaddedIndex,
+        cursor: Option<&MessageId>,
+        limit: usize,
+    ) -> Result<(Vec<MessageId>, Option<MessageId>), <Self as StorageBackend>::Error> {
+        let mut start = index.as_ref().to_vec();
+
+        if let Some(cursor) = cursor {
+            start.extend_from_slice(cursor.as_ref());
+        }
+
+        let mut message_ids = self
+            .inner
+            .prefix_iterator_cf(self.cf_handle(CF_INDEX_TO_MESSAGE_ID)?, start)
+            .map(|(key, _)| {
+                let (_, message_id) = key.split_at(INDEXATION_PADDED_INDEX_LENGTH);
+                // Unpacking from storage is fine.
+                let message_id: [u8; MESSAGE_ID_LENGTH] = message_id.try_into().unwrap();
+                MessageId::from(message_id)
+            })
+            // The cursor is the last message id of the previous page, it is excluded to avoid duplicates.
+            .skip_while(|message_id| Some(message_id) == cursor)
+            // One more message id is fetched to know if there is a next page.
+            .take(limit.saturating_add(1))
+            .collect::<Vec<_>>();
+
+        let next = if message_ids.len() > limit {
+            message_ids.truncate(limit);
+            message_ids.last().copied()
+        } else {
+            None
+        };
+
+        Ok((message_ids, next))
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::OutputDiff;
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::access::{AsIterator, FetchRange};
+
+use crate::storage::{Storage, StorageBackend};
+
+impl FetchRange<MilestoneIndex, OutputDiff> for Storage {
+    /// Milestone indices are encoded in little endian, so the keys are not ordered by index and the column family
+    /// is scanned once instead of fetching every index of the range.
+    fn fetch_range(
+        &self,
+        start: &MilestoneIndex,
+        end: &MilestoneIndex,
+    ) -> Result<Vec<(MilestoneIndex, OutputDiff)>, <Self as StorageBackend>::Error> {
+        let mut output_diffs = Vec::new();
+
+        for result in AsIterator::<MilestoneIndex, OutputDiff>::iter(self)? {
+            let (index, output_diff) = result?;
+
+            if (start..=end).contains(&&index) {
+                output_diffs.push((index, output_diff));
+            }
+        }
+
+        output_diffs.sort_unstable_by_key(|(index, _)| *index);
+
+        Ok(output_diffs)
+    }
+}
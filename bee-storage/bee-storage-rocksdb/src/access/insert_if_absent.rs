@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{Message, MessageId};
+use bee_storage::access::InsertIfAbsent;
+
+use crate::storage::Storage;
+
+// RocksDB has no compare and swap, the default implementation is used.
+impl InsertIfAbsent<MessageId, Message> for Storage {}
@@ -15,7 +15,10 @@ use bee_message::{
     payload::indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
     Message, MessageId, MESSAGE_ID_LENGTH,
 };
-use bee_storage::{access::AsIterator, system::System};
+use bee_storage::{
+    access::{AsIterator, Keys, Values},
+    system::System,
+};
 use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
@@ -47,6 +50,8 @@ macro_rules! impl_iter {
     ($key:ty, $value:ty, $cf:expr) => {
         impl<'a> AsIterator<'a, $key, $value> for Storage {
             type AsIter = StorageIterator<'a, $key, $value>;
+            type AsKeysIter = Keys<StorageIterator<'a, $key, $value>>;
+            type AsValuesIter = Values<StorageIterator<'a, $key, $value>>;
 
             fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
                 Ok(StorageIterator::new(
@@ -54,6 +59,14 @@ macro_rules! impl_iter {
                     None,
                 ))
             }
+
+            fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+                AsIterator::<'a, $key, $value>::iter(self).map(Keys::new)
+            }
+
+            fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+                AsIterator::<'a, $key, $value>::iter(self).map(Values::new)
+            }
         }
 
         /// An iterator over all key-value pairs of a column family.
@@ -327,6 +340,8 @@ impl_iter!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
 
 impl<'a> AsIterator<'a, MessageId, MessageMetadata> for Storage {
     type AsIter = StorageIterator<'a, MessageId, MessageMetadata>;
+    type AsKeysIter = Keys<StorageIterator<'a, MessageId, MessageMetadata>>;
+    type AsValuesIter = Values<StorageIterator<'a, MessageId, MessageMetadata>>;
 
     fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
         Ok(StorageIterator::new(
@@ -335,6 +350,14 @@ impl<'a> AsIterator<'a, MessageId, MessageMetadata> for Storage {
             Some(self.locks.message_id_to_metadata.read()),
         ))
     }
+
+    fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+        AsIterator::<'a, MessageId, MessageMetadata>::iter(self).map(Keys::new)
+    }
+
+    fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+        AsIterator::<'a, MessageId, MessageMetadata>::iter(self).map(Values::new)
+    }
 }
 
 /// An iterator over all key-value pairs of a column family.
@@ -2,10 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod batch;
+pub mod compare_and_swap;
 pub mod delete;
 pub mod exist;
 pub mod fetch;
+pub mod fetch_or_insert;
+pub mod fetch_page;
+pub mod fetch_range;
 pub mod insert;
+pub mod insert_if_absent;
 pub mod iter;
 pub mod multi_fetch;
 pub mod truncate;
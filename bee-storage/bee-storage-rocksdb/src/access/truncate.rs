@@ -25,7 +25,7 @@ use crate::{
 macro_rules! impl_truncate {
     ($key:ty, $value:ty, $cf:expr) => {
         impl Truncate<$key, $value> for Storage {
-            fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
+            fn truncate(&self) -> Result<usize, <Self as StorageBackend>::Error> {
                 let cf_handle = self.cf_handle($cf)?;
 
                 let mut iter = self.inner.raw_iterator_cf(cf_handle);
@@ -37,9 +37,16 @@ macro_rules! impl_truncate {
                     first.to_vec()
                 } else {
                     // There are no keys to remove.
-                    return Ok(());
+                    return Ok(0);
                 };
 
+                // Count the keys to remove.
+                let mut count = 0;
+                while iter.valid() {
+                    count += 1;
+                    iter.next();
+                }
+
                 iter.seek_to_last();
                 // Grab the last key if it exists.
                 let last = if let Some(last) = iter.key() {
@@ -50,12 +57,12 @@ macro_rules! impl_truncate {
                     last
                 } else {
                     // There are no keys to remove.
-                    return Ok(());
+                    return Ok(0);
                 };
 
                 self.inner.delete_range_cf(cf_handle, first, last)?;
 
-                Ok(())
+                Ok(count)
             }
         }
     };
@@ -83,7 +90,7 @@ impl_truncate!((MilestoneIndex, Receipt), (), CF_MILESTONE_INDEX_TO_RECEIPT);
 impl_truncate!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
 
 impl Truncate<MessageId, MessageMetadata> for Storage {
-    fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
+    fn truncate(&self) -> Result<usize, <Self as StorageBackend>::Error> {
         let guard = self.locks.message_id_to_metadata.read();
 
         let cf_handle = self.cf_handle(CF_MESSAGE_ID_TO_METADATA)?;
@@ -97,9 +104,16 @@ impl Truncate<MessageId, MessageMetadata> for Storage {
             first.to_vec()
         } else {
             // There are no keys to remove.
-            return Ok(());
+            return Ok(0);
         };
 
+        // Count the keys to remove.
+        let mut count = 0;
+        while iter.valid() {
+            count += 1;
+            iter.next();
+        }
+
         iter.seek_to_last();
         // Grab the last key if it exists.
         let last = if let Some(last) = iter.key() {
@@ -110,13 +124,13 @@ impl Truncate<MessageId, MessageMetadata> for Storage {
             last
         } else {
             // There are no keys to remove.
-            return Ok(());
+            return Ok(0);
         };
 
         self.inner.delete_range_cf(cf_handle, first, last)?;
 
         drop(guard);
 
-        Ok(())
+        Ok(count)
     }
 }
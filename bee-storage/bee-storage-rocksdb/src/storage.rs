@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use bee_message::{
     address::ED25519_ADDRESS_LENGTH, milestone::MilestoneIndex, payload::indexation::INDEXATION_PADDED_INDEX_LENGTH,
     MESSAGE_ID_LENGTH,
@@ -26,6 +28,7 @@ pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(9);
 
 pub struct Locks {
     pub(crate) message_id_to_metadata: RwLock<()>,
+    pub(crate) ledger_index: RwLock<()>,
 }
 
 pub struct Storage {
@@ -167,6 +170,7 @@ impl Storage {
             inner: db,
             locks: Locks {
                 message_id_to_metadata: RwLock::new(()),
+                ledger_index: RwLock::new(()),
             },
         })
     }
@@ -217,6 +221,16 @@ impl StorageBackend for Storage {
         ))
     }
 
+    fn tree_sizes(&self) -> Result<Option<Vec<(String, usize)>>, Self::Error> {
+        let mut sizes = BTreeMap::<String, usize>::new();
+
+        for file in self.inner.live_files()? {
+            *sizes.entry(file.column_family_name).or_default() += file.size;
+        }
+
+        Ok(Some(sizes.into_iter().collect()))
+    }
+
     fn get_health(&self) -> Result<Option<StorageHealth>, Self::Error> {
         Ok(match Fetch::<u8, System>::fetch(self, &SYSTEM_HEALTH_KEY)? {
             Some(System::Health(health)) => Some(health),
@@ -0,0 +1,61 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Insert, MultiFetch},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfigBuilder, StorageConfigBuilder},
+    storage::Storage,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+use criterion::*;
+
+const KEYS: [usize; 3] = [100, 1_000, 10_000];
+
+fn multi_fetch_bench(c: &mut Criterion, name: &str, parallel_multi_fetch_threshold: usize) {
+    let config = SledConfigBuilder::default()
+        .with_path(format!("./benches/database/{}", name))
+        .with_temporary(true)
+        .with_storage(
+            StorageConfigBuilder::default().with_parallel_multi_fetch_threshold(parallel_multi_fetch_threshold),
+        )
+        .finish();
+    let storage = Storage::start(config).unwrap();
+    let keys = (0..KEYS[KEYS.len() - 1])
+        .map(|_| {
+            let key = rand_message_id();
+            Insert::<MessageId, Message>::insert(&storage, &key, &rand_message()).unwrap();
+            key
+        })
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group(name);
+
+    for len in KEYS {
+        group.bench_with_input(BenchmarkId::from_parameter(len), &keys[..len], |b, keys| {
+            b.iter(|| {
+                MultiFetch::<MessageId, Message>::multi_fetch(&storage, keys)
+                    .unwrap()
+                    .for_each(|result| drop(black_box(result)))
+            })
+        });
+    }
+
+    group.finish();
+    storage.shutdown().unwrap();
+}
+
+fn serial_multi_fetch_bench(c: &mut Criterion) {
+    multi_fetch_bench(c, "serial_multi_fetch", usize::MAX);
+}
+
+// Without the `parallel` feature, this measures the serial multi-fetch as well.
+fn parallel_multi_fetch_bench(c: &mut Criterion) {
+    multi_fetch_bench(c, "parallel_multi_fetch", 0);
+}
+
+criterion_group!(benches, serial_multi_fetch_bench, parallel_multi_fetch_bench);
+criterion_main!(benches);
@@ -18,7 +18,7 @@ use bee_message::{
     Message, MessageId,
 };
 use bee_storage::{
-    access::{Batch, BatchBuilder},
+    access::{Batch, BatchBuilder, Durability},
     backend::StorageBackend,
 };
 use bee_tangle::{
@@ -26,7 +26,7 @@ use bee_tangle::{
 };
 use sled::{transaction::TransactionError, Transactional};
 
-use crate::{storage::Storage, trees::*};
+use crate::{compression::compress, storage::Storage, trees::*};
 
 /// A writing batch that can be applied atomically.
 #[derive(Default)]
@@ -39,27 +39,50 @@ pub struct StorageBatch {
 impl BatchBuilder for Storage {
     type Batch = StorageBatch;
 
-    fn batch_commit(&self, batch: Self::Batch, _durability: bool) -> Result<(), <Self as StorageBackend>::Error> {
+    fn batch_commit_with(
+        &self,
+        batch: Self::Batch,
+        durability: Durability,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+        // A failed background flush is reported before anything else is committed.
+        self.flusher.take_error()?;
+
         let trees = batch
             .inner
             .keys()
-            .map(|tree| self.inner.open_tree(tree))
+            .map(|tree| self.open_tree(tree))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let transaction_result = Transactional::<Infallible>::transaction::<_, ()>(trees.as_slice(), |trees| {
-            for (tree, batch) in trees.iter().zip(batch.inner.values()) {
-                tree.apply_batch(batch)?;
-            }
+        // sled can't run a transaction over no tree, an empty batch only persists the previous commits.
+        if !trees.is_empty() {
+            let transaction_result = Transactional::<Infallible>::transaction::<_, ()>(trees.as_slice(), |trees| {
+                for (tree, batch) in trees.iter().zip(batch.inner.values()) {
+                    tree.apply_batch(batch)?;
+                }
 
-            Ok(())
-        });
+                Ok(())
+            });
 
-        if let Err(err) = transaction_result {
-            match err {
-                TransactionError::Storage(err) => {
-                    return Err(Self::Error::Sled(err));
+            if let Err(err) = transaction_result {
+                match err {
+                    TransactionError::Storage(err) => {
+                        return Err(Self::Error::Sled(err));
+                    }
+                    TransactionError::Abort(err) => match err {},
                 }
-                TransactionError::Abort(err) => match err {},
+            }
+        }
+
+        for tree in batch.inner.keys() {
+            self.invalidate_cached(tree);
+        }
+
+        match durability {
+            Durability::None => {}
+            Durability::Async => self.flusher.request(),
+            Durability::Sync => {
+                self.inner.flush()?;
             }
         }
 
@@ -78,6 +101,10 @@ impl Batch<MessageId, Message> for Storage {
         // Packing to bytes can't fail.
         message.pack(&mut batch.value_buf).unwrap();
 
+        if self.is_compressed(TREE_MESSAGE_ID_TO_MESSAGE) {
+            batch.value_buf = compress(&batch.value_buf);
+        }
+
         batch
             .inner
             .entry(TREE_MESSAGE_ID_TO_MESSAGE)
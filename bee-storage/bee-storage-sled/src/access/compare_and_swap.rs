@@ -0,0 +1,47 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compare and swap access operations.
+
+use bee_common::packable::Packable;
+use bee_ledger::types::LedgerIndex;
+use bee_storage::{access::CompareAndSwap, backend::StorageBackend, system::System};
+
+use crate::{storage::Storage, trees::*};
+
+impl CompareAndSwap<u8, System> for Storage {
+    fn compare_and_swap(
+        &self,
+        &key: &u8,
+        old: Option<&System>,
+        new: Option<&System>,
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
+        Ok(self
+            .inner
+            .compare_and_swap([key], old.map(Packable::pack_new), new.map(Packable::pack_new))?
+            .is_ok())
+    }
+}
+
+impl CompareAndSwap<(), LedgerIndex> for Storage {
+    fn compare_and_swap(
+        &self,
+        (): &(),
+        old: Option<&LedgerIndex>,
+        new: Option<&LedgerIndex>,
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
+        // Opening the tree applies its pending coalesced insertions, they are compared against as well.
+        let swapped = self
+            .open_tree(TREE_LEDGER_INDEX)?
+            .compare_and_swap([0x00u8], old.map(Packable::pack_new), new.map(Packable::pack_new))?
+            .is_ok();
+
+        self.invalidate_cached(TREE_LEDGER_INDEX);
+
+        Ok(swapped)
+    }
+}
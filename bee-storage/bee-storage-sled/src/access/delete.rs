@@ -24,7 +24,9 @@ use crate::{storage::Storage, trees::*};
 
 impl Delete<MessageId, Message> for Storage {
     fn delete(&self, message_id: &MessageId) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner.open_tree(TREE_MESSAGE_ID_TO_MESSAGE)?.remove(message_id)?;
+        self.check_writable()?;
+
+        self.open_tree(TREE_MESSAGE_ID_TO_MESSAGE)?.remove(message_id)?;
 
         Ok(())
     }
@@ -32,7 +34,9 @@ impl Delete<MessageId, Message> for Storage {
 
 impl Delete<MessageId, MessageMetadata> for Storage {
     fn delete(&self, message_id: &MessageId) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner.open_tree(TREE_MESSAGE_ID_TO_METADATA)?.remove(message_id)?;
+        self.check_writable()?;
+
+        self.open_tree(TREE_MESSAGE_ID_TO_METADATA)?.remove(message_id)?;
 
         Ok(())
     }
@@ -40,10 +44,12 @@ impl Delete<MessageId, MessageMetadata> for Storage {
 
 impl Delete<(MessageId, MessageId), ()> for Storage {
     fn delete(&self, (parent, child): &(MessageId, MessageId)) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = parent.as_ref().to_vec();
         key.extend_from_slice(child.as_ref());
 
-        self.inner.open_tree(TREE_MESSAGE_ID_TO_MESSAGE_ID)?.remove(key)?;
+        self.open_tree(TREE_MESSAGE_ID_TO_MESSAGE_ID)?.remove(key)?;
 
         Ok(())
     }
@@ -51,10 +57,12 @@ impl Delete<(MessageId, MessageId), ()> for Storage {
 
 impl Delete<(PaddedIndex, MessageId), ()> for Storage {
     fn delete(&self, (index, message_id): &(PaddedIndex, MessageId)) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = index.as_ref().to_vec();
         key.extend_from_slice(message_id.as_ref());
 
-        self.inner.open_tree(TREE_INDEX_TO_MESSAGE_ID)?.remove(key)?;
+        self.open_tree(TREE_INDEX_TO_MESSAGE_ID)?.remove(key)?;
 
         Ok(())
     }
@@ -62,8 +70,9 @@ impl Delete<(PaddedIndex, MessageId), ()> for Storage {
 
 impl Delete<OutputId, CreatedOutput> for Storage {
     fn delete(&self, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
             .remove(output_id.pack_new())?;
 
         Ok(())
@@ -72,8 +81,9 @@ impl Delete<OutputId, CreatedOutput> for Storage {
 
 impl Delete<OutputId, ConsumedOutput> for Storage {
     fn delete(&self, output_id: &OutputId) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
             .remove(output_id.pack_new())?;
 
         Ok(())
@@ -82,9 +92,9 @@ impl Delete<OutputId, ConsumedOutput> for Storage {
 
 impl Delete<Unspent, ()> for Storage {
     fn delete(&self, unspent: &Unspent) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_UNSPENT)?
-            .remove(unspent.pack_new())?;
+        self.check_writable()?;
+
+        self.open_tree(TREE_OUTPUT_ID_UNSPENT)?.remove(unspent.pack_new())?;
 
         Ok(())
     }
@@ -92,10 +102,12 @@ impl Delete<Unspent, ()> for Storage {
 
 impl Delete<(Ed25519Address, OutputId), ()> for Storage {
     fn delete(&self, (address, output_id): &(Ed25519Address, OutputId)) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = address.as_ref().to_vec();
         key.extend_from_slice(&output_id.pack_new());
 
-        self.inner.open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?.remove(key)?;
+        self.open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?.remove(key)?;
 
         Ok(())
     }
@@ -103,7 +115,10 @@ impl Delete<(Ed25519Address, OutputId), ()> for Storage {
 
 impl Delete<(), LedgerIndex> for Storage {
     fn delete(&self, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner.open_tree(TREE_LEDGER_INDEX)?.remove([0x00u8])?;
+        self.check_writable()?;
+
+        self.open_tree(TREE_LEDGER_INDEX)?.remove([0x00u8])?;
+        self.invalidate_cached(TREE_LEDGER_INDEX);
 
         Ok(())
     }
@@ -111,8 +126,9 @@ impl Delete<(), LedgerIndex> for Storage {
 
 impl Delete<MilestoneIndex, Milestone> for Storage {
     fn delete(&self, index: &MilestoneIndex) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_MILESTONE)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_MILESTONE_INDEX_TO_MILESTONE)?
             .remove(index.pack_new())?;
 
         Ok(())
@@ -121,7 +137,10 @@ impl Delete<MilestoneIndex, Milestone> for Storage {
 
 impl Delete<(), SnapshotInfo> for Storage {
     fn delete(&self, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner.open_tree(TREE_SNAPSHOT_INFO)?.remove([0x00u8])?;
+        self.check_writable()?;
+
+        self.open_tree(TREE_SNAPSHOT_INFO)?.remove([0x00u8])?;
+        self.invalidate_cached(TREE_SNAPSHOT_INFO);
 
         Ok(())
     }
@@ -129,8 +148,9 @@ impl Delete<(), SnapshotInfo> for Storage {
 
 impl Delete<SolidEntryPoint, MilestoneIndex> for Storage {
     fn delete(&self, sep: &SolidEntryPoint) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX)?
             .remove(sep.as_ref())?;
 
         Ok(())
@@ -139,8 +159,9 @@ impl Delete<SolidEntryPoint, MilestoneIndex> for Storage {
 
 impl Delete<MilestoneIndex, OutputDiff> for Storage {
     fn delete(&self, index: &MilestoneIndex) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF)?
             .remove(index.pack_new())?;
 
         Ok(())
@@ -149,9 +170,9 @@ impl Delete<MilestoneIndex, OutputDiff> for Storage {
 
 impl Delete<Address, Balance> for Storage {
     fn delete(&self, address: &Address) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_ADDRESS_TO_BALANCE)?
-            .remove(address.pack_new())?;
+        self.check_writable()?;
+
+        self.open_tree(TREE_ADDRESS_TO_BALANCE)?.remove(address.pack_new())?;
 
         Ok(())
     }
@@ -162,11 +183,12 @@ impl Delete<(MilestoneIndex, UnreferencedMessage), ()> for Storage {
         &self,
         (index, unreferenced_message): &(MilestoneIndex, UnreferencedMessage),
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = index.pack_new();
         key.extend_from_slice(unreferenced_message.as_ref());
 
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?
+        self.open_tree(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?
             .remove(key)?;
 
         Ok(())
@@ -175,10 +197,12 @@ impl Delete<(MilestoneIndex, UnreferencedMessage), ()> for Storage {
 
 impl Delete<(MilestoneIndex, Receipt), ()> for Storage {
     fn delete(&self, (index, receipt): &(MilestoneIndex, Receipt)) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = index.pack_new();
         key.extend_from_slice(&receipt.pack_new());
 
-        self.inner.open_tree(TREE_MILESTONE_INDEX_TO_RECEIPT)?.remove(key)?;
+        self.open_tree(TREE_MILESTONE_INDEX_TO_RECEIPT)?.remove(key)?;
 
         Ok(())
     }
@@ -186,10 +210,12 @@ impl Delete<(MilestoneIndex, Receipt), ()> for Storage {
 
 impl Delete<(bool, TreasuryOutput), ()> for Storage {
     fn delete(&self, (spent, output): &(bool, TreasuryOutput)) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = spent.pack_new();
         key.extend_from_slice(&output.pack_new());
 
-        self.inner.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.remove(key)?;
+        self.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.remove(key)?;
 
         Ok(())
     }
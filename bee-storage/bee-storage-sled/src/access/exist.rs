@@ -24,19 +24,13 @@ use crate::{storage::Storage, trees::*};
 
 impl Exist<MessageId, Message> for Storage {
     fn exist(&self, message_id: &MessageId) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MESSAGE_ID_TO_MESSAGE)?
-            .contains_key(message_id)?)
+        self.contains_key(TREE_MESSAGE_ID_TO_MESSAGE, message_id)
     }
 }
 
 impl Exist<MessageId, MessageMetadata> for Storage {
     fn exist(&self, message_id: &MessageId) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MESSAGE_ID_TO_METADATA)?
-            .contains_key(message_id)?)
+        self.contains_key(TREE_MESSAGE_ID_TO_METADATA, message_id)
     }
 }
 
@@ -45,7 +39,7 @@ impl Exist<(MessageId, MessageId), ()> for Storage {
         let mut key = parent.as_ref().to_vec();
         key.extend_from_slice(child.as_ref());
 
-        Ok(self.inner.open_tree(TREE_MESSAGE_ID_TO_MESSAGE_ID)?.contains_key(key)?)
+        self.contains_key(TREE_MESSAGE_ID_TO_MESSAGE_ID, key)
     }
 }
 
@@ -54,34 +48,25 @@ impl Exist<(PaddedIndex, MessageId), ()> for Storage {
         let mut key = index.as_ref().to_vec();
         key.extend_from_slice(message_id.as_ref());
 
-        Ok(self.inner.open_tree(TREE_INDEX_TO_MESSAGE_ID)?.contains_key(key)?)
+        self.contains_key(TREE_INDEX_TO_MESSAGE_ID, key)
     }
 }
 
 impl Exist<OutputId, CreatedOutput> for Storage {
     fn exist(&self, output_id: &OutputId) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
-            .contains_key(output_id.pack_new())?)
+        self.contains_key(TREE_OUTPUT_ID_TO_CREATED_OUTPUT, output_id.pack_new())
     }
 }
 
 impl Exist<OutputId, ConsumedOutput> for Storage {
     fn exist(&self, output_id: &OutputId) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
-            .contains_key(output_id.pack_new())?)
+        self.contains_key(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT, output_id.pack_new())
     }
 }
 
 impl Exist<Unspent, ()> for Storage {
     fn exist(&self, unspent: &Unspent) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_OUTPUT_ID_UNSPENT)?
-            .contains_key(unspent.pack_new())?)
+        self.contains_key(TREE_OUTPUT_ID_UNSPENT, unspent.pack_new())
     }
 }
 
@@ -93,58 +78,43 @@ impl Exist<(Ed25519Address, OutputId), ()> for Storage {
         let mut key = address.as_ref().to_vec();
         key.extend_from_slice(&output_id.pack_new());
 
-        Ok(self
-            .inner
-            .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
-            .contains_key(key)?)
+        self.contains_key(TREE_ED25519_ADDRESS_TO_OUTPUT_ID, key)
     }
 }
 
 impl Exist<(), LedgerIndex> for Storage {
     fn exist(&self, (): &()) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self.inner.open_tree(TREE_LEDGER_INDEX)?.contains_key([0x00u8])?)
+        self.contains_key(TREE_LEDGER_INDEX, [0x00u8])
     }
 }
 
 impl Exist<MilestoneIndex, Milestone> for Storage {
     fn exist(&self, index: &MilestoneIndex) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_MILESTONE)?
-            .contains_key(index.pack_new())?)
+        self.contains_key(TREE_MILESTONE_INDEX_TO_MILESTONE, index.pack_new())
     }
 }
 
 impl Exist<(), SnapshotInfo> for Storage {
     fn exist(&self, (): &()) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self.inner.open_tree(TREE_SNAPSHOT_INFO)?.contains_key([0x00u8])?)
+        self.contains_key(TREE_SNAPSHOT_INFO, [0x00u8])
     }
 }
 
 impl Exist<SolidEntryPoint, MilestoneIndex> for Storage {
     fn exist(&self, sep: &SolidEntryPoint) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX)?
-            .contains_key(sep.pack_new())?)
+        self.contains_key(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX, sep.pack_new())
     }
 }
 
 impl Exist<MilestoneIndex, OutputDiff> for Storage {
     fn exist(&self, index: &MilestoneIndex) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF)?
-            .contains_key(index.pack_new())?)
+        self.contains_key(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF, index.pack_new())
     }
 }
 
 impl Exist<Address, Balance> for Storage {
     fn exist(&self, address: &Address) -> Result<bool, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_ADDRESS_TO_BALANCE)?
-            .contains_key(address.pack_new())?)
+        self.contains_key(TREE_ADDRESS_TO_BALANCE, address.pack_new())
     }
 }
 
@@ -156,10 +126,7 @@ impl Exist<(MilestoneIndex, UnreferencedMessage), ()> for Storage {
         let mut key = index.pack_new();
         key.extend_from_slice(unreferenced_message.as_ref());
 
-        Ok(self
-            .inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?
-            .contains_key(key)?)
+        self.contains_key(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE, key)
     }
 }
 
@@ -168,10 +135,7 @@ impl Exist<(MilestoneIndex, Receipt), ()> for Storage {
         let mut key = index.pack_new();
         key.extend_from_slice(&receipt.pack_new());
 
-        Ok(self
-            .inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_RECEIPT)?
-            .contains_key(key)?)
+        self.contains_key(TREE_MILESTONE_INDEX_TO_RECEIPT, key)
     }
 }
 
@@ -180,6 +144,6 @@ impl Exist<(bool, TreasuryOutput), ()> for Storage {
         let mut key = spent.pack_new();
         key.extend_from_slice(&output.pack_new());
 
-        Ok(self.inner.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.contains_key(key)?)
+        self.contains_key(TREE_SPENT_TO_TREASURY_OUTPUT, key)
     }
 }
@@ -9,9 +9,9 @@ use bee_ledger::types::{
     TreasuryOutput,
 };
 use bee_message::{
-    address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
+    address::{Address, Ed25519Address},
     milestone::{Milestone, MilestoneIndex},
-    output::{OutputId, OUTPUT_ID_LENGTH},
+    output::OutputId,
     payload::indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
     Message, MessageId, MESSAGE_ID_LENGTH,
 };
@@ -20,53 +20,61 @@ use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
-use crate::{storage::Storage, trees::*};
+use crate::{
+    compression::decompress,
+    storage::{Error, Storage},
+    trees::*,
+};
 
 impl Fetch<u8, System> for Storage {
     fn fetch(&self, &key: &u8) -> Result<Option<System>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .get(&[key])?
-            // Unpacking from storage is fine.
-            .map(|v| System::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.inner.get([key])?.map(|v| self.decode::<System>(&v)).transpose()
     }
 }
 
 impl Fetch<MessageId, Message> for Storage {
     fn fetch(&self, message_id: &MessageId) -> Result<Option<Message>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MESSAGE_ID_TO_MESSAGE)?
-            .get(message_id)?
-            // Unpacking from storage is fine.
-            .map(|v| Message::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_MESSAGE_ID_TO_MESSAGE, message_id)?
+            .map(|v| {
+                if self.is_compressed(TREE_MESSAGE_ID_TO_MESSAGE) {
+                    self.decode(&decompress(&v)?)
+                } else {
+                    self.decode(&v)
+                }
+            })
+            .transpose()
+    }
+}
+
+impl Storage {
+    /// Fetches the packed bytes of a message without unpacking it, e.g. to forward it as is.
+    ///
+    /// The bytes of a compressed message tree are decompressed, so they are always the packed message.
+    pub fn fetch_raw(&self, message_id: &MessageId) -> Result<Option<Box<[u8]>>, Error> {
+        self.get(TREE_MESSAGE_ID_TO_MESSAGE, message_id)?
+            .map(|v| {
+                if self.is_compressed(TREE_MESSAGE_ID_TO_MESSAGE) {
+                    Ok(decompress(&v)?.into_boxed_slice())
+                } else {
+                    Ok(Box::from(v.as_ref()))
+                }
+            })
+            .transpose()
     }
 }
 
 impl Fetch<MessageId, MessageMetadata> for Storage {
     fn fetch(&self, message_id: &MessageId) -> Result<Option<MessageMetadata>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MESSAGE_ID_TO_METADATA)?
-            .get(message_id)?
-            // Unpacking from storage is fine.
-            .map(|v| MessageMetadata::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_MESSAGE_ID_TO_METADATA, message_id)?
+            .map(|v| self.decode::<MessageMetadata>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<MessageId, Vec<MessageId>> for Storage {
     fn fetch(&self, parent: &MessageId) -> Result<Option<Vec<MessageId>>, <Self as StorageBackend>::Error> {
         Ok(Some(
-            self.inner
-                .open_tree(TREE_MESSAGE_ID_TO_MESSAGE_ID)?
-                .scan_prefix(parent)
-                .map(|result| {
-                    let (key, _) = result?;
-                    let (_, child) = key.split_at(MESSAGE_ID_LENGTH);
-                    // Unpacking from storage is fine.
-                    let child: [u8; MESSAGE_ID_LENGTH] = child.try_into().unwrap();
-                    Ok(MessageId::from(child))
-                })
+            self.children_of(parent)?
                 .take(self.config.storage.fetch_edge_limit)
                 .collect::<Result<Vec<MessageId>, Self::Error>>()?,
         ))
@@ -76,8 +84,7 @@ impl Fetch<MessageId, Vec<MessageId>> for Storage {
 impl Fetch<PaddedIndex, Vec<MessageId>> for Storage {
     fn fetch(&self, index: &PaddedIndex) -> Result<Option<Vec<MessageId>>, <Self as StorageBackend>::Error> {
         Ok(Some(
-            self.inner
-                .open_tree(TREE_INDEX_TO_MESSAGE_ID)?
+            self.open_tree(TREE_INDEX_TO_MESSAGE_ID)?
                 .scan_prefix(index)
                 .map(|result| {
                     let (key, _) = result?;
@@ -94,40 +101,24 @@ impl Fetch<PaddedIndex, Vec<MessageId>> for Storage {
 
 impl Fetch<OutputId, CreatedOutput> for Storage {
     fn fetch(&self, output_id: &OutputId) -> Result<Option<CreatedOutput>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
-            .get(output_id.pack_new())?
-            // Unpacking from storage is fine.
-            .map(|v| CreatedOutput::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_OUTPUT_ID_TO_CREATED_OUTPUT, output_id.pack_new())?
+            .map(|v| self.decode::<CreatedOutput>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<OutputId, ConsumedOutput> for Storage {
     fn fetch(&self, output_id: &OutputId) -> Result<Option<ConsumedOutput>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
-            .get(output_id.pack_new())?
-            // Unpacking from storage is fine.
-            .map(|v| ConsumedOutput::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT, output_id.pack_new())?
+            .map(|v| self.decode::<ConsumedOutput>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
     fn fetch(&self, address: &Ed25519Address) -> Result<Option<Vec<OutputId>>, <Self as StorageBackend>::Error> {
         Ok(Some(
-            self.inner
-                .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
-                .scan_prefix(address)
-                .map(|result| {
-                    let (key, _) = result?;
-                    let (_, output_id) = key.split_at(ED25519_ADDRESS_LENGTH);
-                    // Unpacking from storage is fine.
-                    Ok((<[u8; OUTPUT_ID_LENGTH]>::try_from(output_id).unwrap())
-                        .try_into()
-                        .unwrap())
-                })
+            self.output_ids_for_address(address)?
                 .take(self.config.storage.fetch_output_id_limit)
                 .collect::<Result<Vec<OutputId>, Self::Error>>()?,
         ))
@@ -136,67 +127,49 @@ impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
 
 impl Fetch<(), LedgerIndex> for Storage {
     fn fetch(&self, (): &()) -> Result<Option<LedgerIndex>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_LEDGER_INDEX)?
-            .get([0x00u8])?
-            // Unpacking from storage is fine.
-            .map(|v| LedgerIndex::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_LEDGER_INDEX, [0x00u8])?
+            .map(|v| self.decode::<LedgerIndex>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<MilestoneIndex, Milestone> for Storage {
     fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Milestone>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_MILESTONE)?
-            .get(index.pack_new())?
-            // Unpacking from storage is fine.
-            .map(|v| Milestone::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_MILESTONE_INDEX_TO_MILESTONE, index.pack_new())?
+            .map(|v| self.decode::<Milestone>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<(), SnapshotInfo> for Storage {
     fn fetch(&self, (): &()) -> Result<Option<SnapshotInfo>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_SNAPSHOT_INFO)?
-            .get([0x00u8])?
-            // Unpacking from storage is fine.
-            .map(|v| SnapshotInfo::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_SNAPSHOT_INFO, [0x00u8])?
+            .map(|v| self.decode::<SnapshotInfo>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<SolidEntryPoint, MilestoneIndex> for Storage {
     fn fetch(&self, sep: &SolidEntryPoint) -> Result<Option<MilestoneIndex>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX)?
-            .get(sep.as_ref())?
-            // Unpacking from storage is fine.
-            .map(|v| MilestoneIndex::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX, sep.as_ref())?
+            .map(|v| self.decode::<MilestoneIndex>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<MilestoneIndex, OutputDiff> for Storage {
     fn fetch(&self, index: &MilestoneIndex) -> Result<Option<OutputDiff>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF)?
-            .get(index.pack_new())?
-            // Unpacking from storage is fine.
-            .map(|v| OutputDiff::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF, index.pack_new())?
+            .map(|v| self.decode::<OutputDiff>(&v))
+            .transpose()
     }
 }
 
 impl Fetch<Address, Balance> for Storage {
     fn fetch(&self, address: &Address) -> Result<Option<Balance>, <Self as StorageBackend>::Error> {
-        Ok(self
-            .inner
-            .open_tree(TREE_ADDRESS_TO_BALANCE)?
-            .get(address.pack_new())?
-            // Unpacking from storage is fine.
-            .map(|v| Balance::unpack_unchecked(&mut v.as_ref()).unwrap()))
+        self.get(TREE_ADDRESS_TO_BALANCE, address.pack_new())?
+            .map(|v| self.decode::<Balance>(&v))
+            .transpose()
     }
 }
 
@@ -206,8 +179,7 @@ impl Fetch<MilestoneIndex, Vec<UnreferencedMessage>> for Storage {
         index: &MilestoneIndex,
     ) -> Result<Option<Vec<UnreferencedMessage>>, <Self as StorageBackend>::Error> {
         Ok(Some(
-            self.inner
-                .open_tree(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?
+            self.open_tree(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?
                 .scan_prefix(index.pack_new())
                 .map(|result| {
                     let (key, _) = result?;
@@ -224,8 +196,7 @@ impl Fetch<MilestoneIndex, Vec<UnreferencedMessage>> for Storage {
 impl Fetch<MilestoneIndex, Vec<Receipt>> for Storage {
     fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<Receipt>>, <Self as StorageBackend>::Error> {
         Ok(Some(
-            self.inner
-                .open_tree(TREE_MILESTONE_INDEX_TO_RECEIPT)?
+            self.open_tree(TREE_MILESTONE_INDEX_TO_RECEIPT)?
                 .scan_prefix(index.pack_new())
                 .map(|result| {
                     let (mut key, _) = result?;
@@ -242,8 +213,7 @@ impl Fetch<MilestoneIndex, Vec<Receipt>> for Storage {
 impl Fetch<bool, Vec<TreasuryOutput>> for Storage {
     fn fetch(&self, spent: &bool) -> Result<Option<Vec<TreasuryOutput>>, <Self as StorageBackend>::Error> {
         Ok(Some(
-            self.inner
-                .open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?
+            self.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?
                 .scan_prefix(spent.pack_new())
                 .map(|result| {
                     let (mut key, _) = result?;
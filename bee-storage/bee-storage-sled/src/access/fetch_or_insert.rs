@@ -0,0 +1,52 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fetch or insert access operations.
+
+use bee_common::packable::Packable;
+use bee_message::MessageId;
+use bee_storage::{access::FetchOrInsert, backend::StorageBackend};
+use bee_tangle::metadata::MessageMetadata;
+use sled::CompareAndSwapError;
+
+use crate::{storage::Storage, trees::*};
+
+impl FetchOrInsert<MessageId, MessageMetadata> for Storage {
+    fn fetch_or_insert_with<F: FnOnce() -> MessageMetadata>(
+        &self,
+        message_id: &MessageId,
+        f: F,
+    ) -> Result<MessageMetadata, <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
+        let tree = self.open_tree(TREE_MESSAGE_ID_TO_METADATA)?;
+        let mut f = Some(f);
+        let mut metadata = None;
+
+        loop {
+            // A hit is a plain read, nothing is written.
+            if let Some(bytes) = tree.get(message_id)? {
+                // Unpacking from storage is fine.
+                return Ok(MessageMetadata::unpack_unchecked(&mut bytes.as_ref()).unwrap());
+            }
+
+            // The new value is only built once, even if the insertion has to be retried.
+            // Panic: `f` is only taken when `metadata` is still `None`.
+            let new_metadata = metadata.get_or_insert_with(|| (f.take().unwrap())());
+
+            match tree.compare_and_swap(message_id, None as Option<&[u8]>, Some(new_metadata.pack_new()))? {
+                // Panic: `metadata` was set right above.
+                Ok(()) => return Ok(metadata.take().unwrap()),
+                // Another writer inserted a value in the meantime, it is returned instead.
+                Err(CompareAndSwapError {
+                    current: Some(bytes), ..
+                }) => {
+                    // Unpacking from storage is fine.
+                    return Ok(MessageMetadata::unpack_unchecked(&mut bytes.as_ref()).unwrap());
+                }
+                // The concurrently inserted value was already removed, try again.
+                Err(CompareAndSwapError { current: None, .. }) => continue,
+            }
+        }
+    }
+}
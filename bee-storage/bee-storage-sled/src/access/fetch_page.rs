@@ -0,0 +1,55 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fetch page access operations.
+
+use std::ops::Bound;
+
+use bee_message::{
+    payload::indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
+    MessageId, MESSAGE_ID_LENGTH,
+};
+use bee_storage::{access::FetchPage, backend::StorageBackend};
+
+use crate::{storage::Storage, trees::TREE_INDEX_TO_MESSAGE_ID};
+
+impl FetchPage<PaddedIndex, MessageId> for Storage {
+    fn fetch_page(
+        &self,
+        index: &PaddedIndex,
+        cursor: Option<&MessageId>,
+        limit: usize,
+    ) -> Result<(Vec<MessageId>, Option<MessageId>), <Self as StorageBackend>::Error> {
+        let start = match cursor {
+            // The cursor is the last message id of the previous page, it is excluded to avoid duplicates.
+            Some(cursor) => Bound::Excluded([index.as_ref(), cursor.as_ref()].concat()),
+            None => Bound::Included(index.to_vec()),
+        };
+
+        let mut message_ids = self
+            .open_tree(TREE_INDEX_TO_MESSAGE_ID)?
+            .range::<Vec<u8>, _>((start, Bound::Unbounded))
+            .keys()
+            .take_while(|result| result.as_ref().map_or(true, |key| key.starts_with(index.as_ref())))
+            // One more message id is fetched to know if there is a next page.
+            .take(limit.saturating_add(1))
+            .map(|result| {
+                result.map(|key| {
+                    let (_, message_id) = key.split_at(INDEXATION_PADDED_INDEX_LENGTH);
+                    // Unpacking from storage is fine.
+                    let message_id: [u8; MESSAGE_ID_LENGTH] = message_id.try_into().unwrap();
+                    MessageId::from(message_id)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let next = if message_ids.len() > limit {
+            message_ids.truncate(limit);
+            message_ids.last().copied()
+        } else {
+            None
+        };
+
+        Ok((message_ids, next))
+    }
+}
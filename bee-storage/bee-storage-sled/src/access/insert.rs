@@ -24,10 +24,12 @@ use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
-use crate::{storage::Storage, trees::*};
+use crate::{compression::compress, storage::Storage, trees::*};
 
 impl Insert<u8, System> for Storage {
     fn insert(&self, key: &u8, value: &System) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         self.inner.insert(&[*key], value.pack_new())?;
 
         Ok(())
@@ -36,9 +38,15 @@ impl Insert<u8, System> for Storage {
 
 impl Insert<MessageId, Message> for Storage {
     fn insert(&self, message_id: &MessageId, message: &Message) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_MESSAGE_ID_TO_MESSAGE)?
-            .insert(message_id, message.pack_new())?;
+        self.check_writable()?;
+
+        let mut bytes = message.pack_new();
+
+        if self.is_compressed(TREE_MESSAGE_ID_TO_MESSAGE) {
+            bytes = compress(&bytes);
+        }
+
+        self.insert_coalesced(TREE_MESSAGE_ID_TO_MESSAGE, message_id, bytes)?;
 
         Ok(())
     }
@@ -50,8 +58,9 @@ impl InsertStrict<MessageId, MessageMetadata> for Storage {
         message_id: &MessageId,
         metadata: &MessageMetadata,
     ) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_MESSAGE_ID_TO_METADATA)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_MESSAGE_ID_TO_METADATA)?
             .update_and_fetch(message_id, |old_metadata| {
                 old_metadata.map(|b| b.to_vec()).or_else(|| Some(metadata.pack_new()))
             })?;
@@ -62,10 +71,12 @@ impl InsertStrict<MessageId, MessageMetadata> for Storage {
 
 impl Insert<(MessageId, MessageId), ()> for Storage {
     fn insert(&self, (parent, child): &(MessageId, MessageId), (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = parent.as_ref().to_vec();
         key.extend_from_slice(child.as_ref());
 
-        self.inner.open_tree(TREE_MESSAGE_ID_TO_MESSAGE_ID)?.insert(key, &[])?;
+        self.insert_coalesced(TREE_MESSAGE_ID_TO_MESSAGE_ID, key, [])?;
 
         Ok(())
     }
@@ -77,10 +88,12 @@ impl Insert<(PaddedIndex, MessageId), ()> for Storage {
         (index, message_id): &(PaddedIndex, MessageId),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = index.as_ref().to_vec();
         key.extend_from_slice(message_id.as_ref());
 
-        self.inner.open_tree(TREE_INDEX_TO_MESSAGE_ID)?.insert(key, &[])?;
+        self.insert_coalesced(TREE_INDEX_TO_MESSAGE_ID, key, [])?;
 
         Ok(())
     }
@@ -88,9 +101,13 @@ impl Insert<(PaddedIndex, MessageId), ()> for Storage {
 
 impl Insert<OutputId, CreatedOutput> for Storage {
     fn insert(&self, output_id: &OutputId, output: &CreatedOutput) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_TO_CREATED_OUTPUT)?
-            .insert(output_id.pack_new(), output.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(
+            TREE_OUTPUT_ID_TO_CREATED_OUTPUT,
+            output_id.pack_new(),
+            output.pack_new(),
+        )?;
 
         Ok(())
     }
@@ -98,9 +115,13 @@ impl Insert<OutputId, CreatedOutput> for Storage {
 
 impl Insert<OutputId, ConsumedOutput> for Storage {
     fn insert(&self, output_id: &OutputId, output: &ConsumedOutput) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT)?
-            .insert(output_id.pack_new(), output.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(
+            TREE_OUTPUT_ID_TO_CONSUMED_OUTPUT,
+            output_id.pack_new(),
+            output.pack_new(),
+        )?;
 
         Ok(())
     }
@@ -108,9 +129,9 @@ impl Insert<OutputId, ConsumedOutput> for Storage {
 
 impl Insert<Unspent, ()> for Storage {
     fn insert(&self, unspent: &Unspent, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_OUTPUT_ID_UNSPENT)?
-            .insert(unspent.pack_new(), &[])?;
+        self.check_writable()?;
+
+        self.insert_coalesced(TREE_OUTPUT_ID_UNSPENT, unspent.pack_new(), [])?;
 
         Ok(())
     }
@@ -122,12 +143,12 @@ impl Insert<(Ed25519Address, OutputId), ()> for Storage {
         (address, output_id): &(Ed25519Address, OutputId),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = address.as_ref().to_vec();
         key.extend_from_slice(&output_id.pack_new());
 
-        self.inner
-            .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
-            .insert(key, &[])?;
+        self.insert_coalesced(TREE_ED25519_ADDRESS_TO_OUTPUT_ID, key, [])?;
 
         Ok(())
     }
@@ -135,9 +156,9 @@ impl Insert<(Ed25519Address, OutputId), ()> for Storage {
 
 impl Insert<(), LedgerIndex> for Storage {
     fn insert(&self, (): &(), index: &LedgerIndex) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_LEDGER_INDEX)?
-            .insert([0x00u8], index.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(TREE_LEDGER_INDEX, [0x00u8], index.pack_new())?;
 
         Ok(())
     }
@@ -145,9 +166,13 @@ impl Insert<(), LedgerIndex> for Storage {
 
 impl Insert<MilestoneIndex, Milestone> for Storage {
     fn insert(&self, index: &MilestoneIndex, milestone: &Milestone) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_MILESTONE)?
-            .insert(index.pack_new(), milestone.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(
+            TREE_MILESTONE_INDEX_TO_MILESTONE,
+            index.pack_new(),
+            milestone.pack_new(),
+        )?;
 
         Ok(())
     }
@@ -155,9 +180,9 @@ impl Insert<MilestoneIndex, Milestone> for Storage {
 
 impl Insert<(), SnapshotInfo> for Storage {
     fn insert(&self, (): &(), info: &SnapshotInfo) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_SNAPSHOT_INFO)?
-            .insert([0x00u8], info.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(TREE_SNAPSHOT_INFO, [0x00u8], info.pack_new())?;
 
         Ok(())
     }
@@ -165,9 +190,13 @@ impl Insert<(), SnapshotInfo> for Storage {
 
 impl Insert<SolidEntryPoint, MilestoneIndex> for Storage {
     fn insert(&self, sep: &SolidEntryPoint, index: &MilestoneIndex) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX)?
-            .insert(sep.as_ref(), index.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(
+            TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX,
+            sep.as_ref(),
+            index.pack_new(),
+        )?;
 
         Ok(())
     }
@@ -175,9 +204,9 @@ impl Insert<SolidEntryPoint, MilestoneIndex> for Storage {
 
 impl Insert<MilestoneIndex, OutputDiff> for Storage {
     fn insert(&self, index: &MilestoneIndex, diff: &OutputDiff) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF)?
-            .insert(index.pack_new(), diff.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF, index.pack_new(), diff.pack_new())?;
 
         Ok(())
     }
@@ -185,9 +214,9 @@ impl Insert<MilestoneIndex, OutputDiff> for Storage {
 
 impl Insert<Address, Balance> for Storage {
     fn insert(&self, address: &Address, balance: &Balance) -> Result<(), <Self as StorageBackend>::Error> {
-        self.inner
-            .open_tree(TREE_ADDRESS_TO_BALANCE)?
-            .insert(address.pack_new(), balance.pack_new())?;
+        self.check_writable()?;
+
+        self.insert_coalesced(TREE_ADDRESS_TO_BALANCE, address.pack_new(), balance.pack_new())?;
 
         Ok(())
     }
@@ -199,12 +228,12 @@ impl Insert<(MilestoneIndex, UnreferencedMessage), ()> for Storage {
         (index, unreferenced_message): &(MilestoneIndex, UnreferencedMessage),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = index.pack_new();
         key.extend_from_slice(unreferenced_message.as_ref());
 
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE)?
-            .insert(key, &[])?;
+        self.insert_coalesced(TREE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE, key, [])?;
 
         Ok(())
     }
@@ -216,12 +245,12 @@ impl Insert<(MilestoneIndex, Receipt), ()> for Storage {
         (index, receipt): &(MilestoneIndex, Receipt),
         (): &(),
     ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = index.pack_new();
         key.extend_from_slice(&receipt.pack_new());
 
-        self.inner
-            .open_tree(TREE_MILESTONE_INDEX_TO_RECEIPT)?
-            .insert(key, &[])?;
+        self.insert_coalesced(TREE_MILESTONE_INDEX_TO_RECEIPT, key, [])?;
 
         Ok(())
     }
@@ -229,10 +258,12 @@ impl Insert<(MilestoneIndex, Receipt), ()> for Storage {
 
 impl Insert<(bool, TreasuryOutput), ()> for Storage {
     fn insert(&self, (spent, output): &(bool, TreasuryOutput), (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
         let mut key = spent.pack_new();
         key.extend_from_slice(&output.pack_new());
 
-        self.inner.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.insert(key, &[])?;
+        self.insert_coalesced(TREE_SPENT_TO_TREASURY_OUTPUT, key, [])?;
 
         Ok(())
     }
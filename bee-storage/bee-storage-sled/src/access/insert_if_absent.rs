@@ -0,0 +1,32 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Insert if absent access operations.
+
+use bee_common::packable::Packable;
+use bee_message::{Message, MessageId};
+use bee_storage::{access::InsertIfAbsent, backend::StorageBackend};
+
+use crate::{compression::compress, storage::Storage, trees::*};
+
+impl InsertIfAbsent<MessageId, Message> for Storage {
+    fn insert_if_absent(
+        &self,
+        message_id: &MessageId,
+        message: &Message,
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        self.check_writable()?;
+
+        let mut bytes = message.pack_new();
+
+        if self.is_compressed(TREE_MESSAGE_ID_TO_MESSAGE) {
+            bytes = compress(&bytes);
+        }
+
+        // Opening the tree applies its pending coalesced insertions, so that they are not clobbered either.
+        Ok(self
+            .open_tree(TREE_MESSAGE_ID_TO_MESSAGE)?
+            .compare_and_swap(message_id, None as Option<&[u8]>, Some(bytes))?
+            .is_ok())
+    }
+}
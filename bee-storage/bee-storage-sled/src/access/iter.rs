@@ -22,303 +22,463 @@ use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
-use crate::{storage::Storage, trees::*};
+use crate::{
+    compression::decompress,
+    storage::{Error, Storage},
+    trees::*,
+};
 
 /// Type used to iterate a subtree.
 pub struct StorageIterator<'a, K, V> {
     inner: sled::Iter,
+    compressed: bool,
     marker: PhantomData<&'a (K, V)>,
 }
 
 impl<'a, K, V> StorageIterator<'a, K, V> {
-    fn new(inner: sled::Iter) -> Self {
+    fn new(inner: sled::Iter, compressed: bool) -> Self {
         StorageIterator::<K, V> {
             inner,
+            compressed,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Type used to iterate the keys of a subtree, without decoding the values.
+pub struct StorageKeysIterator<'a, K, V> {
+    inner: sled::Iter,
+    marker: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V> StorageKeysIterator<'a, K, V> {
+    fn new(inner: sled::Iter) -> Self {
+        StorageKeysIterator::<K, V> {
+            inner,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Type used to iterate the values of a subtree, without decoding the keys.
+pub struct StorageValuesIterator<'a, K, V> {
+    inner: sled::Iter,
+    compressed: bool,
+    marker: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V> StorageValuesIterator<'a, K, V> {
+    fn new(inner: sled::Iter, compressed: bool) -> Self {
+        StorageValuesIterator::<K, V> {
+            inner,
+            compressed,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Type used to iterate the raw key-value pairs of a subtree, without decoding them.
+///
+/// The values of a compressed subtree are yielded compressed.
+pub struct StorageRawIterator {
+    inner: sled::Iter,
+    compressed: bool,
+}
+
+impl StorageRawIterator {
+    /// Decodes the key-value pairs of the subtree as `(K, V)`, only when they are yielded.
+    pub fn decode<K, V>(self) -> StorageDecodeIterator<K, V> {
+        StorageDecodeIterator {
+            inner: self,
             marker: PhantomData,
         }
     }
 }
 
+impl Iterator for StorageRawIterator {
+    type Item = Result<(Box<[u8]>, Box<[u8]>), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|result| {
+            result
+                .map(|(key, value)| (Box::from(key.as_ref()), Box::from(value.as_ref())))
+                .map_err(From::from)
+        })
+    }
+}
+
+/// Type used to decode the raw key-value pairs of a subtree.
+pub struct StorageDecodeIterator<K, V> {
+    inner: StorageRawIterator,
+    marker: PhantomData<(K, V)>,
+}
+
+impl Storage {
+    /// Iterates the raw key-value pairs of a tree, leaving their decoding to the consumer.
+    pub fn iter_raw(&self, tree: &str) -> Result<StorageRawIterator, Error> {
+        Ok(StorageRawIterator {
+            inner: self.open_tree(tree)?.iter(),
+            compressed: self.is_compressed(tree),
+        })
+    }
+}
+
 macro_rules! impl_iter {
     ($key:ty, $value:ty, $cf:expr) => {
         impl<'a> AsIterator<'a, $key, $value> for Storage {
             type AsIter = StorageIterator<'a, $key, $value>;
+            type AsKeysIter = StorageKeysIterator<'a, $key, $value>;
+            type AsValuesIter = StorageValuesIterator<'a, $key, $value>;
 
             fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
-                Ok(StorageIterator::new(self.inner.open_tree($cf)?.iter()))
+                Ok(StorageIterator::new(
+                    self.open_tree($cf)?.iter(),
+                    self.is_compressed($cf),
+                ))
+            }
+
+            fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+                Ok(StorageKeysIterator::new(self.open_tree($cf)?.iter()))
+            }
+
+            fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+                Ok(StorageValuesIterator::new(
+                    self.open_tree($cf)?.iter(),
+                    self.is_compressed($cf),
+                ))
             }
         }
 
+        impl_iterators!($key, $value);
+    };
+}
+
+macro_rules! impl_iterators {
+    ($key:ty, $value:ty) => {
         /// An iterator over all key-value pairs of a column family.
         impl<'a> Iterator for StorageIterator<'a, $key, $value> {
             type Item = Result<($key, $value), <Storage as StorageBackend>::Error>;
 
+            fn next(&mut self) -> Option<Self::Item> {
+                let compressed = self.compressed;
+
+                self.inner.next().map(|result| {
+                    result.map_err(From::from).and_then(|(key, value)| {
+                        if compressed {
+                            Ok((Self::unpack_key(&key), Self::unpack_value(&decompress(&value)?)))
+                        } else {
+                            Ok((Self::unpack_key(&key), Self::unpack_value(&value)))
+                        }
+                    })
+                })
+            }
+        }
+
+        /// An iterator decoding the raw key-value pairs of a column family.
+        impl Iterator for StorageDecodeIterator<$key, $value> {
+            type Item = Result<($key, $value), <Storage as StorageBackend>::Error>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let compressed = self.inner.compressed;
+
+                self.inner.next().map(|result| {
+                    result.and_then(|(key, value)| {
+                        if compressed {
+                            Ok((
+                                StorageIterator::<$key, $value>::unpack_key(&key),
+                                StorageIterator::<$key, $value>::unpack_value(&decompress(&value)?),
+                            ))
+                        } else {
+                            Ok((
+                                StorageIterator::<$key, $value>::unpack_key(&key),
+                                StorageIterator::<$key, $value>::unpack_value(&value),
+                            ))
+                        }
+                    })
+                })
+            }
+        }
+
+        /// An iterator over all keys of a column family.
+        impl<'a> Iterator for StorageKeysIterator<'a, $key, $value> {
+            type Item = Result<$key, <Storage as StorageBackend>::Error>;
+
             fn next(&mut self) -> Option<Self::Item> {
                 self.inner.next().map(|result| {
                     result
-                        .map(|(key, value)| Self::unpack_key_value(&key, &value))
+                        .map(|(key, _)| StorageIterator::<$key, $value>::unpack_key(&key))
                         .map_err(From::from)
                 })
+            }
+        }
 
-                // inner.status()?;
-                //
-                // if inner.valid() {
-                //     Poll::Ready(item)
-                // } else {
-                //     Poll::Ready(None)
-                // }
+        /// An iterator over all values of a column family.
+        impl<'a> Iterator for StorageValuesIterator<'a, $key, $value> {
+            type Item = Result<$value, <Storage as StorageBackend>::Error>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let compressed = self.compressed;
+
+                self.inner.next().map(|result| {
+                    result.map_err(From::from).and_then(|(_, value)| {
+                        if compressed {
+                            Ok(StorageIterator::<$key, $value>::unpack_value(&decompress(&value)?))
+                        } else {
+                            Ok(StorageIterator::<$key, $value>::unpack_value(&value))
+                        }
+                    })
+                })
             }
         }
     };
 }
 
 impl<'a> StorageIterator<'a, u8, System> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (u8, System) {
-        (
-            // Unpacking from storage is fine.
-            u8::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            System::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> u8 {
+        // Unpacking from storage is fine.
+        u8::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> System {
+        // Unpacking from storage is fine.
+        System::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, MessageId, Message> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (MessageId, Message) {
-        (
-            // Unpacking from storage is fine.
-            MessageId::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            Message::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> MessageId {
+        // Unpacking from storage is fine.
+        MessageId::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> Message {
+        // Unpacking from storage is fine.
+        Message::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, MessageId, MessageMetadata> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (MessageId, MessageMetadata) {
-        (
-            // Unpacking from storage is fine.
-            MessageId::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            MessageMetadata::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> MessageId {
+        // Unpacking from storage is fine.
+        MessageId::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> MessageMetadata {
+        // Unpacking from storage is fine.
+        MessageMetadata::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, (MessageId, MessageId), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((MessageId, MessageId), ()) {
+    fn unpack_key(key: &[u8]) -> (MessageId, MessageId) {
         let (mut parent, mut child) = key.split_at(MESSAGE_ID_LENGTH);
 
         (
-            (
-                // Unpacking from storage is fine.
-                MessageId::unpack_unchecked(&mut parent).unwrap(),
-                // Unpacking from storage is fine.
-                MessageId::unpack_unchecked(&mut child).unwrap(),
-            ),
-            (),
+            // Unpacking from storage is fine.
+            MessageId::unpack_unchecked(&mut parent).unwrap(),
+            // Unpacking from storage is fine.
+            MessageId::unpack_unchecked(&mut child).unwrap(),
         )
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> StorageIterator<'a, (PaddedIndex, MessageId), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((PaddedIndex, MessageId), ()) {
+    fn unpack_key(key: &[u8]) -> (PaddedIndex, MessageId) {
         let (index, mut message_id) = key.split_at(INDEXATION_PADDED_INDEX_LENGTH);
         // Unpacking from storage is fine.
         let index: [u8; INDEXATION_PADDED_INDEX_LENGTH] = index.try_into().unwrap();
 
         (
-            (
-                PaddedIndex::new(index),
-                // Unpacking from storage is fine.
-                MessageId::unpack_unchecked(&mut message_id).unwrap(),
-            ),
-            (),
+            PaddedIndex::new(index),
+            // Unpacking from storage is fine.
+            MessageId::unpack_unchecked(&mut message_id).unwrap(),
         )
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> StorageIterator<'a, OutputId, CreatedOutput> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (OutputId, CreatedOutput) {
-        (
-            // Unpacking from storage is fine.
-            OutputId::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            CreatedOutput::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> OutputId {
+        // Unpacking from storage is fine.
+        OutputId::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> CreatedOutput {
+        // Unpacking from storage is fine.
+        CreatedOutput::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, OutputId, ConsumedOutput> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (OutputId, ConsumedOutput) {
-        (
-            // Unpacking from storage is fine.
-            OutputId::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            ConsumedOutput::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> OutputId {
+        // Unpacking from storage is fine.
+        OutputId::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> ConsumedOutput {
+        // Unpacking from storage is fine.
+        ConsumedOutput::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, Unspent, ()> {
-    fn unpack_key_value(mut key: &[u8], _: &[u8]) -> (Unspent, ()) {
-        (
-            // Unpacking from storage is fine.
-            Unspent::unpack_unchecked(&mut key).unwrap(),
-            (),
-        )
+    fn unpack_key(mut key: &[u8]) -> Unspent {
+        // Unpacking from storage is fine.
+        Unspent::unpack_unchecked(&mut key).unwrap()
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> StorageIterator<'a, (Ed25519Address, OutputId), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((Ed25519Address, OutputId), ()) {
+    fn unpack_key(key: &[u8]) -> (Ed25519Address, OutputId) {
         let (mut address, mut output_id) = key.split_at(MESSAGE_ID_LENGTH);
 
         (
-            (
-                // Unpacking from storage is fine.
-                Ed25519Address::unpack_unchecked(&mut address).unwrap(),
-                // Unpacking from storage is fine.
-                OutputId::unpack_unchecked(&mut output_id).unwrap(),
-            ),
-            (),
+            // Unpacking from storage is fine.
+            Ed25519Address::unpack_unchecked(&mut address).unwrap(),
+            // Unpacking from storage is fine.
+            OutputId::unpack_unchecked(&mut output_id).unwrap(),
         )
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> StorageIterator<'a, (), LedgerIndex> {
-    fn unpack_key_value(_: &[u8], mut value: &[u8]) -> ((), LedgerIndex) {
-        (
-            (),
-            // Unpacking from storage is fine.
-            LedgerIndex::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(_: &[u8]) {}
+
+    fn unpack_value(mut value: &[u8]) -> LedgerIndex {
+        // Unpacking from storage is fine.
+        LedgerIndex::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, MilestoneIndex, Milestone> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (MilestoneIndex, Milestone) {
-        (
-            // Unpacking from storage is fine.
-            MilestoneIndex::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            Milestone::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> MilestoneIndex {
+        // Unpacking from storage is fine.
+        MilestoneIndex::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> Milestone {
+        // Unpacking from storage is fine.
+        Milestone::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, (), SnapshotInfo> {
-    fn unpack_key_value(_: &[u8], mut value: &[u8]) -> ((), SnapshotInfo) {
-        (
-            (),
-            // Unpacking from storage is fine.
-            SnapshotInfo::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(_: &[u8]) {}
+
+    fn unpack_value(mut value: &[u8]) -> SnapshotInfo {
+        // Unpacking from storage is fine.
+        SnapshotInfo::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, SolidEntryPoint, MilestoneIndex> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (SolidEntryPoint, MilestoneIndex) {
-        (
-            // Unpacking from storage is fine.
-            SolidEntryPoint::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            MilestoneIndex::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> SolidEntryPoint {
+        // Unpacking from storage is fine.
+        SolidEntryPoint::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> MilestoneIndex {
+        // Unpacking from storage is fine.
+        MilestoneIndex::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, MilestoneIndex, OutputDiff> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (MilestoneIndex, OutputDiff) {
-        (
-            // Unpacking from storage is fine.
-            MilestoneIndex::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            OutputDiff::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> MilestoneIndex {
+        // Unpacking from storage is fine.
+        MilestoneIndex::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> OutputDiff {
+        // Unpacking from storage is fine.
+        OutputDiff::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, Address, Balance> {
-    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (Address, Balance) {
-        (
-            // Unpacking from storage is fine.
-            Address::unpack_unchecked(&mut key).unwrap(),
-            // Unpacking from storage is fine.
-            Balance::unpack_unchecked(&mut value).unwrap(),
-        )
+    fn unpack_key(mut key: &[u8]) -> Address {
+        // Unpacking from storage is fine.
+        Address::unpack_unchecked(&mut key).unwrap()
+    }
+
+    fn unpack_value(mut value: &[u8]) -> Balance {
+        // Unpacking from storage is fine.
+        Balance::unpack_unchecked(&mut value).unwrap()
     }
 }
 
 impl<'a> StorageIterator<'a, (MilestoneIndex, UnreferencedMessage), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((MilestoneIndex, UnreferencedMessage), ()) {
+    fn unpack_key(key: &[u8]) -> (MilestoneIndex, UnreferencedMessage) {
         let (mut index, mut unreferenced_message) = key.split_at(std::mem::size_of::<MilestoneIndex>());
 
         (
-            (
-                // Unpacking from storage is fine.
-                MilestoneIndex::unpack_unchecked(&mut index).unwrap(),
-                // Unpacking from storage is fine.
-                UnreferencedMessage::unpack_unchecked(&mut unreferenced_message).unwrap(),
-            ),
-            (),
+            // Unpacking from storage is fine.
+            MilestoneIndex::unpack_unchecked(&mut index).unwrap(),
+            // Unpacking from storage is fine.
+            UnreferencedMessage::unpack_unchecked(&mut unreferenced_message).unwrap(),
         )
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> StorageIterator<'a, (MilestoneIndex, Receipt), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((MilestoneIndex, Receipt), ()) {
+    fn unpack_key(key: &[u8]) -> (MilestoneIndex, Receipt) {
         let (mut index, mut receipt) = key.split_at(std::mem::size_of::<MilestoneIndex>());
 
         (
-            (
-                // Unpacking from storage is fine.
-                MilestoneIndex::unpack_unchecked(&mut index).unwrap(),
-                // Unpacking from storage is fine.
-                Receipt::unpack_unchecked(&mut receipt).unwrap(),
-            ),
-            (),
+            // Unpacking from storage is fine.
+            MilestoneIndex::unpack_unchecked(&mut index).unwrap(),
+            // Unpacking from storage is fine.
+            Receipt::unpack_unchecked(&mut receipt).unwrap(),
         )
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> StorageIterator<'a, (bool, TreasuryOutput), ()> {
-    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((bool, TreasuryOutput), ()) {
+    fn unpack_key(key: &[u8]) -> (bool, TreasuryOutput) {
         let (mut index, mut receipt) = key.split_at(std::mem::size_of::<bool>());
 
         (
-            (
-                // Unpacking from storage is fine.
-                bool::unpack_unchecked(&mut index).unwrap(),
-                // Unpacking from storage is fine.
-                TreasuryOutput::unpack_unchecked(&mut receipt).unwrap(),
-            ),
-            (),
+            // Unpacking from storage is fine.
+            bool::unpack_unchecked(&mut index).unwrap(),
+            // Unpacking from storage is fine.
+            TreasuryOutput::unpack_unchecked(&mut receipt).unwrap(),
         )
     }
+
+    fn unpack_value(_: &[u8]) {}
 }
 
 impl<'a> AsIterator<'a, u8, System> for Storage {
     type AsIter = StorageIterator<'a, u8, System>;
+    type AsKeysIter = StorageKeysIterator<'a, u8, System>;
+    type AsValuesIter = StorageValuesIterator<'a, u8, System>;
 
     fn iter(&'a self) -> Result<Self::AsIter, <Self as StorageBackend>::Error> {
-        Ok(StorageIterator::new(self.inner.iter()))
+        Ok(StorageIterator::new(self.inner.iter(), false))
     }
-}
 
-/// An iterator over all key-value pairs of a column family.
-impl<'a> Iterator for StorageIterator<'a, u8, System> {
-    type Item = Result<(u8, System), <Storage as StorageBackend>::Error>;
+    fn iter_keys(&'a self) -> Result<Self::AsKeysIter, <Self as StorageBackend>::Error> {
+        Ok(StorageKeysIterator::new(self.inner.iter()))
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|result| {
-            result
-                .map(|(key, value)| Self::unpack_key_value(&key, &value))
-                .map_err(From::from)
-        })
+    fn iter_values(&'a self) -> Result<Self::AsValuesIter, <Self as StorageBackend>::Error> {
+        Ok(StorageValuesIterator::new(self.inner.iter(), false))
     }
 }
 
+impl_iterators!(u8, System);
+
 impl_iter!(MessageId, Message, TREE_MESSAGE_ID_TO_MESSAGE);
 impl_iter!(MessageId, MessageMetadata, TREE_MESSAGE_ID_TO_METADATA);
 impl_iter!((MessageId, MessageId), (), TREE_MESSAGE_ID_TO_MESSAGE_ID);
@@ -16,27 +16,88 @@ use bee_message::{
 use bee_storage::{access::MultiFetch, backend::StorageBackend, system::System};
 use bee_tangle::{metadata::MessageMetadata, solid_entry_point::SolidEntryPoint};
 
-use crate::{storage::Storage, trees::*};
+use crate::{
+    compression::decompress,
+    storage::{Error, Storage},
+    trees::*,
+};
+
+fn unpack_value<V: Packable>(mut bytes: &[u8], compressed: bool) -> Result<V, Error> {
+    if compressed {
+        // Unpacking from storage is fine.
+        Ok(V::unpack_unchecked(&mut decompress(bytes)?.as_slice()).unwrap())
+    } else {
+        // Unpacking from storage is fine.
+        Ok(V::unpack_unchecked(&mut bytes).unwrap())
+    }
+}
 
 /// Multi-fetch iterator over an inner tree.
 pub struct TreeIter<'a, K, V, E> {
     tree: sled::Tree,
     keys: Iter<'a, K>,
+    compressed: bool,
+    #[cfg(feature = "parallel")]
+    fetched: Option<std::vec::IntoIter<Result<Option<V>, E>>>,
     marker: PhantomData<(V, E)>,
 }
 
-impl<'a, K: Packable, V: Packable, E: From<sled::Error>> Iterator for TreeIter<'a, K, V, E> {
+impl<'a, K, V, E> TreeIter<'a, K, V, E> {
+    fn new(storage: &Storage, tree: &str, keys: &'a [K]) -> Result<Self, Error> {
+        Ok(Self {
+            tree: storage.open_tree(tree)?,
+            keys: keys.iter(),
+            compressed: storage.is_compressed(tree),
+            #[cfg(feature = "parallel")]
+            fetched: None,
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<'a, K: Packable + Sync, V: Packable + Send, E: From<sled::Error> + From<Error> + Send> TreeIter<'a, K, V, E> {
+    /// Fetches all the remaining keys upfront across the threads of the `rayon` pool, keeping their order.
+    fn fetch_parallel(mut self) -> Self {
+        use rayon::prelude::*;
+
+        let (tree, compressed) = (&self.tree, self.compressed);
+
+        self.fetched = Some(
+            self.keys
+                .as_slice()
+                .par_iter()
+                .map(|key| {
+                    tree.get(key.pack_new())
+                        .map_err(E::from)?
+                        .map(|bytes| unpack_value(&bytes, compressed).map_err(E::from))
+                        .transpose()
+                })
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+        self.keys = [].iter();
+
+        self
+    }
+}
+
+impl<'a, K: Packable, V: Packable, E: From<sled::Error> + From<Error>> Iterator for TreeIter<'a, K, V, E> {
     type Item = Result<Option<V>, E>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(feature = "parallel")]
+        if let Some(fetched) = &mut self.fetched {
+            return fetched.next();
+        }
+
         let key = self.keys.next()?.pack_new();
 
-        Some(
-            self.tree
-                .get(key)
-                .map(|option| option.map(|bytes| V::unpack_unchecked(&mut bytes.as_ref()).unwrap()))
-                .map_err(E::from),
-        )
+        Some(self.tree.get(key).map_err(E::from).and_then(|option| {
+            option
+                .map(|bytes| unpack_value(&bytes, self.compressed).map_err(E::from))
+                .transpose()
+        }))
     }
 }
 
@@ -80,11 +141,14 @@ macro_rules! impl_multi_fetch {
             type Iter = TreeIter<'a, $key, $value, <Self as StorageBackend>::Error>;
 
             fn multi_fetch(&'a self, keys: &'a [$key]) -> Result<Self::Iter, <Self as StorageBackend>::Error> {
-                Ok(TreeIter {
-                    tree: self.inner.open_tree($cf)?,
-                    keys: keys.iter(),
-                    marker: PhantomData,
-                })
+                let iter = TreeIter::new(self, $cf, keys)?;
+
+                #[cfg(feature = "parallel")]
+                if keys.len() >= self.config.storage.parallel_multi_fetch_threshold {
+                    return Ok(iter.fetch_parallel());
+                }
+
+                Ok(iter)
             }
         }
     };
@@ -24,10 +24,15 @@ use crate::{storage::Storage, trees::*};
 macro_rules! impl_truncate {
     ($key:ty, $value:ty, $cf:expr) => {
         impl Truncate<$key, $value> for Storage {
-            fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
+            fn truncate(&self) -> Result<usize, <Self as StorageBackend>::Error> {
+                self.check_writable()?;
+
+                let count = self.open_tree($cf)?.len();
+
                 self.inner.drop_tree($cf)?;
+                self.invalidate_cached($cf);
 
-                Ok(())
+                Ok(count)
             }
         }
     };
@@ -12,8 +12,9 @@ use crate::{storage::Storage, trees::*};
 
 impl Update<MessageId, MessageMetadata> for Storage {
     fn update(&self, message_id: &MessageId, mut f: impl FnMut(&mut MessageMetadata)) -> Result<(), Self::Error> {
-        self.inner
-            .open_tree(TREE_MESSAGE_ID_TO_METADATA)?
+        self.check_writable()?;
+
+        self.open_tree(TREE_MESSAGE_ID_TO_METADATA)?
             .fetch_and_update(message_id, move |opt_bytes| {
                 opt_bytes.map(|mut bytes| {
                     // Unpacking from storage is fine.
@@ -0,0 +1,108 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Read cache of the single-row trees.
+//!
+//! When enabled through `StorageConfig`, the value of a cached tree is kept in memory after it was first fetched and
+//! every write to the tree drops it. Each write also bumps the generation of the tree, so that a fetch that raced with
+//! a write never caches the value it read before the write.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
+
+use sled::IVec;
+
+use crate::{
+    storage::{Error, Storage},
+    trees::{TREE_LEDGER_INDEX, TREE_SNAPSHOT_INFO},
+};
+
+/// The trees that can be cached, they only ever hold a single row.
+pub(crate) const CACHEABLE_TREES: &[&str] = &[TREE_LEDGER_INDEX, TREE_SNAPSHOT_INFO];
+
+#[derive(Default)]
+struct CachedRow {
+    generation: u64,
+    row: Option<(IVec, Option<IVec>)>,
+}
+
+/// Cache of the rows of the single-row trees.
+pub(crate) struct ReadCache {
+    rows: Mutex<HashMap<&'static str, CachedRow>>,
+}
+
+impl ReadCache {
+    /// Creates a new `ReadCache` for the given trees, the ones that are not cacheable are ignored.
+    pub(crate) fn new(trees: &[String]) -> Self {
+        let rows = CACHEABLE_TREES
+            .iter()
+            .filter(|tree| trees.iter().any(|name| name == *tree))
+            .map(|tree| (*tree, CachedRow::default()))
+            .collect();
+
+        Self { rows: Mutex::new(rows) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<&'static str, CachedRow>> {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        self.rows.lock().unwrap()
+    }
+
+    /// Returns the cached value of a row, or fetches and caches it unless the tree was written in the meantime.
+    fn get_or_fetch(
+        &self,
+        tree: &str,
+        key: &[u8],
+        fetch: impl FnOnce() -> Result<Option<IVec>, Error>,
+    ) -> Result<Option<IVec>, Error> {
+        let generation = match self.lock().get(tree) {
+            Some(CachedRow {
+                row: Some((cached_key, value)),
+                ..
+            }) if cached_key == key => return Ok(value.clone()),
+            Some(cached) => cached.generation,
+            None => return fetch(),
+        };
+
+        let value = fetch()?;
+
+        if let Some(cached) = self.lock().get_mut(tree) {
+            if cached.generation == generation {
+                cached.row = Some((key.into(), value.clone()));
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn invalidate(&self, tree: &str) {
+        if let Some(cached) = self.lock().get_mut(tree) {
+            cached.generation = cached.generation.wrapping_add(1);
+            cached.row = None;
+        }
+    }
+}
+
+impl Storage {
+    /// Fetches a value from a tree, going through the read cache if the tree is cached.
+    pub(crate) fn get_cached(
+        &self,
+        tree: &str,
+        key: impl AsRef<[u8]>,
+        fetch: impl FnOnce() -> Result<Option<IVec>, Error>,
+    ) -> Result<Option<IVec>, Error> {
+        match &self.read_cache {
+            Some(cache) => cache.get_or_fetch(tree, key.as_ref(), fetch),
+            None => fetch(),
+        }
+    }
+
+    /// Drops the cached value of a tree, it must be called once a write to the tree completed.
+    pub(crate) fn invalidate_cached(&self, tree: &str) {
+        if let Some(cache) = &self.read_cache {
+            cache.invalidate(tree);
+        }
+    }
+}
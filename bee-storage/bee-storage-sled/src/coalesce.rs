@@ -0,0 +1,209 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Coalescing of the individual insertions into batches.
+//!
+//! When enabled through `StorageConfig`, the values written by `Insert` are buffered and applied to their tree as a
+//! single `sled::Batch`, either when the configured window elapses or when the configured number of insertions is
+//! pending. Point reads look the buffered values up first and every other access to a tree applies its pending
+//! insertions beforehand, so that the buffering is never observable.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex, MutexGuard,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use sled::IVec;
+
+use crate::storage::{Error, Storage};
+
+#[derive(Default)]
+struct PendingWrites {
+    trees: HashMap<&'static str, BTreeMap<IVec, IVec>>,
+    len: usize,
+}
+
+impl PendingWrites {
+    fn apply(&mut self, db: &sled::Db, tree: &str) -> Result<(), Error> {
+        if let Some(writes) = self.trees.get(tree) {
+            let mut batch = sled::Batch::default();
+
+            for (key, value) in writes {
+                batch.insert(key, value);
+            }
+
+            db.open_tree(tree)?.apply_batch(batch)?;
+
+            // The writes are only forgotten once they have been applied, a failed batch is tried again later.
+            self.len -= writes.len();
+            self.trees.remove(tree);
+        }
+
+        Ok(())
+    }
+
+    fn apply_all(&mut self, db: &sled::Db) -> Result<(), Error> {
+        let trees = self.trees.keys().copied().collect::<Vec<_>>();
+
+        for tree in trees {
+            self.apply(db, tree)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Shared {
+    db: sled::Db,
+    pending: Mutex<PendingWrites>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, PendingWrites> {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        self.pending.lock().unwrap()
+    }
+}
+
+/// Buffer of the insertions that were not applied to the database yet.
+pub(crate) struct WriteBuffer {
+    shared: Arc<Shared>,
+    threshold: usize,
+    stop: Option<Sender<()>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl WriteBuffer {
+    /// Creates a new `WriteBuffer` applying the pending insertions every `window` and whenever `threshold` insertions
+    /// are pending.
+    pub(crate) fn new(db: sled::Db, window: Duration, threshold: usize) -> Self {
+        let shared = Arc::new(Shared {
+            db,
+            pending: Mutex::new(PendingWrites::default()),
+        });
+        let (stop, stopped) = mpsc::channel();
+        let worker = {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                // The worker stops when the buffer is dropped, which disconnects the channel.
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(window) {
+                    // An error leaves the writes pending, it is reported by the next access to the tree.
+                    drop(shared.lock().apply_all(&shared.db));
+                }
+
+                // The insertions still pending when the storage is dropped without being shut down are written too.
+                let mut pending = shared.lock();
+                if pending.apply_all(&shared.db).is_ok() {
+                    drop(shared.db.flush());
+                }
+            })
+        };
+
+        Self {
+            shared,
+            threshold: threshold.max(1),
+            stop: Some(stop),
+            worker: Some(worker),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PendingWrites> {
+        self.shared.lock()
+    }
+}
+
+impl Drop for WriteBuffer {
+    fn drop(&mut self) {
+        // The worker is joined so that it doesn't keep the database open once the storage is dropped.
+        drop(self.stop.take());
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Storage {
+    /// Opens a tree after applying its pending insertions.
+    pub(crate) fn open_tree(&self, tree: &str) -> Result<sled::Tree, Error> {
+        if let Some(buffer) = &self.write_buffer {
+            buffer.lock().apply(&self.inner, tree)?;
+        }
+
+        Ok(self.inner.open_tree(tree)?)
+    }
+
+    /// Inserts a value in a tree, or buffers the insertion if coalescing is enabled.
+    pub(crate) fn insert_coalesced(
+        &self,
+        tree: &'static str,
+        key: impl AsRef<[u8]>,
+        value: impl AsRef<[u8]>,
+    ) -> Result<(), Error> {
+        match &self.write_buffer {
+            Some(buffer) => {
+                let mut pending = buffer.lock();
+                let writes = pending.trees.entry(tree).or_default();
+
+                if writes.insert(key.as_ref().into(), value.as_ref().into()).is_none() {
+                    pending.len += 1;
+                }
+
+                if pending.len >= buffer.threshold {
+                    pending.apply_all(&self.inner)?;
+                }
+            }
+            None => {
+                self.inner.open_tree(tree)?.insert(key.as_ref(), value.as_ref())?;
+            }
+        }
+
+        self.invalidate_cached(tree);
+
+        Ok(())
+    }
+
+    /// Fetches a value from a tree, looking the read cache and then the pending insertions up first.
+    pub(crate) fn get(&self, tree: &str, key: impl AsRef<[u8]>) -> Result<Option<IVec>, Error> {
+        let key = key.as_ref();
+
+        self.get_cached(tree, key, || {
+            if let Some(buffer) = &self.write_buffer {
+                if let Some(value) = buffer.lock().trees.get(tree).and_then(|writes| writes.get(key)) {
+                    return Ok(Some(value.clone()));
+                }
+            }
+
+            Ok(self.inner.open_tree(tree)?.get(key)?)
+        })
+    }
+
+    /// Checks if a key exists in a tree, looking the pending insertions up first.
+    pub(crate) fn contains_key(&self, tree: &str, key: impl AsRef<[u8]>) -> Result<bool, Error> {
+        if let Some(buffer) = &self.write_buffer {
+            if buffer
+                .lock()
+                .trees
+                .get(tree)
+                .is_some_and(|writes| writes.contains_key(key.as_ref()))
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(self.inner.open_tree(tree)?.contains_key(key)?)
+    }
+
+    /// Applies all the pending insertions.
+    pub(crate) fn flush_writes(&self) -> Result<(), Error> {
+        if let Some(buffer) = &self.write_buffer {
+            buffer.lock().apply_all(&self.inner)?;
+        }
+
+        Ok(())
+    }
+}
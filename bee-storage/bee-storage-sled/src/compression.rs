@@ -0,0 +1,34 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compression of the values stored in the message tree.
+//!
+//! When enabled through `StorageConfig`, messages are packed and then compressed with zstd before being written to
+//! the `TREE_MESSAGE_ID_TO_MESSAGE` tree, and decompressed before being unpacked when read back.
+
+use crate::{
+    storage::{Error, Storage},
+    trees::TREE_MESSAGE_ID_TO_MESSAGE,
+};
+
+/// Zstd compression level used for the stored values, `0` means the zstd default.
+const COMPRESSION_LEVEL: i32 = 0;
+
+impl Storage {
+    /// Returns whether the values of the given tree are stored compressed.
+    pub(crate) fn is_compressed(&self, tree: &str) -> bool {
+        self.config.storage.compression && tree == TREE_MESSAGE_ID_TO_MESSAGE
+    }
+}
+
+/// Compresses a packed value before it is written to storage.
+pub(crate) fn compress(bytes: &[u8]) -> Vec<u8> {
+    // Compressing an in-memory buffer can't fail.
+    zstd::encode_all(bytes, COMPRESSION_LEVEL).unwrap()
+}
+
+/// Decompresses a value read from storage before it is unpacked.
+/// Fails with `Error::Decompression` if the value is corrupted or truncated.
+pub(crate) fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    zstd::decode_all(bytes).map_err(Error::Decompression)
+}
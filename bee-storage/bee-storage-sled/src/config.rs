@@ -3,19 +3,48 @@
 
 //! Types related to the backend configuration.
 
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use serde::Deserialize;
 
+use crate::{cache::CACHEABLE_TREES, storage::Error};
+
 const DEFAULT_PATH: &str = "./storage/mainnet/tangle";
 const DEFAULT_COMPRESSION_FACTOR: Option<usize> = None;
 const DEFAULT_CACHE_CAPACITY: usize = 1_024 * 1_024 * 1_024;
 const DEFAULT_FAST_MODE: bool = false;
+const DEFAULT_FLUSH_EVERY_MS: Option<u64> = Some(500);
 const DEFAULT_TEMPORARY: bool = false;
 const DEFAULT_CREATE_NEW: bool = false;
 const DEFAULT_FETCH_EDGE_LIMIT: usize = 1_000;
 const DEFAULT_FETCH_INDEX_LIMIT: usize = 1_000;
 const DEFAULT_FETCH_OUTPUT_ID_LIMIT: usize = 1_000;
+const DEFAULT_COMPRESSION: bool = false;
+const DEFAULT_WRITE_COALESCING_WINDOW_MS: u64 = 0;
+const DEFAULT_WRITE_COALESCING_THRESHOLD: usize = 1_000;
+const DEFAULT_PARALLEL_MULTI_FETCH_THRESHOLD: usize = 1_000;
+const DEFAULT_READ_ONLY: bool = false;
+const DEFAULT_STRICT_DECODING: bool = false;
+
+/// Mode of the sled database, trading disk space for write throughput.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+pub enum SledMode {
+    /// Keeps the database small on disk. This is the default.
+    #[serde(alias = "lowSpace")]
+    LowSpace,
+    /// Writes faster at the cost of more disk space.
+    #[serde(alias = "highThroughput")]
+    HighThroughput,
+}
+
+impl From<SledMode> for sled::Mode {
+    fn from(mode: SledMode) -> Self {
+        match mode {
+            SledMode::LowSpace => sled::Mode::LowSpace,
+            SledMode::HighThroughput => sled::Mode::HighThroughput,
+        }
+    }
+}
 
 /// Configuration for the sled storage backend.
 #[derive(Clone)]
@@ -24,7 +53,8 @@ pub struct SledConfig {
     pub(crate) path: PathBuf,
     pub(crate) compression_factor: Option<usize>,
     pub(crate) cache_capacity: usize,
-    pub(crate) fast_mode: bool,
+    pub(crate) mode: SledMode,
+    pub(crate) flush_every_ms: Option<u64>,
     pub(crate) temporary: bool,
     pub(crate) create_new: bool,
 }
@@ -41,6 +71,9 @@ pub struct SledConfigBuilder {
     cache_capacity: Option<usize>,
     #[serde(alias = "fastMode")]
     fast_mode: Option<bool>,
+    mode: Option<SledMode>,
+    #[serde(alias = "flushEveryMs")]
+    flush_every_ms: Option<Option<u64>>,
     temporary: Option<bool>,
     #[serde(alias = "createNew")]
     create_new: Option<bool>,
@@ -52,6 +85,12 @@ impl SledConfigBuilder {
         Self::default()
     }
 
+    /// Set the configuration related to the access operations of the storage.
+    pub fn with_storage(mut self, storage: StorageConfigBuilder) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
     /// Set the path where the database will be stored.
     pub fn with_path(mut self, path: String) -> Self {
         self.path = Some(path.into());
@@ -59,24 +98,39 @@ impl SledConfigBuilder {
     }
 
     /// Set the compression factor for zstd, it must be an integer between 1 and 22. Do not use
-    /// compression if the factor is `None`,
+    /// compression if the factor is `None`, which is the default.
     pub fn with_compression_factor(mut self, compression_factor: Option<usize>) -> Self {
         self.compression_factor = Some(compression_factor);
         self
     }
 
-    /// Set the page cache maximum capacity in bytes.
+    /// Set the page cache maximum capacity in bytes, 1GiB by default. It must not be `0`.
     pub fn with_cache_capacity(mut self, cache_capacity: usize) -> Self {
         self.cache_capacity = Some(cache_capacity);
         self
     }
 
     /// Specify if the database should priorize speed (true) or size (false).
+    ///
+    /// Overridden by `with_sled_mode`.
     pub fn with_mode(mut self, fast: bool) -> Self {
         self.fast_mode = Some(fast);
         self
     }
 
+    /// Set the mode of the database, `SledMode::LowSpace` by default.
+    pub fn with_sled_mode(mut self, mode: SledMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Set the interval, in milliseconds, at which the dirty pages are flushed to disk, 500ms by default. Do not
+    /// flush periodically if the interval is `None`, it must not be `0` otherwise.
+    pub fn with_flush_every_ms(mut self, flush_every_ms: Option<u64>) -> Self {
+        self.flush_every_ms = Some(flush_every_ms);
+        self
+    }
+
     /// Set the database to be deleted after `Storage` is dropped.
     pub fn with_temporary(mut self, temporary: bool) -> Self {
         self.temporary = Some(temporary);
@@ -98,13 +152,50 @@ impl SledConfigBuilder {
             path: self.path.unwrap_or_else(|| DEFAULT_PATH.into()),
             compression_factor: self.compression_factor.unwrap_or(DEFAULT_COMPRESSION_FACTOR),
             cache_capacity: self.cache_capacity.unwrap_or(DEFAULT_CACHE_CAPACITY),
-            fast_mode: self.fast_mode.unwrap_or(DEFAULT_FAST_MODE),
+            mode: self.mode.unwrap_or_else(|| {
+                if self.fast_mode.unwrap_or(DEFAULT_FAST_MODE) {
+                    SledMode::HighThroughput
+                } else {
+                    SledMode::LowSpace
+                }
+            }),
+            flush_every_ms: self.flush_every_ms.unwrap_or(DEFAULT_FLUSH_EVERY_MS),
             temporary: self.temporary.unwrap_or(DEFAULT_TEMPORARY),
             create_new: self.create_new.unwrap_or(DEFAULT_CREATE_NEW),
         }
     }
 }
 
+impl SledConfig {
+    /// Checks that the configuration values are in their valid ranges.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.cache_capacity == 0 {
+            return Err(Error::InvalidConfig("the cache capacity must not be 0"));
+        }
+        if self
+            .compression_factor
+            .is_some_and(|factor| !(1..=22).contains(&factor))
+        {
+            return Err(Error::InvalidConfig("the compression factor must be between 1 and 22"));
+        }
+        if self.flush_every_ms == Some(0) {
+            return Err(Error::InvalidConfig("the flush interval must not be 0"));
+        }
+        if self
+            .storage
+            .read_cache_trees
+            .iter()
+            .any(|tree| !CACHEABLE_TREES.contains(&tree.as_str()))
+        {
+            return Err(Error::InvalidConfig(
+                "only the ledger index and snapshot info trees can be read cached",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl From<SledConfigBuilder> for SledConfig {
     fn from(builder: SledConfigBuilder) -> Self {
         builder.finish()
@@ -117,6 +208,14 @@ pub struct StorageConfig {
     pub(crate) fetch_edge_limit: usize,
     pub(crate) fetch_index_limit: usize,
     pub(crate) fetch_output_id_limit: usize,
+    pub(crate) compression: bool,
+    pub(crate) write_coalescing_window: Option<Duration>,
+    pub(crate) write_coalescing_threshold: usize,
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    pub(crate) parallel_multi_fetch_threshold: usize,
+    pub(crate) read_only: bool,
+    pub(crate) read_cache_trees: Vec<String>,
+    pub(crate) strict_decoding: bool,
 }
 
 /// Configuration builder related to the access operations of the storage.
@@ -129,6 +228,19 @@ pub struct StorageConfigBuilder {
     fetch_index_limit: Option<usize>,
     #[serde(alias = "fetchOutputIdLimit")]
     fetch_output_id_limit: Option<usize>,
+    compression: Option<bool>,
+    #[serde(alias = "writeCoalescingWindowMs")]
+    write_coalescing_window_ms: Option<u64>,
+    #[serde(alias = "writeCoalescingThreshold")]
+    write_coalescing_threshold: Option<usize>,
+    #[serde(alias = "parallelMultiFetchThreshold")]
+    parallel_multi_fetch_threshold: Option<usize>,
+    #[serde(alias = "readOnly")]
+    read_only: Option<bool>,
+    #[serde(alias = "readCacheTrees")]
+    read_cache_trees: Option<Vec<String>>,
+    #[serde(alias = "strictDecoding")]
+    strict_decoding: Option<bool>,
 }
 
 impl StorageConfigBuilder {
@@ -137,13 +249,88 @@ impl StorageConfigBuilder {
         Self::default()
     }
 
+    /// Specify if the stored messages should be compressed with zstd.
+    ///
+    /// This only applies to the message tree and is independent from the page compression set by
+    /// `SledConfigBuilder::with_compression_factor`. The setting is stored when the database is created and
+    /// starting an existing database with a different one fails.
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Set the window, in milliseconds, during which the insertions are buffered before being written as a single
+    /// batch. Insertions are written right away if the window is `0`.
+    pub fn with_write_coalescing_window_ms(mut self, window_ms: u64) -> Self {
+        self.write_coalescing_window_ms = Some(window_ms);
+        self
+    }
+
+    /// Set the number of buffered insertions above which they are written without waiting for the window to elapse.
+    pub fn with_write_coalescing_threshold(mut self, threshold: usize) -> Self {
+        self.write_coalescing_threshold = Some(threshold);
+        self
+    }
+
+    /// Set the number of keys from which a multi-fetch is spread across the threads of the `rayon` pool.
+    ///
+    /// Smaller multi-fetches are always serial as the pool overhead outweighs the gain. This has no effect unless the
+    /// `parallel` feature is enabled.
+    pub fn with_parallel_multi_fetch_threshold(mut self, threshold: usize) -> Self {
+        self.parallel_multi_fetch_threshold = Some(threshold);
+        self
+    }
+
+    /// Specify if the storage should be opened in read-only mode.
+    ///
+    /// All the write operations then fail with `Error::ReadOnly` and neither the version, the compression mode nor
+    /// the health of the storage folder are written. As sled locks its folder, the folder is copied into a temporary
+    /// one which is opened instead, so that the folder of a running node can be inspected. The storage then sees the
+    /// data flushed to the folder when it was opened, and the copy takes as much disk space as the folder.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    /// Set the trees whose value is kept in memory once fetched, none by default.
+    ///
+    /// Only the single-row trees, `TREE_LEDGER_INDEX` and `TREE_SNAPSHOT_INFO`, can be cached. A cached value is
+    /// dropped by every write to its tree, so fetches never return stale data.
+    pub fn with_read_cache_trees(mut self, trees: Vec<String>) -> Self {
+        self.read_cache_trees = Some(trees);
+        self
+    }
+
+    /// Specify if the fetched values should be checked and fail with `Error::TrailingBytes` if they don't take their
+    /// whole stored buffer, e.g. to track a corruption down. Otherwise they are trusted, which is faster.
+    pub fn with_strict_decoding(mut self, strict_decoding: bool) -> Self {
+        self.strict_decoding = Some(strict_decoding);
+        self
+    }
+
     /// Build the configuration.
     #[must_use]
     pub fn finish(self) -> StorageConfig {
+        let write_coalescing_window_ms = self
+            .write_coalescing_window_ms
+            .unwrap_or(DEFAULT_WRITE_COALESCING_WINDOW_MS);
+
         StorageConfig {
             fetch_edge_limit: self.fetch_edge_limit.unwrap_or(DEFAULT_FETCH_EDGE_LIMIT),
             fetch_index_limit: self.fetch_index_limit.unwrap_or(DEFAULT_FETCH_INDEX_LIMIT),
             fetch_output_id_limit: self.fetch_output_id_limit.unwrap_or(DEFAULT_FETCH_OUTPUT_ID_LIMIT),
+            compression: self.compression.unwrap_or(DEFAULT_COMPRESSION),
+            write_coalescing_window: (write_coalescing_window_ms != 0)
+                .then(|| Duration::from_millis(write_coalescing_window_ms)),
+            write_coalescing_threshold: self
+                .write_coalescing_threshold
+                .unwrap_or(DEFAULT_WRITE_COALESCING_THRESHOLD),
+            parallel_multi_fetch_threshold: self
+                .parallel_multi_fetch_threshold
+                .unwrap_or(DEFAULT_PARALLEL_MULTI_FETCH_THRESHOLD),
+            read_only: self.read_only.unwrap_or(DEFAULT_READ_ONLY),
+            read_cache_trees: self.read_cache_trees.unwrap_or_default(),
+            strict_decoding: self.strict_decoding.unwrap_or(DEFAULT_STRICT_DECODING),
         }
     }
 }
@@ -0,0 +1,88 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Consistency checks of the stored ledger, e.g. after a crash in the middle of a batch.
+
+use bee_ledger::types::{CreatedOutput, Unspent};
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+};
+use bee_storage::access::{AsIterator, Exist};
+
+use crate::storage::{Error, Storage};
+
+/// An invariant of the stored ledger that doesn't hold.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Inconsistency {
+    /// An output is marked as unspent but was never created.
+    UnspentWithoutCreatedOutput(OutputId),
+    /// The ledger index is ahead of the latest stored milestone.
+    LedgerIndexAheadOfMilestones {
+        /// The ledger index.
+        ledger_index: MilestoneIndex,
+        /// The index of the latest stored milestone.
+        latest_milestone_index: MilestoneIndex,
+    },
+}
+
+/// The inconsistencies found by `Storage::verify_consistency`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConsistencyReport {
+    inconsistencies: Vec<Inconsistency>,
+}
+
+impl ConsistencyReport {
+    /// Returns whether no inconsistency was found.
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+
+    /// Returns the inconsistencies that were found.
+    pub fn inconsistencies(&self) -> &[Inconsistency] {
+        &self.inconsistencies
+    }
+}
+
+impl Storage {
+    /// Cross-checks the invariants of the stored ledger and reports the ones that don't hold, without repairing
+    /// anything. It reads every unspent output and milestone, so it is meant to be run at startup.
+    ///
+    /// The ledger index is only checked against the stored milestones if there are some, a storage loaded from a
+    /// snapshot has a ledger index but no milestone yet.
+    pub fn verify_consistency(&self) -> Result<ConsistencyReport, Error> {
+        let mut report = ConsistencyReport::default();
+
+        for unspent in AsIterator::<Unspent, ()>::iter_keys(self)? {
+            let output_id = *unspent?;
+
+            if !Exist::<OutputId, CreatedOutput>::exist(self, &output_id)? {
+                report
+                    .inconsistencies
+                    .push(Inconsistency::UnspentWithoutCreatedOutput(output_id));
+            }
+        }
+
+        if let Some(ledger_index) = self.get_ledger_index()? {
+            // Milestone indexes are not stored in their numerical order, all of them are read.
+            let mut latest_milestone_index = None;
+
+            for index in AsIterator::<MilestoneIndex, Milestone>::iter_keys(self)? {
+                latest_milestone_index = latest_milestone_index.max(Some(index?));
+            }
+
+            if let Some(latest_milestone_index) = latest_milestone_index {
+                if ledger_index > latest_milestone_index {
+                    report
+                        .inconsistencies
+                        .push(Inconsistency::LedgerIndexAheadOfMilestones {
+                            ledger_index,
+                            latest_milestone_index,
+                        });
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decoding of the fetched values.
+
+use bee_common::packable::Packable;
+
+use crate::storage::{Error, Storage};
+
+impl Storage {
+    /// Unpacks a fetched value.
+    ///
+    /// With strict decoding, the value is checked and must take the whole buffer. Otherwise it is trusted as is and
+    /// any trailing byte is ignored.
+    pub(crate) fn decode<V: Packable>(&self, mut bytes: &[u8]) -> Result<V, Error> {
+        if !self.config.storage.strict_decoding {
+            // Unpacking from storage is fine.
+            return Ok(V::unpack_unchecked(&mut bytes).unwrap());
+        }
+
+        let value = V::unpack(&mut bytes).map_err(|e| Error::InvalidValue(format!("{:?}", e)))?;
+
+        if !bytes.is_empty() {
+            return Err(Error::TrailingBytes(bytes.len()));
+        }
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Checksummed export and import of a tree.
+//!
+//! An export starts with whether the values of the tree are compressed, followed by every entry of the tree as a
+//! `1` tag and the length-prefixed key and value, a `0` tag and finally the CRC32 checksum of all the previous bytes.
+//! All the integers are encoded with `Packable`.
+
+use std::io::{Read, Write};
+
+use bee_common::packable::Packable;
+use crc32fast::Hasher;
+
+use crate::storage::{Error, Storage};
+
+const ENTRY_TAG: u8 = 1;
+const END_TAG: u8 = 0;
+
+/// Writer computing the checksum of the written bytes.
+struct ChecksumWriter<W> {
+    inner: W,
+    hasher: Hasher,
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader computing the checksum of the read bytes.
+struct ChecksumReader<R> {
+    inner: R,
+    hasher: Hasher,
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<(), Error> {
+    (bytes.len() as u64).pack(writer)?;
+    writer.write_all(bytes)?;
+
+    Ok(())
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>, Error> {
+    let len = u64::unpack(reader)?;
+    let mut bytes = Vec::new();
+
+    // Reading through `take` doesn't trust the length to allocate the buffer upfront.
+    if reader.take(len).read_to_end(&mut bytes)? as u64 != len {
+        return Err(Error::Io(std::io::ErrorKind::UnexpectedEof.into()));
+    }
+
+    Ok(bytes)
+}
+
+impl Storage {
+    /// Exports all the entries of a tree to a writer, followed by their checksum, and returns how many entries were
+    /// exported.
+    pub fn export_tree<W: Write>(&self, tree: &str, writer: W) -> Result<usize, Error> {
+        let mut writer = ChecksumWriter {
+            inner: writer,
+            hasher: Hasher::new(),
+        };
+        let mut count = 0;
+
+        self.is_compressed(tree).pack(&mut writer)?;
+
+        for result in self.open_tree(tree)?.iter() {
+            let (key, value) = result?;
+
+            ENTRY_TAG.pack(&mut writer)?;
+            write_bytes(&mut writer, &key)?;
+            write_bytes(&mut writer, &value)?;
+            count += 1;
+        }
+
+        END_TAG.pack(&mut writer)?;
+
+        let ChecksumWriter { mut inner, hasher } = writer;
+
+        hasher.finalize().pack(&mut inner)?;
+        inner.flush()?;
+
+        Ok(count)
+    }
+
+    /// Imports the entries exported by `export_tree` from a reader into a tree and returns how many entries were
+    /// imported.
+    ///
+    /// The entries are buffered until the checksum is verified, nothing is written if the import is corrupted.
+    pub fn import_tree<R: Read>(&self, tree: &str, reader: R) -> Result<usize, Error> {
+        self.check_writable()?;
+
+        let mut reader = ChecksumReader {
+            inner: reader,
+            hasher: Hasher::new(),
+        };
+        let mut batch = sled::Batch::default();
+        let mut count = 0;
+
+        let compressed = bool::unpack(&mut reader)?;
+        if compressed != self.is_compressed(tree) {
+            return Err(Error::CompressionMismatch {
+                stored: compressed,
+                configured: self.is_compressed(tree),
+            });
+        }
+
+        loop {
+            match u8::unpack(&mut reader)? {
+                ENTRY_TAG => {
+                    let key = read_bytes(&mut reader)?;
+                    let value = read_bytes(&mut reader)?;
+
+                    batch.insert(key, value);
+                    count += 1;
+                }
+                END_TAG => break,
+                tag => return Err(Error::InvalidExportTag(tag)),
+            }
+        }
+
+        let ChecksumReader { mut inner, hasher } = reader;
+        let computed = hasher.finalize();
+        let expected = u32::unpack(&mut inner)?;
+
+        if expected != computed {
+            return Err(Error::ChecksumMismatch { expected, computed });
+        }
+
+        self.open_tree(tree)?.apply_batch(batch)?;
+        self.invalidate_cached(tree);
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Background flushing of the batches committed with `Durability::Async`.
+//!
+//! A single worker thread flushes the database whenever it is requested to. Requests made while a flush is pending
+//! are merged into it, and a failed flush is kept until the next commit or flush reports it.
+
+use std::{
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, SyncSender},
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::storage::Error;
+
+/// Worker flushing the database in the background.
+pub(crate) struct Flusher {
+    request: Option<SyncSender<()>>,
+    error: Arc<Mutex<Option<sled::Error>>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Flusher {
+    /// Creates a new `Flusher` of the given database.
+    pub(crate) fn new(db: sled::Db) -> Self {
+        let (request, requested) = mpsc::sync_channel(1);
+        let error = Arc::new(Mutex::new(None));
+        let worker = {
+            let error = error.clone();
+            thread::spawn(move || {
+                // The worker stops when the flusher is dropped, which disconnects the channel.
+                while requested.recv().is_ok() {
+                    if let Err(e) = db.flush() {
+                        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+                        *error.lock().unwrap() = Some(e);
+                    }
+                }
+            })
+        };
+
+        Self {
+            request: Some(request),
+            error,
+            worker: Some(worker),
+        }
+    }
+
+    /// Requests a flush, without waiting for it.
+    pub(crate) fn request(&self) {
+        if let Some(request) = &self.request {
+            // A full channel means a flush is already pending, it will include the latest writes.
+            let _ = request.try_send(());
+        }
+    }
+
+    /// Returns the error of the last failed background flush, if it was not reported yet.
+    pub(crate) fn take_error(&self) -> Result<(), Error> {
+        // Panic: the lock is never held across a panicking operation, the mutex can't be poisoned.
+        match self.error.lock().unwrap().take() {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Flusher {
+    fn drop(&mut self) {
+        // The worker is joined so that it doesn't keep the database open once the storage is dropped.
+        drop(self.request.take());
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
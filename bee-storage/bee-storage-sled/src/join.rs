@@ -0,0 +1,106 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sorted-merge join of two trees sharing their key type.
+
+use std::cmp::Ordering;
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{ConsumedOutput, CreatedOutput};
+use bee_message::output::OutputId;
+use bee_storage::access::AsIterator;
+
+use crate::storage::{Error, Storage};
+
+/// An output identifier along with its created and consumed states, if stored.
+pub type OutputState = (OutputId, Option<CreatedOutput>, Option<ConsumedOutput>);
+
+/// Iterator joining two iterators of (key, value) pairs on their key, created by `stream_join`.
+pub struct StreamJoin<L, R, K, A, B> {
+    left: L,
+    right: R,
+    left_head: Option<(K, A)>,
+    right_head: Option<(K, B)>,
+}
+
+/// Joins two iterators of (key, value) pairs on their key, yielding every key of either iterator once along with its
+/// value in each of them.
+///
+/// Both iterators must yield their keys in the byte order of the packed keys, as the trees of the storage do, or the
+/// same key may be yielded twice.
+pub fn stream_join<L, R, K, A, B, E>(left: L, right: R) -> StreamJoin<L, R, K, A, B>
+where
+    L: Iterator<Item = Result<(K, A), E>>,
+    R: Iterator<Item = Result<(K, B), E>>,
+    K: Packable,
+{
+    StreamJoin {
+        left,
+        right,
+        left_head: None,
+        right_head: None,
+    }
+}
+
+fn fill<I, K, V, E>(iter: &mut I, head: &mut Option<(K, V)>) -> Result<(), E>
+where
+    I: Iterator<Item = Result<(K, V), E>>,
+{
+    if head.is_none() {
+        *head = iter.next().transpose()?;
+    }
+
+    Ok(())
+}
+
+impl<L, R, K, A, B, E> Iterator for StreamJoin<L, R, K, A, B>
+where
+    L: Iterator<Item = Result<(K, A), E>>,
+    R: Iterator<Item = Result<(K, B), E>>,
+    K: Packable,
+{
+    type Item = Result<(K, Option<A>, Option<B>), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = fill(&mut self.left, &mut self.left_head) {
+            return Some(Err(e));
+        }
+        if let Err(e) = fill(&mut self.right, &mut self.right_head) {
+            return Some(Err(e));
+        }
+
+        let order = match (&self.left_head, &self.right_head) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some((left, _)), Some((right, _))) => left.pack_new().cmp(&right.pack_new()),
+        };
+
+        // Panic: the heads that are taken were matched as present right above.
+        Some(Ok(match order {
+            Ordering::Less => {
+                let (key, a) = self.left_head.take().unwrap();
+                (key, Some(a), None)
+            }
+            Ordering::Greater => {
+                let (key, b) = self.right_head.take().unwrap();
+                (key, None, Some(b))
+            }
+            Ordering::Equal => {
+                let (key, a) = self.left_head.take().unwrap();
+                let (_, b) = self.right_head.take().unwrap();
+                (key, Some(a), Some(b))
+            }
+        }))
+    }
+}
+
+impl Storage {
+    /// Iterates over the stored outputs, joining their created and consumed states in a single pass over both trees.
+    pub fn iter_output_states(&self) -> Result<impl Iterator<Item = Result<OutputState, Error>> + '_, Error> {
+        Ok(stream_join(
+            AsIterator::<OutputId, CreatedOutput>::iter(self)?,
+            AsIterator::<OutputId, ConsumedOutput>::iter(self)?,
+        ))
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Guarded access to the ledger index.
+
+use bee_ledger::types::LedgerIndex;
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::access::{CompareAndSwap, Fetch, Insert};
+
+use crate::storage::{Error, Storage};
+
+impl Storage {
+    /// Fetches the ledger index, `None` if no ledger was stored yet.
+    pub fn get_ledger_index(&self) -> Result<Option<MilestoneIndex>, Error> {
+        Ok(Fetch::<(), LedgerIndex>::fetch(self, &())?.map(|index| index.0))
+    }
+
+    /// Sets the ledger index unconditionally, e.g. when importing a snapshot.
+    pub fn set_ledger_index(&self, index: MilestoneIndex) -> Result<(), Error> {
+        Insert::<(), LedgerIndex>::insert(self, &(), &LedgerIndex(index))
+    }
+
+    /// Advances the ledger index to `to`, failing with `Error::LedgerIndexRegression` if it is below the current one.
+    ///
+    /// The ledger index is swapped atomically, so a concurrent update can't be overwritten by an older index.
+    pub fn advance_ledger_index(&self, to: MilestoneIndex) -> Result<(), Error> {
+        let new = LedgerIndex(to);
+
+        loop {
+            let current = Fetch::<(), LedgerIndex>::fetch(self, &())?;
+
+            if let Some(current) = current {
+                if new < current {
+                    return Err(Error::LedgerIndexRegression { current: current.0, to });
+                }
+            }
+
+            if CompareAndSwap::<(), LedgerIndex>::compare_and_swap(self, &(), current.as_ref(), Some(&new))? {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -6,7 +6,23 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+mod cache;
+mod coalesce;
+mod compression;
+mod decode;
+mod export;
+mod flush;
+mod ledger_index;
+mod prune;
+mod scan;
+mod snapshot;
+mod version;
+
 pub mod access;
 pub mod config;
+pub mod consistency;
+pub mod join;
+pub mod migration;
+pub mod solidity;
 pub mod storage;
 pub mod trees;
@@ -0,0 +1,96 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Migrations of the database between storage versions.
+
+use bee_storage::system::StorageVersion;
+
+use crate::storage::{Error, Storage, STORAGE_VERSION};
+
+type MigrationFn = Box<dyn Fn(&Storage) -> Result<(), Error> + Send + Sync>;
+
+struct Migration {
+    from: StorageVersion,
+    to: StorageVersion,
+    func: MigrationFn,
+}
+
+/// The set of migrations that can be applied to a database when it is started, and the storage version they lead
+/// to.
+pub struct Migrations {
+    version: StorageVersion,
+    migrations: Vec<Migration>,
+}
+
+impl Default for Migrations {
+    fn default() -> Self {
+        Self::new(STORAGE_VERSION)
+    }
+}
+
+impl Migrations {
+    /// Creates an empty set of migrations leading to the given storage version.
+    pub fn new(version: StorageVersion) -> Self {
+        Self {
+            version,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Returns the storage version the migrations lead to.
+    pub fn version(&self) -> StorageVersion {
+        self.version
+    }
+
+    /// Registers a migration of the database from the storage version `from` to the storage version `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to` is not strictly greater than `from` or if a migration from `from` was already registered.
+    pub fn register_migration<F>(&mut self, from: StorageVersion, to: StorageVersion, func: F) -> &mut Self
+    where
+        F: Fn(&Storage) -> Result<(), Error> + Send + Sync + 'static,
+    {
+        assert!(
+            from < to,
+            "Migration from {:?} to {:?} does not upgrade the storage.",
+            from,
+            to
+        );
+        assert!(
+            self.migrations.iter().all(|migration| migration.from != from),
+            "A migration from {:?} was already registered.",
+            from
+        );
+
+        self.migrations.push(Migration {
+            from,
+            to,
+            func: Box::new(func),
+        });
+        self
+    }
+
+    /// Runs the registered migrations in order, from the stored version up to the expected version.
+    /// The stored version is updated after every migration so that an interrupted upgrade resumes where it stopped.
+    pub(crate) fn migrate(&self, storage: &Storage, mut stored: StorageVersion) -> Result<(), Error> {
+        while stored < self.version {
+            let migration = self
+                .migrations
+                .iter()
+                .find(|migration| migration.from == stored && migration.to <= self.version)
+                .ok_or(Error::MissingMigration(stored, self.version))?;
+
+            (migration.func)(storage)?;
+
+            // The migrated rows are persisted before the version that acknowledges them.
+            storage.flush_writes()?;
+            storage.set_system_version(migration.to)?;
+            storage.inner.flush()?;
+
+            stored = migration.to;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pruning of the solid entry points and of the unreferenced messages.
+
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::access::{AsIterator, Batch, BatchBuilder, Durability};
+use bee_tangle::{solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage};
+
+use crate::storage::{Error, Storage};
+
+impl Storage {
+    /// Removes the solid entry points referenced by a milestone below `below` and returns how many were removed.
+    ///
+    /// The tree is streamed and the removals are applied as a single batch.
+    pub fn prune_solid_entry_points(&self, below: MilestoneIndex) -> Result<usize, Error> {
+        let mut batch = Self::batch_begin();
+        let mut count = 0;
+
+        for result in AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(self)? {
+            let (sep, index) = result?;
+
+            if index < below {
+                Batch::<SolidEntryPoint, MilestoneIndex>::batch_delete(self, &mut batch, &sep)?;
+                count += 1;
+            }
+        }
+
+        // Sled can't commit an empty batch.
+        if count > 0 {
+            self.batch_commit_with(batch, Durability::Async)?;
+        }
+
+        Ok(count)
+    }
+
+    /// Removes the unreferenced messages of the milestones below `below` and returns how many were removed.
+    ///
+    /// Milestone indices are encoded in little endian, so the keys are not ordered by index and the whole tree is
+    /// scanned, decoding the index prefix of each key.
+    pub fn prune_unreferenced_messages(&self, below: MilestoneIndex) -> Result<usize, Error> {
+        let mut batch = Self::batch_begin();
+        let mut count = 0;
+
+        for result in AsIterator::<(MilestoneIndex, UnreferencedMessage), ()>::iter_keys(self)? {
+            let key = result?;
+
+            if key.0 < below {
+                Batch::<(MilestoneIndex, UnreferencedMessage), ()>::batch_delete(self, &mut batch, &key)?;
+                count += 1;
+            }
+        }
+
+        // Sled can't commit an empty batch.
+        if count > 0 {
+            self.batch_commit_with(batch, Durability::Async)?;
+        }
+
+        Ok(count)
+    }
+}
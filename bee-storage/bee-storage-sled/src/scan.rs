@@ -0,0 +1,55 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Unbounded prefix scans of the trees keyed by pairs.
+
+use bee_message::{
+    address::{Ed25519Address, ED25519_ADDRESS_LENGTH},
+    output::{OutputId, OUTPUT_ID_LENGTH},
+    MessageId, MESSAGE_ID_LENGTH,
+};
+
+use crate::{
+    storage::{Error, Storage},
+    trees::{TREE_ED25519_ADDRESS_TO_OUTPUT_ID, TREE_MESSAGE_ID_TO_MESSAGE_ID},
+};
+
+impl Storage {
+    /// Iterates over the children of `parent`, i.e. the messages approving it.
+    ///
+    /// The edges are keyed by the parent followed by the child, so this is a prefix scan yielding the children
+    /// lazily. Unlike `Fetch<MessageId, Vec<MessageId>>`, the number of children is not limited.
+    pub fn children_of(&self, parent: &MessageId) -> Result<impl Iterator<Item = Result<MessageId, Error>>, Error> {
+        Ok(self
+            .open_tree(TREE_MESSAGE_ID_TO_MESSAGE_ID)?
+            .scan_prefix(parent)
+            .map(|result| {
+                let (key, _) = result?;
+                let (_, child) = key.split_at(MESSAGE_ID_LENGTH);
+                // Unpacking from storage is fine.
+                let child: [u8; MESSAGE_ID_LENGTH] = child.try_into().unwrap();
+                Ok(MessageId::from(child))
+            }))
+    }
+
+    /// Iterates over the identifiers of the outputs of an Ed25519 address.
+    ///
+    /// The outputs are keyed by the address followed by the output identifier, so this is a prefix scan yielding the
+    /// output identifiers lazily. Unlike `Fetch<Ed25519Address, Vec<OutputId>>`, their number is not limited.
+    pub fn output_ids_for_address(
+        &self,
+        address: &Ed25519Address,
+    ) -> Result<impl Iterator<Item = Result<OutputId, Error>>, Error> {
+        Ok(self
+            .open_tree(TREE_ED25519_ADDRESS_TO_OUTPUT_ID)?
+            .scan_prefix(address)
+            .map(|result| {
+                let (key, _) = result?;
+                let (_, output_id) = key.split_at(ED25519_ADDRESS_LENGTH);
+                // Unpacking from storage is fine.
+                Ok((<[u8; OUTPUT_ID_LENGTH]>::try_from(output_id).unwrap())
+                    .try_into()
+                    .unwrap())
+            }))
+    }
+}
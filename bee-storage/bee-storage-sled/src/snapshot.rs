@@ -0,0 +1,25 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Guarded access to the snapshot info.
+
+use bee_ledger::types::snapshot::SnapshotInfo;
+use bee_storage::access::Fetch;
+
+use crate::storage::{Error, Storage};
+
+impl Storage {
+    /// Fetches the snapshot info, failing with `Error::NetworkIdMismatch` if it belongs to another network than
+    /// `expected_network_id`.
+    ///
+    /// Returns `None` if no snapshot was imported yet.
+    pub fn load_snapshot_info(&self, expected_network_id: u64) -> Result<Option<SnapshotInfo>, Error> {
+        match Fetch::<(), SnapshotInfo>::fetch(self, &())? {
+            Some(info) if info.network_id() != expected_network_id => Err(Error::NetworkIdMismatch {
+                stored: info.network_id(),
+                expected: expected_network_id,
+            }),
+            info => Ok(info),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Solidity queries of the stored messages.
+
+use bee_message::{Message, MessageId};
+use bee_storage::access::{Exist, Fetch};
+use bee_tangle::metadata::MessageMetadata;
+
+use crate::storage::{Error, Storage};
+
+/// The solidification status of a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolidifyStatus {
+    /// The message itself is not stored.
+    Missing,
+    /// The message and all its ancestors are stored.
+    Solid,
+    /// The message is not solid yet.
+    Unsolid {
+        /// The parents of the message that are not stored.
+        missing_parents: Vec<MessageId>,
+    },
+}
+
+impl Storage {
+    /// Returns whether a message and all its ancestors are stored, according to the solid flag of its metadata.
+    pub fn is_solid(&self, message_id: &MessageId) -> Result<bool, Error> {
+        Ok(Fetch::<MessageId, MessageMetadata>::fetch(self, message_id)?
+            .is_some_and(|metadata| metadata.flags().is_solid()))
+    }
+
+    /// Returns the solidification status of a message, listing its parents that are not stored if it is not solid.
+    ///
+    /// An unsolid message may have no missing parent if one of its further ancestors is missing.
+    pub fn solidify_status(&self, message_id: &MessageId) -> Result<SolidifyStatus, Error> {
+        if self.is_solid(message_id)? {
+            return Ok(SolidifyStatus::Solid);
+        }
+
+        let message = match Fetch::<MessageId, Message>::fetch(self, message_id)? {
+            Some(message) => message,
+            None => return Ok(SolidifyStatus::Missing),
+        };
+
+        let mut missing_parents = Vec::new();
+
+        for parent in message.parents().iter() {
+            if !Exist::<MessageId, Message>::exist(self, parent)? {
+                missing_parents.push(*parent);
+            }
+        }
+
+        Ok(SolidifyStatus::Unsolid { missing_parents })
+    }
+}
@@ -3,14 +3,27 @@
 
 //! The sled storage backend.
 
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
     access::{Fetch, Insert},
     backend::StorageBackend,
-    system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
+    system::{StorageHealth, StorageVersion, System, SYSTEM_COMPRESSION_KEY, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
 };
 use thiserror::Error;
 
-use crate::config::{SledConfig, SledConfigBuilder};
+use crate::{
+    cache::ReadCache,
+    coalesce::WriteBuffer,
+    config::{SledConfig, SledConfigBuilder},
+    flush::Flusher,
+    migration::Migrations,
+};
 
 /// Error to be raised when a backend operation fails.
 #[derive(Debug, Error)]
@@ -22,9 +35,123 @@ pub enum Error {
     /// storage.
     #[error("Storage version mismatch, {0:?} != {1:?}, remove storage folder and restart")]
     VersionMismatch(StorageVersion, StorageVersion),
+    /// No registered migration upgrades the storage folder from its version.
+    #[error("No migration from storage version {0:?} to {1:?}, remove storage folder and restart")]
+    MissingMigration(StorageVersion, StorageVersion),
     /// The storage was not closed properly.
     #[error("Unhealthy storage: {0:?}, remove storage folder and restart")]
     UnhealthyStorage(StorageHealth),
+    /// An I/O operation failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// An imported tree doesn't match its checksum.
+    #[error("Checksum mismatch of the imported tree, expected {expected:#010x}, computed {computed:#010x}")]
+    ChecksumMismatch {
+        /// The checksum stored in the export.
+        expected: u32,
+        /// The checksum computed from the imported bytes.
+        computed: u32,
+    },
+    /// An imported tree contains an unknown tag.
+    #[error("Invalid tag {0} in the imported tree")]
+    InvalidExportTag(u8),
+    /// The messages of the storage folder are not compressed the way the configuration requires.
+    #[error("Storage compression mismatch, {stored} != {configured}, remove storage folder and restart")]
+    CompressionMismatch {
+        /// Whether the messages of the storage folder are compressed.
+        stored: bool,
+        /// Whether the configuration requires the messages to be compressed.
+        configured: bool,
+    },
+    /// A configuration value is out of its valid range.
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(&'static str),
+    /// A write operation was attempted on a storage opened in read-only mode.
+    #[error("Storage opened in read-only mode")]
+    ReadOnly,
+    /// The snapshot info of the storage folder belongs to another network.
+    #[error("Network id mismatch between storage and configuration, {stored} != {expected}, remove storage folder")]
+    NetworkIdMismatch {
+        /// The network id of the snapshot info.
+        stored: u64,
+        /// The network id of the configuration.
+        expected: u64,
+    },
+    /// The ledger index was requested to go backwards.
+    #[error("Ledger index regression from {current} to {to}")]
+    LedgerIndexRegression {
+        /// The current ledger index.
+        current: MilestoneIndex,
+        /// The requested ledger index.
+        to: MilestoneIndex,
+    },
+    /// A fetched value could not be unpacked, with strict decoding.
+    #[error("Invalid stored value: {0}")]
+    InvalidValue(String),
+    /// A fetched value has trailing bytes, with strict decoding.
+    #[error("{0} trailing bytes after the stored value")]
+    TrailingBytes(usize),
+    /// A fetched compressed value could not be decompressed.
+    #[error("Invalid compressed value: {0}")]
+    Decompression(std::io::Error),
+    /// The storage version was requested to go backwards.
+    #[error("Storage version regression from {current:?} to {to:?}")]
+    VersionRegression {
+        /// The current storage version.
+        current: StorageVersion,
+        /// The requested storage version.
+        to: StorageVersion,
+    },
+}
+
+/// Classification of an `Error`, telling transient failures from the ones that won't go away by retrying.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// A read or write on the file system failed, retrying may succeed.
+    Io,
+    /// The stored data is corrupted.
+    Corruption,
+    /// The file system is full.
+    Full,
+    /// Any other failure.
+    Other,
+}
+
+impl Error {
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sled(sled::Error::Io(error)) | Self::Io(error) => {
+                if is_storage_full(error) {
+                    ErrorKind::Full
+                } else {
+                    ErrorKind::Io
+                }
+            }
+            Self::Sled(sled::Error::Corruption { .. })
+            | Self::ChecksumMismatch { .. }
+            | Self::InvalidExportTag(_)
+            | Self::InvalidValue(_)
+            | Self::TrailingBytes(_)
+            | Self::Decompression(_) => ErrorKind::Corruption,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// Returns whether an I/O error was caused by a full file system, from its OS error code.
+fn is_storage_full(error: &io::Error) -> bool {
+    #[cfg(unix)]
+    const STORAGE_FULL_CODES: &[i32] = &[libc::ENOSPC];
+    // `ERROR_HANDLE_DISK_FULL` and `ERROR_DISK_FULL`.
+    #[cfg(windows)]
+    const STORAGE_FULL_CODES: &[i32] = &[39, 112];
+    #[cfg(not(any(unix, windows)))]
+    const STORAGE_FULL_CODES: &[i32] = &[];
+
+    error
+        .raw_os_error()
+        .is_some_and(|code| STORAGE_FULL_CODES.contains(&code))
 }
 
 pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(0);
@@ -33,60 +160,206 @@ pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion(0);
 pub struct Storage {
     pub(crate) inner: sled::Db,
     pub(crate) config: SledConfig,
+    pub(crate) write_buffer: Option<WriteBuffer>,
+    pub(crate) read_cache: Option<ReadCache>,
+    pub(crate) flusher: Flusher,
 }
 
 impl Storage {
     /// Create a new database from the provided configuration.
+    /// Fails with `Error::InvalidConfig` if a configuration value is out of its valid range.
     pub fn new(config: SledConfig) -> Result<Self, Error> {
+        config.validate()?;
+
+        // sled would otherwise create an empty database.
+        if config.storage.read_only && !config.temporary && !config.path.exists() {
+            return Err(Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "read-only storage folder does not exist",
+            )));
+        }
+
+        // sled locks its folder, a read-only storage opens a copy instead so that the folder of a running node can be
+        // inspected. The copy is removed with the storage.
+        let copy = if config.storage.read_only && !config.temporary {
+            Some(copy_folder(&config.path)?)
+        } else {
+            None
+        };
+
         let sled_cfg = sled::Config::default()
-            .path(&config.path)
+            .path(copy.as_ref().unwrap_or(&config.path))
             .cache_capacity(config.cache_capacity as u64)
-            .mode(if config.fast_mode {
-                sled::Mode::HighThroughput
-            } else {
-                sled::Mode::LowSpace
-            })
+            .mode(config.mode.into())
+            .flush_every_ms(config.flush_every_ms)
             .use_compression(config.compression_factor.is_some())
             .compression_factor(config.compression_factor.unwrap_or(1) as i32)
-            .temporary(config.temporary)
-            .create_new(!config.create_new);
+            .temporary(config.temporary || copy.is_some())
+            .create_new(!config.create_new && copy.is_none());
 
         let inner = sled_cfg.open()?;
+        let write_buffer = config
+            .storage
+            .write_coalescing_window
+            .map(|window| WriteBuffer::new(inner.clone(), window, config.storage.write_coalescing_threshold));
+        let read_cache =
+            (!config.storage.read_cache_trees.is_empty()).then(|| ReadCache::new(&config.storage.read_cache_trees));
+        let flusher = Flusher::new(inner.clone());
 
-        Ok(Self { inner, config })
+        Ok(Self {
+            inner,
+            config,
+            write_buffer,
+            read_cache,
+            flusher,
+        })
     }
-}
 
-impl StorageBackend for Storage {
-    type ConfigBuilder = SledConfigBuilder;
-    type Config = SledConfig;
-    type Error = Error;
-
-    fn start(config: Self::Config) -> Result<Self, Self::Error> {
+    /// Starts the database, running the given migrations if the storage folder has an older version than the one
+    /// they lead to.
+    /// Fails with `Error::VersionMismatch` if the storage folder has a newer version.
+    /// In read-only mode, nothing is migrated and the storage folder must already have the latest version.
+    pub fn start_with_migrations(config: SledConfig, migrations: &Migrations) -> Result<Self, Error> {
         let storage = Self::new(config)?;
+        let version = migrations.version();
 
-        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
-            Some(System::Version(version)) => {
-                if version != STORAGE_VERSION {
-                    return Err(Error::VersionMismatch(version, STORAGE_VERSION));
+        if storage.config.storage.read_only {
+            storage.check_read_only(version)?;
+            return Ok(storage);
+        }
+
+        let (stored, created) = match Fetch::<u8, System>::fetch(&storage, &SYSTEM_VERSION_KEY)? {
+            Some(System::Version(stored)) => {
+                if stored > version {
+                    return Err(Error::VersionMismatch(stored, version));
                 }
+                (stored, false)
+            }
+            None => {
+                Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(version))?;
+                (version, true)
             }
-            None => Insert::<u8, System>::insert(&storage, &SYSTEM_VERSION_KEY, &System::Version(STORAGE_VERSION))?,
             _ => panic!("Another system value was inserted on the version key."),
+        };
+
+        let configured = storage.config.storage.compression;
+
+        match Fetch::<u8, System>::fetch(&storage, &SYSTEM_COMPRESSION_KEY)? {
+            Some(System::Compression(stored)) => {
+                if stored != configured {
+                    return Err(Error::CompressionMismatch { stored, configured });
+                }
+            }
+            // Databases created before the compression mode was stored never compressed their messages.
+            None if !created && configured => {
+                return Err(Error::CompressionMismatch {
+                    stored: false,
+                    configured,
+                });
+            }
+            None => Insert::<u8, System>::insert(&storage, &SYSTEM_COMPRESSION_KEY, &System::Compression(configured))?,
+            _ => panic!("Another system value was inserted on the compression key."),
         }
 
         if let Some(health) = storage.get_health()? {
             if health != StorageHealth::Healthy {
-                return Err(Self::Error::UnhealthyStorage(health));
+                return Err(Error::UnhealthyStorage(health));
             }
         }
 
+        migrations.migrate(&storage, stored)?;
+
         storage.set_health(StorageHealth::Idle)?;
 
         Ok(storage)
     }
 
+    /// Checks that a storage folder opened in read-only mode can be read as is, since it can't be migrated.
+    /// The health is not checked as the folder may be a copy taken while a node was running.
+    fn check_read_only(&self, version: StorageVersion) -> Result<(), Error> {
+        match Fetch::<u8, System>::fetch(self, &SYSTEM_VERSION_KEY)? {
+            Some(System::Version(stored)) if stored > version => Err(Error::VersionMismatch(stored, version)),
+            Some(System::Version(stored)) if stored < version => Err(Error::MissingMigration(stored, version)),
+            // An empty database has nothing to migrate.
+            Some(System::Version(_)) | None => Ok(()),
+            _ => panic!("Another system value was inserted on the version key."),
+        }?;
+
+        let configured = self.config.storage.compression;
+
+        match Fetch::<u8, System>::fetch(self, &SYSTEM_COMPRESSION_KEY)? {
+            Some(System::Compression(stored)) if stored != configured => {
+                Err(Error::CompressionMismatch { stored, configured })
+            }
+            Some(System::Compression(_)) => Ok(()),
+            // Databases created before the compression mode was stored never compressed their messages.
+            None if configured => Err(Error::CompressionMismatch {
+                stored: false,
+                configured,
+            }),
+            None => Ok(()),
+            _ => panic!("Another system value was inserted on the compression key."),
+        }
+    }
+
+    /// Fails with `Error::ReadOnly` if the storage was opened in read-only mode.
+    pub(crate) fn check_writable(&self) -> Result<(), Error> {
+        if self.config.storage.read_only {
+            Err(Error::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Copies a storage folder into a new temporary folder and returns the path of the copy.
+fn copy_folder(from: &Path) -> Result<PathBuf, Error> {
+    fn copy(from: &Path, to: &Path) -> io::Result<()> {
+        fs::create_dir(to)?;
+
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            let to = to.join(entry.file_name());
+
+            if entry.file_type()?.is_dir() {
+                copy(&entry.path(), &to)?;
+            } else {
+                fs::copy(entry.path(), to)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    let to = std::env::temp_dir().join(format!("bee-storage-sled.read-only.{}.{}", std::process::id(), nanos));
+
+    if let Err(e) = copy(from, &to) {
+        let _ = fs::remove_dir_all(&to);
+        return Err(e.into());
+    }
+
+    Ok(to)
+}
+
+impl StorageBackend for Storage {
+    type ConfigBuilder = SledConfigBuilder;
+    type Config = SledConfig;
+    type Error = Error;
+
+    fn start(config: Self::Config) -> Result<Self, Self::Error> {
+        Self::start_with_migrations(config, &Migrations::default())
+    }
+
     fn shutdown(self) -> Result<(), Self::Error> {
+        if self.config.storage.read_only {
+            return Ok(());
+        }
+
+        self.flush_writes()?;
+        self.flusher.take_error()?;
         self.set_health(StorageHealth::Healthy)?;
         self.inner.flush()?;
         Ok(())
@@ -0,0 +1,47 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Guarded access to the storage version.
+
+use bee_storage::{
+    access::{CompareAndSwap, Fetch},
+    system::{StorageVersion, System, SYSTEM_VERSION_KEY},
+};
+
+use crate::storage::{Error, Storage};
+
+impl Storage {
+    /// Fetches the storage version of the database, `None` if it was never started in write mode.
+    pub fn system_version(&self) -> Result<Option<StorageVersion>, Error> {
+        Ok(match Fetch::<u8, System>::fetch(self, &SYSTEM_VERSION_KEY)? {
+            Some(System::Version(version)) => Some(version),
+            None => None,
+            _ => panic!("Another system value was inserted on the version key."),
+        })
+    }
+
+    /// Sets the storage version of the database, failing with `Error::VersionRegression` if it is below the current
+    /// one.
+    ///
+    /// The version is swapped atomically, so a concurrent update can't be overwritten by an older version.
+    pub fn set_system_version(&self, to: StorageVersion) -> Result<(), Error> {
+        let new = System::Version(to);
+
+        loop {
+            let current = Fetch::<u8, System>::fetch(self, &SYSTEM_VERSION_KEY)?;
+
+            match current {
+                Some(System::Version(current)) if to < current => {
+                    return Err(Error::VersionRegression { current, to });
+                }
+                Some(System::Version(_)) | None => {}
+                _ => panic!("Another system value was inserted on the version key."),
+            }
+
+            if CompareAndSwap::<u8, System>::compare_and_swap(self, &SYSTEM_VERSION_KEY, current.as_ref(), Some(&new))?
+            {
+                return Ok(());
+            }
+        }
+    }
+}
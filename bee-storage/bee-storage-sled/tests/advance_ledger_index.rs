@@ -0,0 +1,41 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::backend::StorageBackend;
+use bee_storage_sled::{
+    config::SledConfigBuilder,
+    storage::{Error, Storage},
+};
+
+#[test]
+fn advance_ledger_index() {
+    let path = String::from("./tests/database/advance_ledger_index");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    assert_eq!(storage.get_ledger_index().unwrap(), None);
+
+    storage.advance_ledger_index(MilestoneIndex(10)).unwrap();
+    assert_eq!(storage.get_ledger_index().unwrap(), Some(MilestoneIndex(10)));
+
+    storage.advance_ledger_index(MilestoneIndex(10)).unwrap();
+    storage.advance_ledger_index(MilestoneIndex(15)).unwrap();
+    assert_eq!(storage.get_ledger_index().unwrap(), Some(MilestoneIndex(15)));
+
+    assert!(matches!(
+        storage.advance_ledger_index(MilestoneIndex(14)),
+        Err(Error::LedgerIndexRegression {
+            current: MilestoneIndex(15),
+            to: MilestoneIndex(14)
+        })
+    ));
+    assert_eq!(storage.get_ledger_index().unwrap(), Some(MilestoneIndex(15)));
+
+    storage.set_ledger_index(MilestoneIndex(5)).unwrap();
+    assert_eq!(storage.get_ledger_index().unwrap(), Some(MilestoneIndex(5)));
+
+    let _ = std::fs::remove_dir_all(&path);
+}
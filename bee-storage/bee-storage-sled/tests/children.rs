@@ -0,0 +1,41 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::MessageId;
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_sled::{config::SledConfigBuilder, storage::Storage};
+use bee_test::rand::message::rand_message_id;
+
+#[test]
+fn children_of() {
+    let path = String::from("./tests/database/children_of");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    let parent = rand_message_id();
+    let mut children = (0..5).map(|_| rand_message_id()).collect::<Vec<_>>();
+
+    for child in &children {
+        Insert::<(MessageId, MessageId), ()>::insert(&storage, &(parent, *child), &()).unwrap();
+    }
+
+    for _ in 0..10 {
+        Insert::<(MessageId, MessageId), ()>::insert(&storage, &(rand_message_id(), rand_message_id()), &()).unwrap();
+    }
+
+    let mut fetched = storage
+        .children_of(&parent)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    children.sort();
+    fetched.sort();
+    assert_eq!(fetched, children);
+
+    assert_eq!(storage.children_of(&rand_message_id()).unwrap().count(), 0);
+
+    let _ = std::fs::remove_dir_all(&path);
+}
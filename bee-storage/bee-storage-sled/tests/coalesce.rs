@@ -0,0 +1,165 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::{sync::Arc, thread, time::Duration};
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{AsIterator, Exist, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder, StorageConfigBuilder},
+    storage::Storage,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+const WINDOW_MS: u64 = 50;
+
+fn config(path: &str) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_storage(
+            StorageConfigBuilder::default()
+                .with_write_coalescing_window_ms(WINDOW_MS)
+                .with_write_coalescing_threshold(64),
+        )
+        .finish()
+}
+
+#[test]
+fn coalesced_concurrent_inserts() {
+    let path = "./tests/database/coalesced_concurrent_inserts";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Arc::new(Storage::start(config(path)).unwrap());
+
+    let handles = (0..8)
+        .map(|_| {
+            let storage = storage.clone();
+
+            thread::spawn(move || {
+                (0..250)
+                    .map(|_| {
+                        let edge = (rand_message_id(), rand_message_id());
+
+                        Insert::<(MessageId, MessageId), ()>::insert(&*storage, &edge, &()).unwrap();
+                        // Reads see their own writes, even if they are still buffered.
+                        assert!(Exist::<(MessageId, MessageId), ()>::exist(&*storage, &edge).unwrap());
+
+                        edge
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let edges = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+
+    // Leave time to the window to elapse so that the remaining insertions are written in the background.
+    thread::sleep(Duration::from_millis(WINDOW_MS * 4));
+
+    for edge in &edges {
+        assert!(Exist::<(MessageId, MessageId), ()>::exist(&*storage, edge).unwrap());
+    }
+    assert_eq!(
+        AsIterator::<(MessageId, MessageId), ()>::iter(&*storage)
+            .unwrap()
+            .count(),
+        edges.len()
+    );
+
+    Arc::try_unwrap(storage).ok().unwrap().shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn coalesced_inserts_survive_restart() {
+    let path = "./tests/database/coalesced_inserts_survive_restart";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Storage::start(config(path)).unwrap();
+    let (message_id, message) = (rand_message_id(), rand_message());
+
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+    // The pending insertions are written on shutdown.
+    storage.shutdown().unwrap();
+
+    let storage = common::retry_locked(|| Storage::start(config(path))).unwrap();
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn coalesced_access() {
+    let path = "./tests/database/coalesced_access";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Storage::start(config(path)).unwrap();
+
+    bee_storage_test::message_id_to_message_access(&storage);
+    bee_storage_test::message_id_to_message_id_access(&storage);
+    bee_storage_test::output_id_to_created_output_access(&storage);
+    bee_storage_test::address_to_balance_access(&storage);
+    bee_storage_test::ed25519_address_to_output_id_access(&storage);
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn coalesced_inserts_survive_drop() {
+    let path = "./tests/database/coalesced_inserts_survive_drop";
+    let _ = std::fs::remove_dir_all(path);
+
+    // The window is long enough for the storage to be dropped before it elapses.
+    let config = || {
+        SledConfigBuilder::default()
+            .with_path(path.to_owned())
+            .with_create_new(true)
+            .with_storage(
+                StorageConfigBuilder::default()
+                    .with_write_coalescing_window_ms(60_000)
+                    .with_write_coalescing_threshold(64),
+            )
+            .finish()
+    };
+
+    let storage = Storage::start(config()).unwrap();
+    let (message_id, message) = (rand_message_id(), rand_message());
+
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+    // The pending insertions are written when the storage is dropped without being shut down.
+    drop(storage);
+
+    // The storage was not shut down and is not healthy, it is opened without the startup checks.
+    let storage = common::retry_locked(|| Storage::new(config())).unwrap();
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+    drop(storage);
+
+    let _ = std::fs::remove_dir_all(path);
+}
@@ -0,0 +1,21 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{thread, time::Duration};
+
+use bee_storage_sled::storage::Error;
+
+/// Runs `open` until the database is not locked anymore. The background threads of sled may briefly outlive a database
+/// that was shut down, and keep its lock in the meantime.
+pub fn retry_locked<T>(mut open: impl FnMut() -> Result<T, Error>) -> Result<T, Error> {
+    for _ in 0..50 {
+        match open() {
+            Err(Error::Sled(sled::Error::Io(e))) if e.to_string().starts_with("could not acquire lock") => {
+                thread::sleep(Duration::from_millis(20))
+            }
+            res => return res,
+        }
+    }
+
+    open()
+}
@@ -0,0 +1,151 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Durability, Fetch, Insert, MultiFetch},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfigBuilder, StorageConfigBuilder},
+    storage::{Error, Storage},
+    trees::TREE_MESSAGE_ID_TO_MESSAGE,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+#[test]
+fn compressed_message_round_trip() {
+    let path = String::from("./tests/database/compressed_message_round_trip");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default()
+        .with_path(path.clone())
+        .with_storage(StorageConfigBuilder::default().with_compression(true))
+        .finish();
+    let storage = Storage::start(config).unwrap();
+
+    let (message_id, message) = (rand_message_id(), rand_message());
+
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+
+    let (batch_message_id, batch_message) = (rand_message_id(), rand_message());
+    let mut batch = Storage::batch_begin();
+    Batch::<MessageId, Message>::batch_insert(&storage, &mut batch, &batch_message_id, &batch_message).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
+
+    let results = MultiFetch::<MessageId, Message>::multi_fetch(&storage, &[message_id, batch_message_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert!(matches!(results.first(), Some(Ok(Some(v))) if v == &message));
+    assert!(matches!(results.get(1), Some(Ok(Some(v))) if v == &batch_message));
+
+    let mut count = 0;
+
+    for result in AsIterator::<MessageId, Message>::iter(&storage).unwrap() {
+        let (id, stored) = result.unwrap();
+        if id == message_id {
+            assert_eq!(stored, message);
+        } else {
+            assert_eq!((id, stored), (batch_message_id, batch_message.clone()));
+        }
+        count += 1;
+    }
+
+    assert_eq!(count, 2);
+
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[test]
+fn compression_mismatch() {
+    let path = String::from("./tests/database/compression_mismatch");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let start = |compression| {
+        common::retry_locked(|| {
+            Storage::start(
+                SledConfigBuilder::default()
+                    .with_path(path.clone())
+                    .with_create_new(true)
+                    .with_storage(StorageConfigBuilder::default().with_compression(compression))
+                    .finish(),
+            )
+        })
+    };
+
+    start(true).unwrap().shutdown().unwrap();
+
+    assert!(matches!(
+        start(false),
+        Err(Error::CompressionMismatch {
+            stored: true,
+            configured: false
+        })
+    ));
+
+    start(true).unwrap().shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[test]
+fn corrupted_compressed_message() {
+    let path = String::from("./tests/database/corrupted_compressed_message");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let start = || {
+        common::retry_locked(|| {
+            Storage::start(
+                SledConfigBuilder::default()
+                    .with_path(path.clone())
+                    .with_create_new(true)
+                    .with_storage(StorageConfigBuilder::default().with_compression(true))
+                    .finish(),
+            )
+        })
+    };
+
+    let message_id = rand_message_id();
+
+    start().unwrap().shutdown().unwrap();
+
+    // Garbage is written in place of a compressed message, bypassing the storage.
+    {
+        let db = common::retry_locked(|| Ok(sled::open(&path)?)).unwrap();
+        db.open_tree(TREE_MESSAGE_ID_TO_MESSAGE)
+            .unwrap()
+            .insert(message_id, &[0xde, 0xad, 0xbe, 0xef][..])
+            .unwrap();
+        db.flush().unwrap();
+    }
+
+    let storage = start().unwrap();
+
+    assert!(matches!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id),
+        Err(Error::Decompression(_))
+    ));
+    assert!(matches!(storage.fetch_raw(&message_id), Err(Error::Decompression(_))));
+    assert!(matches!(
+        MultiFetch::<MessageId, Message>::multi_fetch(&storage, &[message_id])
+            .unwrap()
+            .next(),
+        Some(Err(Error::Decompression(_)))
+    ));
+    assert!(matches!(
+        AsIterator::<MessageId, Message>::iter(&storage).unwrap().next(),
+        Some(Err(Error::Decompression(_)))
+    ));
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
@@ -0,0 +1,71 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfigBuilder, SledMode, StorageConfigBuilder},
+    storage::{Error, Storage},
+    trees::TREE_MESSAGE_ID_TO_MESSAGE,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+#[test]
+fn tiny_cache_capacity() {
+    let path = "./tests/database/tiny_cache_capacity";
+    let _ = std::fs::remove_dir_all(path);
+
+    let config = SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_cache_capacity(1_024)
+        .with_sled_mode(SledMode::HighThroughput)
+        .with_flush_every_ms(Some(10))
+        .finish();
+    let storage = Storage::start(config).unwrap();
+
+    let messages = (0..100)
+        .map(|_| (rand_message_id(), rand_message()))
+        .collect::<Vec<_>>();
+
+    for (message_id, message) in &messages {
+        Insert::<MessageId, Message>::insert(&storage, message_id, message).unwrap();
+    }
+    for (message_id, message) in &messages {
+        assert_eq!(
+            Fetch::<MessageId, Message>::fetch(&storage, message_id)
+                .unwrap()
+                .as_ref(),
+            Some(message)
+        );
+    }
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn invalid_config() {
+    let path = "./tests/database/invalid_config";
+    let _ = std::fs::remove_dir_all(path);
+
+    for builder in [
+        SledConfigBuilder::default().with_cache_capacity(0),
+        SledConfigBuilder::default().with_compression_factor(Some(23)),
+        SledConfigBuilder::default().with_flush_every_ms(Some(0)),
+        SledConfigBuilder::default().with_storage(
+            StorageConfigBuilder::default().with_read_cache_trees(vec![TREE_MESSAGE_ID_TO_MESSAGE.to_owned()]),
+        ),
+    ] {
+        assert!(matches!(
+            Storage::start(builder.with_path(path.to_owned()).with_create_new(true).finish()),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    let _ = std::fs::remove_dir_all(path);
+}
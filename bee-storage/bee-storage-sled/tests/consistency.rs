@@ -0,0 +1,63 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::{CreatedOutput, Unspent};
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+};
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_sled::{
+    config::SledConfigBuilder,
+    consistency::{ConsistencyReport, Inconsistency},
+    storage::Storage,
+};
+use bee_test::rand::{
+    milestone::rand_milestone,
+    output::{rand_created_output, rand_output_id},
+};
+
+#[test]
+fn verify_consistency() {
+    let path = String::from("./tests/database/verify_consistency");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    assert!(storage.verify_consistency().unwrap().is_consistent());
+
+    let output_id = rand_output_id();
+    Insert::<OutputId, CreatedOutput>::insert(&storage, &output_id, &rand_created_output()).unwrap();
+    Insert::<Unspent, ()>::insert(&storage, &Unspent::from(output_id), &()).unwrap();
+    for index in [1, 2, 300] {
+        Insert::<MilestoneIndex, Milestone>::insert(&storage, &MilestoneIndex(index), &rand_milestone()).unwrap();
+    }
+    storage.set_ledger_index(MilestoneIndex(300)).unwrap();
+
+    assert_eq!(storage.verify_consistency().unwrap(), ConsistencyReport::default());
+
+    // A crash in the middle of a batch left an unspent output without its created output and moved the ledger index
+    // past the milestones.
+    let dangling_output_id = rand_output_id();
+    Insert::<Unspent, ()>::insert(&storage, &Unspent::from(dangling_output_id), &()).unwrap();
+    storage.set_ledger_index(MilestoneIndex(301)).unwrap();
+
+    let report = storage.verify_consistency().unwrap();
+
+    assert!(!report.is_consistent());
+    assert_eq!(
+        report.inconsistencies(),
+        &[
+            Inconsistency::UnspentWithoutCreatedOutput(dangling_output_id),
+            Inconsistency::LedgerIndexAheadOfMilestones {
+                ledger_index: MilestoneIndex(301),
+                latest_milestone_index: MilestoneIndex(300),
+            },
+        ]
+    );
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Batch, BatchBuilder, Durability, Fetch},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder},
+    storage::Storage,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+fn config(path: &str) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .finish()
+}
+
+#[test]
+fn batch_commit_durability() {
+    let path = "./tests/database/batch_commit_durability";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Storage::start(config(path)).unwrap();
+    let mut messages = Vec::new();
+
+    for durability in [Durability::None, Durability::Async, Durability::Sync] {
+        let (message_id, message) = (rand_message_id(), rand_message());
+        let mut batch = Storage::batch_begin();
+
+        Batch::<MessageId, Message>::batch_insert(&storage, &mut batch, &message_id, &message).unwrap();
+        storage.batch_commit_with(batch, durability).unwrap();
+
+        assert_eq!(
+            Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+                .unwrap()
+                .as_ref(),
+            Some(&message)
+        );
+
+        messages.push((message_id, message));
+    }
+
+    storage.shutdown().unwrap();
+
+    let storage = common::retry_locked(|| Storage::start(config(path))).unwrap();
+
+    for (message_id, message) in &messages {
+        assert_eq!(
+            Fetch::<MessageId, Message>::fetch(&storage, message_id)
+                .unwrap()
+                .as_ref(),
+            Some(message)
+        );
+    }
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+#[allow(deprecated)]
+fn batch_commit_bool() {
+    let path = "./tests/database/batch_commit_bool";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Storage::start(config(path)).unwrap();
+
+    for durability in [false, true] {
+        let (message_id, message) = (rand_message_id(), rand_message());
+        let mut batch = Storage::batch_begin();
+
+        Batch::<MessageId, Message>::batch_insert(&storage, &mut batch, &message_id, &message).unwrap();
+        storage.batch_commit(batch, durability).unwrap();
+
+        assert_eq!(
+            Fetch::<MessageId, Message>::fetch(&storage, &message_id).unwrap(),
+            Some(message)
+        );
+    }
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn batch_commit_async_many() {
+    let path = "./tests/database/batch_commit_async_many";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Storage::start(config(path)).unwrap();
+    let mut messages = Vec::new();
+
+    // The flushes requested while another one is pending are merged into it.
+    for _ in 0..1000 {
+        let (message_id, message) = (rand_message_id(), rand_message());
+        let mut batch = Storage::batch_begin();
+
+        Batch::<MessageId, Message>::batch_insert(&storage, &mut batch, &message_id, &message).unwrap();
+        storage.batch_commit_with(batch, Durability::Async).unwrap();
+
+        messages.push((message_id, message));
+    }
+
+    // An empty batch only persists the previous commits.
+    storage
+        .batch_commit_with(Storage::batch_begin(), Durability::Sync)
+        .unwrap();
+    storage.shutdown().unwrap();
+
+    let storage = common::retry_locked(|| Storage::start(config(path))).unwrap();
+
+    for (message_id, message) in &messages {
+        assert_eq!(
+            Fetch::<MessageId, Message>::fetch(&storage, message_id)
+                .unwrap()
+                .as_ref(),
+            Some(message)
+        );
+    }
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
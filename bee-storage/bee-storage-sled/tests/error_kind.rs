@@ -0,0 +1,46 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io;
+
+use bee_storage_sled::storage::{Error, ErrorKind};
+
+#[test]
+fn error_kind() {
+    let io_error = || io::Error::new(io::ErrorKind::Interrupted, "interrupted");
+    #[cfg(unix)]
+    let full_error = || io::Error::from_raw_os_error(libc::ENOSPC);
+    #[cfg(windows)]
+    let full_error = || io::Error::from_raw_os_error(112);
+
+    assert_eq!(Error::Sled(sled::Error::Io(io_error())).kind(), ErrorKind::Io);
+    assert_eq!(Error::Sled(sled::Error::Io(full_error())).kind(), ErrorKind::Full);
+    assert_eq!(Error::Io(io_error()).kind(), ErrorKind::Io);
+    assert_eq!(Error::Io(full_error()).kind(), ErrorKind::Full);
+    assert_eq!(
+        Error::Sled(sled::Error::Corruption { at: None, bt: () }).kind(),
+        ErrorKind::Corruption
+    );
+    assert_eq!(
+        Error::Sled(sled::Error::CollectionNotFound(sled::IVec::from("tree"))).kind(),
+        ErrorKind::Other
+    );
+    assert_eq!(
+        Error::Sled(sled::Error::Unsupported(String::from("unsupported"))).kind(),
+        ErrorKind::Other
+    );
+    assert_eq!(
+        Error::Sled(sled::Error::ReportableBug(String::from("bug"))).kind(),
+        ErrorKind::Other
+    );
+    assert_eq!(
+        Error::ChecksumMismatch {
+            expected: 0,
+            computed: 1
+        }
+        .kind(),
+        ErrorKind::Corruption
+    );
+    assert_eq!(Error::InvalidExportTag(0xff).kind(), ErrorKind::Corruption);
+    assert_eq!(Error::ReadOnly.kind(), ErrorKind::Other);
+}
@@ -0,0 +1,69 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::milestone::{Milestone, MilestoneIndex};
+use bee_storage::{
+    access::{AsIterator, Insert, Truncate},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::SledConfigBuilder,
+    storage::{Error, Storage},
+    trees::TREE_MILESTONE_INDEX_TO_MILESTONE,
+};
+use bee_test::rand::milestone::{rand_milestone, rand_milestone_index};
+
+fn milestones(storage: &Storage) -> Vec<(MilestoneIndex, Milestone)> {
+    AsIterator::<MilestoneIndex, Milestone>::iter(storage)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+#[test]
+fn export_import_round_trip() {
+    let path = String::from("./tests/database/export_import_round_trip");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    for _ in 0..10 {
+        Insert::<MilestoneIndex, Milestone>::insert(&storage, &rand_milestone_index(), &rand_milestone()).unwrap();
+    }
+
+    let exported = milestones(&storage);
+    let mut export = Vec::new();
+
+    assert_eq!(
+        storage
+            .export_tree(TREE_MILESTONE_INDEX_TO_MILESTONE, &mut export)
+            .unwrap(),
+        exported.len()
+    );
+
+    Truncate::<MilestoneIndex, Milestone>::truncate(&storage).unwrap();
+    assert!(milestones(&storage).is_empty());
+
+    // A corrupted export is rejected and nothing is imported.
+    let mut corrupted = export.clone();
+    // Corrupts a byte of the last value, right before the end tag and the checksum.
+    let last_value_byte = corrupted.len() - 6;
+    corrupted[last_value_byte] ^= 0xff;
+
+    assert!(matches!(
+        storage.import_tree(TREE_MILESTONE_INDEX_TO_MILESTONE, corrupted.as_slice()),
+        Err(Error::ChecksumMismatch { .. })
+    ));
+    assert!(milestones(&storage).is_empty());
+
+    assert_eq!(
+        storage
+            .import_tree(TREE_MILESTONE_INDEX_TO_MILESTONE, export.as_slice())
+            .unwrap(),
+        exported.len()
+    );
+    assert_eq!(milestones(&storage), exported);
+
+    let _ = std::fs::remove_dir_all(&path);
+}
@@ -0,0 +1,50 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfigBuilder, StorageConfigBuilder},
+    storage::Storage,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+fn fetch_raw(path: &str, compression: bool) {
+    let _ = std::fs::remove_dir_all(path);
+
+    let config = SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_storage(StorageConfigBuilder::default().with_compression(compression))
+        .finish();
+    let storage = Storage::start(config).unwrap();
+
+    let (message_id, message) = (rand_message_id(), rand_message());
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+
+    let fetched = Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+        .unwrap()
+        .unwrap();
+    let raw = storage.fetch_raw(&message_id).unwrap().unwrap();
+    assert_eq!(raw.as_ref(), fetched.pack_new().as_slice());
+
+    assert!(storage.fetch_raw(&rand_message_id()).unwrap().is_none());
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn fetch_raw_uncompressed() {
+    fetch_raw("./tests/database/fetch_raw_uncompressed", false);
+}
+
+#[test]
+fn fetch_raw_compressed() {
+    fetch_raw("./tests/database/fetch_raw_compressed", true);
+}
@@ -0,0 +1,62 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{ConsumedOutput, CreatedOutput};
+use bee_message::output::OutputId;
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_sled::{config::SledConfigBuilder, join::stream_join, storage::Storage};
+use bee_test::rand::output::{rand_consumed_output, rand_created_output, rand_output_id};
+
+#[test]
+fn iter_output_states() {
+    let path = String::from("./tests/database/iter_output_states");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    assert_eq!(storage.iter_output_states().unwrap().count(), 0);
+
+    let (created_id, spent_id, consumed_id) = (rand_output_id(), rand_output_id(), rand_output_id());
+    let (created, spent_created) = (rand_created_output(), rand_created_output());
+    let (spent_consumed, consumed) = (rand_consumed_output(), rand_consumed_output());
+
+    Insert::<OutputId, CreatedOutput>::insert(&storage, &created_id, &created).unwrap();
+    Insert::<OutputId, CreatedOutput>::insert(&storage, &spent_id, &spent_created).unwrap();
+    Insert::<OutputId, ConsumedOutput>::insert(&storage, &spent_id, &spent_consumed).unwrap();
+    Insert::<OutputId, ConsumedOutput>::insert(&storage, &consumed_id, &consumed).unwrap();
+
+    let mut expected = vec![
+        (created_id, Some(created), None),
+        (spent_id, Some(spent_created), Some(spent_consumed)),
+        (consumed_id, None, Some(consumed)),
+    ];
+    // The trees are ordered by packed key.
+    expected.sort_by_key(|(output_id, _, _)| output_id.pack_new());
+
+    assert_eq!(
+        storage
+            .iter_output_states()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        expected
+    );
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[test]
+fn stream_join_error() {
+    let output_id = rand_output_id();
+    let left = vec![Err("left"), Ok((output_id, 1))].into_iter();
+    let right = vec![Ok((output_id, 2))].into_iter();
+
+    assert_eq!(
+        stream_join(left, right).collect::<Vec<_>>(),
+        vec![Err("left"), Ok((output_id, Some(1), Some(2)))]
+    );
+}
@@ -0,0 +1,37 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::snapshot::SnapshotInfo;
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_sled::{
+    config::SledConfigBuilder,
+    storage::{Error, Storage},
+};
+use bee_test::rand::snapshot::rand_snapshot_info;
+
+#[test]
+fn load_snapshot_info() {
+    let path = String::from("./tests/database/load_snapshot_info");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    assert!(storage.load_snapshot_info(1).unwrap().is_none());
+
+    let info = rand_snapshot_info();
+    Insert::<(), SnapshotInfo>::insert(&storage, &(), &info).unwrap();
+
+    assert_eq!(
+        storage.load_snapshot_info(info.network_id()).unwrap(),
+        Some(info.clone())
+    );
+
+    let expected = info.network_id().wrapping_add(1);
+    assert!(matches!(
+        storage.load_snapshot_info(expected),
+        Err(Error::NetworkIdMismatch { stored, expected: e }) if stored == info.network_id() && e == expected
+    ));
+
+    let _ = std::fs::remove_dir_all(&path);
+}
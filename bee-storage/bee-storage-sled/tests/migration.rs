@@ -0,0 +1,113 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Delete, Exist, Fetch, Insert},
+    backend::StorageBackend,
+    system::{StorageVersion, System, SYSTEM_VERSION_KEY},
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder},
+    migration::Migrations,
+    storage::{Error, Storage},
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+fn config(path: &str) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .finish()
+}
+
+fn start(path: &str, migrations: &Migrations) -> Result<Storage, Error> {
+    common::retry_locked(|| Storage::start_with_migrations(config(path), migrations))
+}
+
+fn stored_version(storage: &Storage) -> StorageVersion {
+    match Fetch::<u8, System>::fetch(storage, &SYSTEM_VERSION_KEY).unwrap() {
+        Some(System::Version(version)) => version,
+        _ => panic!("No storage version."),
+    }
+}
+
+#[test]
+fn migration_v1_to_v2() {
+    let path = "./tests/database/migration_v1_to_v2";
+    let _ = std::fs::remove_dir_all(path);
+
+    let (message_id, message) = (rand_message_id(), rand_message());
+    let migrated_message_id = rand_message_id();
+
+    let storage = start(path, &Migrations::new(StorageVersion(1))).unwrap();
+    assert_eq!(stored_version(&storage), StorageVersion(1));
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+    storage.shutdown().unwrap();
+
+    // The fake v2 schema stores the messages under another id.
+    let mut migrations = Migrations::new(StorageVersion(2));
+    migrations.register_migration(StorageVersion(1), StorageVersion(2), move |storage| {
+        if let Some(message) = Fetch::<MessageId, Message>::fetch(storage, &message_id)? {
+            Insert::<MessageId, Message>::insert(storage, &migrated_message_id, &message)?;
+            Delete::<MessageId, Message>::delete(storage, &message_id)?;
+        }
+        Ok(())
+    });
+
+    let storage = start(path, &migrations).unwrap();
+    assert_eq!(stored_version(&storage), StorageVersion(2));
+    assert!(!Exist::<MessageId, Message>::exist(&storage, &message_id).unwrap());
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &migrated_message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+    storage.shutdown().unwrap();
+
+    // The migration already ran, starting again doesn't run it twice.
+    let storage = start(path, &migrations).unwrap();
+    assert!(Exist::<MessageId, Message>::exist(&storage, &migrated_message_id).unwrap());
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn migration_newer_version() {
+    let path = "./tests/database/migration_newer_version";
+    let _ = std::fs::remove_dir_all(path);
+
+    start(path, &Migrations::new(StorageVersion(2)))
+        .unwrap()
+        .shutdown()
+        .unwrap();
+
+    assert!(matches!(
+        start(path, &Migrations::new(StorageVersion(1))),
+        Err(Error::VersionMismatch(StorageVersion(2), StorageVersion(1)))
+    ));
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn migration_missing() {
+    let path = "./tests/database/migration_missing";
+    let _ = std::fs::remove_dir_all(path);
+
+    start(path, &Migrations::new(StorageVersion(1)))
+        .unwrap()
+        .shutdown()
+        .unwrap();
+
+    assert!(matches!(
+        start(path, &Migrations::new(StorageVersion(2))),
+        Err(Error::MissingMigration(StorageVersion(1), StorageVersion(2)))
+    ));
+
+    let _ = std::fs::remove_dir_all(path);
+}
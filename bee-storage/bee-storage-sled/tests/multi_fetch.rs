@@ -0,0 +1,65 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Insert, MultiFetch},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder, StorageConfigBuilder},
+    storage::Storage,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+fn config(path: &str, parallel_multi_fetch_threshold: usize) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_storage(
+            StorageConfigBuilder::default().with_parallel_multi_fetch_threshold(parallel_multi_fetch_threshold),
+        )
+        .finish()
+}
+
+fn multi_fetch(storage: &Storage, keys: &[MessageId]) -> Vec<Option<Message>> {
+    MultiFetch::<MessageId, Message>::multi_fetch(storage, keys)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+#[test]
+fn serial_and_parallel_multi_fetch() {
+    let path = "./tests/database/serial_and_parallel_multi_fetch";
+    let _ = std::fs::remove_dir_all(path);
+
+    // Every fourth key is missing so that the results interleave hits and misses.
+    let keys = (0..2_000).map(|_| rand_message_id()).collect::<Vec<_>>();
+    let messages = keys
+        .iter()
+        .enumerate()
+        .map(|(i, _)| (i % 4 != 0).then(rand_message))
+        .collect::<Vec<_>>();
+
+    let storage = Storage::start(config(path, usize::MAX)).unwrap();
+    for (key, message) in keys.iter().zip(&messages) {
+        if let Some(message) = message {
+            Insert::<MessageId, Message>::insert(&storage, key, message).unwrap();
+        }
+    }
+    let serial = multi_fetch(&storage, &keys);
+    storage.shutdown().unwrap();
+
+    let storage = common::retry_locked(|| Storage::start(config(path, 0))).unwrap();
+    let parallel = multi_fetch(&storage, &keys);
+    assert_eq!(multi_fetch(&storage, &[]).len(), 0);
+    storage.shutdown().unwrap();
+
+    assert_eq!(serial, messages);
+    assert_eq!(parallel, serial);
+
+    let _ = std::fs::remove_dir_all(path);
+}
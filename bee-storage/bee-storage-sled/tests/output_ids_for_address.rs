@@ -0,0 +1,43 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{address::Ed25519Address, output::OutputId};
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_sled::{config::SledConfigBuilder, storage::Storage};
+use bee_test::rand::{address::rand_ed25519_address, output::rand_output_id};
+
+#[test]
+fn output_ids_for_address() {
+    let path = String::from("./tests/database/output_ids_for_address");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    let (address, other) = (rand_ed25519_address(), rand_ed25519_address());
+    let mut output_ids = (0..5).map(|_| rand_output_id()).collect::<Vec<_>>();
+
+    for output_id in &output_ids {
+        Insert::<(Ed25519Address, OutputId), ()>::insert(&storage, &(address, *output_id), &()).unwrap();
+    }
+
+    for _ in 0..10 {
+        Insert::<(Ed25519Address, OutputId), ()>::insert(&storage, &(other, rand_output_id()), &()).unwrap();
+    }
+
+    let mut fetched = storage
+        .output_ids_for_address(&address)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    output_ids.sort();
+    fetched.sort();
+    assert_eq!(fetched, output_ids);
+
+    assert_eq!(storage.output_ids_for_address(&other).unwrap().count(), 10);
+    let unknown = rand_ed25519_address();
+    assert_eq!(storage.output_ids_for_address(&unknown).unwrap().count(), 0);
+
+    let _ = std::fs::remove_dir_all(&path);
+}
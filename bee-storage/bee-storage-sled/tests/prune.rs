@@ -0,0 +1,81 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::{
+    access::{Exist, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{config::SledConfigBuilder, storage::Storage};
+use bee_tangle::{solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage};
+use bee_test::rand::{message::rand_message_id, solid_entry_point::rand_solid_entry_point};
+
+#[test]
+fn prune_solid_entry_points() {
+    let path = String::from("./tests/database/prune_solid_entry_points");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    let seps = [5, 10, 15, 20, 25]
+        .into_iter()
+        .map(|index| (rand_solid_entry_point(), MilestoneIndex(index)))
+        .collect::<Vec<_>>();
+
+    for (sep, index) in &seps {
+        Insert::<SolidEntryPoint, MilestoneIndex>::insert(&storage, sep, index).unwrap();
+    }
+
+    assert_eq!(storage.prune_solid_entry_points(MilestoneIndex(15)).unwrap(), 2);
+
+    for (sep, index) in &seps {
+        let fetched = Fetch::<SolidEntryPoint, MilestoneIndex>::fetch(&storage, sep).unwrap();
+
+        if *index < MilestoneIndex(15) {
+            assert!(fetched.is_none());
+        } else {
+            assert_eq!(fetched, Some(*index));
+        }
+    }
+
+    assert_eq!(storage.prune_solid_entry_points(MilestoneIndex(15)).unwrap(), 0);
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[test]
+fn prune_unreferenced_messages() {
+    let path = String::from("./tests/database/prune_unreferenced_messages");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    // Indices whose little endian encodings don't follow their order.
+    let entries = [1, 255, 256, 300, 65536]
+        .into_iter()
+        .flat_map(|index| (0..3).map(move |_| (MilestoneIndex(index), UnreferencedMessage::from(rand_message_id()))))
+        .collect::<Vec<_>>();
+
+    for entry in &entries {
+        Insert::<(MilestoneIndex, UnreferencedMessage), ()>::insert(&storage, entry, &()).unwrap();
+    }
+
+    assert_eq!(storage.prune_unreferenced_messages(MilestoneIndex(300)).unwrap(), 9);
+
+    for entry in &entries {
+        assert_eq!(
+            Exist::<(MilestoneIndex, UnreferencedMessage), ()>::exist(&storage, entry).unwrap(),
+            entry.0 >= MilestoneIndex(300)
+        );
+    }
+
+    assert_eq!(storage.prune_unreferenced_messages(MilestoneIndex(300)).unwrap(), 0);
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
@@ -0,0 +1,72 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{AsIterator, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder, StorageConfigBuilder},
+    storage::Storage,
+    trees::TREE_MESSAGE_ID_TO_MESSAGE,
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+fn config(path: &str, compression: bool) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_storage(StorageConfigBuilder::default().with_compression(compression))
+        .finish()
+}
+
+fn raw_iter_decode(path: &str, compression: bool) {
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Storage::start(config(path, compression)).unwrap();
+
+    for _ in 0..100 {
+        Insert::<MessageId, Message>::insert(&storage, &rand_message_id(), &rand_message()).unwrap();
+    }
+
+    let mut typed = AsIterator::<MessageId, Message>::iter(&storage)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    typed.sort_by_key(|(message_id, _)| *message_id);
+
+    let raw = storage
+        .iter_raw(TREE_MESSAGE_ID_TO_MESSAGE)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    assert_eq!(raw.len(), typed.len());
+    for ((raw_key, _), (message_id, _)) in raw.iter().zip(&typed) {
+        assert_eq!(raw_key.as_ref(), message_id.pack_new().as_slice());
+    }
+
+    let mut decoded = storage
+        .iter_raw(TREE_MESSAGE_ID_TO_MESSAGE)
+        .unwrap()
+        .decode::<MessageId, Message>()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    decoded.sort_by_key(|(message_id, _)| *message_id);
+    assert_eq!(decoded, typed);
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn raw_iter_decode_uncompressed() {
+    raw_iter_decode("./tests/database/raw_iter_decode_uncompressed", false);
+}
+
+#[test]
+fn raw_iter_decode_compressed() {
+    raw_iter_decode("./tests/database/raw_iter_decode_compressed", true);
+}
@@ -0,0 +1,86 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use bee_ledger::types::LedgerIndex;
+use bee_message::milestone::MilestoneIndex;
+use bee_storage::{
+    access::{Batch, BatchBuilder, Delete, Durability, Fetch},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder, StorageConfigBuilder},
+    storage::Storage,
+    trees::{TREE_LEDGER_INDEX, TREE_SNAPSHOT_INFO},
+};
+
+fn config(path: &str) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_storage(
+            StorageConfigBuilder::default()
+                .with_read_cache_trees(vec![TREE_LEDGER_INDEX.to_owned(), TREE_SNAPSHOT_INFO.to_owned()]),
+        )
+        .finish()
+}
+
+#[test]
+fn read_cache_concurrent_writes() {
+    let path = "./tests/database/read_cache_concurrent_writes";
+    let _ = std::fs::remove_dir_all(path);
+
+    let storage = Arc::new(Storage::start(config(path)).unwrap());
+    let done = Arc::new(AtomicBool::new(false));
+
+    // Readers keep filling the cache while the ledger index is written.
+    let readers = (0..4)
+        .map(|_| {
+            let storage = storage.clone();
+            let done = done.clone();
+
+            thread::spawn(move || {
+                let mut last = None;
+
+                while !done.load(Ordering::Relaxed) {
+                    let index = storage.get_ledger_index().unwrap();
+                    assert!(index >= last, "{:?} read after {:?}", index, last);
+                    last = index;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for index in 1..=1_000 {
+        if index % 2 == 0 {
+            storage.set_ledger_index(MilestoneIndex(index)).unwrap();
+        } else {
+            let mut batch = Storage::batch_begin();
+            Batch::<(), LedgerIndex>::batch_insert(&*storage, &mut batch, &(), &LedgerIndex(MilestoneIndex(index)))
+                .unwrap();
+            storage.batch_commit_with(batch, Durability::Async).unwrap();
+        }
+
+        // The write is visible right away, the cached value it replaces is never returned.
+        assert_eq!(storage.get_ledger_index().unwrap(), Some(MilestoneIndex(index)));
+    }
+
+    done.store(true, Ordering::Relaxed);
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    Delete::<(), LedgerIndex>::delete(&*storage, &()).unwrap();
+    assert!(Fetch::<(), LedgerIndex>::fetch(&*storage, &()).unwrap().is_none());
+
+    Arc::try_unwrap(storage).ok().unwrap().shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
@@ -0,0 +1,120 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use bee_message::{Message, MessageId};
+use bee_storage::{
+    access::{Batch, BatchBuilder, Delete, Durability, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfigBuilder, StorageConfigBuilder},
+    storage::{Error, Storage},
+};
+use bee_test::rand::message::{rand_message, rand_message_id};
+
+#[test]
+fn read_only() {
+    let path = String::from("./tests/database/read_only");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let start = |read_only| {
+        common::retry_locked(|| {
+            Storage::start(
+                SledConfigBuilder::default()
+                    .with_path(path.clone())
+                    .with_create_new(true)
+                    .with_storage(StorageConfigBuilder::default().with_read_only(read_only))
+                    .finish(),
+            )
+        })
+    };
+
+    assert!(matches!(start(true), Err(Error::Io(_))));
+
+    let (message_id, message) = (rand_message_id(), rand_message());
+
+    let storage = start(false).unwrap();
+    Insert::<MessageId, Message>::insert(&storage, &message_id, &message).unwrap();
+    storage.shutdown().unwrap();
+
+    let storage = start(true).unwrap();
+
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+    assert!(matches!(
+        Insert::<MessageId, Message>::insert(&storage, &rand_message_id(), &message),
+        Err(Error::ReadOnly)
+    ));
+    assert!(matches!(
+        Delete::<MessageId, Message>::delete(&storage, &message_id),
+        Err(Error::ReadOnly)
+    ));
+
+    let mut batch = Storage::batch_begin();
+    Batch::<MessageId, Message>::batch_delete(&storage, &mut batch, &message_id).unwrap();
+    assert!(matches!(
+        storage.batch_commit_with(batch, Durability::Sync),
+        Err(Error::ReadOnly)
+    ));
+
+    let fetched = Fetch::<MessageId, Message>::fetch(&storage, &message_id).unwrap();
+    assert!(fetched.is_some());
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[test]
+fn read_only_while_running() {
+    let path = String::from("./tests/database/read_only_while_running");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = |read_only| {
+        SledConfigBuilder::default()
+            .with_path(path.clone())
+            .with_create_new(true)
+            .with_storage(StorageConfigBuilder::default().with_read_only(read_only))
+            .finish()
+    };
+
+    let (message_id, message) = (rand_message_id(), rand_message());
+
+    let running = Storage::start(config(false)).unwrap();
+    let mut batch = Storage::batch_begin();
+    Batch::<MessageId, Message>::batch_insert(&running, &mut batch, &message_id, &message).unwrap();
+    running.batch_commit_with(batch, Durability::Sync).unwrap();
+
+    // The running storage still holds the lock of its folder.
+    let storage = Storage::start(config(true)).unwrap();
+
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(&storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        message
+    );
+    assert!(matches!(
+        Insert::<MessageId, Message>::insert(&storage, &rand_message_id(), &message),
+        Err(Error::ReadOnly)
+    ));
+
+    storage.shutdown().unwrap();
+
+    // The running storage is not affected by the read-only one.
+    Delete::<MessageId, Message>::delete(&running, &message_id).unwrap();
+    assert!(
+        Fetch::<MessageId, Message>::fetch(&running, &message_id)
+            .unwrap()
+            .is_none()
+    );
+    running.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
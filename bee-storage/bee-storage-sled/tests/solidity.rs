@@ -0,0 +1,63 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{parents::Parents, Message, MessageId};
+use bee_storage::{
+    access::{Insert, InsertStrict},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{config::SledConfigBuilder, solidity::SolidifyStatus, storage::Storage};
+use bee_tangle::metadata::MessageMetadata;
+use bee_test::rand::message::{rand_message_id, rand_message_with_parents};
+
+#[test]
+fn solidify_status() {
+    let path = String::from("./tests/database/solidify_status");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start(config).unwrap();
+
+    let (parent_1_id, parent_2_id) = (rand_message_id(), rand_message_id());
+    let parent_1 = rand_message_with_parents(Parents::new(vec![rand_message_id()]).unwrap());
+
+    Insert::<MessageId, Message>::insert(&storage, &parent_1_id, &parent_1).unwrap();
+
+    // A solid message.
+    let solid_id = rand_message_id();
+    let solid = rand_message_with_parents(Parents::new(vec![parent_1_id]).unwrap());
+    let mut metadata = MessageMetadata::arrived();
+    metadata.mark_solid();
+
+    Insert::<MessageId, Message>::insert(&storage, &solid_id, &solid).unwrap();
+    InsertStrict::<MessageId, MessageMetadata>::insert_strict(&storage, &solid_id, &metadata).unwrap();
+
+    assert!(storage.is_solid(&solid_id).unwrap());
+    assert_eq!(storage.solidify_status(&solid_id).unwrap(), SolidifyStatus::Solid);
+
+    // An unsolid message missing one of its parents.
+    let mut parents = vec![parent_1_id, parent_2_id];
+    parents.sort();
+    let unsolid_id = rand_message_id();
+    let unsolid = rand_message_with_parents(Parents::new(parents).unwrap());
+
+    Insert::<MessageId, Message>::insert(&storage, &unsolid_id, &unsolid).unwrap();
+    InsertStrict::<MessageId, MessageMetadata>::insert_strict(&storage, &unsolid_id, &MessageMetadata::arrived())
+        .unwrap();
+
+    assert!(!storage.is_solid(&unsolid_id).unwrap());
+    assert_eq!(
+        storage.solidify_status(&unsolid_id).unwrap(),
+        SolidifyStatus::Unsolid {
+            missing_parents: vec![parent_2_id]
+        }
+    );
+
+    // A message that is not stored.
+    let missing_id = rand_message_id();
+
+    assert!(!storage.is_solid(&missing_id).unwrap());
+    assert_eq!(storage.solidify_status(&missing_id).unwrap(), SolidifyStatus::Missing);
+
+    let _ = std::fs::remove_dir_all(&path);
+}
@@ -0,0 +1,71 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use bee_common::packable::Packable;
+use bee_message::milestone::{Milestone, MilestoneIndex};
+use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_sled::{
+    config::{SledConfig, SledConfigBuilder, StorageConfigBuilder},
+    storage::{Error, Storage},
+    trees::TREE_MILESTONE_INDEX_TO_MILESTONE,
+};
+use bee_test::rand::milestone::rand_milestone;
+
+fn config(path: &str, strict_decoding: bool) -> SledConfig {
+    SledConfigBuilder::default()
+        .with_path(path.to_owned())
+        .with_create_new(true)
+        .with_storage(StorageConfigBuilder::default().with_strict_decoding(strict_decoding))
+        .finish()
+}
+
+fn start(path: &str, strict_decoding: bool) -> Storage {
+    common::retry_locked(|| Storage::start(config(path, strict_decoding))).unwrap()
+}
+
+#[test]
+fn strict_decoding_trailing_bytes() {
+    let path = "./tests/database/strict_decoding_trailing_bytes";
+    let _ = std::fs::remove_dir_all(path);
+
+    let (index, milestone) = (MilestoneIndex(42), rand_milestone());
+
+    let storage = start(path, true);
+    Insert::<MilestoneIndex, Milestone>::insert(&storage, &index, &milestone).unwrap();
+    assert_eq!(
+        Fetch::<MilestoneIndex, Milestone>::fetch(&storage, &index).unwrap(),
+        Some(milestone.clone())
+    );
+    storage.shutdown().unwrap();
+
+    // Garbage is appended to the stored value behind the storage's back.
+    {
+        let db = common::retry_locked(|| Ok(sled::open(path)?)).unwrap();
+        let tree = db.open_tree(TREE_MILESTONE_INDEX_TO_MILESTONE).unwrap();
+        let mut value = tree.get(index.pack_new()).unwrap().unwrap().to_vec();
+        value.extend_from_slice(&[0xde, 0xad, 0xbe]);
+        tree.insert(index.pack_new(), value).unwrap();
+        db.flush().unwrap();
+    }
+
+    let storage = start(path, true);
+    assert!(matches!(
+        Fetch::<MilestoneIndex, Milestone>::fetch(&storage, &index),
+        Err(Error::TrailingBytes(3))
+    ));
+    storage.shutdown().unwrap();
+
+    let storage = start(path, false);
+    assert_eq!(
+        Fetch::<MilestoneIndex, Milestone>::fetch(&storage, &index).unwrap(),
+        Some(milestone)
+    );
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(path);
+}
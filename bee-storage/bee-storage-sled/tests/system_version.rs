@@ -0,0 +1,48 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_storage::{backend::StorageBackend, system::StorageVersion};
+use bee_storage_sled::{
+    config::SledConfigBuilder,
+    migration::Migrations,
+    storage::{Error, Storage},
+};
+
+#[test]
+fn system_version() {
+    let path = String::from("./tests/database/system_version");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let config = SledConfigBuilder::default().with_path(path.clone()).finish();
+    let storage = Storage::start_with_migrations(config, &Migrations::new(StorageVersion(1))).unwrap();
+
+    assert_eq!(storage.system_version().unwrap(), Some(StorageVersion(1)));
+
+    storage.set_system_version(StorageVersion(1)).unwrap();
+    storage.set_system_version(StorageVersion(3)).unwrap();
+    assert_eq!(storage.system_version().unwrap(), Some(StorageVersion(3)));
+
+    assert!(matches!(
+        storage.set_system_version(StorageVersion(2)),
+        Err(Error::VersionRegression {
+            current: StorageVersion(3),
+            to: StorageVersion(2)
+        })
+    ));
+    assert_eq!(storage.system_version().unwrap(), Some(StorageVersion(3)));
+
+    std::thread::scope(|scope| {
+        for version in 4..20 {
+            let storage = &storage;
+            scope.spawn(move || {
+                // Older versions set concurrently may be refused, the newest one must win.
+                let _ = storage.set_system_version(StorageVersion(version));
+            });
+        }
+    });
+    assert_eq!(storage.system_version().unwrap(), Some(StorageVersion(19)));
+
+    storage.shutdown().unwrap();
+
+    let _ = std::fs::remove_dir_all(&path);
+}
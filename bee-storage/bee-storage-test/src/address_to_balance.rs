@@ -5,11 +5,13 @@ use bee_common::packable::Packable;
 use bee_ledger::types::Balance;
 use bee_message::address::Address;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, MultiFetch, Truncate},
     backend,
 };
 use bee_test::rand::{address::rand_address, balance::rand_balance};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<Address, Balance>
@@ -94,7 +96,7 @@ pub fn address_to_balance_access<B: StorageBackend>(storage: &B) {
         balances.push((address, Some(balance)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<Address, Balance>::iter(storage).unwrap();
     let mut count = 0;
@@ -117,9 +119,5 @@ pub fn address_to_balance_access<B: StorageBackend>(storage: &B) {
         assert_eq!(balance, result.unwrap());
     }
 
-    Truncate::<Address, Balance>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<Address, Balance>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<Address, Balance, _>(storage);
 }
@@ -5,11 +5,13 @@ use std::collections::HashMap;
 
 use bee_message::{address::Ed25519Address, output::OutputId};
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_test::rand::{address::rand_ed25519_address, output::rand_output_id};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(Ed25519Address, OutputId), ()>
@@ -87,7 +89,7 @@ pub fn ed25519_address_to_output_id_access<B: StorageBackend>(storage: &B) {
         }
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<(Ed25519Address, OutputId), ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -100,9 +102,5 @@ pub fn ed25519_address_to_output_id_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, output_ids.iter().fold(0, |acc, v| acc + v.1.len()));
 
-    Truncate::<(Ed25519Address, OutputId), ()>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<(Ed25519Address, OutputId), ()>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<(Ed25519Address, OutputId), (), _>(storage);
 }
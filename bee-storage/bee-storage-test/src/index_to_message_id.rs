@@ -5,15 +5,18 @@ use std::collections::HashMap;
 
 use bee_message::{payload::indexation::PaddedIndex, MessageId};
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, FetchPage, Insert, Truncate},
     backend,
 };
 use bee_test::rand::{message::rand_message_id, payload::rand_indexation_payload};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(PaddedIndex, MessageId), ()>
     + Fetch<PaddedIndex, Vec<MessageId>>
+    + FetchPage<PaddedIndex, MessageId>
     + Insert<(PaddedIndex, MessageId), ()>
     + Delete<(PaddedIndex, MessageId), ()>
     + BatchBuilder
@@ -27,6 +30,7 @@ impl<T> StorageBackend for T where
     T: backend::StorageBackend
         + Exist<(PaddedIndex, MessageId), ()>
         + Fetch<PaddedIndex, Vec<MessageId>>
+        + FetchPage<PaddedIndex, MessageId>
         + Insert<(PaddedIndex, MessageId), ()>
         + Delete<(PaddedIndex, MessageId), ()>
         + BatchBuilder
@@ -87,7 +91,7 @@ pub fn index_to_message_id_access<B: StorageBackend>(storage: &B) {
         }
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<(PaddedIndex, MessageId), ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -100,9 +104,26 @@ pub fn index_to_message_id_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, message_ids.iter().fold(0, |acc, v| acc + v.1.len()));
 
-    Truncate::<(PaddedIndex, MessageId), ()>::truncate(storage).unwrap();
+    for (index, message_ids) in message_ids.iter_mut() {
+        message_ids.sort();
+
+        let mut pages = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let (page, next) =
+                FetchPage::<PaddedIndex, MessageId>::fetch_page(storage, index, cursor.as_ref(), 2).unwrap();
+            assert!(page.len() <= 2);
+            pages.extend(page);
 
-    let mut iter = AsIterator::<(PaddedIndex, MessageId), ()>::iter(storage).unwrap();
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(&pages, message_ids);
+    }
 
-    assert!(iter.next().is_none());
+    check_projections::<(PaddedIndex, MessageId), (), _>(storage);
 }
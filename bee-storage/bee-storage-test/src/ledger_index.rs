@@ -4,16 +4,19 @@
 use bee_ledger::types::LedgerIndex;
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, CompareAndSwap, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(), LedgerIndex>
     + Fetch<(), LedgerIndex>
     + Insert<(), LedgerIndex>
     + Delete<(), LedgerIndex>
+    + CompareAndSwap<(), LedgerIndex>
     + BatchBuilder
     + Batch<(), LedgerIndex>
     + for<'a> AsIterator<'a, (), LedgerIndex>
@@ -27,6 +30,7 @@ impl<T> StorageBackend for T where
         + Fetch<(), LedgerIndex>
         + Insert<(), LedgerIndex>
         + Delete<(), LedgerIndex>
+        + CompareAndSwap<(), LedgerIndex>
         + BatchBuilder
         + Batch<(), LedgerIndex>
         + for<'a> AsIterator<'a, (), LedgerIndex>
@@ -36,6 +40,7 @@ impl<T> StorageBackend for T where
 
 pub fn ledger_index_access<B: StorageBackend>(storage: &B) {
     let index = LedgerIndex::from(MilestoneIndex::from(42));
+    let other_index = LedgerIndex::from(MilestoneIndex::from(43));
 
     assert!(!Exist::<(), LedgerIndex>::exist(storage, &()).unwrap());
     assert!(Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().is_none());
@@ -50,11 +55,30 @@ pub fn ledger_index_access<B: StorageBackend>(storage: &B) {
     assert!(!Exist::<(), LedgerIndex>::exist(storage, &()).unwrap());
     assert!(Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().is_none());
 
+    assert!(CompareAndSwap::<(), LedgerIndex>::compare_and_swap(storage, &(), None, Some(&index)).unwrap());
+    assert_eq!(Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().unwrap(), index);
+
+    // The swaps expecting another value than the current one fail and leave it untouched.
+    assert!(!CompareAndSwap::<(), LedgerIndex>::compare_and_swap(storage, &(), None, Some(&other_index)).unwrap());
+    assert!(!CompareAndSwap::<(), LedgerIndex>::compare_and_swap(storage, &(), Some(&other_index), None).unwrap());
+    assert_eq!(Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().unwrap(), index);
+
+    assert!(
+        CompareAndSwap::<(), LedgerIndex>::compare_and_swap(storage, &(), Some(&index), Some(&other_index)).unwrap()
+    );
+    assert_eq!(
+        Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().unwrap(),
+        other_index
+    );
+
+    assert!(CompareAndSwap::<(), LedgerIndex>::compare_and_swap(storage, &(), Some(&other_index), None).unwrap());
+    assert!(!Exist::<(), LedgerIndex>::exist(storage, &()).unwrap());
+
     let mut batch = B::batch_begin();
 
     Batch::<(), LedgerIndex>::batch_insert(storage, &mut batch, &(), &index).unwrap();
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     assert!(Exist::<(), LedgerIndex>::exist(storage, &()).unwrap());
     assert_eq!(Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().unwrap(), index);
@@ -63,7 +87,7 @@ pub fn ledger_index_access<B: StorageBackend>(storage: &B) {
 
     Batch::<(), LedgerIndex>::batch_delete(storage, &mut batch, &()).unwrap();
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     assert!(!Exist::<(), LedgerIndex>::exist(storage, &()).unwrap());
     assert!(Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().is_none());
@@ -81,11 +105,7 @@ pub fn ledger_index_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, 1);
 
-    Truncate::<(), LedgerIndex>::truncate(storage).unwrap();
+    check_projections::<(), LedgerIndex, _>(storage);
 
     assert!(!Exist::<(), LedgerIndex>::exist(storage, &()).unwrap());
-
-    let mut iter = AsIterator::<(), LedgerIndex>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
 }
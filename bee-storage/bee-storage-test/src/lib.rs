@@ -15,6 +15,7 @@ mod milestone_index_to_unreferenced_message;
 mod output_id_to_consumed_output;
 mod output_id_to_created_output;
 mod output_id_unspent;
+mod projections;
 mod snapshot_info;
 mod solid_entry_point_to_milestone_index;
 mod spent_to_treasury_output;
@@ -3,17 +3,22 @@
 
 use bee_message::{Message, MessageId};
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    access::{
+        AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, InsertIfAbsent, MultiFetch, Truncate,
+    },
     backend,
 };
 use bee_test::rand::message::{rand_message, rand_message_id};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<MessageId, Message>
     + Fetch<MessageId, Message>
     + for<'a> MultiFetch<'a, MessageId, Message>
     + Insert<MessageId, Message>
+    + InsertIfAbsent<MessageId, Message>
     + Delete<MessageId, Message>
     + BatchBuilder
     + Batch<MessageId, Message>
@@ -28,6 +33,7 @@ impl<T> StorageBackend for T where
         + Fetch<MessageId, Message>
         + for<'a> MultiFetch<'a, MessageId, Message>
         + Insert<MessageId, Message>
+        + InsertIfAbsent<MessageId, Message>
         + Delete<MessageId, Message>
         + BatchBuilder
         + Batch<MessageId, Message>
@@ -51,6 +57,16 @@ pub fn message_id_to_message_access<B: StorageBackend>(storage: &B) {
     assert_eq!(results.len(), 1);
     assert!(matches!(results.get(0), Some(Ok(None))));
 
+    assert!(InsertIfAbsent::<MessageId, Message>::insert_if_absent(storage, &message_id, &message).unwrap());
+    assert!(!InsertIfAbsent::<MessageId, Message>::insert_if_absent(storage, &message_id, &rand_message()).unwrap());
+    assert_eq!(
+        Fetch::<MessageId, Message>::fetch(storage, &message_id)
+            .unwrap()
+            .as_ref(),
+        Some(&message),
+        "insert_if_absent should not overwrite"
+    );
+
     Insert::<MessageId, Message>::insert(storage, &message_id, &message).unwrap();
 
     let message = rand_message();
@@ -109,7 +125,7 @@ pub fn message_id_to_message_access<B: StorageBackend>(storage: &B) {
         messages.push((message_id, Some(message)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<MessageId, Message>::iter(storage).unwrap();
     let mut count = 0;
@@ -128,13 +144,17 @@ pub fn message_id_to_message_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(results.len(), message_ids.len());
 
+    let map = MultiFetch::<MessageId, Message>::multi_fetch_map(storage, &message_ids).unwrap();
+
+    assert_eq!(map.len(), 10);
+
+    for (message_id, message) in &messages {
+        assert_eq!(map.get(message_id), message.as_ref());
+    }
+
     for ((_, message), result) in messages.into_iter().zip(results.into_iter()) {
         assert_eq!(message, result.unwrap());
     }
 
-    Truncate::<MessageId, Message>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<MessageId, Message>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<MessageId, Message, _>(storage);
 }
@@ -5,11 +5,13 @@ use std::collections::HashMap;
 
 use bee_message::MessageId;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_test::rand::message::rand_message_id;
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(MessageId, MessageId), ()>
@@ -86,7 +88,7 @@ pub fn message_id_to_message_id_access<B: StorageBackend>(storage: &B) {
         }
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<(MessageId, MessageId), ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -99,9 +101,5 @@ pub fn message_id_to_message_id_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, edges.iter().fold(0, |acc, v| acc + v.1.len()));
 
-    Truncate::<(MessageId, MessageId), ()>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<(MessageId, MessageId), ()>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<(MessageId, MessageId), (), _>(storage);
 }
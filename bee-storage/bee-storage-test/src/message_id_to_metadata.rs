@@ -3,16 +3,22 @@
 
 use bee_message::{prelude::MilestoneIndex, MessageId};
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, InsertStrict, MultiFetch, Truncate, Update},
+    access::{
+        AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, FetchOrInsert, InsertStrict, MultiFetch,
+        Truncate, Update,
+    },
     backend,
 };
 use bee_tangle::metadata::MessageMetadata;
 use bee_test::rand::{message::rand_message_id, metadata::rand_message_metadata};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<MessageId, MessageMetadata>
     + Fetch<MessageId, MessageMetadata>
+    + FetchOrInsert<MessageId, MessageMetadata>
     + for<'a> MultiFetch<'a, MessageId, MessageMetadata>
     + InsertStrict<MessageId, MessageMetadata>
     + Delete<MessageId, MessageMetadata>
@@ -28,6 +34,7 @@ impl<T> StorageBackend for T where
     T: backend::StorageBackend
         + Exist<MessageId, MessageMetadata>
         + Fetch<MessageId, MessageMetadata>
+        + FetchOrInsert<MessageId, MessageMetadata>
         + for<'a> MultiFetch<'a, MessageId, MessageMetadata>
         + InsertStrict<MessageId, MessageMetadata>
         + Delete<MessageId, MessageMetadata>
@@ -117,6 +124,30 @@ pub fn message_id_to_metadata_access<B: StorageBackend>(storage: &B) {
     assert_eq!(results.len(), 1);
     assert!(matches!(results.get(0), Some(Ok(None))));
 
+    // `fetch_or_insert_with` should insert the value on a miss.
+    assert_eq!(
+        FetchOrInsert::<MessageId, MessageMetadata>::fetch_or_insert_with(storage, &message_id, || metadata.clone())
+            .unwrap(),
+        metadata
+    );
+    assert_eq!(
+        Fetch::<MessageId, MessageMetadata>::fetch(storage, &message_id)
+            .unwrap()
+            .unwrap(),
+        metadata
+    );
+
+    // `fetch_or_insert_with` should return the stored value on a hit without calling the closure.
+    assert_eq!(
+        FetchOrInsert::<MessageId, MessageMetadata>::fetch_or_insert_with(storage, &message_id, || {
+            panic!("`fetch_or_insert_with` should not call the closure on a hit")
+        })
+        .unwrap(),
+        metadata
+    );
+
+    Delete::<MessageId, MessageMetadata>::delete(storage, &message_id).unwrap();
+
     let mut batch = B::batch_begin();
     let mut message_ids = Vec::new();
     let mut metadatas = Vec::new();
@@ -136,7 +167,7 @@ pub fn message_id_to_metadata_access<B: StorageBackend>(storage: &B) {
         metadatas.push((message_id, Some(metadata)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<MessageId, MessageMetadata>::iter(storage).unwrap();
     let mut count = 0;
@@ -159,9 +190,33 @@ pub fn message_id_to_metadata_access<B: StorageBackend>(storage: &B) {
         assert_eq!(metadata, result.unwrap());
     }
 
-    Truncate::<MessageId, MessageMetadata>::truncate(storage).unwrap();
+    check_projections::<MessageId, MessageMetadata, _>(storage);
+    assert_eq!(Truncate::<MessageId, MessageMetadata>::truncate(storage).unwrap(), 0);
+
+    let pairs = (0..50)
+        .map(|_| (rand_message_id(), rand_message_metadata()))
+        .collect::<Vec<_>>();
+    let mut batch = B::batch_begin();
+    Batch::<MessageId, MessageMetadata>::batch_insert_many(storage, &mut batch, pairs.clone()).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
+
+    for (message_id, metadata) in &pairs {
+        assert_eq!(
+            Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+                .unwrap()
+                .as_ref(),
+            Some(metadata)
+        );
+    }
 
-    let mut iter = AsIterator::<MessageId, MessageMetadata>::iter(storage).unwrap();
+    let mut batch = B::batch_begin();
+    Batch::<MessageId, MessageMetadata>::batch_delete_many(
+        storage,
+        &mut batch,
+        pairs.iter().map(|(message_id, _)| *message_id),
+    )
+    .unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
-    assert!(iter.next().is_none());
+    assert_eq!(Truncate::<MessageId, MessageMetadata>::truncate(storage).unwrap(), 0);
 }
@@ -3,11 +3,13 @@
 
 use bee_message::milestone::{Milestone, MilestoneIndex};
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, MultiFetch, Truncate},
     backend,
 };
 use bee_test::rand::milestone::{rand_milestone, rand_milestone_index};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<MilestoneIndex, Milestone>
@@ -99,7 +101,7 @@ pub fn milestone_index_to_milestone_access<B: StorageBackend>(storage: &B) {
         milestones.push((index, Some(milestone)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<MilestoneIndex, Milestone>::iter(storage).unwrap();
     let mut count = 0;
@@ -122,9 +124,5 @@ pub fn milestone_index_to_milestone_access<B: StorageBackend>(storage: &B) {
         assert_eq!(milestone, result.unwrap());
     }
 
-    Truncate::<MilestoneIndex, Milestone>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<MilestoneIndex, Milestone>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<MilestoneIndex, Milestone, _>(storage);
 }
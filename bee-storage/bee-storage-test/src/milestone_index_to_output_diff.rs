@@ -5,11 +5,15 @@ use bee_common::packable::Packable;
 use bee_ledger::types::OutputDiff;
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    access::{
+        AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, FetchRange, Insert, MultiFetch, Truncate,
+    },
     backend,
 };
 use bee_test::rand::{milestone::rand_milestone_index, output_diff::rand_output_diff};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<MilestoneIndex, OutputDiff>
@@ -21,6 +25,7 @@ pub trait StorageBackend:
     + Batch<MilestoneIndex, OutputDiff>
     + for<'a> AsIterator<'a, MilestoneIndex, OutputDiff>
     + Truncate<MilestoneIndex, OutputDiff>
+    + FetchRange<MilestoneIndex, OutputDiff>
 {
 }
 
@@ -35,6 +40,7 @@ impl<T> StorageBackend for T where
         + Batch<MilestoneIndex, OutputDiff>
         + for<'a> AsIterator<'a, MilestoneIndex, OutputDiff>
         + Truncate<MilestoneIndex, OutputDiff>
+        + FetchRange<MilestoneIndex, OutputDiff>
 {
 }
 
@@ -102,7 +108,7 @@ pub fn milestone_index_to_output_diff_access<B: StorageBackend>(storage: &B) {
         output_diffs.push((index, Some(output_diff)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<MilestoneIndex, OutputDiff>::iter(storage).unwrap();
     let mut count = 0;
@@ -115,6 +121,23 @@ pub fn milestone_index_to_output_diff_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, 10);
 
+    let mut inserted = output_diffs
+        .iter()
+        .filter_map(|(index, output_diff)| output_diff.clone().map(|output_diff| (*index, output_diff)))
+        .collect::<Vec<_>>();
+    inserted.sort_unstable_by_key(|(index, _)| *index);
+    let (start, end) = (inserted[2].0, inserted[7].0);
+
+    assert_eq!(
+        FetchRange::<MilestoneIndex, OutputDiff>::fetch_range(storage, &start, &end).unwrap(),
+        inserted[2..=7]
+    );
+    assert!(
+        FetchRange::<MilestoneIndex, OutputDiff>::fetch_range(storage, &end, &start)
+            .unwrap()
+            .is_empty()
+    );
+
     let results = MultiFetch::<MilestoneIndex, OutputDiff>::multi_fetch(storage, &indexes)
         .unwrap()
         .collect::<Vec<_>>();
@@ -125,9 +148,5 @@ pub fn milestone_index_to_output_diff_access<B: StorageBackend>(storage: &B) {
         assert_eq!(diff, result.unwrap());
     }
 
-    Truncate::<MilestoneIndex, OutputDiff>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<MilestoneIndex, OutputDiff>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<MilestoneIndex, OutputDiff, _>(storage);
 }
@@ -6,11 +6,13 @@ use std::collections::HashMap;
 use bee_ledger::types::Receipt;
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_test::rand::{milestone::rand_milestone_index, receipt::rand_ledger_receipt};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(MilestoneIndex, Receipt), ()>
@@ -88,7 +90,7 @@ pub fn milestone_index_to_receipt_access<B: StorageBackend>(storage: &B) {
         }
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<(MilestoneIndex, Receipt), ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -101,9 +103,5 @@ pub fn milestone_index_to_receipt_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, receipts.iter().fold(0, |acc, v| acc + v.1.len()));
 
-    Truncate::<(MilestoneIndex, Receipt), ()>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<(MilestoneIndex, Receipt), ()>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<(MilestoneIndex, Receipt), (), _>(storage);
 }
@@ -5,12 +5,14 @@ use std::collections::HashMap;
 
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_tangle::unreferenced_message::UnreferencedMessage;
 use bee_test::rand::{milestone::rand_milestone_index, unreferenced_message::rand_unreferenced_message};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(MilestoneIndex, UnreferencedMessage), ()>
@@ -108,7 +110,7 @@ pub fn milestone_index_to_unreferenced_message_access<B: StorageBackend>(storage
         }
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<(MilestoneIndex, UnreferencedMessage), ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -121,9 +123,5 @@ pub fn milestone_index_to_unreferenced_message_access<B: StorageBackend>(storage
 
     assert_eq!(count, unreferenced_messages.iter().fold(0, |acc, v| acc + v.1.len()));
 
-    Truncate::<(MilestoneIndex, UnreferencedMessage), ()>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<(MilestoneIndex, UnreferencedMessage), ()>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<(MilestoneIndex, UnreferencedMessage), (), _>(storage);
 }
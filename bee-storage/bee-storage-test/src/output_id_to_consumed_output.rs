@@ -4,11 +4,13 @@
 use bee_ledger::types::ConsumedOutput;
 use bee_message::output::OutputId;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, MultiFetch, Truncate},
     backend,
 };
 use bee_test::rand::output::{rand_consumed_output, rand_output_id};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<OutputId, ConsumedOutput>
@@ -100,7 +102,7 @@ pub fn output_id_to_consumed_output_access<B: StorageBackend>(storage: &B) {
         consumed_outputs.push((output_id, Some(consumed_output)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<OutputId, ConsumedOutput>::iter(storage).unwrap();
     let mut count = 0;
@@ -123,9 +125,5 @@ pub fn output_id_to_consumed_output_access<B: StorageBackend>(storage: &B) {
         assert_eq!(consumed_output, result.unwrap());
     }
 
-    Truncate::<OutputId, ConsumedOutput>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<OutputId, ConsumedOutput>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<OutputId, ConsumedOutput, _>(storage);
 }
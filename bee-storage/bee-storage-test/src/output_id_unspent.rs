@@ -3,11 +3,13 @@
 
 use bee_ledger::types::Unspent;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Insert, Truncate},
     backend,
 };
 use bee_test::rand::output::rand_unspent_output_id;
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<Unspent, ()>
@@ -61,7 +63,7 @@ pub fn output_id_unspent_access<B: StorageBackend>(storage: &B) {
         unspents.push(unspent);
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<Unspent, ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -74,9 +76,5 @@ pub fn output_id_unspent_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, unspents.len());
 
-    Truncate::<Unspent, ()>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<Unspent, ()>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<Unspent, (), _>(storage);
 }
@@ -0,0 +1,40 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+
+use bee_storage::access::{AsIterator, Truncate};
+
+/// Checks that the keys-only and values-only iterators of the (K, V) pair are the projections of its iterator, then
+/// truncates it and checks that it is empty.
+pub(crate) fn check_projections<K, V, B>(storage: &B)
+where
+    K: Debug + PartialEq,
+    V: Debug + PartialEq,
+    B: for<'a> AsIterator<'a, K, V> + Truncate<K, V>,
+{
+    let (keys, values): (Vec<_>, Vec<_>) = AsIterator::<K, V>::iter(storage)
+        .unwrap()
+        .map(Result::unwrap)
+        .unzip();
+    assert_eq!(
+        AsIterator::<K, V>::iter_keys(storage)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>(),
+        keys
+    );
+    assert_eq!(
+        AsIterator::<K, V>::iter_values(storage)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>(),
+        values
+    );
+
+    assert_eq!(Truncate::<K, V>::truncate(storage).unwrap(), keys.len());
+
+    let mut iter = AsIterator::<K, V>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
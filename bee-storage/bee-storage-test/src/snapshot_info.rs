@@ -3,11 +3,13 @@
 
 use bee_ledger::types::snapshot::SnapshotInfo;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_test::rand::snapshot::rand_snapshot_info;
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(), SnapshotInfo>
@@ -57,7 +59,7 @@ pub fn snapshot_info_access<B: StorageBackend>(storage: &B) {
 
     Batch::<(), SnapshotInfo>::batch_insert(storage, &mut batch, &(), &snapshot_info).unwrap();
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     assert!(Exist::<(), SnapshotInfo>::exist(storage, &()).unwrap());
     assert_eq!(
@@ -69,7 +71,7 @@ pub fn snapshot_info_access<B: StorageBackend>(storage: &B) {
 
     Batch::<(), SnapshotInfo>::batch_delete(storage, &mut batch, &()).unwrap();
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     assert!(!Exist::<(), SnapshotInfo>::exist(storage, &()).unwrap());
     assert!(Fetch::<(), SnapshotInfo>::fetch(storage, &()).unwrap().is_none());
@@ -87,11 +89,7 @@ pub fn snapshot_info_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, 1);
 
-    Truncate::<(), SnapshotInfo>::truncate(storage).unwrap();
+    check_projections::<(), SnapshotInfo, _>(storage);
 
     assert!(!Exist::<(), SnapshotInfo>::exist(storage, &()).unwrap());
-
-    let mut iter = AsIterator::<(), SnapshotInfo>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
 }
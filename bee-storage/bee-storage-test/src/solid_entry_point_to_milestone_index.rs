@@ -3,12 +3,14 @@
 
 use bee_message::milestone::MilestoneIndex;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, MultiFetch, Truncate},
     backend,
 };
 use bee_tangle::solid_entry_point::SolidEntryPoint;
 use bee_test::rand::{milestone::rand_milestone_index, solid_entry_point::rand_solid_entry_point};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<SolidEntryPoint, MilestoneIndex>
@@ -100,7 +102,7 @@ pub fn solid_entry_point_to_milestone_index_access<B: StorageBackend>(storage: &
         seps.push((sep, Some(index)));
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
 
     let iter = AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(storage).unwrap();
     let mut count = 0;
@@ -123,9 +125,5 @@ pub fn solid_entry_point_to_milestone_index_access<B: StorageBackend>(storage: &
         assert_eq!(index, result.unwrap());
     }
 
-    Truncate::<SolidEntryPoint, MilestoneIndex>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<SolidEntryPoint, MilestoneIndex, _>(storage);
 }
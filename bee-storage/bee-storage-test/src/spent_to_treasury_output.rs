@@ -5,11 +5,13 @@ use std::collections::HashMap;
 
 use bee_ledger::types::TreasuryOutput;
 use bee_storage::{
-    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    access::{AsIterator, Batch, BatchBuilder, Delete, Durability, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_test::rand::{bool::rand_bool, output::rand_ledger_treasury_output};
 
+use crate::projections::check_projections;
+
 pub trait StorageBackend:
     backend::StorageBackend
     + Exist<(bool, TreasuryOutput), ()>
@@ -93,7 +95,18 @@ pub fn spent_to_treasury_output_access<B: StorageBackend>(storage: &B) {
         treasury_outputs.entry(spent).or_default().push(treasury_output);
     }
 
-    storage.batch_commit(batch, true).unwrap();
+    storage.batch_commit_with(batch, Durability::Sync).unwrap();
+
+    // Fetching by the `spent` flag only returns the outputs with that flag.
+    for spent in [false, true] {
+        let fetched = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &spent)
+            .unwrap()
+            .unwrap();
+        let expected = treasury_outputs.get(&spent).unwrap();
+
+        assert_eq!(fetched.len(), expected.len());
+        assert!(fetched.iter().all(|treasury_output| expected.contains(treasury_output)));
+    }
 
     let iter = AsIterator::<(bool, TreasuryOutput), ()>::iter(storage).unwrap();
     let mut count = 0;
@@ -106,9 +119,5 @@ pub fn spent_to_treasury_output_access<B: StorageBackend>(storage: &B) {
 
     assert_eq!(count, treasury_outputs.iter().fold(0, |acc, v| acc + v.1.len()));
 
-    Truncate::<(bool, TreasuryOutput), ()>::truncate(storage).unwrap();
-
-    let mut iter = AsIterator::<(bool, TreasuryOutput), ()>::iter(storage).unwrap();
-
-    assert!(iter.next().is_none());
+    check_projections::<(bool, TreasuryOutput), (), _>(storage);
 }
@@ -3,6 +3,17 @@
 
 use crate::backend::StorageBackend;
 
+/// Determines how much effort a backend should put into persisting a committed batch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Durability {
+    /// The batch is not explicitly persisted and may be lost if the node crashes.
+    None,
+    /// The batch is persisted asynchronously, the commit returns before the data reached the disk.
+    Async,
+    /// The batch is persisted synchronously, the commit returns once the data reached the disk.
+    Sync,
+}
+
 /// `BatchBuilder` trait extends the `StorageBackend` with batch builder functionality; therefore it should be
 /// explicitly implemented for the corresponding `StorageBackend`.
 pub trait BatchBuilder: StorageBackend {
@@ -15,8 +26,15 @@ pub trait BatchBuilder: StorageBackend {
     }
 
     /// Takes ownership of a batch object in order to commit it to the backend.
-    /// Durability argument determines if the batch needs to be logged into a write ahead log or not.
-    fn batch_commit(&self, batch: Self::Batch, durability: bool) -> Result<(), Self::Error>;
+    /// Durability argument determines how the batch is persisted, see `Durability`.
+    fn batch_commit_with(&self, batch: Self::Batch, durability: Durability) -> Result<(), Self::Error>;
+
+    /// Takes ownership of a batch object in order to commit it to the backend.
+    /// Durability argument determines if the batch needs to be persisted synchronously or not.
+    #[deprecated(note = "use `batch_commit_with` with a `Durability`")]
+    fn batch_commit(&self, batch: Self::Batch, durability: bool) -> Result<(), Self::Error> {
+        self.batch_commit_with(batch, if durability { Durability::Sync } else { Durability::None })
+    }
 }
 
 /// `Batch<K, V>` trait extends the `StorageBackend` with batch operations for the (key: K, value: V) pair;
@@ -26,4 +44,30 @@ pub trait Batch<K, V>: BatchBuilder {
     fn batch_insert(&self, batch: &mut Self::Batch, key: &K, value: &V) -> Result<(), Self::Error>;
     /// Add Delete batch operation for the provided key value pair into the Batch memory buffer.
     fn batch_delete(&self, batch: &mut Self::Batch, key: &K) -> Result<(), Self::Error>;
+
+    /// Add Insert batch operations for all the provided key value pairs into the Batch memory buffer.
+    fn batch_insert_many<I: IntoIterator<Item = (K, V)>>(
+        &self,
+        batch: &mut Self::Batch,
+        pairs: I,
+    ) -> Result<(), Self::Error> {
+        for (key, value) in pairs {
+            self.batch_insert(batch, &key, &value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Add Delete batch operations for all the provided keys into the Batch memory buffer.
+    fn batch_delete_many<I: IntoIterator<Item = K>>(
+        &self,
+        batch: &mut Self::Batch,
+        keys: I,
+    ) -> Result<(), Self::Error> {
+        for key in keys {
+            self.batch_delete(batch, &key)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,14 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::backend::StorageBackend;
+
+/// `CompareAndSwap<K, V>` trait extends the `StorageBackend` with `compare_and_swap` operation for the
+/// (key: K, value: V) pair; therefore, it should be explicitly implemented for the corresponding `StorageBackend`.
+pub trait CompareAndSwap<K, V>: StorageBackend {
+    /// Atomically replaces the value associated with the key by `new` if it currently is `old`, and returns whether
+    /// the value was replaced.
+    ///
+    /// A `None` for `old` expects the key to be absent and a `None` for `new` deletes it.
+    fn compare_and_swap(&self, key: &K, old: Option<&V>, new: Option<&V>) -> Result<bool, Self::Error>;
+}
@@ -0,0 +1,24 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::access::{Fetch, InsertStrict};
+
+/// `FetchOrInsert<K, V>` trait extends the `StorageBackend` with `fetch_or_insert_with` operation for the
+/// (key: K, value: V) pair; therefore, it should be explicitly implemented for the corresponding `StorageBackend`.
+///
+/// The default implementation relies on `Fetch` and `InsertStrict` and is therefore not atomic, backends that can
+/// provide an atomic version should override it.
+pub trait FetchOrInsert<K, V>: Fetch<K, V> + InsertStrict<K, V> {
+    /// Fetches the value associated with the key from the storage or, if there is none, inserts the value returned by
+    /// `f` and returns it.
+    fn fetch_or_insert_with<F: FnOnce() -> V>(&self, key: &K, f: F) -> Result<V, Self::Error> {
+        if let Some(value) = self.fetch(key)? {
+            return Ok(value);
+        }
+
+        let value = f();
+        self.insert_strict(key, &value)?;
+
+        Ok(value)
+    }
+}
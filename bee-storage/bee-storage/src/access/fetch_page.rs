@@ -0,0 +1,13 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::backend::StorageBackend;
+
+/// `FetchPage<K, V>` trait extends the `StorageBackend` with `fetch_page` operation for the (key: K, values: Vec<V>)
+/// pair; therefore, it should be explicitly implemented for the corresponding `StorageBackend`.
+pub trait FetchPage<K, V>: StorageBackend {
+    /// Fetches, in ascending order, at most `limit` values associated with the key from the storage, starting right
+    /// after the `cursor` value or from the first one if there is no cursor.
+    /// Also returns the cursor to fetch the next page from, `None` if this page is the last one.
+    fn fetch_page(&self, key: &K, cursor: Option<&V>, limit: usize) -> Result<(Vec<V>, Option<V>), Self::Error>;
+}
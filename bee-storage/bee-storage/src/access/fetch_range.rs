@@ -0,0 +1,12 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::backend::StorageBackend;
+
+/// `FetchRange<K, V>` trait extends the `StorageBackend` with `fetch_range` operation for the (key: K, value: V) pair;
+/// therefore, it should be explicitly implemented for the corresponding `StorageBackend`.
+pub trait FetchRange<K, V>: StorageBackend {
+    /// Fetches the key-value pairs whose key is between `start` and `end`, both included, in ascending key order.
+    /// The result is empty if `start` is greater than `end`.
+    fn fetch_range(&self, start: &K, end: &K) -> Result<Vec<(K, V)>, Self::Error>;
+}
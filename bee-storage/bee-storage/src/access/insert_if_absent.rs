@@ -0,0 +1,23 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::access::{Exist, Insert};
+
+/// `InsertIfAbsent<K, V>` trait extends the `StorageBackend` with `insert_if_absent` operation for the
+/// (key: K, value: V) pair; therefore, it should be explicitly implemented for the corresponding `StorageBackend`.
+///
+/// The default implementation relies on `Exist` and `Insert` and is therefore not atomic, backends that can provide an
+/// atomic version should override it.
+pub trait InsertIfAbsent<K, V>: Exist<K, V> + Insert<K, V> {
+    /// Inserts the (K, V) pair in the storage unless the key is already present, leaving its value untouched.
+    /// Returns whether the pair was inserted.
+    fn insert_if_absent(&self, key: &K, value: &V) -> Result<bool, Self::Error> {
+        if self.exist(key)? {
+            return Ok(false);
+        }
+
+        self.insert(key, value)?;
+
+        Ok(true)
+    }
+}
@@ -8,7 +8,61 @@ use crate::backend::StorageBackend;
 pub trait AsIterator<'a, K, V>: StorageBackend {
     /// Type to iterate through the <K, V> collection.
     type AsIter: Iterator<Item = Result<(K, V), Self::Error>>;
+    /// Type to iterate through the keys of the <K, V> collection.
+    type AsKeysIter: Iterator<Item = Result<K, Self::Error>>;
+    /// Type to iterate through the values of the <K, V> collection.
+    type AsValuesIter: Iterator<Item = Result<V, Self::Error>>;
 
     /// Returns a `Iterator` object for the provided <K, V> collection.
     fn iter(&'a self) -> Result<Self::AsIter, Self::Error>;
+
+    /// Returns a `Iterator` object over the keys of the provided <K, V> collection.
+    /// Backends should avoid decoding the values when possible.
+    fn iter_keys(&'a self) -> Result<Self::AsKeysIter, Self::Error>;
+
+    /// Returns a `Iterator` object over the values of the provided <K, V> collection.
+    /// Backends should avoid decoding the keys when possible.
+    fn iter_values(&'a self) -> Result<Self::AsValuesIter, Self::Error>;
+}
+
+/// An iterator adapter projecting the (key, value) pairs of another iterator onto their keys.
+pub struct Keys<I>(I);
+
+impl<I> Keys<I> {
+    /// Creates a new `Keys` adapter over an iterator of (key, value) pairs.
+    pub fn new(iter: I) -> Self {
+        Self(iter)
+    }
+}
+
+impl<I, K, V, E> Iterator for Keys<I>
+where
+    I: Iterator<Item = Result<(K, V), E>>,
+{
+    type Item = Result<K, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|result| result.map(|(key, _)| key))
+    }
+}
+
+/// An iterator adapter projecting the (key, value) pairs of another iterator onto their values.
+pub struct Values<I>(I);
+
+impl<I> Values<I> {
+    /// Creates a new `Values` adapter over an iterator of (key, value) pairs.
+    pub fn new(iter: I) -> Self {
+        Self(iter)
+    }
+}
+
+impl<I, K, V, E> Iterator for Values<I>
+where
+    I: Iterator<Item = Result<(K, V), E>>,
+{
+    type Item = Result<V, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|result| result.map(|(_, value)| value))
+    }
 }
@@ -6,14 +6,24 @@
 
 /// Holds the contract for batch access operation.
 mod batch;
+/// Holds the contract for compare and swap access operation.
+mod compare_and_swap;
 /// Holds the contract for delete access operation.
 mod delete;
 /// Holds the contract for exist access operation.
 mod exist;
 /// Holds the contract for fetch access operation.
 mod fetch;
+/// Holds the contract for fetch or insert access operation.
+mod fetch_or_insert;
+/// Holds the contract for fetch page access operation.
+mod fetch_page;
+/// Holds the contract for fetch range access operation.
+mod fetch_range;
 /// Holds the contract for insert access operation.
 mod insert;
+/// Holds the contract for insert if absent access operation.
+mod insert_if_absent;
 /// Holds the contract for iter access operations.
 mod iter;
 /// Holds the contract for multiple fetch access operation.
@@ -24,12 +34,17 @@ mod truncate;
 mod update;
 
 pub use self::{
-    batch::{Batch, BatchBuilder},
+    batch::{Batch, BatchBuilder, Durability},
+    compare_and_swap::CompareAndSwap,
     delete::Delete,
     exist::Exist,
     fetch::Fetch,
+    fetch_or_insert::FetchOrInsert,
+    fetch_page::FetchPage,
+    fetch_range::FetchRange,
     insert::{Insert, InsertStrict},
-    iter::AsIterator,
+    insert_if_absent::InsertIfAbsent,
+    iter::{AsIterator, Keys, Values},
     multi_fetch::MultiFetch,
     truncate::Truncate,
     update::Update,
@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{collections::HashMap, hash::Hash};
+
 use crate::backend::StorageBackend;
 
 /// `MultiFetch<'a, K, V>` trait extends the `StorageBackend` with `multi_fetch` operation for the (key: K, value: V)
@@ -11,4 +13,15 @@ pub trait MultiFetch<'a, K, V>: StorageBackend {
 
     /// Fetches the values associated with the keys from the storage.
     fn multi_fetch(&'a self, keys: &'a [K]) -> Result<Self::Iter, Self::Error>;
+
+    /// Fetches the values associated with the keys from the storage into a map, leaving the absent ones out.
+    fn multi_fetch_map(&'a self, keys: &'a [K]) -> Result<HashMap<K, V>, Self::Error>
+    where
+        K: Hash + Eq + Clone,
+    {
+        keys.iter()
+            .zip(self.multi_fetch(keys)?)
+            .filter_map(|(key, result)| result.map(|value| value.map(|value| (key.clone(), value))).transpose())
+            .collect()
+    }
 }
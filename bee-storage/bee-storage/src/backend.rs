@@ -28,6 +28,12 @@ pub trait StorageBackend: Send + Sized + Sync + 'static {
     /// Not all backends may be able to provide this operation.
     fn size(&self) -> Result<Option<usize>, Self::Error>;
 
+    /// Returns the size of each tree of the database in bytes, by tree name.
+    /// Not all backends may be able to provide this operation.
+    fn tree_sizes(&self) -> Result<Option<Vec<(String, usize)>>, Self::Error> {
+        Ok(None)
+    }
+
     /// Returns the health status of the database.
     /// Not all backends may be able to provide this operation.
     fn get_health(&self) -> Result<Option<StorageHealth>, Self::Error>;
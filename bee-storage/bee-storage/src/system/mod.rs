@@ -17,6 +17,8 @@ pub use self::{
 pub const SYSTEM_VERSION_KEY: u8 = 0;
 /// Key used to store the system health.
 pub const SYSTEM_HEALTH_KEY: u8 = 1;
+/// Key used to store whether the values are stored compressed.
+pub const SYSTEM_COMPRESSION_KEY: u8 = 2;
 
 /// Errors to be raised if packing/unpacking `System` fails.
 #[derive(Debug, thiserror::Error)]
@@ -39,6 +41,8 @@ pub enum System {
     Version(StorageVersion),
     /// The health status of the storage.
     Health(StorageHealth),
+    /// Whether the values are stored compressed.
+    Compression(bool),
 }
 
 impl Packable for System {
@@ -48,6 +52,7 @@ impl Packable for System {
         match self {
             System::Version(version) => SYSTEM_VERSION_KEY.packed_len() + version.packed_len(),
             System::Health(health) => SYSTEM_HEALTH_KEY.packed_len() + health.packed_len(),
+            System::Compression(compression) => SYSTEM_COMPRESSION_KEY.packed_len() + compression.packed_len(),
         }
     }
 
@@ -61,6 +66,10 @@ impl Packable for System {
                 SYSTEM_HEALTH_KEY.pack(writer)?;
                 health.pack(writer)?;
             }
+            System::Compression(compression) => {
+                SYSTEM_COMPRESSION_KEY.pack(writer)?;
+                compression.pack(writer)?;
+            }
         }
 
         Ok(())
@@ -70,6 +79,7 @@ impl Packable for System {
         match u8::unpack_inner::<R, CHECK>(reader)? {
             SYSTEM_VERSION_KEY => Ok(System::Version(StorageVersion::unpack_inner::<R, CHECK>(reader)?)),
             SYSTEM_HEALTH_KEY => Ok(System::Health(StorageHealth::unpack_inner::<R, CHECK>(reader)?)),
+            SYSTEM_COMPRESSION_KEY => Ok(System::Compression(bool::unpack_inner::<R, CHECK>(reader)?)),
             s => Err(Error::UnknownSystemKey(s)),
         }
     }
@@ -4,7 +4,7 @@
 use bee_common::packable::{Packable, Read, Write};
 
 /// Version of the storage.
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct StorageVersion(pub u64);
 
 impl Packable for StorageVersion {